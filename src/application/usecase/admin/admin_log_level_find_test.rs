@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::admin::admin_log_level_find::AdminLogLevelFindUsecase;
+    use crate::application::usecase::admin::admin_log_level_find::AdminLogLevelFindUsecaseTrait;
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger
+            .expect_get_filters()
+            .returning(|| "info,rust_axum_domain::infrastructure=debug".to_string());
+
+        // ユースケースのインスタンス化
+        let admin_log_level_find_usecase = AdminLogLevelFindUsecase {
+            logger: Arc::new(mock_logger),
+        };
+
+        // 共通コンテキスト設定
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        let ctx = ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/admin/log-level".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles: vec!["admin".to_string()],
+            }),
+        };
+
+        // テスト実行
+        let res = admin_log_level_find_usecase.exec(ctx).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(
+            res_data["filters"],
+            "info,rust_axum_domain::infrastructure=debug"
+        );
+    }
+}
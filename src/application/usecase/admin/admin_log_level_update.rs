@@ -0,0 +1,111 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize, Debug)]
+pub struct AdminLogLevelUpdateInput {
+    // 例）"info,rust_axum_domain::infrastructure=debug"
+    pub filters: String,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AdminLogLevelUpdateUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: AdminLogLevelUpdateInput) -> Response;
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct AdminLogLevelUpdateUsecase {
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AdminLogLevelUpdateUsecase {
+    pub fn new(logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        AdminLogLevelUpdateUsecase { logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl AdminLogLevelUpdateUsecaseTrait for AdminLogLevelUpdateUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: AdminLogLevelUpdateInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("AdminLogLevelUpdateUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // ログフィルター設定の変更
+        if let Err(msg) = self.logger.set_filters(&input.filters) {
+            let err = ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_REQUEST,
+                message: format!("ログフィルターの設定が不正です: {}", msg),
+            };
+
+            // エラーログ出力
+            let err_msg = format!("AdminLogLevelUpdateUsecaseでエラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // ステータスコードの設定
+            let status_code = err.status_code();
+
+            // レスポンス結果の設定
+            let res = (status_code, res_header, json_msg).into_response();
+
+            // 戻り値としてレスポンス結果を返す
+            return res;
+        }
+
+        // 変更内容のログ出力
+        let msg = format!("ログフィルターを変更しました: {}", input.filters);
+        self.logger.info(&ctx, &msg);
+
+        // レスポンスボディの設定
+        let res_body = Json(json!({ "filters": input.filters }));
+
+        // レスポンス結果を設定して戻り値として返す
+        (StatusCode::OK, res_header, res_body).into_response()
+    }
+}
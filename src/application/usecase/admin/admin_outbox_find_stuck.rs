@@ -0,0 +1,96 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::outbox::outbox_repository::OutboxRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AdminOutboxFindStuckUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct AdminOutboxFindStuckRepository {
+    pub outbox_repository: Arc<dyn OutboxRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct AdminOutboxFindStuckUsecase {
+    pub repo: AdminOutboxFindStuckRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AdminOutboxFindStuckUsecase {
+    pub fn new(
+        repo: AdminOutboxFindStuckRepository,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        AdminOutboxFindStuckUsecase { repo, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl AdminOutboxFindStuckUsecaseTrait for AdminOutboxFindStuckUsecase {
+    async fn exec(&self, ctx: ContextRequest) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("AdminOutboxFindStuckUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 滞留しているメッセージ取得処理
+        let messages = match self.repo.outbox_repository.find_stuck(&ctx).await {
+            Ok(messages) => messages,
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("AdminOutboxFindStuckUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // レスポンス結果を設定して戻り値として返す
+        (StatusCode::OK, res_header, Json(json!(messages))).into_response()
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::outbox::outbox_repository::MockOutboxRepositoryTrait;
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::admin::admin_outbox_find_stuck::AdminOutboxFindStuckRepository;
+    use crate::application::usecase::admin::admin_outbox_find_stuck::AdminOutboxFindStuckUsecase;
+    use crate::application::usecase::admin::admin_outbox_find_stuck::AdminOutboxFindStuckUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/admin/outbox/stuck".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // リポジトリのモック化（送信を中止したメッセージ）
+        let mut mock_repo = MockOutboxRepositoryTrait::new();
+        mock_repo.expect_find_stuck().returning(|_| {
+            let mut user = User::new(
+                "xxxx-xxxx-xxxx-0003".to_string(),
+                "鈴木".to_string(),
+                "三郎".to_string(),
+                "s.suzuki@example.com".to_string(),
+            );
+            let mut message = OutboxMessage::from_events(&user.take_events()).remove(0);
+            message.id = 1;
+            let policy = RetryPolicy {
+                max_attempts: 1,
+                backoff_base_secs: 1,
+                backoff_max_secs: 1,
+            };
+            message.mark_failed("connection refused", &policy, jst_now());
+            Ok(vec![message])
+        });
+
+        // ユースケースのインスタンス化
+        let admin_outbox_find_stuck_usecase = AdminOutboxFindStuckUsecase {
+            repo: AdminOutboxFindStuckRepository {
+                outbox_repository: Arc::new(mock_repo),
+            },
+            logger: Arc::new(MockLoggerTrait::new()),
+        };
+
+        // テスト実行
+        let res = admin_outbox_find_stuck_usecase
+            .exec(create_ctx(vec!["admin".to_string()]))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data[0]["id"], 1);
+        assert_eq!(res_data[0]["event_type"], "user.created");
+        assert_eq!(res_data[0]["status"], "failed");
+        assert_eq!(res_data[0]["attempts"], 1);
+        assert_eq!(res_data[0]["last_error"], "connection refused");
+        assert_eq!(res_data[0]["payload"]["type"], "UserCreated");
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().return_const(());
+
+        // リポジトリのモック化（参照されないこと）
+        let mut mock_repo = MockOutboxRepositoryTrait::new();
+        mock_repo.expect_find_stuck().never();
+
+        // ユースケースのインスタンス化
+        let admin_outbox_find_stuck_usecase = AdminOutboxFindStuckUsecase {
+            repo: AdminOutboxFindStuckRepository {
+                outbox_repository: Arc::new(mock_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let res = admin_outbox_find_stuck_usecase
+            .exec(create_ctx(vec!["operator".to_string()]))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod admin_log_level_find;
+pub mod admin_log_level_update;
+pub mod admin_outbox_find_stuck;
+
+// テストコード用のモジュール
+pub mod admin_log_level_find_test;
+pub mod admin_log_level_update_test;
+pub mod admin_outbox_find_stuck_test;
@@ -0,0 +1,103 @@
+use axum::http::StatusCode;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+// ドメイン
+use crate::domain::api_key::{api_key_model::ApiKey, api_key_repository::ApiKeyRepositoryTrait};
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::time::time_jst::jst_now;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+// ※認証ミドルウェアから利用するため、レスポンスではなく認証情報を返す
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ApiKeyAuthenticateUsecaseTrait {
+    async fn exec(&self, ctx: &ContextRequest, plain_key: &str)
+    -> Result<AuthContext, ErrorCommon>;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct ApiKeyAuthenticateRepository {
+    pub api_key_repository: Arc<dyn ApiKeyRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct ApiKeyAuthenticateUsecase {
+    pub repo: ApiKeyAuthenticateRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl ApiKeyAuthenticateUsecase {
+    pub fn new(repo: ApiKeyAuthenticateRepository, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        ApiKeyAuthenticateUsecase { repo, logger }
+    }
+
+    // 401エラーの作成
+    fn unauthorized() -> ErrorCommon {
+        ErrorCommon::CustomError {
+            status_code: StatusCode::UNAUTHORIZED,
+            message: "APIキーが無効です。".to_string(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyAuthenticateUsecaseTrait for ApiKeyAuthenticateUsecase {
+    async fn exec(
+        &self,
+        ctx: &ContextRequest,
+        plain_key: &str,
+    ) -> Result<AuthContext, ErrorCommon> {
+        // key_idの取得
+        let key_id = match ApiKey::parse_key_id(plain_key) {
+            Some(key_id) => key_id,
+            None => return Err(ApiKeyAuthenticateUsecase::unauthorized()),
+        };
+
+        // APIキーの取得と検証
+        let api_key = match self
+            .repo
+            .api_key_repository
+            .find_by_key_id(ctx, key_id)
+            .await?
+        {
+            Some(api_key) if api_key.verify(plain_key) => api_key,
+            _ => return Err(ApiKeyAuthenticateUsecase::unauthorized()),
+        };
+
+        // 失効・有効期限切れのチェック
+        let now = chrono::Utc::now().fixed_offset();
+        if !api_key.is_active(now) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::UNAUTHORIZED,
+                message: "APIキーが失効または有効期限切れです。".to_string(),
+            });
+        }
+
+        // 最終利用日時の更新（失敗しても認証は継続）
+        if let Err(err) = self
+            .repo
+            .api_key_repository
+            .touch_last_used(ctx, &api_key.key_id, jst_now())
+            .await
+        {
+            let msg = format!("APIキーの最終利用日時の更新に失敗しました: {}", err);
+            self.logger.warn(ctx, &msg);
+        }
+
+        Ok(AuthContext {
+            subject: format!("api_key:{}", api_key.key_id),
+            scopes: api_key.scopes,
+            roles: vec![],
+        })
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::api_key::api_key_model::ApiKey;
+    use crate::domain::api_key::api_key_repository::MockApiKeyRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::api_key::api_key_authenticate::ApiKeyAuthenticateRepository;
+    use crate::application::usecase::api_key::api_key_authenticate::ApiKeyAuthenticateUsecase;
+    use crate::application::usecase::api_key::api_key_authenticate::ApiKeyAuthenticateUsecaseTrait;
+
+    // 共通コンテキスト設定
+    fn new_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/users".to_string(),
+            auth: None,
+        }
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase(api_key: ApiKey) -> ApiKeyAuthenticateUsecase {
+        // リポジトリのモック化（最終利用日時のみ更新し、APIキー全体は更新しないこと）
+        let key_id = api_key.key_id.clone();
+        let mut mock_repo = MockApiKeyRepositoryTrait::new();
+        mock_repo
+            .expect_find_by_key_id()
+            .returning(move |_, _| Ok(Some(api_key.clone())));
+        mock_repo
+            .expect_touch_last_used()
+            .withf(move |_, k, _| k == key_id)
+            .returning(|_, _, _| Ok(()));
+        mock_repo.expect_update().never();
+
+        let repo = ApiKeyAuthenticateRepository {
+            api_key_repository: Arc::new(mock_repo),
+        };
+
+        ApiKeyAuthenticateUsecase {
+            repo,
+            logger: Arc::new(MockLoggerTrait::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let (api_key, plain_key) =
+            ApiKey::issue("batch".to_string(), vec!["users:read".to_string()], None).unwrap();
+        let key_id = api_key.key_id.clone();
+        let usecase = new_usecase(api_key);
+
+        // テスト実行
+        let auth = usecase.exec(&new_ctx(), &plain_key).await.unwrap();
+
+        // 検証
+        assert_eq!(auth.subject, format!("api_key:{}", key_id));
+        assert_eq!(auth.scopes, vec!["users:read".to_string()]);
+        assert!(auth.roles.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_exec_wrong_secret() {
+        let (api_key, _) =
+            ApiKey::issue("batch".to_string(), vec!["users:read".to_string()], None).unwrap();
+        let wrong_key = format!("ak_{}_wrong", api_key.key_id);
+        let usecase = new_usecase(api_key);
+
+        // テスト実行
+        let err = usecase.exec(&new_ctx(), &wrong_key).await.unwrap_err();
+
+        // 検証
+        assert_eq!(err.status_code(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_revoked() {
+        let (mut api_key, plain_key) =
+            ApiKey::issue("batch".to_string(), vec!["users:read".to_string()], None).unwrap();
+        api_key.revoke();
+        let usecase = new_usecase(api_key);
+
+        // テスト実行
+        let err = usecase.exec(&new_ctx(), &plain_key).await.unwrap_err();
+
+        // 検証
+        assert_eq!(err.status_code(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_invalid_format() {
+        let (api_key, _) =
+            ApiKey::issue("batch".to_string(), vec!["users:read".to_string()], None).unwrap();
+        let usecase = new_usecase(api_key);
+
+        // テスト実行
+        let err = usecase.exec(&new_ctx(), "invalid").await.unwrap_err();
+
+        // 検証
+        assert_eq!(err.status_code(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,93 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::api_key::api_key_repository::ApiKeyRepositoryTrait;
+use crate::domain::auth::auth_policy::Permission;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ApiKeyFindAllUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct ApiKeyFindAllRepository {
+    pub api_key_repository: Arc<dyn ApiKeyRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct ApiKeyFindAllUsecase {
+    pub repo: ApiKeyFindAllRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl ApiKeyFindAllUsecase {
+    pub fn new(repo: ApiKeyFindAllRepository, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        ApiKeyFindAllUsecase { repo, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyFindAllUsecaseTrait for ApiKeyFindAllUsecase {
+    async fn exec(&self, ctx: ContextRequest) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("ApiKeyFindAllUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 全てのAPIキー取得処理
+        let api_keys = match self.repo.api_key_repository.find_all(&ctx).await {
+            Ok(api_keys) => api_keys,
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("ApiKeyFindAllUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // レスポンス結果を設定して戻り値として返す（ハッシュ値はシリアライズ対象外）
+        (StatusCode::OK, res_header, Json(json!(api_keys))).into_response()
+    }
+}
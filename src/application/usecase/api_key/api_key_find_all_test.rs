@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::api_key::api_key_model::ApiKey;
+    use crate::domain::api_key::api_key_repository::MockApiKeyRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::api_key::api_key_find_all::ApiKeyFindAllRepository;
+    use crate::application::usecase::api_key::api_key_find_all::ApiKeyFindAllUsecase;
+    use crate::application::usecase::api_key::api_key_find_all::ApiKeyFindAllUsecaseTrait;
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // リポジトリのモック化
+        let mut mock_repo = MockApiKeyRepositoryTrait::new();
+        mock_repo.expect_find_all().returning(|_| {
+            let (mut api_key, _) =
+                ApiKey::issue("batch".to_string(), vec!["users:read".to_string()], None).unwrap();
+            api_key.id = 1;
+            Ok(vec![api_key])
+        });
+
+        // ユースケースのインスタンス化
+        let repo = ApiKeyFindAllRepository {
+            api_key_repository: Arc::new(mock_repo),
+        };
+        let api_key_find_all_usecase = ApiKeyFindAllUsecase {
+            repo,
+            logger: Arc::new(MockLoggerTrait::new()),
+        };
+
+        // 共通コンテキスト設定
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        let ctx = ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/admin/api-keys".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles: vec!["admin".to_string()],
+            }),
+        };
+
+        // テスト実行
+        let res = api_key_find_all_usecase.exec(ctx).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data[0]["id"], 1);
+        assert_eq!(res_data[0]["name"], "batch");
+        assert!(res_data[0].get("key_hash").is_none());
+    }
+}
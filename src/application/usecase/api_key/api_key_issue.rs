@@ -0,0 +1,134 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 日時
+use chrono::{DateTime, FixedOffset};
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::api_key::{api_key_model::ApiKey, api_key_repository::ApiKeyRepositoryTrait};
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize, Debug)]
+pub struct ApiKeyIssueInput {
+    pub name: String,
+    pub scopes: Vec<String>,
+    // 有効期限（未指定の場合は無期限）
+    #[serde(default)]
+    pub expires_at: Option<DateTime<FixedOffset>>,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ApiKeyIssueUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: ApiKeyIssueInput) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct ApiKeyIssueRepository {
+    pub api_key_repository: Arc<dyn ApiKeyRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct ApiKeyIssueUsecase {
+    pub repo: ApiKeyIssueRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl ApiKeyIssueUsecase {
+    pub fn new(repo: ApiKeyIssueRepository, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        ApiKeyIssueUsecase { repo, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyIssueUsecaseTrait for ApiKeyIssueUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: ApiKeyIssueInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("ApiKeyIssueUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // APIキーの発行
+        let (api_key, plain_key) = match ApiKey::issue(input.name, input.scopes, input.expires_at) {
+            Ok(issued) => issued,
+            Err(msg) => {
+                let err = ErrorCommon::CustomError {
+                    status_code: StatusCode::BAD_REQUEST,
+                    message: msg,
+                };
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // APIキーの保存
+        let api_key = match self.repo.api_key_repository.create(&ctx, &api_key).await {
+            Ok(api_key) => api_key,
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("ApiKeyIssueUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // 発行内容のログ出力（平文のキーは出力しない）
+        let msg = format!("APIキーを発行しました: key_id={}", api_key.key_id);
+        self.logger.info(&ctx, &msg);
+
+        // レスポンスボディの設定（平文のキーはこのレスポンスでのみ返す）
+        let res_body = Json(json!({ "key": plain_key, "api_key": api_key }));
+
+        // レスポンス結果を設定して戻り値として返す
+        (StatusCode::CREATED, res_header, res_body).into_response()
+    }
+}
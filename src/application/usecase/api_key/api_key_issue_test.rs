@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::api_key::api_key_model::ApiKey;
+    use crate::domain::api_key::api_key_repository::MockApiKeyRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::api_key::api_key_issue::ApiKeyIssueInput;
+    use crate::application::usecase::api_key::api_key_issue::ApiKeyIssueRepository;
+    use crate::application::usecase::api_key::api_key_issue::ApiKeyIssueUsecase;
+    use crate::application::usecase::api_key::api_key_issue::ApiKeyIssueUsecaseTrait;
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/admin/api-keys".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase() -> ApiKeyIssueUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockApiKeyRepositoryTrait::new();
+        mock_repo.expect_create().returning(|_, api_key| {
+            let mut api_key: ApiKey = api_key.clone();
+            api_key.id = 1;
+            Ok(api_key)
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        let repo = ApiKeyIssueRepository {
+            api_key_repository: Arc::new(mock_repo),
+        };
+
+        ApiKeyIssueUsecase {
+            repo,
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let input = ApiKeyIssueInput {
+            name: "batch".to_string(),
+            scopes: vec!["users:read".to_string()],
+            expires_at: None,
+        };
+
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(vec!["admin".to_string()]), input)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        // レスポンスボディの検証（平文のキーは返却し、ハッシュ値は返却しない）
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert!(res_data["key"].as_str().unwrap().starts_with("ak_"));
+        assert_eq!(res_data["api_key"]["id"], 1);
+        assert_eq!(res_data["api_key"]["name"], "batch");
+        assert!(res_data["api_key"].get("key_hash").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exec_invalid_scope() {
+        let input = ApiKeyIssueInput {
+            name: "batch".to_string(),
+            scopes: vec!["unknown".to_string()],
+            expires_at: None,
+        };
+
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(vec!["admin".to_string()]), input)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        let input = ApiKeyIssueInput {
+            name: "batch".to_string(),
+            scopes: vec!["users:read".to_string()],
+            expires_at: None,
+        };
+
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(vec!["operator".to_string()]), input)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,122 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::api_key::api_key_repository::ApiKeyRepositoryTrait;
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ApiKeyRevokeUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, key_id: String) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct ApiKeyRevokeRepository {
+    pub api_key_repository: Arc<dyn ApiKeyRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct ApiKeyRevokeUsecase {
+    pub repo: ApiKeyRevokeRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl ApiKeyRevokeUsecase {
+    pub fn new(repo: ApiKeyRevokeRepository, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        ApiKeyRevokeUsecase { repo, logger }
+    }
+
+    // APIキーの失効処理
+    async fn revoke(&self, ctx: &ContextRequest, key_id: &str) -> Result<(), ErrorCommon> {
+        // 対象APIキーの取得
+        let mut api_key = match self
+            .repo
+            .api_key_repository
+            .find_by_key_id(ctx, key_id)
+            .await?
+        {
+            Some(api_key) if api_key.revoked_at.is_none() => api_key,
+            _ => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::NOT_FOUND,
+                    message: "対象のAPIキーが存在しません。".to_string(),
+                });
+            }
+        };
+
+        // 失効設定
+        api_key.revoke();
+
+        self.repo.api_key_repository.update(ctx, &api_key).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyRevokeUsecaseTrait for ApiKeyRevokeUsecase {
+    async fn exec(&self, ctx: ContextRequest, key_id: String) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("ApiKeyRevokeUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // APIキーの失効処理
+        match self.revoke(&ctx, &key_id).await {
+            Ok(_) => {
+                let msg = format!("APIキーを失効しました: key_id={}", key_id);
+                self.logger.info(&ctx, &msg);
+
+                (StatusCode::NO_CONTENT, res_header).into_response()
+            }
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("ApiKeyRevokeUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::api_key::api_key_model::ApiKey;
+    use crate::domain::api_key::api_key_repository::MockApiKeyRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::api_key::api_key_revoke::ApiKeyRevokeRepository;
+    use crate::application::usecase::api_key::api_key_revoke::ApiKeyRevokeUsecase;
+    use crate::application::usecase::api_key::api_key_revoke::ApiKeyRevokeUsecaseTrait;
+
+    // 共通コンテキスト設定
+    fn new_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "DELETE".to_string(),
+            uri: "/api/v1/admin/api-keys/xxxx".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles: vec!["admin".to_string()],
+            }),
+        }
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase(api_key: Option<ApiKey>) -> ApiKeyRevokeUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockApiKeyRepositoryTrait::new();
+        mock_repo
+            .expect_find_by_key_id()
+            .returning(move |_, _| Ok(api_key.clone()));
+        mock_repo.expect_update().returning(|_, api_key| {
+            assert!(api_key.revoked_at.is_some());
+            Ok(api_key.clone())
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        let repo = ApiKeyRevokeRepository {
+            api_key_repository: Arc::new(mock_repo),
+        };
+
+        ApiKeyRevokeUsecase {
+            repo,
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let (api_key, _) =
+            ApiKey::issue("batch".to_string(), vec!["users:read".to_string()], None).unwrap();
+        let key_id = api_key.key_id.clone();
+
+        // テスト実行
+        let res = new_usecase(Some(api_key)).exec(new_ctx(), key_id).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // テスト実行
+        let res = new_usecase(None).exec(new_ctx(), "xxxx".to_string()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+}
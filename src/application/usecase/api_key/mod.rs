@@ -0,0 +1,10 @@
+pub mod api_key_authenticate;
+pub mod api_key_find_all;
+pub mod api_key_issue;
+pub mod api_key_revoke;
+
+// テストコード用のモジュール
+pub mod api_key_authenticate_test;
+pub mod api_key_find_all_test;
+pub mod api_key_issue_test;
+pub mod api_key_revoke_test;
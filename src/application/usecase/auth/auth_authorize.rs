@@ -0,0 +1,29 @@
+use axum::http::StatusCode;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::{Permission, Principal};
+use crate::domain::error::error_common::ErrorCommon;
+
+// 共通コンテキストの実行者に対する権限チェック（各ユースケースの最初に実行）
+// owner_uid: 操作対象のユーザーuid（本人の操作かどうかの判定に利用）
+pub fn authorize(
+    ctx: &ContextRequest,
+    permission: Permission,
+    owner_uid: Option<&str>,
+) -> Result<(), ErrorCommon> {
+    let auth = match &ctx.auth {
+        Some(auth) => auth,
+        None => {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::UNAUTHORIZED,
+                message: "認証されていません。".to_string(),
+            });
+        }
+    };
+
+    let principal = Principal::new(auth.subject.clone(), &auth.roles, &auth.scopes);
+    principal.authorize(permission, owner_uid)
+}
@@ -0,0 +1,203 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::credential::{
+    credential_repository::CredentialRepositoryTrait,
+    refresh_token_repository::RefreshTokenRepositoryTrait,
+};
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::user::user_repository::UserRepositoryTrait;
+
+// 認証
+use crate::application::usecase::auth::auth_password_trait::AuthPasswordTrait;
+use crate::application::usecase::auth::auth_token_issue::issue_tokens;
+use crate::application::usecase::auth::auth_token_trait::AuthTokenTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize)]
+pub struct AuthLoginInput {
+    pub email: String,
+    pub password: String,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthLoginUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: AuthLoginInput) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct AuthLoginRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+    pub credential_repository: Arc<dyn CredentialRepositoryTrait + 'static>,
+    pub refresh_token_repository: Arc<dyn RefreshTokenRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct AuthLoginUsecase {
+    pub repo: AuthLoginRepository,
+    pub auth_token: Arc<dyn AuthTokenTrait + 'static>,
+    pub auth_password: Arc<dyn AuthPasswordTrait + 'static>,
+    // リフレッシュトークンの有効期間（秒）
+    pub refresh_token_ttl_secs: i64,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AuthLoginUsecase {
+    pub fn new(
+        repo: AuthLoginRepository,
+        auth_token: Arc<dyn AuthTokenTrait + 'static>,
+        auth_password: Arc<dyn AuthPasswordTrait + 'static>,
+        refresh_token_ttl_secs: i64,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        AuthLoginUsecase {
+            repo,
+            auth_token,
+            auth_password,
+            refresh_token_ttl_secs,
+            logger,
+        }
+    }
+
+    // 401エラーの作成（メールアドレスの存在有無が分からないように同じメッセージを返す）
+    fn unauthorized() -> ErrorCommon {
+        ErrorCommon::CustomError {
+            status_code: StatusCode::UNAUTHORIZED,
+            message: "メールアドレスまたはパスワードが正しくありません。".to_string(),
+        }
+    }
+
+    // ログイン処理
+    async fn login(
+        &self,
+        ctx: &ContextRequest,
+        input: AuthLoginInput,
+    ) -> Result<serde_json::Value, ErrorCommon> {
+        // 対象ユーザーの取得（存在しない場合もダミーの検証で応答時間を揃える）
+        let user = match self
+            .repo
+            .user_repository
+            .find_by_email(ctx, &input.email)
+            .await?
+        {
+            Some(user) => user,
+            None => {
+                self.auth_password.dummy_verify(&input.password).await;
+                return Err(AuthLoginUsecase::unauthorized());
+            }
+        };
+
+        // 認証情報の取得
+        let credential = match self
+            .repo
+            .credential_repository
+            .find_by_user_uid(ctx, &user.uid)
+            .await?
+        {
+            Some(credential) => credential,
+            None => {
+                self.auth_password.dummy_verify(&input.password).await;
+                return Err(AuthLoginUsecase::unauthorized());
+            }
+        };
+
+        // ロック中のチェック
+        let now = jst_now();
+        if credential.is_locked(now) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::LOCKED,
+                message: "ログインの失敗が続いたため、アカウントがロックされています。しばらくしてから再度お試し下さい。".to_string(),
+            });
+        }
+
+        // パスワードの検証（失敗した場合は失敗回数を記録）
+        if !self
+            .auth_password
+            .verify(&input.password, &credential.password_hash)
+            .await
+        {
+            let credential = self
+                .repo
+                .credential_repository
+                .record_failure(ctx, &user.uid, now)
+                .await?;
+            if credential.is_locked(now) {
+                let msg = format!("ログインの失敗が続いたためロックしました: uid={}", user.uid);
+                self.logger.warn(ctx, &msg);
+            }
+            return Err(AuthLoginUsecase::unauthorized());
+        }
+
+        // ログイン成功の記録
+        self.repo
+            .credential_repository
+            .record_success(ctx, &user.uid, now)
+            .await?;
+
+        // アクセストークンとリフレッシュトークンの発行（新しいファミリーを作成）
+        issue_tokens(
+            ctx,
+            self.auth_token.as_ref(),
+            self.repo.refresh_token_repository.as_ref(),
+            user.uid,
+            None,
+            self.refresh_token_ttl_secs,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthLoginUsecaseTrait for AuthLoginUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: AuthLoginInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定（トークンはキャッシュさせない）
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id), ("Cache-Control", "no-store")];
+
+        // ログイン処理
+        match self.login(&ctx, input).await {
+            Ok(res_body) => (StatusCode::OK, res_header, Json(res_body)).into_response(),
+            Err(err) => {
+                // ログ出力（認証失敗は警告、それ以外はエラー）
+                let err_msg = format!("AuthLoginUsecaseでエラー: {}", err);
+                if err.status_code().is_client_error() {
+                    self.logger.warn(&ctx, &err_msg);
+                } else {
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
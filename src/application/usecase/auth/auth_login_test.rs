@@ -0,0 +1,299 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use chrono::Duration;
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::credential::credential_model::{Credential, MAX_FAILED_ATTEMPTS};
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // DB・リポジトリ
+    use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
+    use crate::infrastructure::persistence::credential::credential_repository::CredentialRepository;
+
+    // リポジトリのモック
+    use crate::domain::credential::credential_repository::{
+        CredentialRepositoryTrait, MockCredentialRepositoryTrait,
+    };
+    use crate::domain::credential::refresh_token_repository::MockRefreshTokenRepositoryTrait;
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // 認証のモック
+    use crate::application::usecase::auth::auth_password_trait::MockAuthPasswordTrait;
+    use crate::application::usecase::auth::auth_token_trait::{AccessToken, MockAuthTokenTrait};
+
+    // ユースケース
+    use crate::application::usecase::auth::auth_login::AuthLoginInput;
+    use crate::application::usecase::auth::auth_login::AuthLoginRepository;
+    use crate::application::usecase::auth::auth_login::AuthLoginUsecase;
+    use crate::application::usecase::auth::auth_login::AuthLoginUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/auth/login".to_string(),
+            auth: None,
+        }
+    }
+
+    // 入力値の作成
+    fn create_input(password: &str) -> AuthLoginInput {
+        AuthLoginInput {
+            email: "t.tanaka@example.com".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    // ユースケースの作成
+    // dummy_verify_times: ダミーの検証が実行される回数
+    fn create_usecase(
+        credential: Credential,
+        mock_credential_repo: MockCredentialRepositoryTrait,
+        dummy_verify_times: usize,
+    ) -> AuthLoginUsecase {
+        // 認証情報リポジトリのモック化
+        let mut mock_credential_repo = mock_credential_repo;
+        mock_credential_repo
+            .expect_find_by_user_uid()
+            .returning(move |_, _| Ok(Some(credential.clone())));
+
+        create_usecase_with_repo(mock_credential_repo, dummy_verify_times)
+    }
+
+    // 認証情報リポジトリを指定してユースケースを作成
+    fn create_usecase_with_repo(
+        credential_repo: impl CredentialRepositoryTrait + 'static,
+        dummy_verify_times: usize,
+    ) -> AuthLoginUsecase {
+        // ユーザーリポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_find_by_email().returning(|_, email| {
+            if email != "t.tanaka@example.com" {
+                return Ok(None);
+            }
+            Ok(Some(User::new(
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                "田中".to_string(),
+                "太郎".to_string(),
+                email.to_string(),
+            )))
+        });
+
+        // リフレッシュトークンリポジトリのモック化
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_create()
+            .returning(|_, refresh_token| Ok(refresh_token.clone()));
+
+        // 認証のモック化
+        let mut mock_auth_token = MockAuthTokenTrait::new();
+        mock_auth_token.expect_issue().returning(|auth| {
+            Ok(AccessToken {
+                token: format!("token-{}", auth.subject),
+                expires_in: 900,
+            })
+        });
+        let mut mock_auth_password = MockAuthPasswordTrait::new();
+        mock_auth_password
+            .expect_verify()
+            .returning(|password, _| password == "password-0001");
+        mock_auth_password
+            .expect_dummy_verify()
+            .times(dummy_verify_times)
+            .returning(|_| ());
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        AuthLoginUsecase {
+            repo: AuthLoginRepository {
+                user_repository: Arc::new(mock_user_repo),
+                credential_repository: Arc::new(credential_repo),
+                refresh_token_repository: Arc::new(mock_refresh_token_repo),
+            },
+            auth_token: Arc::new(mock_auth_token),
+            auth_password: Arc::new(mock_auth_password),
+            refresh_token_ttl_secs: 3600,
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // 認証情報（失敗回数あり）
+        let mut credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+        credential.failed_attempts = 2;
+
+        // 認証情報リポジトリのモック化（失敗回数がリセットされること）
+        let mut mock_credential_repo = MockCredentialRepositoryTrait::new();
+        mock_credential_repo
+            .expect_record_success()
+            .withf(|_, user_uid, _| user_uid == "xxxx-xxxx-xxxx-0001")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock_credential_repo.expect_record_failure().never();
+
+        // テスト実行
+        let usecase = create_usecase(credential, mock_credential_repo, 0);
+        let res = usecase
+            .exec(create_ctx(), create_input("password-0001"))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get("Cache-Control").unwrap(), "no-store");
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data["access_token"], "token-xxxx-xxxx-xxxx-0001");
+        assert_eq!(res_data["token_type"], "Bearer");
+        assert_eq!(res_data["expires_in"], 900);
+        assert!(
+            res_data["refresh_token"]
+                .as_str()
+                .unwrap()
+                .starts_with("rt_")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exec_wrong_password() {
+        let credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+
+        // 認証情報リポジトリのモック化（失敗回数が記録されること）
+        let mut mock_credential_repo = MockCredentialRepositoryTrait::new();
+        let stored_credential = credential.clone();
+        mock_credential_repo
+            .expect_record_failure()
+            .withf(|_, user_uid, _| user_uid == "xxxx-xxxx-xxxx-0001")
+            .times(1)
+            .returning(move |_, _, now| {
+                let mut credential = stored_credential.clone();
+                credential.record_failure(now);
+                Ok(credential)
+            });
+        mock_credential_repo.expect_record_success().never();
+
+        // テスト実行
+        let usecase = create_usecase(credential, mock_credential_repo, 0);
+        let res = usecase
+            .exec(create_ctx(), create_input("password-9999"))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_lock_after_max_failures() {
+        // 認証情報（ロック直前の失敗回数）
+        let mut credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+        credential.failed_attempts = MAX_FAILED_ATTEMPTS - 1;
+
+        // 認証情報リポジトリのモック化（保存済みの値に対して失敗が記録されること）
+        let mut mock_credential_repo = MockCredentialRepositoryTrait::new();
+        let stored_credential = credential.clone();
+        mock_credential_repo
+            .expect_record_failure()
+            .times(1)
+            .returning(move |_, _, now| {
+                let mut credential = stored_credential.clone();
+                credential.record_failure(now);
+                Ok(credential)
+            });
+
+        // テスト実行
+        let usecase = create_usecase(credential, mock_credential_repo, 0);
+        let res = usecase
+            .exec(create_ctx(), create_input("password-9999"))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_locked() {
+        // 認証情報（ロック中）
+        let mut credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+        credential.locked_until = Some(credential.created_at + Duration::minutes(15));
+
+        // テスト実行（正しいパスワードでもロック中はログイン不可）
+        let usecase = create_usecase(credential, MockCredentialRepositoryTrait::new(), 0);
+        let res = usecase
+            .exec(create_ctx(), create_input("password-0001"))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::LOCKED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_unknown_email() {
+        let credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+        let usecase = create_usecase(credential, MockCredentialRepositoryTrait::new(), 1);
+
+        // テスト実行
+        let input = AuthLoginInput {
+            email: "unknown@example.com".to_string(),
+            password: "password-0001".to_string(),
+        };
+        let res = usecase.exec(create_ctx(), input).await;
+
+        // 検証（存在しないメールアドレスでもダミーの検証を実行）
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_exec_concurrent_failures() {
+        // 事前準備（ダミーDBに認証情報を保存）
+        let db = new_db_dummy_connection().await.unwrap();
+        db.credentials.write().unwrap().push(Credential::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "hash".to_string(),
+        ));
+        let usecase = Arc::new(create_usecase_with_repo(
+            CredentialRepository::new(db.clone(), Arc::new(MockLoggerTrait::new())),
+            0,
+        ));
+
+        // テスト実行（上限回数のログイン失敗を同時に実行）
+        let handles: Vec<_> = (0..MAX_FAILED_ATTEMPTS)
+            .map(|_| {
+                let usecase = usecase.clone();
+                tokio::spawn(async move {
+                    usecase
+                        .exec(create_ctx(), create_input("password-9999"))
+                        .await
+                        .status()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), StatusCode::UNAUTHORIZED);
+        }
+
+        // 検証（失敗回数が失われずにロックされること）
+        let credentials = db.credentials.read().unwrap();
+        assert!(credentials[0].locked_until.is_some());
+    }
+}
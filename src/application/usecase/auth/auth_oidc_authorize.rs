@@ -0,0 +1,122 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::credential::{
+    oidc_session_model::OidcSession, oidc_session_repository::OidcSessionRepositoryTrait,
+};
+use crate::domain::error::error_common::ErrorCommon;
+
+// OIDCクライアント
+use crate::application::usecase::auth::auth_oidc_trait::AuthOidcTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthOidcAuthorizeUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct AuthOidcAuthorizeRepository {
+    pub oidc_session_repository: Arc<dyn OidcSessionRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct AuthOidcAuthorizeUsecase {
+    pub repo: AuthOidcAuthorizeRepository,
+    // OIDCクライアント（未設定の場合はNone）
+    pub auth_oidc: Option<Arc<dyn AuthOidcTrait + 'static>>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AuthOidcAuthorizeUsecase {
+    pub fn new(
+        repo: AuthOidcAuthorizeRepository,
+        auth_oidc: Option<Arc<dyn AuthOidcTrait + 'static>>,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        AuthOidcAuthorizeUsecase {
+            repo,
+            auth_oidc,
+            logger,
+        }
+    }
+
+    // 認可リクエストURLの作成処理（ログインセッションを保存）
+    async fn authorize(&self, ctx: &ContextRequest) -> Result<String, ErrorCommon> {
+        let auth_oidc = match &self.auth_oidc {
+            Some(auth_oidc) => auth_oidc,
+            None => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::SERVICE_UNAVAILABLE,
+                    message: "OIDCによるログインは設定されていません。".to_string(),
+                });
+            }
+        };
+
+        // ログインセッションの作成
+        let session = OidcSession::new();
+        let url = auth_oidc
+            .authorization_url(&session.state, &session.nonce, &session.code_challenge())
+            .await?;
+        self.repo
+            .oidc_session_repository
+            .create(ctx, &session)
+            .await?;
+
+        Ok(url)
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthOidcAuthorizeUsecaseTrait for AuthOidcAuthorizeUsecase {
+    async fn exec(&self, ctx: ContextRequest) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+
+        // 認可リクエストURLの作成処理
+        match self.authorize(&ctx).await {
+            // IDプロバイダーの認可エンドポイントにリダイレクト
+            Ok(url) => {
+                let res_header = [
+                    ("X-Request-Id", request_id),
+                    ("Location", url.as_str()),
+                    ("Cache-Control", "no-store"),
+                ];
+                (StatusCode::FOUND, res_header).into_response()
+            }
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("AuthOidcAuthorizeUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                let res_header = [("X-Request-Id", request_id)];
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::credential::oidc_session_repository::MockOidcSessionRepositoryTrait;
+
+    // OIDCクライアントのモック
+    use crate::application::usecase::auth::auth_oidc_trait::MockAuthOidcTrait;
+
+    // ユースケース
+    use crate::application::usecase::auth::auth_oidc_authorize::AuthOidcAuthorizeRepository;
+    use crate::application::usecase::auth::auth_oidc_authorize::AuthOidcAuthorizeUsecase;
+    use crate::application::usecase::auth::auth_oidc_authorize::AuthOidcAuthorizeUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/auth/oidc/authorize".to_string(),
+            auth: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_redirect() {
+        // リポジトリのモック化（ログインセッションが保存されること）
+        let mut mock_oidc_session_repo = MockOidcSessionRepositoryTrait::new();
+        mock_oidc_session_repo
+            .expect_create()
+            .withf(|_, session| !session.state.is_empty() && !session.nonce.is_empty())
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // OIDCクライアントのモック化
+        let mut mock_auth_oidc = MockAuthOidcTrait::new();
+        mock_auth_oidc
+            .expect_authorization_url()
+            .returning(|state, _, code_challenge| {
+                Ok(format!(
+                    "https://idp.example.com/authorize?state={}&code_challenge={}",
+                    state, code_challenge
+                ))
+            });
+
+        // ユースケースのインスタンス化
+        let usecase = AuthOidcAuthorizeUsecase {
+            repo: AuthOidcAuthorizeRepository {
+                oidc_session_repository: Arc::new(mock_oidc_session_repo),
+            },
+            auth_oidc: Some(Arc::new(mock_auth_oidc)),
+            logger: Arc::new(MockLoggerTrait::new()),
+        };
+
+        // テスト実行
+        let res = usecase.exec(create_ctx()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FOUND);
+        let location = res.headers().get("Location").unwrap().to_str().unwrap();
+        assert!(location.starts_with("https://idp.example.com/authorize?state="));
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_configured() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // ユースケースのインスタンス化
+        let usecase = AuthOidcAuthorizeUsecase {
+            repo: AuthOidcAuthorizeRepository {
+                oidc_session_repository: Arc::new(MockOidcSessionRepositoryTrait::new()),
+            },
+            auth_oidc: None,
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let res = usecase.exec(create_ctx()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,254 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::credential::{
+    oidc_session_repository::OidcSessionRepositoryTrait,
+    refresh_token_repository::RefreshTokenRepositoryTrait,
+};
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::user::{user_model::User, user_repository::UserRepositoryTrait};
+
+// 認証
+use crate::application::usecase::auth::auth_oidc_trait::{AuthOidcTrait, OidcUserInfo};
+use crate::application::usecase::auth::auth_token_issue::issue_tokens;
+use crate::application::usecase::auth::auth_token_trait::AuthTokenTrait;
+
+// ユーザー作成（ジャストインタイムのプロビジョニング）
+use crate::application::usecase::user::user_create::{UserCreateInput, UserCreateUsecaseTrait};
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体（IDプロバイダーからのクエリパラメータ）
+#[derive(Clone, Deserialize, Debug)]
+pub struct AuthOidcCallbackInput {
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub state: Option<String>,
+    // IDプロバイダーで認証が拒否された場合のエラーコード
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+// IDトークンに姓名が含まれていない場合に設定する仮の値
+pub const NAME_PLACEHOLDER: &str = "未設定";
+
+// 姓名の取得（未設定または空の場合は仮の値）
+fn name_or_placeholder(name: Option<String>) -> String {
+    match name {
+        Some(name) if !name.trim().is_empty() => name,
+        _ => NAME_PLACEHOLDER.to_string(),
+    }
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthOidcCallbackUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: AuthOidcCallbackInput) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct AuthOidcCallbackRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+    pub refresh_token_repository: Arc<dyn RefreshTokenRepositoryTrait + 'static>,
+    pub oidc_session_repository: Arc<dyn OidcSessionRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct AuthOidcCallbackUsecase {
+    pub repo: AuthOidcCallbackRepository,
+    // 未登録ユーザーの作成に利用するユースケース
+    pub user_create: Arc<dyn UserCreateUsecaseTrait + Send + Sync + 'static>,
+    pub auth_token: Arc<dyn AuthTokenTrait + 'static>,
+    // OIDCクライアント（未設定の場合はNone）
+    pub auth_oidc: Option<Arc<dyn AuthOidcTrait + 'static>>,
+    // リフレッシュトークンの有効期間（秒）
+    pub refresh_token_ttl_secs: i64,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AuthOidcCallbackUsecase {
+    pub fn new(
+        repo: AuthOidcCallbackRepository,
+        user_create: Arc<dyn UserCreateUsecaseTrait + Send + Sync + 'static>,
+        auth_token: Arc<dyn AuthTokenTrait + 'static>,
+        auth_oidc: Option<Arc<dyn AuthOidcTrait + 'static>>,
+        refresh_token_ttl_secs: i64,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        AuthOidcCallbackUsecase {
+            repo,
+            user_create,
+            auth_token,
+            auth_oidc,
+            refresh_token_ttl_secs,
+            logger,
+        }
+    }
+
+    // 401エラーの作成
+    fn unauthorized(message: &str) -> ErrorCommon {
+        ErrorCommon::CustomError {
+            status_code: StatusCode::UNAUTHORIZED,
+            message: message.to_string(),
+        }
+    }
+
+    // メールアドレスに対応するユーザーの取得（未登録の場合は作成）
+    async fn find_or_provision_user(
+        &self,
+        ctx: &ContextRequest,
+        user_info: OidcUserInfo,
+    ) -> Result<User, ErrorCommon> {
+        if let Some(user) = self
+            .repo
+            .user_repository
+            .find_by_email(ctx, &user_info.email)
+            .await?
+        {
+            return Ok(user);
+        }
+
+        // ユーザー作成（family_nameを姓、given_nameを名に設定し、IDトークンにない場合は仮の値を設定）
+        let input = UserCreateInput {
+            last_name: name_or_placeholder(user_info.family_name),
+            first_name: name_or_placeholder(user_info.given_name),
+            email: user_info.email,
+        };
+        let user = self.user_create.create_user(ctx, input).await?;
+
+        let msg = format!(
+            "OIDCログインでユーザーを作成しました: uid={} subject={}",
+            user.uid, user_info.subject
+        );
+        self.logger.info(ctx, &msg);
+
+        Ok(user)
+    }
+
+    // コールバック処理（認可コードの交換からトークンの発行まで）
+    async fn callback(
+        &self,
+        ctx: &ContextRequest,
+        input: AuthOidcCallbackInput,
+    ) -> Result<serde_json::Value, ErrorCommon> {
+        let auth_oidc = match &self.auth_oidc {
+            Some(auth_oidc) => auth_oidc,
+            None => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::SERVICE_UNAVAILABLE,
+                    message: "OIDCによるログインは設定されていません。".to_string(),
+                });
+            }
+        };
+
+        // IDプロバイダーでのエラーのチェック
+        if let Some(error) = input.error {
+            return Err(AuthOidcCallbackUsecase::unauthorized(&format!(
+                "IDプロバイダーで認証が拒否されました: {}",
+                error
+            )));
+        }
+        let (code, state) = match (input.code, input.state) {
+            (Some(code), Some(state)) => (code, state),
+            _ => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::BAD_REQUEST,
+                    message: "codeとstateは必須です。".to_string(),
+                });
+            }
+        };
+
+        // ログインセッションの取得（1回のみ使用可能）
+        let now = chrono::Utc::now().fixed_offset();
+        let session = match self
+            .repo
+            .oidc_session_repository
+            .take_by_state(ctx, &state)
+            .await?
+        {
+            Some(session) if !session.is_expired(now) => session,
+            _ => {
+                return Err(AuthOidcCallbackUsecase::unauthorized(
+                    "ログインセッションが無効または期限切れです。",
+                ));
+            }
+        };
+
+        // 認可コードの交換とIDトークンの検証
+        let user_info = auth_oidc
+            .exchange_code(&code, &session.code_verifier, &session.nonce)
+            .await?;
+        // メールアドレスで既存ユーザーと紐付けるため、確認済みと明示された場合のみ許可
+        if user_info.email_verified != Some(true) {
+            return Err(AuthOidcCallbackUsecase::unauthorized(
+                "メールアドレスが確認されていません。",
+            ));
+        }
+
+        // ユーザーの取得または作成
+        let user = self.find_or_provision_user(ctx, user_info).await?;
+
+        // アクセストークンとリフレッシュトークンの発行
+        issue_tokens(
+            ctx,
+            self.auth_token.as_ref(),
+            self.repo.refresh_token_repository.as_ref(),
+            user.uid,
+            None,
+            self.refresh_token_ttl_secs,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthOidcCallbackUsecaseTrait for AuthOidcCallbackUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: AuthOidcCallbackInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定（トークンはキャッシュさせない）
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id), ("Cache-Control", "no-store")];
+
+        // コールバック処理
+        match self.callback(&ctx, input).await {
+            Ok(res_body) => (StatusCode::OK, res_header, Json(res_body)).into_response(),
+            Err(err) => {
+                // ログ出力（認証失敗は警告、それ以外はエラー）
+                let err_msg = format!("AuthOidcCallbackUsecaseでエラー: {}", err);
+                if err.status_code().is_client_error() {
+                    self.logger.warn(&ctx, &err_msg);
+                } else {
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
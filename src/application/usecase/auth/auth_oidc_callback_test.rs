@@ -0,0 +1,294 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::credential::oidc_session_model::OidcSession;
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::credential::oidc_session_repository::MockOidcSessionRepositoryTrait;
+    use crate::domain::credential::refresh_token_repository::MockRefreshTokenRepositoryTrait;
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // 認証のモック
+    use crate::application::usecase::auth::auth_oidc_trait::{MockAuthOidcTrait, OidcUserInfo};
+    use crate::application::usecase::auth::auth_token_trait::{AccessToken, MockAuthTokenTrait};
+
+    // ユーザー作成ユースケースのモック
+    use crate::application::usecase::user::user_create::MockUserCreateUsecaseTrait;
+
+    // ユースケース
+    use crate::application::usecase::auth::auth_oidc_callback::AuthOidcCallbackInput;
+    use crate::application::usecase::auth::auth_oidc_callback::AuthOidcCallbackRepository;
+    use crate::application::usecase::auth::auth_oidc_callback::AuthOidcCallbackUsecase;
+    use crate::application::usecase::auth::auth_oidc_callback::AuthOidcCallbackUsecaseTrait;
+    use crate::application::usecase::auth::auth_oidc_callback::NAME_PLACEHOLDER;
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/auth/oidc/callback".to_string(),
+            auth: None,
+        }
+    }
+
+    // 入力値の作成
+    fn create_input() -> AuthOidcCallbackInput {
+        AuthOidcCallbackInput {
+            code: Some("test-code".to_string()),
+            state: Some("test-state".to_string()),
+            error: None,
+        }
+    }
+
+    // IDトークンから取得するユーザー情報の作成
+    fn create_user_info(email: &str, email_verified: Option<bool>) -> OidcUserInfo {
+        OidcUserInfo {
+            subject: "idp-user-0001".to_string(),
+            email: email.to_string(),
+            email_verified,
+            given_name: Some("花子".to_string()),
+            family_name: Some("鈴木".to_string()),
+        }
+    }
+
+    // ユースケースの作成
+    fn create_usecase(
+        session: Option<OidcSession>,
+        user_info: OidcUserInfo,
+        mock_user_create: MockUserCreateUsecaseTrait,
+    ) -> AuthOidcCallbackUsecase {
+        // ユーザーリポジトリのモック化（既存ユーザーはt.tanaka@example.comのみ）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_find_by_email().returning(|_, email| {
+            if email != "t.tanaka@example.com" {
+                return Ok(None);
+            }
+            Ok(Some(User::new(
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                "田中".to_string(),
+                "太郎".to_string(),
+                email.to_string(),
+            )))
+        });
+
+        // リフレッシュトークンリポジトリのモック化
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_create()
+            .returning(|_, refresh_token| Ok(refresh_token.clone()));
+
+        // ログインセッションリポジトリのモック化
+        let mut mock_oidc_session_repo = MockOidcSessionRepositoryTrait::new();
+        mock_oidc_session_repo
+            .expect_take_by_state()
+            .returning(move |_, _| Ok(session.clone()));
+
+        // 認証のモック化
+        let mut mock_auth_token = MockAuthTokenTrait::new();
+        mock_auth_token.expect_issue().returning(|auth| {
+            Ok(AccessToken {
+                token: format!("token-{}", auth.subject),
+                expires_in: 900,
+            })
+        });
+        let mut mock_auth_oidc = MockAuthOidcTrait::new();
+        mock_auth_oidc
+            .expect_exchange_code()
+            .returning(move |_, _, _| Ok(user_info.clone()));
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        AuthOidcCallbackUsecase {
+            repo: AuthOidcCallbackRepository {
+                user_repository: Arc::new(mock_user_repo),
+                refresh_token_repository: Arc::new(mock_refresh_token_repo),
+                oidc_session_repository: Arc::new(mock_oidc_session_repo),
+            },
+            user_create: Arc::new(mock_user_create),
+            auth_token: Arc::new(mock_auth_token),
+            auth_oidc: Some(Arc::new(mock_auth_oidc)),
+            refresh_token_ttl_secs: 3600,
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    // レスポンスボディの取得
+    async fn get_body(res: axum::response::Response) -> serde_json::Value {
+        let bytes = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_exec_existing_user() {
+        // ユーザー作成ユースケースのモック化（既存ユーザーの場合は呼ばれないこと）
+        let mut mock_user_create = MockUserCreateUsecaseTrait::new();
+        mock_user_create.expect_create_user().never();
+
+        // テスト実行
+        let usecase = create_usecase(
+            Some(OidcSession::new()),
+            create_user_info("t.tanaka@example.com", Some(true)),
+            mock_user_create,
+        );
+        let res = usecase.exec(create_ctx(), create_input()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        let res_data = get_body(res).await;
+        assert_eq!(res_data["access_token"], "token-xxxx-xxxx-xxxx-0001");
+        assert!(
+            res_data["refresh_token"]
+                .as_str()
+                .unwrap()
+                .starts_with("rt_")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exec_provision_user() {
+        // ユーザー作成ユースケースのモック化（IDトークンの姓名がマッピングされること）
+        let mut mock_user_create = MockUserCreateUsecaseTrait::new();
+        mock_user_create
+            .expect_create_user()
+            .withf(|_, input| {
+                input.last_name == "鈴木"
+                    && input.first_name == "花子"
+                    && input.email == "h.suzuki@example.com"
+            })
+            .times(1)
+            .returning(|_, input| {
+                Ok(User::new(
+                    "xxxx-xxxx-xxxx-0009".to_string(),
+                    input.last_name,
+                    input.first_name,
+                    input.email,
+                ))
+            });
+
+        // テスト実行
+        let usecase = create_usecase(
+            Some(OidcSession::new()),
+            create_user_info("h.suzuki@example.com", Some(true)),
+            mock_user_create,
+        );
+        let res = usecase.exec(create_ctx(), create_input()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        let res_data = get_body(res).await;
+        assert_eq!(res_data["access_token"], "token-xxxx-xxxx-xxxx-0009");
+    }
+
+    #[tokio::test]
+    async fn test_exec_invalid_state() {
+        // テスト実行（ログインセッションが存在しない）
+        let usecase = create_usecase(
+            None,
+            create_user_info("t.tanaka@example.com", Some(true)),
+            MockUserCreateUsecaseTrait::new(),
+        );
+        let res = usecase.exec(create_ctx(), create_input()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_email_not_verified() {
+        // テスト実行
+        let usecase = create_usecase(
+            Some(OidcSession::new()),
+            create_user_info("t.tanaka@example.com", Some(false)),
+            MockUserCreateUsecaseTrait::new(),
+        );
+        let res = usecase.exec(create_ctx(), create_input()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_email_verified_missing() {
+        // テスト実行（email_verifiedのクレームがない場合も既存ユーザーと紐付けない）
+        let usecase = create_usecase(
+            Some(OidcSession::new()),
+            create_user_info("t.tanaka@example.com", None),
+            MockUserCreateUsecaseTrait::new(),
+        );
+        let res = usecase.exec(create_ctx(), create_input()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_provision_user_without_name() {
+        // ユーザー作成ユースケースのモック化（姓名がない場合は仮の値が設定されること）
+        let mut mock_user_create = MockUserCreateUsecaseTrait::new();
+        mock_user_create
+            .expect_create_user()
+            .withf(|_, input| {
+                input.last_name == NAME_PLACEHOLDER && input.first_name == NAME_PLACEHOLDER
+            })
+            .times(1)
+            .returning(|_, input| {
+                Ok(User::new(
+                    "xxxx-xxxx-xxxx-0009".to_string(),
+                    input.last_name,
+                    input.first_name,
+                    input.email,
+                ))
+            });
+
+        // テスト実行
+        let user_info = OidcUserInfo {
+            given_name: None,
+            family_name: Some("".to_string()),
+            ..create_user_info("h.suzuki@example.com", Some(true))
+        };
+        let usecase = create_usecase(Some(OidcSession::new()), user_info, mock_user_create);
+        let res = usecase.exec(create_ctx(), create_input()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_exec_provider_error() {
+        // テスト実行（IDプロバイダーで認証が拒否された）
+        let usecase = create_usecase(
+            Some(OidcSession::new()),
+            create_user_info("t.tanaka@example.com", Some(true)),
+            MockUserCreateUsecaseTrait::new(),
+        );
+        let input = AuthOidcCallbackInput {
+            code: None,
+            state: Some("test-state".to_string()),
+            error: Some("access_denied".to_string()),
+        };
+        let res = usecase.exec(create_ctx(), input).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,32 @@
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// IDトークンから取得したユーザー情報
+#[derive(Clone, Debug, PartialEq)]
+pub struct OidcUserInfo {
+    pub subject: String,
+    pub email: String,
+    pub email_verified: Option<bool>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+}
+
+// OIDCクライアントのトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthOidcTrait: Send + Sync {
+    // 認可エンドポイントのURLを作成する（PKCEのcode_challengeはS256）
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, ErrorCommon>;
+    // 認可コードをトークンに交換し、IDトークンを検証してユーザー情報を返す
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcUserInfo, ErrorCommon>;
+}
@@ -0,0 +1,16 @@
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// パスワードハッシュのトレイト（モック化もできるように定義）
+// ※ハッシュ化・検証は負荷が高いため、非同期ランタイムをブロックしないように非同期で定義
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthPasswordTrait: Send + Sync {
+    // パスワードをハッシュ化する
+    async fn hash(&self, password: &str) -> Result<String, ErrorCommon>;
+    // パスワードとハッシュ値が一致するかどうか
+    async fn verify(&self, password: &str, password_hash: &str) -> bool;
+    // 検証対象のハッシュ値がない場合に同等の時間をかけてダミーの検証を行う
+    // ※応答時間からメールアドレスの存在有無を推測されないようにする
+    async fn dummy_verify(&self, password: &str);
+}
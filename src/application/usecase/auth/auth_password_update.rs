@@ -0,0 +1,243 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::credential::{
+    credential_model::Credential, credential_repository::CredentialRepositoryTrait,
+    refresh_token_repository::RefreshTokenRepositoryTrait,
+};
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::user::user_repository::UserRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// パスワードハッシュ
+use crate::application::usecase::auth::auth_password_trait::AuthPasswordTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize)]
+pub struct AuthPasswordUpdateInput {
+    pub password: String,
+    // 現在のパスワード（本人が変更する場合は必須）
+    #[serde(default)]
+    pub current_password: Option<String>,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthPasswordUpdateUsecaseTrait {
+    async fn exec(
+        &self,
+        ctx: ContextRequest,
+        uid: String,
+        input: AuthPasswordUpdateInput,
+    ) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct AuthPasswordUpdateRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+    pub credential_repository: Arc<dyn CredentialRepositoryTrait + 'static>,
+    pub refresh_token_repository: Arc<dyn RefreshTokenRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct AuthPasswordUpdateUsecase {
+    pub repo: AuthPasswordUpdateRepository,
+    pub auth_password: Arc<dyn AuthPasswordTrait + 'static>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AuthPasswordUpdateUsecase {
+    pub fn new(
+        repo: AuthPasswordUpdateRepository,
+        auth_password: Arc<dyn AuthPasswordTrait + 'static>,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        AuthPasswordUpdateUsecase {
+            repo,
+            auth_password,
+            logger,
+        }
+    }
+
+    // 現在のパスワードの確認（本人が変更する場合のみ）
+    async fn verify_current_password(
+        &self,
+        ctx: &ContextRequest,
+        uid: &str,
+        credential: Option<&Credential>,
+        current_password: Option<&str>,
+    ) -> Result<(), ErrorCommon> {
+        // 他人のパスワードの変更（管理者）またはパスワードが未設定の場合は確認不要
+        let is_owner = ctx.auth.as_ref().map(|auth| auth.subject.as_str()) == Some(uid);
+        let credential = match credential {
+            Some(credential) if is_owner => credential,
+            _ => return Ok(()),
+        };
+
+        let current_password = match current_password {
+            Some(current_password) if !current_password.is_empty() => current_password,
+            _ => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::BAD_REQUEST,
+                    message: "現在のパスワードを指定して下さい。".to_string(),
+                });
+            }
+        };
+
+        if !self
+            .auth_password
+            .verify(current_password, &credential.password_hash)
+            .await
+        {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::FORBIDDEN,
+                message: "現在のパスワードが正しくありません。".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    // パスワード設定処理（認証情報が未作成の場合は作成）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        uid: &str,
+        input: AuthPasswordUpdateInput,
+    ) -> Result<(), ErrorCommon> {
+        // 対象ユーザーの存在チェック
+        if self
+            .repo
+            .user_repository
+            .find_by_uid(ctx, uid)
+            .await?
+            .is_none()
+        {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::NOT_FOUND,
+                message: "対象のユーザーが存在しません。".to_string(),
+            });
+        }
+
+        // パスワードのチェック
+        if let Err(msg) = Credential::validate_password(&input.password) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_REQUEST,
+                message: msg,
+            });
+        }
+
+        // 現在のパスワードの確認
+        let credential = self
+            .repo
+            .credential_repository
+            .find_by_user_uid(ctx, uid)
+            .await?;
+        self.verify_current_password(
+            ctx,
+            uid,
+            credential.as_ref(),
+            input.current_password.as_deref(),
+        )
+        .await?;
+
+        // パスワードのハッシュ化
+        let password_hash = self.auth_password.hash(&input.password).await?;
+
+        // 認証情報の作成または更新
+        match credential {
+            Some(mut credential) => {
+                credential.change_password(password_hash);
+                self.repo
+                    .credential_repository
+                    .update(ctx, &credential)
+                    .await?;
+            }
+            None => {
+                let credential = Credential::new(uid.to_string(), password_hash);
+                self.repo
+                    .credential_repository
+                    .create(ctx, &credential)
+                    .await?;
+            }
+        }
+
+        // 発行済みのリフレッシュトークンを全て失効（他の端末のセッションを無効化）
+        self.repo
+            .refresh_token_repository
+            .revoke_all_by_user_uid(ctx, uid)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthPasswordUpdateUsecaseTrait for AuthPasswordUpdateUsecase {
+    async fn exec(
+        &self,
+        ctx: ContextRequest,
+        uid: String,
+        input: AuthPasswordUpdateInput,
+    ) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック（本人または管理者のみ変更可能）
+        if let Err(err) = authorize(&ctx, Permission::PasswordUpdate, Some(&uid)) {
+            // ログ出力
+            let err_msg = format!("AuthPasswordUpdateUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // パスワード設定処理
+        match self.update(&ctx, &uid, input).await {
+            Ok(_) => (StatusCode::NO_CONTENT, res_header).into_response(),
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("AuthPasswordUpdateUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::credential::credential_model::{Credential, MAX_FAILED_ATTEMPTS};
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::credential::credential_repository::MockCredentialRepositoryTrait;
+    use crate::domain::credential::refresh_token_repository::MockRefreshTokenRepositoryTrait;
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // パスワードハッシュのモック
+    use crate::application::usecase::auth::auth_password_trait::MockAuthPasswordTrait;
+
+    // ユースケース
+    use crate::application::usecase::auth::auth_password_update::AuthPasswordUpdateInput;
+    use crate::application::usecase::auth::auth_password_update::AuthPasswordUpdateRepository;
+    use crate::application::usecase::auth::auth_password_update::AuthPasswordUpdateUsecase;
+    use crate::application::usecase::auth::auth_password_update::AuthPasswordUpdateUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(subject: &str) -> ContextRequest {
+        create_ctx_with_roles(subject, &[])
+    }
+
+    // ロールを指定した共通コンテキストの作成
+    fn create_ctx_with_roles(subject: &str, roles: &[&str]) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "PUT".to_string(),
+            uri: "/api/v1/users/xxxx-xxxx-xxxx-0001/password".to_string(),
+            auth: Some(AuthContext {
+                subject: subject.to_string(),
+                scopes: vec![],
+                roles: roles.iter().map(|role| role.to_string()).collect(),
+            }),
+        }
+    }
+
+    // 入力値の作成
+    fn create_input(password: &str, current_password: Option<&str>) -> AuthPasswordUpdateInput {
+        AuthPasswordUpdateInput {
+            password: password.to_string(),
+            current_password: current_password.map(|p| p.to_string()),
+        }
+    }
+
+    // 既存の認証情報を返すリポジトリのモック
+    fn create_credential_repo() -> MockCredentialRepositoryTrait {
+        let mut mock_credential_repo = MockCredentialRepositoryTrait::new();
+        mock_credential_repo
+            .expect_find_by_user_uid()
+            .returning(|_, uid| {
+                Ok(Some(Credential::new(
+                    uid.to_string(),
+                    "old-hash".to_string(),
+                )))
+            });
+        mock_credential_repo
+    }
+
+    // ユースケースの作成
+    // revoke_times: リフレッシュトークンの一括失効が実行される回数
+    fn create_usecase(
+        mock_credential_repo: MockCredentialRepositoryTrait,
+        revoke_times: usize,
+    ) -> AuthPasswordUpdateUsecase {
+        // ユーザーリポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_find_by_uid().returning(|_, uid| {
+            Ok(Some(User::new(
+                uid.to_string(),
+                "田中".to_string(),
+                "太郎".to_string(),
+                "t.tanaka@example.com".to_string(),
+            )))
+        });
+
+        // パスワードハッシュのモック化
+        let mut mock_auth_password = MockAuthPasswordTrait::new();
+        mock_auth_password
+            .expect_hash()
+            .returning(|password| Ok(format!("hash-{}", password)));
+        mock_auth_password
+            .expect_verify()
+            .returning(|password, hash| password == "current-0001" && hash == "old-hash");
+
+        // リフレッシュトークンリポジトリのモック化
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_revoke_all_by_user_uid()
+            .withf(|_, uid| uid == "xxxx-xxxx-xxxx-0001")
+            .times(revoke_times)
+            .returning(|_, _| Ok(()));
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        AuthPasswordUpdateUsecase {
+            repo: AuthPasswordUpdateRepository {
+                user_repository: Arc::new(mock_user_repo),
+                credential_repository: Arc::new(mock_credential_repo),
+                refresh_token_repository: Arc::new(mock_refresh_token_repo),
+            },
+            auth_password: Arc::new(mock_auth_password),
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_create_credential() {
+        // リポジトリのモック化（認証情報が未作成の場合は作成）
+        let mut mock_credential_repo = MockCredentialRepositoryTrait::new();
+        mock_credential_repo
+            .expect_find_by_user_uid()
+            .returning(|_, _| Ok(None));
+        mock_credential_repo
+            .expect_create()
+            .withf(|_, credential| credential.password_hash == "hash-password-0001")
+            .times(1)
+            .returning(|_, credential| Ok(credential.clone()));
+
+        // テスト実行
+        let usecase = create_usecase(mock_credential_repo, 1);
+        let res = usecase
+            .exec(
+                create_ctx("xxxx-xxxx-xxxx-0001"),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("password-0001", None),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_update_credential() {
+        // リポジトリのモック化（既存の認証情報はパスワード変更とロック解除）
+        let mut mock_credential_repo = MockCredentialRepositoryTrait::new();
+        mock_credential_repo
+            .expect_find_by_user_uid()
+            .returning(|_, uid| {
+                let mut credential = Credential::new(uid.to_string(), "old-hash".to_string());
+                for _ in 0..MAX_FAILED_ATTEMPTS {
+                    credential.record_failure(credential.created_at);
+                }
+                Ok(Some(credential))
+            });
+        mock_credential_repo
+            .expect_update()
+            .withf(|_, credential| {
+                credential.password_hash == "hash-password-0002"
+                    && credential.locked_until.is_none()
+            })
+            .times(1)
+            .returning(|_, credential| Ok(credential.clone()));
+
+        // テスト実行
+        let usecase = create_usecase(mock_credential_repo, 1);
+        let res = usecase
+            .exec(
+                create_ctx("xxxx-xxxx-xxxx-0001"),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("password-0002", Some("current-0001")),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_bad_request() {
+        // テスト実行
+        let usecase = create_usecase(MockCredentialRepositoryTrait::new(), 0);
+        let res = usecase
+            .exec(
+                create_ctx("xxxx-xxxx-xxxx-0001"),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("short", None),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行（他人のパスワードは変更不可）
+        let usecase = create_usecase(MockCredentialRepositoryTrait::new(), 0);
+        let res = usecase
+            .exec(
+                create_ctx("xxxx-xxxx-xxxx-0002"),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("password-0001", None),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_exec_current_password_required() {
+        // テスト実行（本人の変更で現在のパスワードが未指定）
+        let usecase = create_usecase(create_credential_repo(), 0);
+        let res = usecase
+            .exec(
+                create_ctx("xxxx-xxxx-xxxx-0001"),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("password-0002", None),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_exec_wrong_current_password() {
+        // テスト実行（現在のパスワードが誤り）
+        let usecase = create_usecase(create_credential_repo(), 0);
+        let res = usecase
+            .exec(
+                create_ctx("xxxx-xxxx-xxxx-0001"),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("password-0002", Some("wrong-0001")),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_exec_admin() {
+        // リポジトリのモック化
+        let mut mock_credential_repo = create_credential_repo();
+        mock_credential_repo
+            .expect_update()
+            .withf(|_, credential| credential.password_hash == "hash-password-0002")
+            .times(1)
+            .returning(|_, credential| Ok(credential.clone()));
+
+        // テスト実行（管理者は現在のパスワードなしで他人のパスワードを変更可能）
+        let usecase = create_usecase(mock_credential_repo, 1);
+        let res = usecase
+            .exec(
+                create_ctx_with_roles("xxxx-xxxx-xxxx-0009", &["admin"]),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("password-0002", None),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_operator_forbidden() {
+        // テスト実行（オペレーターは他人のパスワードを変更不可）
+        let usecase = create_usecase(MockCredentialRepositoryTrait::new(), 0);
+        let res = usecase
+            .exec(
+                create_ctx_with_roles("xxxx-xxxx-xxxx-0009", &["operator"]),
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("password-0002", None),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,210 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::credential::{
+    refresh_token_model::RefreshToken, refresh_token_repository::RefreshTokenRepositoryTrait,
+};
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::user::user_repository::UserRepositoryTrait;
+
+// 認証
+use crate::application::usecase::auth::auth_token_issue::issue_tokens;
+use crate::application::usecase::auth::auth_token_trait::AuthTokenTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize)]
+pub struct AuthRefreshInput {
+    pub refresh_token: String,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthRefreshUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: AuthRefreshInput) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct AuthRefreshRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+    pub refresh_token_repository: Arc<dyn RefreshTokenRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct AuthRefreshUsecase {
+    pub repo: AuthRefreshRepository,
+    pub auth_token: Arc<dyn AuthTokenTrait + 'static>,
+    // リフレッシュトークンの有効期間（秒）
+    pub refresh_token_ttl_secs: i64,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AuthRefreshUsecase {
+    pub fn new(
+        repo: AuthRefreshRepository,
+        auth_token: Arc<dyn AuthTokenTrait + 'static>,
+        refresh_token_ttl_secs: i64,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        AuthRefreshUsecase {
+            repo,
+            auth_token,
+            refresh_token_ttl_secs,
+            logger,
+        }
+    }
+
+    // 401エラーの作成
+    fn unauthorized(message: &str) -> ErrorCommon {
+        ErrorCommon::CustomError {
+            status_code: StatusCode::UNAUTHORIZED,
+            message: message.to_string(),
+        }
+    }
+
+    // 再利用を検知した場合の処理（ファミリーを全て失効）
+    async fn revoke_reused(
+        &self,
+        ctx: &ContextRequest,
+        refresh_token: &RefreshToken,
+    ) -> Result<(), ErrorCommon> {
+        self.repo
+            .refresh_token_repository
+            .revoke_family(ctx, &refresh_token.family_id)
+            .await?;
+        let msg = format!(
+            "リフレッシュトークンの再利用を検知したため、関連するトークンを失効しました: uid={}",
+            refresh_token.user_uid
+        );
+        self.logger.warn(ctx, &msg);
+        Ok(())
+    }
+
+    // トークンの再発行処理（リフレッシュトークンのローテーション）
+    async fn refresh(
+        &self,
+        ctx: &ContextRequest,
+        input: AuthRefreshInput,
+    ) -> Result<serde_json::Value, ErrorCommon> {
+        // リフレッシュトークンの取得
+        let token_hash = RefreshToken::hash(&input.refresh_token);
+        let refresh_token = match self
+            .repo
+            .refresh_token_repository
+            .find_by_token_hash(ctx, &token_hash)
+            .await?
+        {
+            Some(refresh_token) => refresh_token,
+            None => {
+                return Err(AuthRefreshUsecase::unauthorized(
+                    "リフレッシュトークンが無効です。",
+                ));
+            }
+        };
+
+        // 再利用の検知（ローテーション済みのトークンが使われた場合はファミリーを全て失効）
+        if refresh_token.revoked_at.is_some() {
+            self.revoke_reused(ctx, &refresh_token).await?;
+            return Err(AuthRefreshUsecase::unauthorized(
+                "リフレッシュトークンが無効です。",
+            ));
+        }
+
+        // 有効期限のチェック
+        if !refresh_token.is_active(jst_now()) {
+            return Err(AuthRefreshUsecase::unauthorized(
+                "リフレッシュトークンの有効期限が切れています。",
+            ));
+        }
+
+        // 現在のトークンを失効（同時リクエストで先に失効された場合は再利用として扱う）
+        if !self
+            .repo
+            .refresh_token_repository
+            .revoke_if_active(ctx, &token_hash)
+            .await?
+        {
+            self.revoke_reused(ctx, &refresh_token).await?;
+            return Err(AuthRefreshUsecase::unauthorized(
+                "リフレッシュトークンが無効です。",
+            ));
+        }
+
+        // ユーザーの存在チェック（削除済みの場合は再発行しない）
+        if self
+            .repo
+            .user_repository
+            .find_by_uid(ctx, &refresh_token.user_uid)
+            .await?
+            .is_none()
+        {
+            return Err(AuthRefreshUsecase::unauthorized(
+                "リフレッシュトークンが無効です。",
+            ));
+        }
+
+        // アクセストークンと新しいリフレッシュトークンの発行（同じファミリーを引き継ぐ）
+        issue_tokens(
+            ctx,
+            self.auth_token.as_ref(),
+            self.repo.refresh_token_repository.as_ref(),
+            refresh_token.user_uid,
+            Some(refresh_token.family_id),
+            self.refresh_token_ttl_secs,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthRefreshUsecaseTrait for AuthRefreshUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: AuthRefreshInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定（トークンはキャッシュさせない）
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id), ("Cache-Control", "no-store")];
+
+        // トークンの再発行処理
+        match self.refresh(&ctx, input).await {
+            Ok(res_body) => (StatusCode::OK, res_header, Json(res_body)).into_response(),
+            Err(err) => {
+                // ログ出力（認証失敗は警告、それ以外はエラー）
+                let err_msg = format!("AuthRefreshUsecaseでエラー: {}", err);
+                if err.status_code().is_client_error() {
+                    self.logger.warn(&ctx, &err_msg);
+                } else {
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
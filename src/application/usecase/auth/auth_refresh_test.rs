@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::credential::refresh_token_model::RefreshToken;
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::credential::refresh_token_repository::{
+        MockRefreshTokenRepositoryTrait, RefreshTokenRepositoryTrait,
+    };
+
+    // DB・リポジトリ
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+    use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
+    use crate::infrastructure::persistence::credential::refresh_token_repository::RefreshTokenRepository;
+
+    // 認証のモック
+    use crate::application::usecase::auth::auth_token_trait::{AccessToken, MockAuthTokenTrait};
+
+    // ユースケース
+    use crate::application::usecase::auth::auth_refresh::AuthRefreshInput;
+    use crate::application::usecase::auth::auth_refresh::AuthRefreshRepository;
+    use crate::application::usecase::auth::auth_refresh::AuthRefreshUsecase;
+    use crate::application::usecase::auth::auth_refresh::AuthRefreshUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/auth/refresh".to_string(),
+            auth: None,
+        }
+    }
+
+    // ユースケースの作成
+    fn create_usecase(
+        refresh_token_repo: impl RefreshTokenRepositoryTrait + 'static,
+    ) -> AuthRefreshUsecase {
+        // ユーザーリポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_find_by_uid().returning(|_, uid| {
+            Ok(Some(User::new(
+                uid.to_string(),
+                "田中".to_string(),
+                "太郎".to_string(),
+                "t.tanaka@example.com".to_string(),
+            )))
+        });
+
+        // 認証のモック化
+        let mut mock_auth_token = MockAuthTokenTrait::new();
+        mock_auth_token.expect_issue().returning(|auth| {
+            Ok(AccessToken {
+                token: format!("token-{}", auth.subject),
+                expires_in: 900,
+            })
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        AuthRefreshUsecase {
+            repo: AuthRefreshRepository {
+                user_repository: Arc::new(mock_user_repo),
+                refresh_token_repository: Arc::new(refresh_token_repo),
+            },
+            auth_token: Arc::new(mock_auth_token),
+            refresh_token_ttl_secs: 3600,
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_rotation() {
+        let (refresh_token, plain_token) =
+            RefreshToken::issue("xxxx-xxxx-xxxx-0001".to_string(), None, 3600);
+        let family_id = refresh_token.family_id.clone();
+
+        // リポジトリのモック化（現在のトークンを失効し、同じファミリーで新しいトークンを作成）
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_find_by_token_hash()
+            .returning(move |_, _| Ok(Some(refresh_token.clone())));
+        let expected_token_hash = RefreshToken::hash(&plain_token);
+        mock_refresh_token_repo
+            .expect_revoke_if_active()
+            .withf(move |_, token_hash| token_hash == expected_token_hash)
+            .times(1)
+            .returning(|_, _| Ok(true));
+        let expected_family_id = family_id.clone();
+        mock_refresh_token_repo
+            .expect_create()
+            .withf(move |_, refresh_token| {
+                refresh_token.family_id == expected_family_id && refresh_token.revoked_at.is_none()
+            })
+            .times(1)
+            .returning(|_, refresh_token| Ok(refresh_token.clone()));
+
+        // テスト実行
+        let usecase = create_usecase(mock_refresh_token_repo);
+        let input = AuthRefreshInput {
+            refresh_token: plain_token.clone(),
+        };
+        let res = usecase.exec(create_ctx(), input).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data["access_token"], "token-xxxx-xxxx-xxxx-0001");
+        assert_ne!(res_data["refresh_token"], plain_token.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_exec_reuse_detected() {
+        // ローテーション済みのトークン
+        let (mut refresh_token, plain_token) =
+            RefreshToken::issue("xxxx-xxxx-xxxx-0001".to_string(), None, 3600);
+        refresh_token.revoke();
+        let family_id = refresh_token.family_id.clone();
+
+        // リポジトリのモック化（ファミリーが一括で失効されること）
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_find_by_token_hash()
+            .returning(move |_, _| Ok(Some(refresh_token.clone())));
+        mock_refresh_token_repo
+            .expect_revoke_family()
+            .withf(move |_, id| id == family_id)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // テスト実行
+        let usecase = create_usecase(mock_refresh_token_repo);
+        let input = AuthRefreshInput {
+            refresh_token: plain_token,
+        };
+        let res = usecase.exec(create_ctx(), input).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exec_revoked_concurrently() {
+        let (refresh_token, plain_token) =
+            RefreshToken::issue("xxxx-xxxx-xxxx-0001".to_string(), None, 3600);
+
+        // リポジトリのモック化（取得時は有効だが、失効前に別のリクエストで失効された場合）
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_find_by_token_hash()
+            .returning(move |_, _| Ok(Some(refresh_token.clone())));
+        mock_refresh_token_repo
+            .expect_revoke_if_active()
+            .returning(|_, _| Ok(false));
+        mock_refresh_token_repo
+            .expect_revoke_family()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_refresh_token_repo.expect_create().never();
+
+        // テスト実行
+        let usecase = create_usecase(mock_refresh_token_repo);
+        let input = AuthRefreshInput {
+            refresh_token: plain_token,
+        };
+        let res = usecase.exec(create_ctx(), input).await;
+
+        // 検証（再利用として扱い、トークンを発行しない）
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_exec_concurrent_refresh() {
+        // 事前準備（ダミーDBにリフレッシュトークンを保存）
+        let db = new_db_dummy_connection().await.unwrap();
+        let (refresh_token, plain_token) =
+            RefreshToken::issue("xxxx-xxxx-xxxx-0001".to_string(), None, 3600);
+        db.refresh_tokens.write().unwrap().push(refresh_token);
+        let mut mock_repo_logger = MockLoggerTrait::new();
+        mock_repo_logger.expect_info().returning(|_, _| ());
+        let usecase = Arc::new(create_usecase(RefreshTokenRepository::new(
+            db.clone(),
+            Arc::new(mock_repo_logger),
+        )));
+
+        // テスト実行（同じトークンで同時に再発行）
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let usecase = usecase.clone();
+                let input = AuthRefreshInput {
+                    refresh_token: plain_token.clone(),
+                };
+                tokio::spawn(async move { usecase.exec(create_ctx(), input).await.status() })
+            })
+            .collect();
+        let mut statuses = Vec::new();
+        for handle in handles {
+            statuses.push(handle.await.unwrap());
+        }
+        statuses.sort();
+
+        // 検証（再発行は1回のみで、もう一方は再利用として扱う）
+        assert_eq!(statuses, vec![StatusCode::OK, StatusCode::UNAUTHORIZED]);
+        let refresh_tokens = db.refresh_tokens.read().unwrap();
+        assert_eq!(refresh_tokens.len(), 2);
+        assert!(refresh_tokens[0].revoked_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_exec_unknown_token() {
+        // リポジトリのモック化
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_find_by_token_hash()
+            .returning(|_, _| Ok(None));
+
+        // テスト実行
+        let usecase = create_usecase(mock_refresh_token_repo);
+        let input = AuthRefreshInput {
+            refresh_token: "rt_unknown".to_string(),
+        };
+        let res = usecase.exec(create_ctx(), input).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+}
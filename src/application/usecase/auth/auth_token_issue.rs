@@ -0,0 +1,45 @@
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+// ドメイン
+use crate::domain::credential::{
+    refresh_token_model::RefreshToken, refresh_token_repository::RefreshTokenRepositoryTrait,
+};
+use crate::domain::error::error_common::ErrorCommon;
+
+// 認証トークン
+use crate::application::usecase::auth::auth_token_trait::AuthTokenTrait;
+
+// アクセストークンとリフレッシュトークンの発行（ログイン・再発行の共通処理）
+// family_idが未指定の場合は新しいファミリーでリフレッシュトークンを発行
+pub async fn issue_tokens(
+    ctx: &ContextRequest,
+    auth_token: &dyn AuthTokenTrait,
+    refresh_token_repository: &dyn RefreshTokenRepositoryTrait,
+    user_uid: String,
+    family_id: Option<String>,
+    refresh_token_ttl_secs: i64,
+) -> Result<serde_json::Value, ErrorCommon> {
+    // アクセストークンの発行
+    let auth = AuthContext {
+        subject: user_uid.clone(),
+        scopes: vec![],
+        roles: vec![],
+    };
+    let access_token = auth_token.issue(&auth)?;
+
+    // リフレッシュトークンの発行
+    let (refresh_token, plain_refresh_token) =
+        RefreshToken::issue(user_uid, family_id, refresh_token_ttl_secs);
+    refresh_token_repository.create(ctx, &refresh_token).await?;
+
+    Ok(json!({
+        "access_token": access_token.token,
+        "token_type": "Bearer",
+        "expires_in": access_token.expires_in,
+        "refresh_token": plain_refresh_token,
+    }))
+}
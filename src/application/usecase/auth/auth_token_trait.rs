@@ -0,0 +1,22 @@
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::AuthContext;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// 発行したアクセストークン
+#[derive(Clone, Debug)]
+pub struct AccessToken {
+    pub token: String,
+    // 有効期間（秒）
+    pub expires_in: i64,
+}
+
+// 認証トークンのトレイト（モック化もできるように定義）
+#[mockall::automock]
+pub trait AuthTokenTrait: Send + Sync {
+    // トークンを検証して認証情報を返す
+    fn verify(&self, token: &str) -> Result<AuthContext, ErrorCommon>;
+    // 認証情報からアクセストークンを発行する
+    fn issue(&self, auth: &AuthContext) -> Result<AccessToken, ErrorCommon>;
+}
@@ -0,0 +1,17 @@
+pub mod auth_authorize;
+pub mod auth_login;
+pub mod auth_oidc_authorize;
+pub mod auth_oidc_callback;
+pub mod auth_oidc_trait;
+pub mod auth_password_trait;
+pub mod auth_password_update;
+pub mod auth_refresh;
+pub mod auth_token_issue;
+pub mod auth_token_trait;
+
+// テストコード用のモジュール
+pub mod auth_login_test;
+pub mod auth_oidc_authorize_test;
+pub mod auth_oidc_callback_test;
+pub mod auth_password_update_test;
+pub mod auth_refresh_test;
@@ -1,12 +1,45 @@
 // axum
 use axum::{extract::Request, http::header::HeaderMap};
 
+// UUID
+use uuid::Uuid;
+
+// 認証情報の構造体
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthContext {
+    // 認証されたユーザーの識別子（JWTのsub）
+    pub subject: String,
+    pub scopes: Vec<String>,
+    // ロール名（例: "admin", "operator"）
+    pub roles: Vec<String>,
+}
+
 // 共通コンテキストの構造体
 #[derive(Clone, Debug)]
 pub struct ContextRequest {
     pub header: HeaderMap,
     pub method: String,
     pub uri: String,
+    // 認証ミドルウェアで設定（未認証の場合はNone）
+    pub auth: Option<AuthContext>,
+}
+
+impl ContextRequest {
+    // ログ出力用の実行者ID
+    pub fn actor_id(&self) -> &str {
+        match &self.auth {
+            Some(auth) => &auth.subject,
+            None => "-",
+        }
+    }
+
+    // リクエストID（X-Request-Id）
+    pub fn request_id(&self) -> &str {
+        self.header
+            .get("X-Request-Id")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("-")
+    }
 }
 
 // リクエスト用コンテキストの作成
@@ -20,5 +53,20 @@ pub fn new_context_request(req: &Request) -> ContextRequest {
         header: hm,
         method: req.method().to_string(),
         uri: req.uri().to_string(),
+        auth: None,
+    }
+}
+
+// バックグラウンド処理用コンテキストの作成（nameはログ出力のuriに設定）
+pub fn new_context_background(name: &str) -> ContextRequest {
+    let mut hm = HeaderMap::new();
+    let request_id = Uuid::new_v4().to_string();
+    hm.insert("X-Request-Id", request_id.parse().unwrap());
+
+    ContextRequest {
+        header: hm,
+        method: "-".to_string(),
+        uri: name.to_string(),
+        auth: None,
     }
 }
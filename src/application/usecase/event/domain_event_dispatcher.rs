@@ -0,0 +1,76 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::{Arc, RwLock};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::event::domain_event::DomainEvent;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ドメインイベントのハンドラーのトレイト（プロセス内で通知を受け取る）
+#[mockall::automock]
+#[async_trait]
+pub trait DomainEventHandlerTrait: Send + Sync {
+    // ハンドラー名（ログ出力用）
+    fn name(&self) -> &'static str;
+    async fn handle(&self, ctx: &ContextRequest, event: &DomainEvent) -> Result<(), ErrorCommon>;
+}
+
+// ドメインイベントのディスパッチャーのトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait]
+pub trait DomainEventDispatcherTrait: Send + Sync {
+    // ハンドラーの登録
+    fn register(&self, handler: Arc<dyn DomainEventHandlerTrait + 'static>);
+    // 登録済みのハンドラーにイベントを通知（永続化の完了後に呼び出す）
+    async fn dispatch(&self, ctx: &ContextRequest, events: Vec<DomainEvent>);
+}
+
+// ディスパッチャーの構造体
+pub struct DomainEventDispatcher {
+    handlers: RwLock<Vec<Arc<dyn DomainEventHandlerTrait + 'static>>>,
+    logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl DomainEventDispatcher {
+    pub fn new(logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        DomainEventDispatcher {
+            handlers: RwLock::new(Vec::new()),
+            logger,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventDispatcherTrait for DomainEventDispatcher {
+    fn register(&self, handler: Arc<dyn DomainEventHandlerTrait + 'static>) {
+        self.handlers.write().unwrap().push(handler);
+    }
+
+    async fn dispatch(&self, ctx: &ContextRequest, events: Vec<DomainEvent>) {
+        // ロックを保持したままawaitしないように複製
+        let handlers = self.handlers.read().unwrap().clone();
+
+        // 永続化は完了しているため、ハンドラーのエラーはログ出力のみとして後続のハンドラーも実行
+        for event in events.iter() {
+            for handler in handlers.iter() {
+                if let Err(err) = handler.handle(ctx, event).await {
+                    let err_msg = format!(
+                        "ドメインイベントのハンドラーでエラー（{}, {}）: {}",
+                        handler.name(),
+                        event.event_type(),
+                        err
+                    );
+                    self.logger.error(ctx, &err_msg);
+                }
+            }
+        }
+    }
+}
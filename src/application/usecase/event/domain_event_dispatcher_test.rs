@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ディスパッチャー
+    use crate::application::usecase::event::domain_event_dispatcher::{
+        DomainEventDispatcher, DomainEventDispatcherTrait, MockDomainEventHandlerTrait,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/users".to_string(),
+            auth: None,
+        }
+    }
+
+    // ドメインイベントを記録したユーザーの作成
+    fn create_user() -> User {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "佐藤".to_string(),
+            "太郎".to_string(),
+            "t.sato@example.com".to_string(),
+        );
+        user.set_delete();
+        user
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_all_handlers() {
+        // ハンドラーのモック化（イベントの発生順に通知されること）
+        let mut seq = mockall::Sequence::new();
+        let mut mock_handler = MockDomainEventHandlerTrait::new();
+        mock_handler
+            .expect_handle()
+            .withf(|_, event| event.event_type() == "user.created")
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| Ok(()));
+        mock_handler
+            .expect_handle()
+            .withf(|_, event| event.event_type() == "user.deleted")
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| Ok(()));
+        let mut mock_handler_other = MockDomainEventHandlerTrait::new();
+        mock_handler_other
+            .expect_handle()
+            .times(2)
+            .returning(|_, _| Ok(()));
+
+        // ディスパッチャーのインスタンス化とハンドラーの登録
+        let dispatcher = DomainEventDispatcher::new(Arc::new(MockLoggerTrait::new()));
+        dispatcher.register(Arc::new(mock_handler));
+        dispatcher.register(Arc::new(mock_handler_other));
+
+        // テスト実行
+        let events = create_user().take_events();
+        dispatcher.dispatch(&create_ctx(), events).await;
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_handler_error() {
+        // ロガーのモック化（ハンドラーのエラーはログ出力のみ）
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger
+            .expect_error()
+            .withf(|_, msg| msg.contains("FailingHandler") && msg.contains("user.created"))
+            .times(1)
+            .return_const(());
+
+        // ハンドラーのモック化（エラー後も後続のハンドラーが実行されること）
+        let mut mock_handler_error = MockDomainEventHandlerTrait::new();
+        mock_handler_error
+            .expect_name()
+            .return_const("FailingHandler");
+        mock_handler_error
+            .expect_handle()
+            .withf(|_, event| event.event_type() == "user.created")
+            .returning(|_, _| {
+                Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                    message: "error".to_string(),
+                })
+            });
+        mock_handler_error.expect_handle().returning(|_, _| Ok(()));
+        let mut mock_handler = MockDomainEventHandlerTrait::new();
+        mock_handler
+            .expect_handle()
+            .times(2)
+            .returning(|_, _| Ok(()));
+
+        // ディスパッチャーのインスタンス化とハンドラーの登録
+        let dispatcher = DomainEventDispatcher::new(Arc::new(mock_logger));
+        dispatcher.register(Arc::new(mock_handler_error));
+        dispatcher.register(Arc::new(mock_handler));
+
+        // テスト実行
+        let events = create_user().take_events();
+        dispatcher.dispatch(&create_ctx(), events).await;
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_no_handlers() {
+        // ハンドラー未登録でもエラーにならないこと
+        let dispatcher = DomainEventDispatcher::new(Arc::new(MockLoggerTrait::new()));
+        let events = create_user().take_events();
+        dispatcher.dispatch(&create_ctx(), events).await;
+    }
+}
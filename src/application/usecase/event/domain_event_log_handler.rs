@@ -0,0 +1,48 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::event::domain_event::DomainEvent;
+
+// ディスパッチャー
+use crate::application::usecase::event::domain_event_dispatcher::DomainEventHandlerTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ドメインイベントをログ出力するハンドラー
+pub struct DomainEventLogHandler {
+    logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl DomainEventLogHandler {
+    pub fn new(logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        DomainEventLogHandler { logger }
+    }
+}
+
+#[async_trait]
+impl DomainEventHandlerTrait for DomainEventLogHandler {
+    fn name(&self) -> &'static str {
+        "DomainEventLogHandler"
+    }
+
+    async fn handle(&self, ctx: &ContextRequest, event: &DomainEvent) -> Result<(), ErrorCommon> {
+        let msg = format!(
+            "ドメインイベント: {} (uid={}, occurred_at={})",
+            event.event_type(),
+            event.aggregate_id(),
+            event.occurred_at().to_rfc3339()
+        );
+        self.logger.info(ctx, &msg);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// 両端キュー（再送用の通知の保持用）
+use std::collections::VecDeque;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::{Arc, Mutex};
+
+// 接続への配信用のチャネル
+use tokio::sync::broadcast;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::event::domain_event::DomainEvent;
+
+// ディスパッチャー
+use crate::application::usecase::event::domain_event_dispatcher::DomainEventHandlerTrait;
+
+// 通知の受信結果
+#[derive(Clone, Debug, PartialEq)]
+pub enum StreamItem {
+    // ドメインイベントの通知（idはLast-Event-IDに指定する値）
+    Event { id: String, event: DomainEvent },
+    // 再送できない通知がある（一覧を再取得してからidの後の通知を受信する）
+    Reset { id: String },
+}
+
+// ドメインイベントの通知のトレイト（モック化もできるように定義）
+#[mockall::automock]
+pub trait DomainEventStreamTrait: Send + Sync {
+    // 購読の開始（Last-Event-IDを指定した場合は、その後の通知から再送）
+    fn subscribe(&self, last_event_id: Option<String>) -> DomainEventSubscription;
+}
+
+// 連番付きのドメインイベント
+#[derive(Clone)]
+struct StreamEntry {
+    seq: u64,
+    event: DomainEvent,
+}
+
+// 再送用に保持する通知（古いものから削除）
+struct StreamBuffer {
+    latest_seq: u64,
+    entries: VecDeque<StreamEntry>,
+    capacity: usize,
+}
+
+impl StreamBuffer {
+    fn push(&mut self, event: DomainEvent) -> StreamEntry {
+        self.latest_seq += 1;
+        let entry = StreamEntry {
+            seq: self.latest_seq,
+            event,
+        };
+
+        if self.capacity > 0 {
+            if self.entries.len() >= self.capacity {
+                self.entries.pop_front();
+            }
+            self.entries.push_back(entry.clone());
+        }
+
+        entry
+    }
+
+    // 連番より後の通知の取得（保持していない通知がある場合はNone）
+    fn after(&self, seq: u64) -> Option<VecDeque<StreamEntry>> {
+        if seq > self.latest_seq {
+            return None;
+        }
+        if seq == self.latest_seq {
+            return Some(VecDeque::new());
+        }
+
+        match self.entries.front() {
+            Some(oldest) if oldest.seq <= seq + 1 => Some(
+                self.entries
+                    .iter()
+                    .filter(|entry| entry.seq > seq)
+                    .cloned()
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+// プロセス内のドメインイベントの通知（ディスパッチャーにハンドラーとして登録）
+pub struct DomainEventStream {
+    // プロセスの起動ごとの識別子（再起動前のLast-Event-IDを判別）
+    epoch: String,
+    buffer: Arc<Mutex<StreamBuffer>>,
+    sender: broadcast::Sender<StreamEntry>,
+}
+
+impl DomainEventStream {
+    // buffer_size: 再送用に保持する最大件数、channel_capacity: 接続ごとの未送信の通知の上限
+    pub fn new(buffer_size: usize, channel_capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(channel_capacity.max(1));
+        DomainEventStream {
+            epoch: chrono::Utc::now().timestamp_millis().to_string(),
+            buffer: Arc::new(Mutex::new(StreamBuffer {
+                latest_seq: 0,
+                entries: VecDeque::new(),
+                capacity: buffer_size,
+            })),
+            sender,
+        }
+    }
+
+    // Last-Event-IDから連番を取得（「{識別子}-{連番}」形式で識別子が一致する場合のみ）
+    fn parse_event_id(&self, event_id: &str) -> Option<u64> {
+        let (epoch, seq) = event_id.trim().split_once('-')?;
+        if epoch != self.epoch {
+            return None;
+        }
+        seq.parse::<u64>().ok()
+    }
+}
+
+impl DomainEventStreamTrait for DomainEventStream {
+    fn subscribe(&self, last_event_id: Option<String>) -> DomainEventSubscription {
+        // 保持している通知の取得と購読の開始の間に通知されないようにロック
+        let buffer = self.buffer.lock().unwrap();
+        let receiver = self.sender.subscribe();
+
+        let resumed = last_event_id
+            .as_deref()
+            .and_then(|event_id| self.parse_event_id(event_id))
+            .and_then(|seq| buffer.after(seq).map(|pending| (seq, pending)));
+
+        let (last_seq, pending, reset) = match (last_event_id, resumed) {
+            (_, Some((seq, pending))) => (seq, pending, false),
+            // 再送できない場合（保持件数の超過、再起動前のID等）は再取得を促す
+            (Some(_), None) => (buffer.latest_seq, VecDeque::new(), true),
+            (None, None) => (buffer.latest_seq, VecDeque::new(), false),
+        };
+
+        DomainEventSubscription {
+            epoch: self.epoch.clone(),
+            last_seq,
+            reset,
+            pending,
+            receiver,
+            buffer: self.buffer.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventHandlerTrait for DomainEventStream {
+    fn name(&self) -> &'static str {
+        "DomainEventStream"
+    }
+
+    async fn handle(&self, _ctx: &ContextRequest, event: &DomainEvent) -> Result<(), ErrorCommon> {
+        // 購読の開始と順序が前後しないようにロックしたまま配信
+        let mut buffer = self.buffer.lock().unwrap();
+        let entry = buffer.push(event.clone());
+
+        // 接続がない場合のエラーは無視
+        let _ = self.sender.send(entry);
+
+        Ok(())
+    }
+}
+
+// ドメインイベントの通知の購読（接続ごとに作成）
+pub struct DomainEventSubscription {
+    epoch: String,
+    // 送信済みの最後の連番
+    last_seq: u64,
+    reset: bool,
+    pending: VecDeque<StreamEntry>,
+    receiver: broadcast::Receiver<StreamEntry>,
+    buffer: Arc<Mutex<StreamBuffer>>,
+}
+
+impl DomainEventSubscription {
+    fn event_id(&self, seq: u64) -> String {
+        format!("{}-{}", self.epoch, seq)
+    }
+
+    // 次の通知の受信（通知元が破棄された場合はNone）
+    pub async fn next(&mut self) -> Option<StreamItem> {
+        if self.reset {
+            self.reset = false;
+            return Some(StreamItem::Reset {
+                id: self.event_id(self.last_seq),
+            });
+        }
+
+        loop {
+            let entry = match self.pending.pop_front() {
+                Some(entry) => entry,
+                None => match self.receiver.recv().await {
+                    Ok(entry) => entry,
+                    // 受信が遅れて破棄された通知は保持している通知から再送（保持していない場合は再取得を促す）
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        let buffer = self.buffer.lock().unwrap();
+                        match buffer.after(self.last_seq) {
+                            Some(pending) => {
+                                self.pending = pending;
+                                continue;
+                            }
+                            None => {
+                                self.last_seq = buffer.latest_seq;
+                                drop(buffer);
+                                return Some(StreamItem::Reset {
+                                    id: self.event_id(self.last_seq),
+                                });
+                            }
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                },
+            };
+
+            // 再送済みの通知は読み飛ばす
+            if entry.seq <= self.last_seq {
+                continue;
+            }
+            self.last_seq = entry.seq;
+
+            return Some(StreamItem::Event {
+                id: self.event_id(entry.seq),
+                event: entry.event,
+            });
+        }
+    }
+}
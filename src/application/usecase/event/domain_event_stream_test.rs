@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::event::domain_event::DomainEvent;
+    use crate::domain::user::user_model::User;
+
+    // ディスパッチャー
+    use crate::application::usecase::event::domain_event_dispatcher::DomainEventHandlerTrait;
+
+    // ドメインイベントの通知
+    use crate::application::usecase::event::domain_event_stream::{
+        DomainEventStream, DomainEventStreamTrait, StreamItem,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/users".to_string(),
+            auth: None,
+        }
+    }
+
+    // ユーザー作成イベントの作成
+    fn create_event(uid: &str) -> DomainEvent {
+        let mut user = User::new(
+            uid.to_string(),
+            "佐藤".to_string(),
+            "太郎".to_string(),
+            "t.sato@example.com".to_string(),
+        );
+        user.take_events().remove(0)
+    }
+
+    // 通知の取得（通知でない場合はエラー）
+    fn unwrap_event(item: Option<StreamItem>) -> (String, DomainEvent) {
+        match item {
+            Some(StreamItem::Event { id, event }) => (id, event),
+            other => panic!("通知ではありません: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_receives_events() {
+        let stream = DomainEventStream::new(10, 10);
+        let ctx = create_ctx();
+
+        // 購読開始前の通知は受信しないこと
+        stream
+            .handle(&ctx, &create_event("xxxx-xxxx-xxxx-0001"))
+            .await
+            .unwrap();
+        let mut subscription = stream.subscribe(None);
+
+        stream
+            .handle(&ctx, &create_event("xxxx-xxxx-xxxx-0002"))
+            .await
+            .unwrap();
+        stream
+            .handle(&ctx, &create_event("xxxx-xxxx-xxxx-0003"))
+            .await
+            .unwrap();
+
+        // 検証（発生順に受信し、idは連番になること）
+        let (id1, event1) = unwrap_event(subscription.next().await);
+        assert_eq!(event1.aggregate_id(), "xxxx-xxxx-xxxx-0002");
+        assert!(id1.ends_with("-2"));
+
+        let (id2, event2) = unwrap_event(subscription.next().await);
+        assert_eq!(event2.aggregate_id(), "xxxx-xxxx-xxxx-0003");
+        assert!(id2.ends_with("-3"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resume_from_last_event_id() {
+        let stream = DomainEventStream::new(10, 10);
+        let ctx = create_ctx();
+
+        // 1件目のidを取得
+        let mut subscription = stream.subscribe(None);
+        for uid in [
+            "xxxx-xxxx-xxxx-0001",
+            "xxxx-xxxx-xxxx-0002",
+            "xxxx-xxxx-xxxx-0003",
+        ] {
+            stream.handle(&ctx, &create_event(uid)).await.unwrap();
+        }
+        let (last_event_id, _) = unwrap_event(subscription.next().await);
+        drop(subscription);
+
+        // 再接続（Last-Event-IDの後の通知から再送されること）
+        let mut subscription = stream.subscribe(Some(last_event_id));
+        let (_, event) = unwrap_event(subscription.next().await);
+        assert_eq!(event.aggregate_id(), "xxxx-xxxx-xxxx-0002");
+        let (_, event) = unwrap_event(subscription.next().await);
+        assert_eq!(event.aggregate_id(), "xxxx-xxxx-xxxx-0003");
+
+        // 再送後は新しい通知を受信すること
+        stream
+            .handle(&ctx, &create_event("xxxx-xxxx-xxxx-0004"))
+            .await
+            .unwrap();
+        let (_, event) = unwrap_event(subscription.next().await);
+        assert_eq!(event.aggregate_id(), "xxxx-xxxx-xxxx-0004");
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_reset_unknown_last_event_id() {
+        let stream = DomainEventStream::new(1, 10);
+        let ctx = create_ctx();
+
+        let mut subscription = stream.subscribe(None);
+        for uid in [
+            "xxxx-xxxx-xxxx-0001",
+            "xxxx-xxxx-xxxx-0002",
+            "xxxx-xxxx-xxxx-0003",
+        ] {
+            stream.handle(&ctx, &create_event(uid)).await.unwrap();
+        }
+        let (first_event_id, _) = unwrap_event(subscription.next().await);
+        let (_, _) = unwrap_event(subscription.next().await);
+        let (latest_event_id, _) = unwrap_event(subscription.next().await);
+
+        // 保持件数を超えたID、再起動前のID、不正なIDは再取得を促すこと
+        for last_event_id in [first_event_id, "0-1".to_string(), "xxx".to_string()] {
+            let mut subscription = stream.subscribe(Some(last_event_id));
+            assert_eq!(
+                subscription.next().await,
+                Some(StreamItem::Reset {
+                    id: latest_event_id.clone()
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_lagged_resend_from_buffer() {
+        // 接続ごとの未送信の上限（1件）を超えても、保持している通知から再送されること
+        let stream = DomainEventStream::new(10, 1);
+        let ctx = create_ctx();
+
+        let mut subscription = stream.subscribe(None);
+        for uid in [
+            "xxxx-xxxx-xxxx-0001",
+            "xxxx-xxxx-xxxx-0002",
+            "xxxx-xxxx-xxxx-0003",
+        ] {
+            stream.handle(&ctx, &create_event(uid)).await.unwrap();
+        }
+
+        // 検証（欠落・重複なく発生順に受信すること）
+        for uid in [
+            "xxxx-xxxx-xxxx-0001",
+            "xxxx-xxxx-xxxx-0002",
+            "xxxx-xxxx-xxxx-0003",
+        ] {
+            let (_, event) = unwrap_event(subscription.next().await);
+            assert_eq!(event.aggregate_id(), uid);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_lagged_reset() {
+        // 保持件数（1件）を超えて受信が遅れた場合は再取得を促すこと
+        let stream = DomainEventStream::new(1, 1);
+        let ctx = create_ctx();
+
+        let mut subscription = stream.subscribe(None);
+        for uid in [
+            "xxxx-xxxx-xxxx-0001",
+            "xxxx-xxxx-xxxx-0002",
+            "xxxx-xxxx-xxxx-0003",
+        ] {
+            stream.handle(&ctx, &create_event(uid)).await.unwrap();
+        }
+
+        // 検証（リセット後は新しい通知から受信すること）
+        match subscription.next().await {
+            Some(StreamItem::Reset { id }) => assert!(id.ends_with("-3")),
+            other => panic!("リセットではありません: {:?}", other),
+        }
+        stream
+            .handle(&ctx, &create_event("xxxx-xxxx-xxxx-0004"))
+            .await
+            .unwrap();
+        let (_, event) = unwrap_event(subscription.next().await);
+        assert_eq!(event.aggregate_id(), "xxxx-xxxx-xxxx-0004");
+    }
+}
@@ -0,0 +1,58 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage};
+
+// メッセージブローカーに送信するメッセージ
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventMessage {
+    // 送信先（NATSのsubject、Kafkaのtopic）
+    pub topic: String,
+    // パーティションキー（集約ID、同じ集約のイベントの順序を保証）
+    pub key: String,
+    pub headers: Vec<(String, String)>,
+    pub payload: Vec<u8>,
+}
+
+impl EventMessage {
+    // アウトボックスのメッセージから作成（送信先は「{プレフィックス}.{イベント種別}」）
+    pub fn from_outbox(topic_prefix: &str, message: &OutboxMessage) -> Self {
+        Self {
+            topic: format!("{}.{}", topic_prefix, message.event_type),
+            key: message.aggregate_id.clone(),
+            headers: vec![
+                ("Content-Type".to_string(), "application/json".to_string()),
+                ("Event-Id".to_string(), message.event_id.clone()),
+                ("Event-Type".to_string(), message.event_type.clone()),
+            ],
+            payload: message.envelope().to_string().into_bytes(),
+        }
+    }
+
+    // ヘッダーの値の取得
+    #[cfg_attr(not(feature = "nats"), allow(dead_code))]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+// メッセージブローカーへの送信用のトレイト（ブローカーを差し替えできるように定義）
+#[mockall::automock]
+#[async_trait]
+pub trait EventPublisherTrait: Send + Sync {
+    // ブローカー名（ログ出力用）
+    fn name(&self) -> &'static str;
+    // メッセージの送信（ブローカーが受領を確認した場合のみOk）
+    async fn publish(
+        &self,
+        ctx: &ContextRequest,
+        message: &EventMessage,
+    ) -> Result<(), ErrorCommon>;
+}
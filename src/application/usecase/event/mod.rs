@@ -0,0 +1,10 @@
+pub mod domain_event_dispatcher;
+pub mod domain_event_log_handler;
+pub mod domain_event_stream;
+pub mod event_publisher_trait;
+pub mod user_live_hub;
+
+// テストコード用のモジュール
+pub mod domain_event_dispatcher_test;
+pub mod domain_event_stream_test;
+pub mod user_live_hub_test;
@@ -0,0 +1,117 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// ハッシュマップ
+use std::collections::HashMap;
+
+// Mutex（複数のスレッド間で安全に値を更新するためのロック）
+use std::sync::Mutex;
+
+// 接続への配信用のチャネル
+use tokio::sync::broadcast;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::event::domain_event::DomainEvent;
+
+// ディスパッチャー
+use crate::application::usecase::event::domain_event_dispatcher::DomainEventHandlerTrait;
+
+// 接続に配信するメッセージ
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiveMessage {
+    // ユーザーの変更（ドメインイベント）
+    Event(DomainEvent),
+    // ユーザーの接続状態の変化（最初の接続でtrue、最後の切断でfalse）
+    Presence { uid: String, online: bool },
+}
+
+// ユーザーの変更と接続状態の配信のトレイト（モック化もできるように定義）
+#[mockall::automock]
+pub trait UserLiveHubTrait: Send + Sync {
+    // 配信の受信の開始
+    fn subscribe(&self) -> broadcast::Receiver<LiveMessage>;
+    // ユーザーの接続（接続数を加算）
+    fn join(&self, uid: &str);
+    // ユーザーの切断（接続数を減算）
+    fn leave(&self, uid: &str);
+    // ユーザーが接続中かどうか
+    fn is_online(&self, uid: &str) -> bool;
+}
+
+// プロセス内のユーザーの変更と接続状態の配信（ディスパッチャーにハンドラーとして登録）
+pub struct UserLiveHub {
+    // ユーザーごとの接続数
+    connections: Mutex<HashMap<String, usize>>,
+    sender: broadcast::Sender<LiveMessage>,
+}
+
+impl UserLiveHub {
+    // channel_capacity: 接続ごとの未送信のメッセージの上限
+    pub fn new(channel_capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(channel_capacity.max(1));
+        UserLiveHub {
+            connections: Mutex::new(HashMap::new()),
+            sender,
+        }
+    }
+}
+
+impl UserLiveHubTrait for UserLiveHub {
+    fn subscribe(&self) -> broadcast::Receiver<LiveMessage> {
+        self.sender.subscribe()
+    }
+
+    fn join(&self, uid: &str) {
+        let mut connections = self.connections.lock().unwrap();
+        let count = connections.entry(uid.to_string()).or_insert(0);
+        *count += 1;
+
+        // 最初の接続の場合のみ通知（接続がない場合のエラーは無視）
+        if *count == 1 {
+            let _ = self.sender.send(LiveMessage::Presence {
+                uid: uid.to_string(),
+                online: true,
+            });
+        }
+    }
+
+    fn leave(&self, uid: &str) {
+        let mut connections = self.connections.lock().unwrap();
+        let count = match connections.get_mut(uid) {
+            Some(count) => count,
+            None => return,
+        };
+        *count -= 1;
+
+        // 最後の切断の場合のみ通知
+        if *count == 0 {
+            connections.remove(uid);
+            let _ = self.sender.send(LiveMessage::Presence {
+                uid: uid.to_string(),
+                online: false,
+            });
+        }
+    }
+
+    fn is_online(&self, uid: &str) -> bool {
+        self.connections.lock().unwrap().contains_key(uid)
+    }
+}
+
+#[async_trait]
+impl DomainEventHandlerTrait for UserLiveHub {
+    fn name(&self) -> &'static str {
+        "UserLiveHub"
+    }
+
+    async fn handle(&self, _ctx: &ContextRequest, event: &DomainEvent) -> Result<(), ErrorCommon> {
+        // 接続がない場合のエラーは無視
+        let _ = self.sender.send(LiveMessage::Event(event.clone()));
+
+        Ok(())
+    }
+}
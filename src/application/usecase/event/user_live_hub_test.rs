@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::user::user_model::User;
+
+    // ディスパッチャー
+    use crate::application::usecase::event::domain_event_dispatcher::DomainEventHandlerTrait;
+
+    // ユーザーの変更と接続状態の配信
+    use crate::application::usecase::event::user_live_hub::{
+        LiveMessage, UserLiveHub, UserLiveHubTrait,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/users".to_string(),
+            auth: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_broadcast_event() {
+        let hub = UserLiveHub::new(10);
+        let mut receiver = hub.subscribe();
+
+        // ドメインイベントの通知
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        );
+        let event = user.take_events().remove(0);
+        hub.handle(&create_ctx(), &event).await.unwrap();
+
+        // 検証
+        assert_eq!(receiver.recv().await.unwrap(), LiveMessage::Event(event));
+    }
+
+    #[tokio::test]
+    async fn test_join_leave_presence() {
+        let hub = UserLiveHub::new(10);
+        let mut receiver = hub.subscribe();
+
+        // 同じユーザーの複数の接続
+        hub.join("xxxx-xxxx-xxxx-0001");
+        hub.join("xxxx-xxxx-xxxx-0001");
+        assert!(hub.is_online("xxxx-xxxx-xxxx-0001"));
+
+        // 最後の接続が切断されるまで接続中であること
+        hub.leave("xxxx-xxxx-xxxx-0001");
+        assert!(hub.is_online("xxxx-xxxx-xxxx-0001"));
+        hub.leave("xxxx-xxxx-xxxx-0001");
+        assert!(!hub.is_online("xxxx-xxxx-xxxx-0001"));
+
+        // 未接続のユーザーの切断は無視
+        hub.leave("xxxx-xxxx-xxxx-0002");
+
+        // 検証（最初の接続と最後の切断のみ通知されること）
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            LiveMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0001".to_string(),
+                online: true,
+            }
+        );
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            LiveMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0001".to_string(),
+                online: false,
+            }
+        );
+        assert!(receiver.try_recv().is_err());
+    }
+}
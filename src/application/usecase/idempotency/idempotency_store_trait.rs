@@ -0,0 +1,43 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// 保存したレスポンス（リトライ時に再送する）
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdempotencyResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+// 処理開始時の判定結果
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdempotencyBegin {
+    // 初回のリクエスト（処理を実行する）
+    Started,
+    // 同じキーのリクエストを処理中
+    InFlight,
+    // 処理済み（保存したレスポンスを再送する）
+    Completed(IdempotencyResponse),
+    // 同じキーが異なるリクエスト内容で使用された
+    Mismatch,
+}
+
+// 冪等キーのストアのトレイト（共有ストアに差し替えできるように定義）
+#[mockall::automock]
+#[async_trait]
+pub trait IdempotencyStoreTrait: Send + Sync {
+    // キーを処理中として登録する（登録済みの場合は状態を返す）
+    async fn begin(
+        &self,
+        key: &str,
+        request_hash: &str,
+        ttl_secs: u64,
+    ) -> Result<IdempotencyBegin, ErrorCommon>;
+    // 処理結果のレスポンスを保存する
+    async fn complete(&self, key: &str, response: IdempotencyResponse) -> Result<(), ErrorCommon>;
+    // 処理中のキーを削除する（処理に失敗した場合にリトライできるようにする）
+    async fn release(&self, key: &str) -> Result<(), ErrorCommon>;
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use serde_json::json;
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::job::job_model::Job;
+    use crate::domain::time::time_jst::jst_now;
+
+    // リポジトリのモック
+    use crate::domain::job::job_repository::MockJobRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::job::job_dead_letter_find_all::{
+        JobDeadLetterFindAllRepository, JobDeadLetterFindAllUsecase,
+        JobDeadLetterFindAllUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/admin/jobs/dead-letter".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase() -> JobDeadLetterFindAllUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockJobRepositoryTrait::new();
+        mock_repo.expect_find_dead_lettered().returning(|_| {
+            let mut job = Job::new("test.job", json!({}), jst_now());
+            job.mark_dead_lettered(jst_now());
+            Ok(vec![job])
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        JobDeadLetterFindAllUsecase::new(
+            JobDeadLetterFindAllRepository {
+                job_repository: Arc::new(mock_repo),
+            },
+            Arc::new(mock_logger),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // テスト実行
+        let res = new_usecase().exec(new_ctx(vec!["admin".to_string()])).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data.as_array().unwrap().len(), 1);
+        assert_eq!(res_data[0]["status"], "dead_lettered");
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(vec!["operator".to_string()]))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
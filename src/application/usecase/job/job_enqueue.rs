@@ -0,0 +1,155 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 日時
+use chrono::{DateTime, FixedOffset};
+
+// json変換用マクロ
+use serde_json::{Value, json};
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::job::job_model::Job;
+use crate::domain::job::job_repository::JobRepositoryTrait;
+use crate::domain::time::time_jst::jst_now;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize, Debug)]
+pub struct JobEnqueueInput {
+    pub job_type: String,
+    #[serde(default)]
+    pub payload: Value,
+    // 実行可能日時（未指定の場合は即時）
+    pub run_at: Option<DateTime<FixedOffset>>,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait JobEnqueueUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: JobEnqueueInput) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct JobEnqueueRepository {
+    pub job_repository: Arc<dyn JobRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct JobEnqueueUsecase {
+    pub repo: JobEnqueueRepository,
+    // 登録済みのジョブの種類（ハンドラーが未登録のジョブは登録しない）
+    pub job_types: Vec<String>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl JobEnqueueUsecase {
+    pub fn new(
+        repo: JobEnqueueRepository,
+        job_types: Vec<String>,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        JobEnqueueUsecase {
+            repo,
+            job_types,
+            logger,
+        }
+    }
+
+    // ジョブの登録処理
+    async fn enqueue(
+        &self,
+        ctx: &ContextRequest,
+        input: JobEnqueueInput,
+    ) -> Result<Job, ErrorCommon> {
+        if !self.job_types.contains(&input.job_type) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_REQUEST,
+                message: format!("ジョブの種類「{}」は登録されていません。", input.job_type),
+            });
+        }
+
+        let payload = match input.payload {
+            Value::Null => json!({}),
+            payload => payload,
+        };
+        let job = Job::new(
+            &input.job_type,
+            payload,
+            input.run_at.unwrap_or_else(jst_now),
+        );
+
+        self.repo.job_repository.add(ctx, &job).await
+    }
+}
+
+#[async_trait::async_trait]
+impl JobEnqueueUsecaseTrait for JobEnqueueUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: JobEnqueueInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("JobEnqueueUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // ジョブの登録処理
+        match self.enqueue(&ctx, input).await {
+            Ok(job) => {
+                let msg = format!(
+                    "ジョブを登録しました: job_id={}, job_type={}",
+                    job.job_id, job.job_type
+                );
+                self.logger.info(&ctx, &msg);
+
+                (StatusCode::CREATED, res_header, Json(json!(job))).into_response()
+            }
+            Err(err) => {
+                // エラーログ出力（入力値のエラーは除く）
+                if !err.status_code().is_client_error() {
+                    let err_msg = format!("JobEnqueueUsecaseでエラー: {}", err);
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
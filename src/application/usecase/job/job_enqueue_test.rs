@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use serde_json::json;
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // リポジトリのモック
+    use crate::domain::job::job_repository::MockJobRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::job::job_enqueue::{
+        JobEnqueueInput, JobEnqueueRepository, JobEnqueueUsecase, JobEnqueueUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/admin/jobs".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 管理者のロール
+    fn admin() -> Vec<String> {
+        vec!["admin".to_string()]
+    }
+
+    // 入力値
+    fn new_input(job_type: &str) -> JobEnqueueInput {
+        JobEnqueueInput {
+            job_type: job_type.to_string(),
+            payload: serde_json::Value::Null,
+            run_at: None,
+        }
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase() -> JobEnqueueUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockJobRepositoryTrait::new();
+        mock_repo.expect_add().returning(|_, job| {
+            let mut job = job.clone();
+            job.id = 1;
+            Ok(job)
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        JobEnqueueUsecase::new(
+            JobEnqueueRepository {
+                job_repository: Arc::new(mock_repo),
+            },
+            vec!["test.job".to_string()],
+            Arc::new(mock_logger),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(admin()), new_input("test.job"))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data["job_type"], "test.job");
+        assert_eq!(res_data["status"], "pending");
+        assert_eq!(res_data["payload"], json!({}));
+    }
+
+    #[tokio::test]
+    async fn test_exec_unknown_job_type() {
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(admin()), new_input("unknown.job"))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(vec!["operator".to_string()]), new_input("test.job"))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
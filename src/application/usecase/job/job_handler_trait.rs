@@ -0,0 +1,19 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::job::job_model::Job;
+
+// ジョブのハンドラーのトレイト（ジョブの種類ごとに実装してワーカーに登録）
+// ※失敗時はリトライするため、同じジョブが複数回実行されても問題ないように実装する
+#[mockall::automock]
+#[async_trait]
+pub trait JobHandlerTrait: Send + Sync {
+    // 処理するジョブの種類
+    fn job_type(&self) -> &'static str;
+    async fn handle(&self, ctx: &ContextRequest, job: &Job) -> Result<(), ErrorCommon>;
+}
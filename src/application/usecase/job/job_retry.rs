@@ -0,0 +1,130 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::job::job_model::Job;
+use crate::domain::job::job_repository::JobRepositoryTrait;
+use crate::domain::time::time_jst::jst_now;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait JobRetryUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, job_id: String) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct JobRetryRepository {
+    pub job_repository: Arc<dyn JobRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct JobRetryUsecase {
+    pub repo: JobRetryRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl JobRetryUsecase {
+    pub fn new(repo: JobRetryRepository, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        JobRetryUsecase { repo, logger }
+    }
+
+    // 再実行処理（試行回数をリセットして実行待ちに戻す）
+    async fn retry(&self, ctx: &ContextRequest, job_id: &str) -> Result<Job, ErrorCommon> {
+        // 対象のジョブの取得
+        let mut job = match self.repo.job_repository.find_by_job_id(ctx, job_id).await? {
+            Some(job) => job,
+            None => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::NOT_FOUND,
+                    message: "対象のジョブが存在しません。".to_string(),
+                });
+            }
+        };
+
+        let expected_locked_until = job.locked_until;
+        if let Err(msg) = job.retry(jst_now()) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::CONFLICT,
+                message: msg,
+            });
+        }
+
+        self.repo
+            .job_repository
+            .update(ctx, &job, expected_locked_until)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl JobRetryUsecaseTrait for JobRetryUsecase {
+    async fn exec(&self, ctx: ContextRequest, job_id: String) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("JobRetryUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 再実行処理（実行結果はワーカーで処理）
+        match self.retry(&ctx, &job_id).await {
+            Ok(job) => {
+                let msg = format!(
+                    "ジョブを再実行待ちに戻しました: job_id={}, job_type={}",
+                    job.job_id, job.job_type
+                );
+                self.logger.info(&ctx, &msg);
+
+                (StatusCode::OK, res_header, Json(json!(job))).into_response()
+            }
+            Err(err) => {
+                // エラーログ出力（入力値のエラーは除く）
+                if !err.status_code().is_client_error() {
+                    let err_msg = format!("JobRetryUsecaseでエラー: {}", err);
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use serde_json::json;
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::job::job_model::Job;
+    use crate::domain::time::time_jst::jst_now;
+
+    // リポジトリのモック
+    use crate::domain::job::job_repository::MockJobRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::job::job_retry::{
+        JobRetryRepository, JobRetryUsecase, JobRetryUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/admin/jobs/xxxx/retry".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 管理者のロール
+    fn admin() -> Vec<String> {
+        vec!["admin".to_string()]
+    }
+
+    // ジョブ（dead_lettered: デッドレターかどうか）
+    fn new_job(dead_lettered: bool) -> Job {
+        let mut job = Job::new("test.job", json!({}), jst_now());
+        job.attempts = 5;
+        if dead_lettered {
+            job.mark_dead_lettered(jst_now());
+        }
+        job
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase(job: Option<Job>) -> JobRetryUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockJobRepositoryTrait::new();
+        mock_repo
+            .expect_find_by_job_id()
+            .returning(move |_, _| Ok(job.clone()));
+        mock_repo
+            .expect_update()
+            .withf(|_, _, expected_locked_until| expected_locked_until.is_none())
+            .returning(|_, job, _| Ok(job.clone()));
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        JobRetryUsecase::new(
+            JobRetryRepository {
+                job_repository: Arc::new(mock_repo),
+            },
+            Arc::new(mock_logger),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let job = new_job(true);
+        let job_id = job.job_id.clone();
+
+        // テスト実行
+        let res = new_usecase(Some(job)).exec(new_ctx(admin()), job_id).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data["status"], "pending");
+        assert_eq!(res_data["attempts"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_dead_lettered() {
+        let job = new_job(false);
+        let job_id = job.job_id.clone();
+
+        // テスト実行
+        let res = new_usecase(Some(job)).exec(new_ctx(admin()), job_id).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // テスト実行
+        let res = new_usecase(None)
+            .exec(new_ctx(admin()), "xxxx".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行
+        let res = new_usecase(None)
+            .exec(new_ctx(vec!["operator".to_string()]), "xxxx".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,231 @@
+// ハッシュマップ
+use std::collections::HashMap;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// axum
+use axum::http::StatusCode;
+
+// 日時
+use chrono::{DateTime, FixedOffset};
+
+// json変換用マクロ
+use serde_json::json;
+
+// 停止の通知用のチャネル
+use tokio::sync::watch;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::{
+    ContextRequest, new_context_background,
+};
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::job::job_model::Job;
+use crate::domain::job::job_repository::JobRepositoryTrait;
+use crate::domain::job::job_schedule::CronSchedule;
+use crate::domain::time::time_jst::jst_now;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ジョブの実行スケジュール
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobSchedule {
+    pub job_type: String,
+    pub cron: CronSchedule,
+}
+
+// 実行スケジュールの設定値の解析（「ジョブの種類=cron形式」をセミコロン区切りで指定）
+// 例: "user.purge=0 3 * * *;report.daily=30 6 * * 1-5"
+pub fn parse_job_schedules(value: &str) -> Result<Vec<JobSchedule>, String> {
+    let mut schedules = Vec::new();
+
+    for rule in value
+        .split(';')
+        .map(str::trim)
+        .filter(|rule| !rule.is_empty())
+    {
+        let (job_type, expression) = match rule.split_once('=') {
+            Some((job_type, expression)) if !job_type.trim().is_empty() => {
+                (job_type.trim(), expression.trim())
+            }
+            _ => {
+                return Err(format!(
+                    "スケジュール「{}」は「ジョブの種類=cron形式」で指定して下さい。",
+                    rule
+                ));
+            }
+        };
+
+        if schedules
+            .iter()
+            .any(|schedule: &JobSchedule| schedule.job_type == job_type)
+        {
+            return Err(format!(
+                "ジョブの種類「{}」のスケジュールが重複しています。",
+                job_type
+            ));
+        }
+
+        schedules.push(JobSchedule {
+            job_type: job_type.to_string(),
+            cron: CronSchedule::parse(expression)?,
+        });
+    }
+
+    Ok(schedules)
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait JobSchedulerUsecaseTrait {
+    // 実行日時になったスケジュールのジョブを登録（登録した件数を返す）
+    async fn enqueue_due(
+        &self,
+        ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+    ) -> Result<usize, ErrorCommon>;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct JobSchedulerRepository {
+    pub job_repository: Arc<dyn JobRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct JobSchedulerUsecase {
+    pub repo: JobSchedulerRepository,
+    pub schedules: Vec<JobSchedule>,
+    // ジョブの種類ごとの次回の実行日時（初回は起動後の最初の実行日時）
+    pub next_runs: Arc<Mutex<HashMap<String, DateTime<FixedOffset>>>>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl JobSchedulerUsecase {
+    pub fn new(
+        repo: JobSchedulerRepository,
+        schedules: Vec<JobSchedule>,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        JobSchedulerUsecase {
+            repo,
+            schedules,
+            next_runs: Arc::new(Mutex::new(HashMap::new())),
+            logger,
+        }
+    }
+
+    // 次回の実行日時（未設定の場合は指定日時の後で設定）
+    fn next_run(
+        &self,
+        schedule: &JobSchedule,
+        now: DateTime<FixedOffset>,
+    ) -> Option<DateTime<FixedOffset>> {
+        let mut next_runs = self.next_runs.lock().unwrap();
+        if let Some(next_run) = next_runs.get(&schedule.job_type) {
+            return Some(*next_run);
+        }
+
+        let next_run = schedule.cron.next_after(now)?;
+        next_runs.insert(schedule.job_type.clone(), next_run);
+        Some(next_run)
+    }
+
+    // 次回の実行日時を更新（停止中に過ぎた実行日時はまとめて1回のみ実行）
+    fn advance(&self, schedule: &JobSchedule, now: DateTime<FixedOffset>) {
+        let mut next_runs = self.next_runs.lock().unwrap();
+        match schedule.cron.next_after(now) {
+            Some(next_run) => next_runs.insert(schedule.job_type.clone(), next_run),
+            None => next_runs.remove(&schedule.job_type),
+        };
+    }
+}
+
+#[async_trait::async_trait]
+impl JobSchedulerUsecaseTrait for JobSchedulerUsecase {
+    async fn enqueue_due(
+        &self,
+        ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+    ) -> Result<usize, ErrorCommon> {
+        let mut count = 0;
+
+        for schedule in self.schedules.iter() {
+            let next_run = match self.next_run(schedule, now) {
+                Some(next_run) if next_run <= now => next_run,
+                _ => continue,
+            };
+
+            // 複数のインスタンスで同じ実行日時のジョブを登録しないように一意キーを設定
+            let unique_key = format!(
+                "schedule:{}:{}",
+                schedule.job_type,
+                next_run.format("%Y%m%d%H%M")
+            );
+            let job = Job::new(&schedule.job_type, json!({ "scheduled_at": next_run }), now)
+                .with_unique_key(&unique_key);
+
+            match self.repo.job_repository.add(ctx, &job).await {
+                Ok(job) => {
+                    count += 1;
+                    let msg = format!(
+                        "スケジュールのジョブを登録しました: job_id={}, job_type={}, cron={}",
+                        job.job_id,
+                        job.job_type,
+                        schedule.cron.expression()
+                    );
+                    self.logger.info(ctx, &msg);
+                }
+                // 他のインスタンスで登録済み
+                Err(err) if err.status_code() == StatusCode::CONFLICT => {}
+                // 次回に再試行
+                Err(err) => return Err(err),
+            }
+
+            self.advance(schedule, now);
+        }
+
+        Ok(count)
+    }
+}
+
+// 実行スケジュールのジョブを一定間隔で登録（main.rsでバックグラウンドタスクとして起動）
+pub async fn run_job_scheduler<S>(
+    scheduler: S,
+    interval: Duration,
+    mut shutdown: watch::Receiver<bool>,
+    logger: Arc<dyn LoggerTrait + 'static>,
+) where
+    S: JobSchedulerUsecaseTrait + Send + Sync,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    while !*shutdown.borrow() {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = shutdown.changed() => break,
+        }
+
+        let ctx = new_context_background("job-scheduler");
+        match scheduler.enqueue_due(&ctx, jst_now()).await {
+            Ok(_) => {}
+            Err(err) => {
+                let err_msg = format!("スケジュールのジョブの登録処理でエラー: {}", err);
+                logger.error(&ctx, &err_msg);
+            }
+        }
+    }
+
+    logger.info(
+        &new_context_background("job-scheduler"),
+        "ジョブのスケジューラーを停止しました",
+    );
+}
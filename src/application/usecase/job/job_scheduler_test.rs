@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use chrono::{DateTime, FixedOffset};
+    use std::sync::{Arc, Mutex};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::job::job_repository::MockJobRepositoryTrait;
+
+    // ユースケース
+    use crate::application::usecase::job::job_scheduler::{
+        JobSchedulerRepository, JobSchedulerUsecase, JobSchedulerUsecaseTrait, parse_job_schedules,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "job-scheduler".to_string(),
+            auth: None,
+        }
+    }
+
+    // テスト用の日時
+    fn dt(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    // ユースケースのインスタンス化（登録したジョブの一意キーを保持）
+    fn create_usecase(conflict: bool, unique_keys: Arc<Mutex<Vec<String>>>) -> JobSchedulerUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockJobRepositoryTrait::new();
+        mock_repo.expect_add().returning(move |_, job| {
+            unique_keys
+                .lock()
+                .unwrap()
+                .push(job.unique_key.clone().unwrap());
+            if conflict {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::CONFLICT,
+                    message: "登録済み".to_string(),
+                });
+            }
+            Ok(job.clone())
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        JobSchedulerUsecase::new(
+            JobSchedulerRepository {
+                job_repository: Arc::new(mock_repo),
+            },
+            parse_job_schedules("test.job=0 3 * * *").unwrap(),
+            Arc::new(mock_logger),
+        )
+    }
+
+    #[test]
+    fn test_parse_job_schedules() {
+        let schedules = parse_job_schedules(" a.job=*/5 * * * * ; b.job=0 3 * * *;").unwrap();
+
+        // 検証
+        assert_eq!(schedules.len(), 2);
+        assert_eq!(schedules[0].job_type, "a.job");
+        assert_eq!(schedules[0].cron.expression(), "*/5 * * * *");
+        assert_eq!(schedules[1].job_type, "b.job");
+        assert!(parse_job_schedules("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_job_schedules_error() {
+        // 検証
+        assert!(parse_job_schedules("a.job").is_err());
+        assert!(parse_job_schedules("=0 3 * * *").is_err());
+        assert!(parse_job_schedules("a.job=0 3 * *").is_err());
+        assert!(parse_job_schedules("a.job=0 3 * * *;a.job=0 4 * * *").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_due() {
+        let unique_keys = Arc::new(Mutex::new(Vec::new()));
+        let usecase = create_usecase(false, unique_keys.clone());
+        let ctx = create_ctx();
+
+        // テスト実行（初回は次回の実行日時の設定のみ）
+        let first = usecase
+            .enqueue_due(&ctx, dt("2026-01-01T02:00:00+09:00"))
+            .await
+            .unwrap();
+        let before = usecase
+            .enqueue_due(&ctx, dt("2026-01-01T02:59:00+09:00"))
+            .await
+            .unwrap();
+        let due = usecase
+            .enqueue_due(&ctx, dt("2026-01-01T03:00:30+09:00"))
+            .await
+            .unwrap();
+        let after = usecase
+            .enqueue_due(&ctx, dt("2026-01-01T03:01:00+09:00"))
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!((first, before, due, after), (0, 0, 1, 0));
+        assert_eq!(
+            *unique_keys.lock().unwrap(),
+            vec!["schedule:test.job:202601010300".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_due_missed() {
+        let unique_keys = Arc::new(Mutex::new(Vec::new()));
+        let usecase = create_usecase(false, unique_keys.clone());
+        let ctx = create_ctx();
+
+        // テスト実行（停止中に過ぎた実行日時は1回のみ）
+        usecase
+            .enqueue_due(&ctx, dt("2026-01-01T02:00:00+09:00"))
+            .await
+            .unwrap();
+        let count = usecase
+            .enqueue_due(&ctx, dt("2026-01-04T12:00:00+09:00"))
+            .await
+            .unwrap();
+
+        // 検証（次回は翌日の実行日時）
+        assert_eq!(count, 1);
+        assert_eq!(
+            usecase.next_runs.lock().unwrap().get("test.job"),
+            Some(&dt("2026-01-05T03:00:00+09:00"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_due_conflict() {
+        let unique_keys = Arc::new(Mutex::new(Vec::new()));
+        let usecase = create_usecase(true, unique_keys.clone());
+        let ctx = create_ctx();
+
+        // テスト実行（他のインスタンスで登録済みの場合）
+        usecase
+            .enqueue_due(&ctx, dt("2026-01-01T02:00:00+09:00"))
+            .await
+            .unwrap();
+        let count = usecase
+            .enqueue_due(&ctx, dt("2026-01-01T03:00:00+09:00"))
+            .await
+            .unwrap();
+
+        // 検証（エラーにせずに次回の実行日時に進める）
+        assert_eq!(count, 0);
+        assert_eq!(
+            usecase.next_runs.lock().unwrap().get("test.job"),
+            Some(&dt("2026-01-02T03:00:00+09:00"))
+        );
+    }
+}
@@ -0,0 +1,238 @@
+// axum
+use axum::http::StatusCode;
+
+// ハッシュマップ
+use std::collections::HashMap;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+use std::time::Duration;
+
+// 停止の通知用のチャネル
+use tokio::sync::watch;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::{
+    ContextRequest, new_context_background,
+};
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::job::job_model::{Job, JobStatus};
+use crate::domain::job::job_repository::JobRepositoryTrait;
+use crate::domain::retry::retry_policy::RetryPolicy;
+use crate::domain::time::time_jst::jst_now;
+
+// ジョブのハンドラー
+use crate::application::usecase::job::job_handler_trait::JobHandlerTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ジョブの実行結果
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobRunResult {
+    pub succeeded: usize,
+    pub retrying: usize,
+    pub dead_lettered: usize,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait JobWorkerUsecaseTrait {
+    // 実行対象のジョブを実行して結果を保存
+    async fn run_due(&self, ctx: &ContextRequest) -> Result<JobRunResult, ErrorCommon>;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct JobWorkerRepository {
+    pub job_repository: Arc<dyn JobRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct JobWorkerUsecase {
+    pub repo: JobWorkerRepository,
+    // ジョブの種類ごとのハンドラー
+    pub handlers: HashMap<String, Arc<dyn JobHandlerTrait + 'static>>,
+    pub retry_policy: RetryPolicy,
+    pub batch_size: usize,
+    // 実行中のジョブの占有期間（秒）
+    pub lease_secs: u64,
+    // ハンドラーの実行タイムアウト（秒、占有期限内に実行結果を保存するため占有期間より短くする）
+    pub timeout_secs: u64,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl JobWorkerUsecase {
+    pub fn new(
+        repo: JobWorkerRepository,
+        handlers: Vec<Arc<dyn JobHandlerTrait + 'static>>,
+        retry_policy: RetryPolicy,
+        batch_size: usize,
+        lease_secs: u64,
+        timeout_secs: u64,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        let handlers = handlers
+            .into_iter()
+            .map(|handler| (handler.job_type().to_string(), handler))
+            .collect();
+
+        JobWorkerUsecase {
+            repo,
+            handlers,
+            retry_policy,
+            batch_size,
+            lease_secs,
+            timeout_secs,
+            logger,
+        }
+    }
+
+    // 登録済みのジョブの種類
+    pub fn job_types(&self) -> Vec<String> {
+        let mut job_types: Vec<String> = self.handlers.keys().cloned().collect();
+        job_types.sort();
+        job_types
+    }
+
+    // ジョブの実行（実行タイムアウトを超えた場合は失敗）
+    async fn execute(&self, ctx: &ContextRequest, job: &mut Job) {
+        let handler = match self.handlers.get(&job.job_type) {
+            Some(handler) => handler,
+            None => {
+                // ハンドラーが未登録の場合はリトライしない
+                job.last_error = Some("ジョブのハンドラーが登録されていません。".to_string());
+                job.mark_dead_lettered(jst_now());
+                return;
+            }
+        };
+
+        let timeout = Duration::from_secs(self.timeout_secs);
+        let result = match tokio::time::timeout(timeout, handler.handle(ctx, job)).await {
+            Ok(result) => result.map_err(|err| err.to_string()),
+            Err(_) => Err(format!("タイムアウトしました（{}秒）", self.timeout_secs)),
+        };
+
+        match result {
+            Ok(_) => job.mark_succeeded(jst_now()),
+            Err(err) => job.mark_failed(&err, &self.retry_policy, jst_now()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl JobWorkerUsecaseTrait for JobWorkerUsecase {
+    async fn run_due(&self, ctx: &ContextRequest) -> Result<JobRunResult, ErrorCommon> {
+        let mut result = JobRunResult::default();
+
+        // 実行対象のジョブを1件ずつ取得して実行（占有期間はジョブごとに取得時から開始）
+        for _ in 0..self.batch_size {
+            let mut job = match self
+                .repo
+                .job_repository
+                .claim_due(ctx, jst_now(), self.lease_secs)
+                .await?
+            {
+                Some(job) => job,
+                None => break,
+            };
+            let expected_locked_until = job.locked_until;
+
+            self.execute(ctx, &mut job).await;
+
+            // 実行結果の保存（占有期限が切れて他のワーカーが取得した場合は保存しない）
+            match self
+                .repo
+                .job_repository
+                .update(ctx, &job, expected_locked_until)
+                .await
+            {
+                Ok(_) => {}
+                Err(err) if err.status_code() == StatusCode::CONFLICT => {
+                    let err_msg = format!(
+                        "ジョブの占有期限が切れたため実行結果を保存しません（job_id={}, job_type={}）",
+                        job.job_id, job.job_type
+                    );
+                    self.logger.warn(ctx, &err_msg);
+                    continue;
+                }
+                Err(err) => return Err(err),
+            }
+
+            match job.status {
+                JobStatus::Succeeded => result.succeeded += 1,
+                JobStatus::Pending => {
+                    result.retrying += 1;
+                    let err_msg = format!(
+                        "ジョブの実行エラー（job_id={}, job_type={}, attempts={}）: {}",
+                        job.job_id,
+                        job.job_type,
+                        job.attempts,
+                        job.last_error.as_deref().unwrap_or("-")
+                    );
+                    self.logger.warn(ctx, &err_msg);
+                }
+                _ => {
+                    result.dead_lettered += 1;
+                    let err_msg = format!(
+                        "ジョブをデッドレターに移動しました（job_id={}, job_type={}, attempts={}）: {}",
+                        job.job_id,
+                        job.job_type,
+                        job.attempts,
+                        job.last_error.as_deref().unwrap_or("-")
+                    );
+                    self.logger.error(ctx, &err_msg);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+// 実行対象のジョブを一定間隔で実行（main.rsでバックグラウンドタスクとして起動）
+// ※停止が通知された場合は実行中のジョブの完了後に終了
+pub async fn run_job_worker<W>(
+    worker: W,
+    interval: Duration,
+    mut shutdown: watch::Receiver<bool>,
+    logger: Arc<dyn LoggerTrait + 'static>,
+) where
+    W: JobWorkerUsecaseTrait + Send + Sync,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    while !*shutdown.borrow() {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = shutdown.changed() => break,
+        }
+
+        let ctx = new_context_background("job-worker");
+        match worker.run_due(&ctx).await {
+            Ok(result) => {
+                if result != JobRunResult::default() {
+                    let msg = format!(
+                        "ジョブを実行しました（succeeded={}, retrying={}, dead_lettered={}）",
+                        result.succeeded, result.retrying, result.dead_lettered
+                    );
+                    logger.info(&ctx, &msg);
+                }
+            }
+            Err(err) => {
+                let err_msg = format!("ジョブの実行処理でエラー: {}", err);
+                logger.error(&ctx, &err_msg);
+            }
+        }
+    }
+
+    logger.info(
+        &new_context_background("job-worker"),
+        "ジョブのワーカーを停止しました",
+    );
+}
@@ -0,0 +1,360 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::job::job_model::{Job, JobStatus};
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::time::time_jst::jst_now;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::job::job_repository::{JobRepositoryTrait, MockJobRepositoryTrait};
+    use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
+    use crate::infrastructure::persistence::job::job_repository::JobRepository;
+
+    // ジョブのハンドラーのモック
+    use crate::application::usecase::job::job_handler_trait::{
+        JobHandlerTrait, MockJobHandlerTrait,
+    };
+
+    // ユースケース
+    use crate::application::usecase::job::job_worker::{
+        JobRunResult, JobWorkerRepository, JobWorkerUsecase, JobWorkerUsecaseTrait,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "job-worker".to_string(),
+            auth: None,
+        }
+    }
+
+    // 実行対象のジョブ
+    fn create_job(job_type: &str, attempts: u32) -> Job {
+        let mut job = Job::new(job_type, json!({}), jst_now());
+        job.id = 1;
+        job.attempts = attempts;
+        job.mark_running(300, jst_now());
+        job
+    }
+
+    // ジョブのハンドラー（実行結果を指定）
+    fn create_handler(result: Result<(), ErrorCommon>) -> Arc<dyn JobHandlerTrait> {
+        let mut mock_handler = MockJobHandlerTrait::new();
+        mock_handler.expect_job_type().return_const("test.job");
+        mock_handler
+            .expect_handle()
+            .returning(move |_, _| result.clone());
+        Arc::new(mock_handler)
+    }
+
+    // 指定時間後に完了するジョブのハンドラー
+    struct SleepJobHandler {
+        duration: Duration,
+    }
+
+    #[async_trait::async_trait]
+    impl JobHandlerTrait for SleepJobHandler {
+        fn job_type(&self) -> &'static str {
+            "test.job"
+        }
+
+        async fn handle(&self, _ctx: &ContextRequest, _job: &Job) -> Result<(), ErrorCommon> {
+            tokio::time::sleep(self.duration).await;
+            Ok(())
+        }
+    }
+
+    // ユースケースのインスタンス化
+    fn create_usecase(
+        job: Job,
+        handler: Arc<dyn JobHandlerTrait>,
+        expected_status: JobStatus,
+    ) -> JobWorkerUsecase {
+        // リポジトリのモック化（1件ずつ取得して取得時の占有期限で実行結果が保存されること）
+        let locked_until = job.locked_until;
+        let claimable = Mutex::new(Some(job));
+        let mut mock_repo = MockJobRepositoryTrait::new();
+        mock_repo
+            .expect_claim_due()
+            .times(0..=2)
+            .returning(move |_, _, _| Ok(claimable.lock().unwrap().take()));
+        mock_repo
+            .expect_update()
+            .times(0..=1)
+            .returning(move |_, job, expected_locked_until| {
+                assert_eq!(job.status, expected_status);
+                assert_eq!(job.locked_until, None);
+                assert_eq!(expected_locked_until, locked_until);
+                Ok(job.clone())
+            });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        JobWorkerUsecase::new(
+            JobWorkerRepository {
+                job_repository: Arc::new(mock_repo),
+            },
+            vec![handler],
+            RetryPolicy {
+                max_attempts: 3,
+                backoff_base_secs: 10,
+                backoff_max_secs: 60,
+            },
+            10,
+            300,
+            240,
+            Arc::new(mock_logger),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_run_due_succeeded() {
+        let usecase = create_usecase(
+            create_job("test.job", 0),
+            create_handler(Ok(())),
+            JobStatus::Succeeded,
+        );
+
+        // テスト実行
+        let result = usecase.run_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(
+            result,
+            JobRunResult {
+                succeeded: 1,
+                retrying: 0,
+                dead_lettered: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_due_retrying() {
+        let err = ErrorCommon::CustomError {
+            status_code: StatusCode::BAD_GATEWAY,
+            message: "error".to_string(),
+        };
+        let usecase = create_usecase(
+            create_job("test.job", 1),
+            create_handler(Err(err)),
+            JobStatus::Pending,
+        );
+
+        // テスト実行
+        let result = usecase.run_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(result.retrying, 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_due_dead_lettered() {
+        let usecase = create_usecase(
+            create_job("test.job", 2),
+            create_handler(Err(ErrorCommon::InternalServerError)),
+            JobStatus::DeadLettered,
+        );
+
+        // テスト実行（最大試行回数に達した場合）
+        let result = usecase.run_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(result.dead_lettered, 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_due_unknown_job_type() {
+        let usecase = create_usecase(
+            create_job("unknown.job", 0),
+            create_handler(Ok(())),
+            JobStatus::DeadLettered,
+        );
+
+        // テスト実行（ハンドラーが未登録の場合はリトライしない）
+        let result = usecase.run_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(result.dead_lettered, 1);
+    }
+
+    #[tokio::test]
+    async fn test_job_types() {
+        let usecase = create_usecase(
+            create_job("test.job", 0),
+            create_handler(Ok(())),
+            JobStatus::Succeeded,
+        );
+
+        // 検証
+        assert_eq!(usecase.job_types(), vec!["test.job".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_run_due_batch_size() {
+        // リポジトリのモック化（実行対象が残っていてもバッチサイズで終了すること）
+        let mut mock_repo = MockJobRepositoryTrait::new();
+        mock_repo
+            .expect_claim_due()
+            .times(2)
+            .returning(|_, _, _| Ok(Some(create_job("test.job", 0))));
+        mock_repo
+            .expect_update()
+            .times(2)
+            .returning(|_, job, _| Ok(job.clone()));
+
+        let usecase = JobWorkerUsecase::new(
+            JobWorkerRepository {
+                job_repository: Arc::new(mock_repo),
+            },
+            vec![create_handler(Ok(()))],
+            RetryPolicy {
+                max_attempts: 3,
+                backoff_base_secs: 10,
+                backoff_max_secs: 60,
+            },
+            2,
+            300,
+            240,
+            Arc::new(MockLoggerTrait::new()),
+        );
+
+        // テスト実行
+        let result = usecase.run_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(result.succeeded, 2);
+    }
+
+    #[tokio::test]
+    async fn test_run_due_lease_expired() {
+        // リポジトリのモック化（占有期限が切れて他のワーカーが取得済み）
+        let claimable = Mutex::new(Some(create_job("test.job", 0)));
+        let mut mock_repo = MockJobRepositoryTrait::new();
+        mock_repo
+            .expect_claim_due()
+            .returning(move |_, _, _| Ok(claimable.lock().unwrap().take()));
+        mock_repo.expect_update().times(1).returning(|_, _, _| {
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::CONFLICT,
+                message: "対象のジョブは他の処理により更新されています。".to_string(),
+            })
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().times(1).returning(|_, _| ());
+
+        let usecase = JobWorkerUsecase::new(
+            JobWorkerRepository {
+                job_repository: Arc::new(mock_repo),
+            },
+            vec![create_handler(Ok(()))],
+            RetryPolicy {
+                max_attempts: 3,
+                backoff_base_secs: 10,
+                backoff_max_secs: 60,
+            },
+            10,
+            300,
+            240,
+            Arc::new(mock_logger),
+        );
+
+        // テスト実行（実行結果は保存されずに集計もしない）
+        let result = usecase.run_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(result, JobRunResult::default());
+    }
+
+    #[tokio::test]
+    async fn test_run_due_near_timeout() {
+        // 事前準備（ダミーDBにジョブを登録）
+        let db = new_db_dummy_connection().await.unwrap();
+        let mut mock_repo_logger = MockLoggerTrait::new();
+        mock_repo_logger.expect_warn().never();
+        let job_repo = Arc::new(JobRepository::new(db.clone(), Arc::new(mock_repo_logger)));
+        let ctx = create_ctx();
+        job_repo
+            .add(&ctx, &Job::new("test.job", json!({}), jst_now()))
+            .await
+            .unwrap();
+        job_repo
+            .add(&ctx, &Job::new("test.job", json!({}), jst_now()))
+            .await
+            .unwrap();
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        // ユースケースのインスタンス化（占有期間2秒、実行タイムアウト1秒）
+        let create_worker = |duration: Duration| {
+            JobWorkerUsecase::new(
+                JobWorkerRepository {
+                    job_repository: job_repo.clone(),
+                },
+                vec![Arc::new(SleepJobHandler { duration })],
+                RetryPolicy {
+                    max_attempts: 3,
+                    backoff_base_secs: 10,
+                    backoff_max_secs: 60,
+                },
+                1,
+                2,
+                1,
+                Arc::new(MockLoggerTrait::new()),
+            )
+        };
+
+        // テスト実行（実行タイムアウトの直前に完了）
+        let result = create_worker(Duration::from_millis(900))
+            .run_due(&ctx)
+            .await
+            .unwrap();
+
+        // 検証（占有期限内に実行結果が保存されること）
+        assert_eq!(result.succeeded, 1);
+        let jobs = db.jobs.read().unwrap().clone();
+        assert_eq!(jobs[0].status, JobStatus::Succeeded);
+        assert_eq!(jobs[0].locked_until, None);
+
+        // テスト実行（占有期間内だが実行タイムアウトを超過）
+        let mut worker = create_worker(Duration::from_millis(1900));
+        worker.logger = Arc::new(mock_logger);
+        let result = worker.run_due(&ctx).await.unwrap();
+
+        // 検証（占有期限が切れる前にタイムアウトとして実行結果が保存されること）
+        assert_eq!(result.retrying, 1);
+        let jobs = db.jobs.read().unwrap().clone();
+        assert_eq!(jobs[1].status, JobStatus::Pending);
+        assert_eq!(jobs[1].attempts, 1);
+        assert_eq!(jobs[1].locked_until, None);
+        assert_eq!(
+            jobs[1].last_error.as_deref(),
+            Some("タイムアウトしました（1秒）")
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod job_dead_letter_find_all;
+pub mod job_enqueue;
+pub mod job_handler_trait;
+pub mod job_retry;
+pub mod job_scheduler;
+pub mod job_worker;
+
+// テストコード用のモジュール
+mod job_dead_letter_find_all_test;
+mod job_enqueue_test;
+mod job_retry_test;
+mod job_scheduler_test;
+mod job_worker_test;
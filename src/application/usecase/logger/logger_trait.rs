@@ -10,4 +10,8 @@ pub trait LoggerTrait: Send + Sync {
     fn warn(&self, ctx: &ContextRequest, msg: &str);
     #[allow(dead_code)]
     fn error(&self, ctx: &ContextRequest, msg: &str);
+    // 現在のログフィルター設定を取得
+    fn get_filters(&self) -> String;
+    // ログフィルター設定を実行時に変更（例: "info,rust_axum_domain::infrastructure=debug"）
+    fn set_filters(&self, filters: &str) -> Result<(), String>;
 }
@@ -1,3 +1,12 @@
+pub mod admin;
+pub mod api_key;
+pub mod auth;
 pub mod context;
+pub mod event;
+pub mod idempotency;
+pub mod job;
 pub mod logger;
+pub mod outbox;
+pub mod rate_limit;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,9 @@
+pub mod outbox_publisher_broker;
+pub mod outbox_publisher_group;
+pub mod outbox_publisher_trait;
+pub mod outbox_relay;
+
+// テストコード用のモジュール
+pub mod outbox_publisher_broker_test;
+pub mod outbox_publisher_group_test;
+pub mod outbox_relay_test;
@@ -0,0 +1,46 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage};
+
+// メッセージブローカー
+use crate::application::usecase::event::event_publisher_trait::{
+    EventMessage, EventPublisherTrait,
+};
+
+// 送信先のトレイト
+use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+
+// アウトボックスのメッセージをメッセージブローカーに送信する送信先
+pub struct OutboxPublisherBroker {
+    publisher: Arc<dyn EventPublisherTrait + 'static>,
+    topic_prefix: String,
+}
+
+impl OutboxPublisherBroker {
+    pub fn new(publisher: Arc<dyn EventPublisherTrait + 'static>, topic_prefix: &str) -> Self {
+        OutboxPublisherBroker {
+            publisher,
+            topic_prefix: topic_prefix.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl OutboxPublisherTrait for OutboxPublisherBroker {
+    async fn publish(
+        &self,
+        ctx: &ContextRequest,
+        message: &OutboxMessage,
+    ) -> Result<(), ErrorCommon> {
+        let event_message = EventMessage::from_outbox(&self.topic_prefix, message);
+        self.publisher.publish(ctx, &event_message).await
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::user::user_model::User;
+
+    // メッセージブローカーのモック
+    use crate::application::usecase::event::event_publisher_trait::MockEventPublisherTrait;
+
+    // 送信先
+    use crate::application::usecase::outbox::outbox_publisher_broker::OutboxPublisherBroker;
+    use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "outbox-relay".to_string(),
+            auth: None,
+        }
+    }
+
+    // 送信対象のメッセージ
+    fn create_message() -> OutboxMessage {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0003".to_string(),
+            "鈴木".to_string(),
+            "三郎".to_string(),
+            "s.suzuki@example.com".to_string(),
+        );
+        OutboxMessage::from_events(&user.take_events()).remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_publish() {
+        let message = create_message();
+        let event_id = message.event_id.clone();
+
+        // メッセージブローカーのモック化
+        let mut mock_publisher = MockEventPublisherTrait::new();
+        mock_publisher
+            .expect_publish()
+            .withf(move |_, event_message| {
+                let payload: serde_json::Value =
+                    serde_json::from_slice(&event_message.payload).unwrap();
+                event_message.topic == "events.user.created"
+                    && event_message.key == "xxxx-xxxx-xxxx-0003"
+                    && event_message.header("event-id") == Some(event_id.as_str())
+                    && event_message.header("Event-Type") == Some("user.created")
+                    && payload["id"] == event_id.as_str()
+                    && payload["data"]["email"] == "s.suzuki@example.com"
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let publisher = OutboxPublisherBroker::new(Arc::new(mock_publisher), "events");
+
+        // テスト実行
+        let res = publisher.publish(&create_ctx(), &message).await;
+
+        // 検証
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_publish_error() {
+        // メッセージブローカーのモック化（送信エラーはリトライ対象として返す）
+        let mut mock_publisher = MockEventPublisherTrait::new();
+        mock_publisher.expect_publish().times(1).returning(|_, _| {
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::SERVICE_UNAVAILABLE,
+                message: "connection refused".to_string(),
+            })
+        });
+
+        let publisher = OutboxPublisherBroker::new(Arc::new(mock_publisher), "events");
+
+        // テスト実行
+        let res = publisher.publish(&create_ctx(), &create_message()).await;
+
+        // 検証
+        assert_eq!(
+            res.unwrap_err().status_code(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage};
+
+// 送信先のトレイト
+use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+
+// 複数の送信先にまとめて送信する送信先
+// ※いずれかの送信先でエラーの場合はメッセージ全体をリトライするため、各送信先は重複した送信を許容すること
+pub struct OutboxPublisherGroup {
+    publishers: Vec<Arc<dyn OutboxPublisherTrait + 'static>>,
+}
+
+impl OutboxPublisherGroup {
+    pub fn new(publishers: Vec<Arc<dyn OutboxPublisherTrait + 'static>>) -> Self {
+        OutboxPublisherGroup { publishers }
+    }
+}
+
+#[async_trait]
+impl OutboxPublisherTrait for OutboxPublisherGroup {
+    async fn publish(
+        &self,
+        ctx: &ContextRequest,
+        message: &OutboxMessage,
+    ) -> Result<(), ErrorCommon> {
+        // 全ての送信先に送信して、最初のエラーを返す
+        let mut first_err = None;
+        for publisher in self.publishers.iter() {
+            if let Err(err) = publisher.publish(ctx, message).await {
+                first_err.get_or_insert(err);
+            }
+        }
+
+        match first_err {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::user::user_model::User;
+
+    // 送信先
+    use crate::application::usecase::outbox::outbox_publisher_group::OutboxPublisherGroup;
+    use crate::application::usecase::outbox::outbox_publisher_trait::{
+        MockOutboxPublisherTrait, OutboxPublisherTrait,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "outbox-relay".to_string(),
+            auth: None,
+        }
+    }
+
+    // 送信対象のメッセージ
+    fn create_message() -> OutboxMessage {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0003".to_string(),
+            "鈴木".to_string(),
+            "三郎".to_string(),
+            "s.suzuki@example.com".to_string(),
+        );
+        OutboxMessage::from_events(&user.take_events()).remove(0)
+    }
+
+    // 送信結果を返す送信先のモック（呼び出しは1回）
+    fn create_publisher(ok: bool) -> Arc<dyn OutboxPublisherTrait> {
+        let mut mock_publisher = MockOutboxPublisherTrait::new();
+        mock_publisher
+            .expect_publish()
+            .times(1)
+            .returning(move |_, _| {
+                if ok {
+                    Ok(())
+                } else {
+                    Err(ErrorCommon::CustomError {
+                        status_code: StatusCode::SERVICE_UNAVAILABLE,
+                        message: "connection refused".to_string(),
+                    })
+                }
+            });
+        Arc::new(mock_publisher)
+    }
+
+    #[tokio::test]
+    async fn test_publish_success() {
+        let group = OutboxPublisherGroup::new(vec![create_publisher(true), create_publisher(true)]);
+
+        // テスト実行
+        let res = group.publish(&create_ctx(), &create_message()).await;
+
+        // 検証
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_publish_error() {
+        // エラーの場合も後続の送信先に送信する
+        let group =
+            OutboxPublisherGroup::new(vec![create_publisher(false), create_publisher(true)]);
+
+        // テスト実行
+        let res = group.publish(&create_ctx(), &create_message()).await;
+
+        // 検証
+        assert_eq!(
+            res.unwrap_err().status_code(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+}
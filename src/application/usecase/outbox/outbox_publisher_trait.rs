@@ -0,0 +1,20 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage};
+
+// アウトボックスのメッセージの送信先のトレイト（送信先を差し替えできるように定義）
+#[mockall::automock]
+#[async_trait]
+pub trait OutboxPublisherTrait: Send + Sync {
+    // メッセージの送信（エラーの場合はリトライ対象）
+    async fn publish(
+        &self,
+        ctx: &ContextRequest,
+        message: &OutboxMessage,
+    ) -> Result<(), ErrorCommon>;
+}
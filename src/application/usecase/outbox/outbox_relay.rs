@@ -0,0 +1,173 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+use std::time::Duration;
+
+// 停止の通知用のチャネル
+use tokio::sync::watch;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::{
+    ContextRequest, new_context_background,
+};
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::outbox::outbox_model::{OutboxMessage, OutboxStatus};
+use crate::domain::outbox::outbox_repository::OutboxRepositoryTrait;
+use crate::domain::retry::retry_policy::RetryPolicy;
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::transaction::unit_of_work::{UnitOfWorkTrait, finish_transaction};
+
+// 送信先
+use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 送信処理の結果
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OutboxRelayResult {
+    pub delivered: usize,
+    pub retrying: usize,
+    pub failed: usize,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait OutboxRelayUsecaseTrait {
+    // 送信対象のメッセージを送信して結果を保存
+    async fn relay_due(&self, ctx: &ContextRequest) -> Result<OutboxRelayResult, ErrorCommon>;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct OutboxRelayRepository {
+    pub outbox_repository: Arc<dyn OutboxRepositoryTrait + 'static>,
+    pub unit_of_work: Arc<dyn UnitOfWorkTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct OutboxRelayUsecase {
+    pub repo: OutboxRelayRepository,
+    pub publisher: Arc<dyn OutboxPublisherTrait + 'static>,
+    pub retry_policy: RetryPolicy,
+    pub batch_size: usize,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl OutboxRelayUsecase {
+    pub fn new(
+        repo: OutboxRelayRepository,
+        publisher: Arc<dyn OutboxPublisherTrait + 'static>,
+        retry_policy: RetryPolicy,
+        batch_size: usize,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        OutboxRelayUsecase {
+            repo,
+            publisher,
+            retry_policy,
+            batch_size,
+            logger,
+        }
+    }
+
+    // 送信結果の保存（ユーザー更新と排他制御するためトランザクション内で実行）
+    async fn save(&self, ctx: &ContextRequest, message: &OutboxMessage) -> Result<(), ErrorCommon> {
+        let tx = self.repo.unit_of_work.begin(ctx).await?;
+        let result = tx.outbox_repository().update(ctx, message).await;
+
+        finish_transaction(tx, result).await.map(|_| ())
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxRelayUsecaseTrait for OutboxRelayUsecase {
+    async fn relay_due(&self, ctx: &ContextRequest) -> Result<OutboxRelayResult, ErrorCommon> {
+        let mut result = OutboxRelayResult::default();
+
+        // 送信対象のメッセージ取得
+        let messages = self
+            .repo
+            .outbox_repository
+            .find_due(ctx, jst_now(), self.batch_size)
+            .await?;
+
+        for mut message in messages.into_iter() {
+            // メッセージの送信
+            match self.publisher.publish(ctx, &message).await {
+                Ok(_) => {
+                    message.mark_delivered(jst_now());
+                    result.delivered += 1;
+                }
+                Err(err) => {
+                    message.mark_failed(&err.to_string(), &self.retry_policy, jst_now());
+                    if message.status == OutboxStatus::Pending {
+                        result.retrying += 1;
+                        let err_msg = format!(
+                            "アウトボックスのメッセージ送信エラー（id={}, attempts={}）: {}",
+                            message.id, message.attempts, err
+                        );
+                        self.logger.warn(ctx, &err_msg);
+                    } else {
+                        result.failed += 1;
+                        let err_msg = format!(
+                            "アウトボックスのメッセージ送信を中止しました（id={}, attempts={}）: {}",
+                            message.id, message.attempts, err
+                        );
+                        self.logger.error(ctx, &err_msg);
+                    }
+                }
+            }
+
+            // 送信結果の保存
+            self.save(ctx, &message).await?;
+        }
+
+        Ok(result)
+    }
+}
+
+// 送信対象のメッセージを一定間隔で送信（main.rsでバックグラウンドタスクとして起動）
+pub async fn run_outbox_relay<R>(
+    relay: R,
+    interval: Duration,
+    mut shutdown: watch::Receiver<bool>,
+    logger: Arc<dyn LoggerTrait + 'static>,
+) where
+    R: OutboxRelayUsecaseTrait + Send + Sync,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    while !*shutdown.borrow() {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = shutdown.changed() => break,
+        }
+
+        let ctx = new_context_background("outbox-relay");
+        match relay.relay_due(&ctx).await {
+            Ok(result) => {
+                if result != OutboxRelayResult::default() {
+                    let msg = format!(
+                        "アウトボックスのメッセージを送信しました（delivered={}, retrying={}, failed={}）",
+                        result.delivered, result.retrying, result.failed
+                    );
+                    logger.info(&ctx, &msg);
+                }
+            }
+            Err(err) => {
+                let err_msg = format!("アウトボックスの送信処理でエラー: {}", err);
+                logger.error(&ctx, &err_msg);
+            }
+        }
+    }
+
+    logger.info(
+        &new_context_background("outbox-relay"),
+        "アウトボックスの送信処理を停止しました",
+    );
+}
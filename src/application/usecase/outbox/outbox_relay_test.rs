@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::outbox::outbox_model::{OutboxMessage, OutboxStatus};
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // 送信先のモック
+    use crate::application::usecase::outbox::outbox_publisher_trait::MockOutboxPublisherTrait;
+
+    // リポジトリのモック
+    use crate::domain::outbox::outbox_repository::MockOutboxRepositoryTrait;
+    use crate::domain::transaction::unit_of_work_test_helper::{
+        ExpectedFinish, create_mock_unit_of_work_with_outbox,
+    };
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // ユースケース
+    use crate::application::usecase::outbox::outbox_relay::{
+        OutboxRelayRepository, OutboxRelayResult, OutboxRelayUsecase, OutboxRelayUsecaseTrait,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "outbox-relay".to_string(),
+            auth: None,
+        }
+    }
+
+    // 送信対象のメッセージ
+    fn create_message(attempts: u32) -> OutboxMessage {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0003".to_string(),
+            "鈴木".to_string(),
+            "三郎".to_string(),
+            "s.suzuki@example.com".to_string(),
+        );
+        let mut message = OutboxMessage::from_events(&user.take_events()).remove(0);
+        message.id = 1;
+        message.attempts = attempts;
+        message
+    }
+
+    // 送信対象のメッセージを返すリポジトリのモック
+    fn create_mock_outbox_repo(attempts: u32) -> MockOutboxRepositoryTrait {
+        let mut mock_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_outbox_repo
+            .expect_find_due()
+            .withf(|_, _, limit| *limit == 10)
+            .returning(move |_, _, _| Ok(vec![create_message(attempts)]));
+        mock_outbox_repo
+    }
+
+    // ユースケースのインスタンス化
+    fn create_usecase(
+        outbox_repo: MockOutboxRepositoryTrait,
+        tx_outbox_repo: MockOutboxRepositoryTrait,
+        publisher: MockOutboxPublisherTrait,
+        logger: MockLoggerTrait,
+    ) -> OutboxRelayUsecase {
+        OutboxRelayUsecase {
+            repo: OutboxRelayRepository {
+                outbox_repository: Arc::new(outbox_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work_with_outbox(
+                    MockUserRepositoryTrait::new(),
+                    tx_outbox_repo,
+                    ExpectedFinish::Commit,
+                )),
+            },
+            publisher: Arc::new(publisher),
+            retry_policy: RetryPolicy {
+                max_attempts: 3,
+                backoff_base_secs: 2,
+                backoff_max_secs: 60,
+            },
+            batch_size: 10,
+            logger: Arc::new(logger),
+        }
+    }
+
+    // 送信エラーを返す送信先のモック
+    fn create_failing_publisher() -> MockOutboxPublisherTrait {
+        let mut mock_publisher = MockOutboxPublisherTrait::new();
+        mock_publisher.expect_publish().times(1).returning(|_, _| {
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::SERVICE_UNAVAILABLE,
+                message: "connection refused".to_string(),
+            })
+        });
+        mock_publisher
+    }
+
+    #[tokio::test]
+    async fn test_relay_due_delivered() {
+        // 送信先のモック化
+        let mut mock_publisher = MockOutboxPublisherTrait::new();
+        mock_publisher
+            .expect_publish()
+            .withf(|_, message| message.event_type == "user.created")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // 送信結果の保存（トランザクション内）
+        let mut mock_tx_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_tx_outbox_repo
+            .expect_update()
+            .withf(|_, message| {
+                message.status == OutboxStatus::Delivered
+                    && message.attempts == 1
+                    && message.delivered_at.is_some()
+            })
+            .times(1)
+            .returning(|_, message| Ok(message.clone()));
+
+        // テスト実行
+        let usecase = create_usecase(
+            create_mock_outbox_repo(0),
+            mock_tx_outbox_repo,
+            mock_publisher,
+            MockLoggerTrait::new(),
+        );
+        let result = usecase.relay_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(
+            result,
+            OutboxRelayResult {
+                delivered: 1,
+                retrying: 0,
+                failed: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_relay_due_retrying() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().times(1).return_const(());
+
+        // 送信結果の保存（バックオフ後にリトライ）
+        let mut mock_tx_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_tx_outbox_repo
+            .expect_update()
+            .withf(|_, message| {
+                message.status == OutboxStatus::Pending
+                    && message.attempts == 1
+                    && message.last_error.as_deref() == Some("connection refused")
+                    && message.next_attempt_at > message.created_at
+            })
+            .times(1)
+            .returning(|_, message| Ok(message.clone()));
+
+        // テスト実行
+        let usecase = create_usecase(
+            create_mock_outbox_repo(0),
+            mock_tx_outbox_repo,
+            create_failing_publisher(),
+            mock_logger,
+        );
+        let result = usecase.relay_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(result.retrying, 1);
+        assert_eq!(result.delivered, 0);
+    }
+
+    #[tokio::test]
+    async fn test_relay_due_failed() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().times(1).return_const(());
+
+        // 送信結果の保存（最大試行回数に達して送信を中止）
+        let mut mock_tx_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_tx_outbox_repo
+            .expect_update()
+            .withf(|_, message| message.status == OutboxStatus::Failed && message.attempts == 3)
+            .times(1)
+            .returning(|_, message| Ok(message.clone()));
+
+        // テスト実行
+        let usecase = create_usecase(
+            create_mock_outbox_repo(2),
+            mock_tx_outbox_repo,
+            create_failing_publisher(),
+            mock_logger,
+        );
+        let result = usecase.relay_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(result.failed, 1);
+    }
+}
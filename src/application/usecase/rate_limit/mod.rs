@@ -0,0 +1,5 @@
+pub mod rate_limit_policy;
+pub mod rate_limit_store_trait;
+
+// テストコード用のモジュール
+pub mod rate_limit_policy_test;
@@ -0,0 +1,71 @@
+// ハッシュマップ
+use std::collections::HashMap;
+
+// ルートグループの指定がない場合に適用するポリシー名
+pub const DEFAULT_GROUP: &str = "default";
+
+// レート制限のポリシー（トークンバケット）
+// ※period_secsの間にcapacity回までリクエストを許可し、トークンは一定の速度で補充する
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimitPolicy {
+    pub capacity: u32,
+    pub period_secs: u64,
+}
+
+impl RateLimitPolicy {
+    // 1秒あたりのトークン補充数
+    pub fn refill_per_sec(&self) -> f64 {
+        self.capacity as f64 / self.period_secs as f64
+    }
+
+    // 「回数/秒数」形式の文字列からポリシーを生成
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let (capacity, period_secs) = match value.trim().split_once('/') {
+            Some(v) => v,
+            None => return Err(format!("レート制限の値「{}」が不正です。", value)),
+        };
+
+        let capacity = capacity.trim().parse::<u32>().unwrap_or(0);
+        let period_secs = period_secs.trim().parse::<u64>().unwrap_or(0);
+        if capacity == 0 || period_secs == 0 {
+            return Err(format!("レート制限の値「{}」が不正です。", value));
+        }
+
+        Ok(Self {
+            capacity,
+            period_secs,
+        })
+    }
+}
+
+// 「グループ名=回数/秒数」のカンマ区切り形式の文字列からグループ別のポリシーを生成
+// ※空文字の場合はレート制限を無効にする
+pub fn parse_rules(rules: &str) -> Result<HashMap<String, RateLimitPolicy>, String> {
+    let mut policies = HashMap::new();
+
+    for rule in rules.split(',').map(|rule| rule.trim()) {
+        if rule.is_empty() {
+            continue;
+        }
+
+        let (group, value) = match rule.split_once('=') {
+            Some((group, value)) if !group.trim().is_empty() => (group.trim(), value),
+            _ => return Err(format!("レート制限の設定「{}」が不正です。", rule)),
+        };
+
+        policies.insert(group.to_string(), RateLimitPolicy::parse(value)?);
+    }
+
+    Ok(policies)
+}
+
+// ルートグループに適用するポリシーを取得（未設定の場合はデフォルトのポリシー）
+pub fn find_policy(
+    policies: &HashMap<String, RateLimitPolicy>,
+    group: &str,
+) -> Option<RateLimitPolicy> {
+    policies
+        .get(group)
+        .or_else(|| policies.get(DEFAULT_GROUP))
+        .cloned()
+}
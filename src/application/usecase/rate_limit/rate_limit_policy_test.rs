@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::application::usecase::rate_limit::rate_limit_policy::{
+        RateLimitPolicy, find_policy, parse_rules,
+    };
+
+    #[test]
+    fn test_parse() {
+        // テスト実行
+        let policy = RateLimitPolicy::parse("120/60").unwrap();
+
+        // 検証
+        assert_eq!(policy.capacity, 120);
+        assert_eq!(policy.period_secs, 60);
+        assert_eq!(policy.refill_per_sec(), 2.0);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        // 検証
+        assert!(RateLimitPolicy::parse("120").is_err());
+        assert!(RateLimitPolicy::parse("0/60").is_err());
+        assert!(RateLimitPolicy::parse("120/0").is_err());
+        assert!(RateLimitPolicy::parse("abc/60").is_err());
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        // テスト実行
+        let policies = parse_rules("default=120/60, auth=20/60").unwrap();
+
+        // 検証
+        assert_eq!(policies.len(), 2);
+        assert_eq!(
+            policies["default"],
+            RateLimitPolicy::parse("120/60").unwrap()
+        );
+        assert_eq!(policies["auth"], RateLimitPolicy::parse("20/60").unwrap());
+
+        // 空文字の場合はレート制限なし
+        assert!(parse_rules("").unwrap().is_empty());
+
+        // 不正な形式
+        assert!(parse_rules("default").is_err());
+        assert!(parse_rules("=120/60").is_err());
+        assert!(parse_rules("auth=20").is_err());
+    }
+
+    #[test]
+    fn test_find_policy() {
+        let policies = parse_rules("default=120/60,auth=20/60").unwrap();
+
+        // 検証
+        assert_eq!(find_policy(&policies, "auth").unwrap().capacity, 20);
+        assert_eq!(find_policy(&policies, "users").unwrap().capacity, 120);
+        assert!(find_policy(&parse_rules("auth=20/60").unwrap(), "users").is_none());
+    }
+}
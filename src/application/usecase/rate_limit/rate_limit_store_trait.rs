@@ -0,0 +1,35 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// レート制限のポリシー
+use crate::application::usecase::rate_limit::rate_limit_policy::RateLimitPolicy;
+
+// レート制限の判定結果
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimitDecision {
+    // リクエストを許可するかどうか
+    pub allowed: bool,
+    // 期間内の上限回数
+    pub limit: u32,
+    // 残りのリクエスト可能回数
+    pub remaining: u32,
+    // バケットが満タンに戻るまでの秒数
+    pub reset_secs: u64,
+    // 次のリクエストが可能になるまでの秒数（許可された場合は0）
+    pub retry_after_secs: u64,
+}
+
+// レート制限のストアのトレイト（共有ストアに差し替えできるように定義）
+#[mockall::automock]
+#[async_trait]
+pub trait RateLimitStoreTrait: Send + Sync {
+    // キーに対応するバケットからトークンを1つ取得する
+    async fn acquire(
+        &self,
+        key: &str,
+        policy: &RateLimitPolicy,
+    ) -> Result<RateLimitDecision, ErrorCommon>;
+}
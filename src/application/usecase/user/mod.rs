@@ -1,4 +1,20 @@
+pub mod user_audit_find_all;
+pub mod user_create;
+pub mod user_delete;
 pub mod user_find_all;
+pub mod user_find_by_uid;
+pub mod user_live;
+pub mod user_purge;
+pub mod user_stream;
+pub mod user_update;
 
 // テストコード用のモジュール
+pub mod user_audit_find_all_test;
+pub mod user_create_test;
+pub mod user_delete_test;
 pub mod user_find_all_test;
+pub mod user_find_by_uid_test;
+pub mod user_live_test;
+pub mod user_purge_test;
+pub mod user_stream_test;
+pub mod user_update_test;
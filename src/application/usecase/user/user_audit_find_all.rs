@@ -0,0 +1,126 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::audit::audit_log_model::AuditLog;
+use crate::domain::audit::audit_log_repository::AuditLogRepositoryTrait;
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::user::user_repository::UserRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UserAuditFindAllUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, uid: String) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct UserAuditFindAllRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+    pub audit_log_repository: Arc<dyn AuditLogRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct UserAuditFindAllUsecase {
+    pub repo: UserAuditFindAllRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UserAuditFindAllUsecase {
+    pub fn new(repo: UserAuditFindAllRepository, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        UserAuditFindAllUsecase { repo, logger }
+    }
+
+    // 監査ログの取得処理（削除済みのユーザーも監査ログがあれば返す）
+    async fn find_all(
+        &self,
+        ctx: &ContextRequest,
+        uid: &str,
+    ) -> Result<Vec<AuditLog>, ErrorCommon> {
+        let audit_logs = self
+            .repo
+            .audit_log_repository
+            .find_by_target_uid(ctx, uid)
+            .await?;
+
+        // 監査ログがなく、ユーザーも存在しない場合はエラー
+        if audit_logs.is_empty()
+            && self
+                .repo
+                .user_repository
+                .find_by_uid(ctx, uid)
+                .await?
+                .is_none()
+        {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::NOT_FOUND,
+                message: "対象のユーザーが存在しません。".to_string(),
+            });
+        }
+
+        Ok(audit_logs)
+    }
+}
+
+#[async_trait::async_trait]
+impl UserAuditFindAllUsecaseTrait for UserAuditFindAllUsecase {
+    async fn exec(&self, ctx: ContextRequest, uid: String) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック（本人であっても参照不可）
+        if let Err(err) = authorize(&ctx, Permission::UserAuditRead, Some(&uid)) {
+            // ログ出力
+            let err_msg = format!("UserAuditFindAllUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 監査ログの取得処理
+        match self.find_all(&ctx, &uid).await {
+            Ok(audit_logs) => (StatusCode::OK, res_header, Json(json!(audit_logs))).into_response(),
+            Err(err) => {
+                // エラーログ出力（入力値のエラーは除く）
+                if !err.status_code().is_client_error() {
+                    let err_msg = format!("UserAuditFindAllUsecaseでエラー: {}", err);
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::audit::audit_log_model::{AuditAction, AuditLog};
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::audit::audit_log_repository::MockAuditLogRepositoryTrait;
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // ユースケース
+    use crate::application::usecase::user::user_audit_find_all::UserAuditFindAllRepository;
+    use crate::application::usecase::user::user_audit_find_all::UserAuditFindAllUsecase;
+    use crate::application::usecase::user::user_audit_find_all::UserAuditFindAllUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(subject: &str, roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/users/xxxx-xxxx-xxxx-0001/audit".to_string(),
+            auth: Some(AuthContext {
+                subject: subject.to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // ユーザーの作成
+    fn create_user(uid: &str) -> User {
+        User::new(
+            uid.to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化（監査ログがある場合はユーザーの存在確認をしないこと）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_find_by_uid().never();
+
+        let mut mock_audit_log_repo = MockAuditLogRepositoryTrait::new();
+        mock_audit_log_repo
+            .expect_find_by_target_uid()
+            .withf(|_, uid| uid == "xxxx-xxxx-xxxx-0001")
+            .returning(|_, uid| {
+                let user = create_user(uid);
+                Ok(vec![AuditLog::for_user(
+                    "xxxx-xxxx-xxxx-0009",
+                    "xxx-yyy-zzz-000",
+                    AuditAction::Create,
+                    None,
+                    &user,
+                )])
+            });
+
+        // ユースケースのインスタンス化
+        let user_audit_find_all_usecase = UserAuditFindAllUsecase {
+            repo: UserAuditFindAllRepository {
+                user_repository: Arc::new(mock_user_repo),
+                audit_log_repository: Arc::new(mock_audit_log_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_audit_find_all_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: Vec<AuditLog> = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data.len(), 1);
+        assert_eq!(res_data[0].action, AuditAction::Create);
+        assert_eq!(res_data[0].actor, "xxxx-xxxx-xxxx-0009");
+        assert_eq!(res_data[0].target_uid, "xxxx-xxxx-xxxx-0001");
+    }
+
+    #[tokio::test]
+    async fn test_exec_success_empty() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化（ユーザーは存在するが監査ログがない）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_find_by_uid()
+            .returning(|_, uid| Ok(Some(create_user(uid))));
+
+        let mut mock_audit_log_repo = MockAuditLogRepositoryTrait::new();
+        mock_audit_log_repo
+            .expect_find_by_target_uid()
+            .returning(|_, _| Ok(vec![]));
+
+        // ユースケースのインスタンス化
+        let user_audit_find_all_usecase = UserAuditFindAllUsecase {
+            repo: UserAuditFindAllRepository {
+                user_repository: Arc::new(mock_user_repo),
+                audit_log_repository: Arc::new(mock_audit_log_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_audit_find_all_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: Vec<AuditLog> = serde_json::from_str(body_str).unwrap();
+        assert!(res_data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_find_by_uid()
+            .returning(|_, _| Ok(None));
+
+        let mut mock_audit_log_repo = MockAuditLogRepositoryTrait::new();
+        mock_audit_log_repo
+            .expect_find_by_target_uid()
+            .returning(|_, _| Ok(vec![]));
+
+        // ユースケースのインスタンス化
+        let user_audit_find_all_usecase = UserAuditFindAllUsecase {
+            repo: UserAuditFindAllRepository {
+                user_repository: Arc::new(mock_user_repo),
+                audit_log_repository: Arc::new(mock_audit_log_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_audit_find_all_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // 本人・operatorロールのどちらも参照不可
+        let cases = vec![
+            ("xxxx-xxxx-xxxx-0001", vec![]),
+            ("xxxx-xxxx-xxxx-0002", vec!["operator".to_string()]),
+        ];
+
+        for (subject, roles) in cases {
+            // ロガーのモック化
+            let mut mock_logger = MockLoggerTrait::new();
+            mock_logger.expect_warn().returning(|_, _| ());
+
+            // リポジトリのモック化（呼ばれないこと）
+            let mut mock_user_repo = MockUserRepositoryTrait::new();
+            mock_user_repo.expect_find_by_uid().never();
+
+            let mut mock_audit_log_repo = MockAuditLogRepositoryTrait::new();
+            mock_audit_log_repo.expect_find_by_target_uid().never();
+
+            // ユースケースのインスタンス化
+            let user_audit_find_all_usecase = UserAuditFindAllUsecase {
+                repo: UserAuditFindAllRepository {
+                    user_repository: Arc::new(mock_user_repo),
+                    audit_log_repository: Arc::new(mock_audit_log_repo),
+                },
+                logger: Arc::new(mock_logger),
+            };
+
+            // テスト実行
+            let ctx = create_ctx(subject, roles);
+            let res = user_audit_find_all_usecase
+                .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string())
+                .await;
+
+            // 検証
+            assert_eq!(res.status(), StatusCode::FORBIDDEN);
+        }
+    }
+}
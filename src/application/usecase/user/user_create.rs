@@ -0,0 +1,197 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// UUID
+use uuid::Uuid;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::audit::audit_log_model::{AuditAction, AuditLog};
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::outbox::outbox_model::OutboxMessage;
+use crate::domain::transaction::unit_of_work::{
+    TransactionTrait, UnitOfWorkTrait, finish_transaction,
+};
+use crate::domain::user::user_model::User;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ドメインイベントのディスパッチャー
+use crate::application::usecase::event::domain_event_dispatcher::DomainEventDispatcherTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize, Debug)]
+pub struct UserCreateInput {
+    pub last_name: String,
+    pub first_name: String,
+    pub email: String,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UserCreateUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: UserCreateInput) -> Response;
+    // 権限チェックなしでのユーザー作成（外部IDプロバイダーからのプロビジョニング用）
+    async fn create_user(
+        &self,
+        ctx: &ContextRequest,
+        input: UserCreateInput,
+    ) -> Result<User, ErrorCommon>;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct UserCreateRepository {
+    pub unit_of_work: Arc<dyn UnitOfWorkTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct UserCreateUsecase {
+    pub repo: UserCreateRepository,
+    pub event_dispatcher: Arc<dyn DomainEventDispatcherTrait + 'static>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UserCreateUsecase {
+    pub fn new(
+        repo: UserCreateRepository,
+        event_dispatcher: Arc<dyn DomainEventDispatcherTrait + 'static>,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        UserCreateUsecase {
+            repo,
+            event_dispatcher,
+            logger,
+        }
+    }
+
+    // ユーザー作成処理（トランザクション内で実行）
+    async fn create_in_tx(
+        &self,
+        ctx: &ContextRequest,
+        tx: &dyn TransactionTrait,
+        user: &User,
+    ) -> Result<User, ErrorCommon> {
+        let created_user = tx.user_repository().create(ctx, user).await?;
+
+        // ドメインイベントをアウトボックスに保存（ユーザー作成と同じトランザクション）
+        tx.outbox_repository()
+            .add(ctx, &OutboxMessage::from_events(&user.events))
+            .await?;
+
+        // 監査ログの保存（ユーザー作成と同じトランザクション）
+        let audit_log = AuditLog::for_user(
+            ctx.actor_id(),
+            ctx.request_id(),
+            AuditAction::Create,
+            None,
+            &created_user,
+        );
+        tx.audit_log_repository().add(ctx, &audit_log).await?;
+
+        Ok(created_user)
+    }
+}
+
+#[async_trait::async_trait]
+impl UserCreateUsecaseTrait for UserCreateUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: UserCreateInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::UserCreate, None) {
+            // ログ出力
+            let err_msg = format!("UserCreateUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // ユーザー作成処理
+        let user = match self.create_user(&ctx, input).await {
+            Ok(user) => user,
+            Err(err) => {
+                // エラーログ出力（入力値のエラーは除く）
+                if !err.status_code().is_client_error() {
+                    let err_msg = format!("UserCreateUsecaseでエラー: {}", err);
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // ETagはドメインモデルから算出して付与
+        let etag = user.etag();
+        let res_header = [("X-Request-Id", request_id), ("ETag", etag.as_str())];
+
+        // レスポンス結果を設定して戻り値として返す
+        (StatusCode::CREATED, res_header, Json(json!(user))).into_response()
+    }
+
+    async fn create_user(
+        &self,
+        ctx: &ContextRequest,
+        input: UserCreateInput,
+    ) -> Result<User, ErrorCommon> {
+        // パラメータチェック
+        if let Err(msg) = User::validate_profile(&input.last_name, &input.first_name, &input.email)
+        {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_REQUEST,
+                message: msg,
+            });
+        }
+
+        // ユーザー作成処理（トランザクション内で実行）
+        let mut user = User::new(
+            Uuid::new_v4().to_string(),
+            input.last_name,
+            input.first_name,
+            input.email,
+        );
+        let tx = self.repo.unit_of_work.begin(ctx).await?;
+        let result = self.create_in_tx(ctx, tx.as_ref(), &user).await;
+        let created_user = finish_transaction(tx, result).await?;
+
+        // ドメインイベントの通知（コミット後）
+        self.event_dispatcher
+            .dispatch(ctx, user.take_events())
+            .await;
+
+        Ok(created_user)
+    }
+}
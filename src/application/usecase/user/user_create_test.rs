@@ -0,0 +1,271 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::{error::error_common::ErrorCommon, user::user_model::User};
+
+    // ドメインイベントのディスパッチャーのモック
+    use crate::application::usecase::event::domain_event_dispatcher::MockDomainEventDispatcherTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::outbox::outbox_repository::MockOutboxRepositoryTrait;
+    use crate::domain::transaction::unit_of_work_test_helper::{
+        ExpectedFinish, create_mock_unit_of_work, create_mock_unit_of_work_with_outbox,
+    };
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // ユースケース
+    use crate::application::usecase::user::user_create::UserCreateInput;
+    use crate::application::usecase::user::user_create::UserCreateRepository;
+    use crate::application::usecase::user::user_create::UserCreateUsecase;
+    use crate::application::usecase::user::user_create::UserCreateUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/users".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 入力値の作成
+    fn create_input(last_name: &str) -> UserCreateInput {
+        UserCreateInput {
+            last_name: last_name.to_string(),
+            first_name: "三郎".to_string(),
+            email: "s.suzuki@example.com".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_create().returning(|_, user| {
+            let mut user = user.clone();
+            user.id = 3;
+            Ok(user)
+        });
+
+        // アウトボックスのモック化（同じトランザクションで保存されること）
+        let mut mock_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_outbox_repo
+            .expect_add()
+            .withf(|_, messages| messages.len() == 1 && messages[0].event_type == "user.created")
+            .times(1)
+            .returning(|_, messages| Ok(messages.to_vec()));
+
+        // ドメインイベントのディスパッチャーのモック化（コミット後に通知されること）
+        let mut mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+        mock_event_dispatcher
+            .expect_dispatch()
+            .withf(|_, events| events.len() == 1 && events[0].event_type() == "user.created")
+            .times(1)
+            .return_const(());
+
+        // ユースケースのインスタンス化
+        let user_create_usecase = UserCreateUsecase {
+            repo: UserCreateRepository {
+                unit_of_work: Arc::new(create_mock_unit_of_work_with_outbox(
+                    mock_user_repo,
+                    mock_outbox_repo,
+                    ExpectedFinish::Commit,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx(vec!["admin".to_string()]);
+        let res = user_create_usecase.exec(ctx, create_input("鈴木")).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::CREATED);
+        let etag = res.headers()["ETag"].to_str().unwrap().to_string();
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: User = serde_json::from_str(body_str).unwrap();
+        assert_eq!(etag, res_data.etag());
+        assert_eq!(res_data.id, 3);
+        assert!(!res_data.uid.is_empty());
+        assert_eq!(res_data.last_name, "鈴木");
+        assert_eq!(res_data.first_name, "三郎");
+        assert_eq!(res_data.email, "s.suzuki@example.com");
+        assert!(res_data.deleted_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exec_bad_request() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化（呼ばれないこと）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_create().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_create_usecase = UserCreateUsecase {
+            repo: UserCreateRepository {
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx(vec!["admin".to_string()]);
+        let res = user_create_usecase.exec(ctx, create_input("")).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        assert!(body_str.contains("last_nameは必須です。"));
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        // リポジトリのモック化（呼ばれないこと）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_create().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_create_usecase = UserCreateUsecase {
+            repo: UserCreateRepository {
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行（operatorは作成不可）
+        let ctx = create_ctx(vec!["operator".to_string()]);
+        let res = user_create_usecase.exec(ctx, create_input("鈴木")).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_exec_error() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_create()
+            .returning(|_, _| Err(ErrorCommon::InternalServerError));
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_create_usecase = UserCreateUsecase {
+            repo: UserCreateRepository {
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::Rollback,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx(vec!["admin".to_string()]);
+        let res = user_create_usecase.exec(ctx, create_input("鈴木")).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_exec_outbox_error() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_create()
+            .returning(|_, user| Ok(user.clone()));
+
+        // アウトボックスのモック化（保存に失敗した場合はユーザー作成もロールバックされること）
+        let mut mock_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_outbox_repo
+            .expect_add()
+            .returning(|_, _| Err(ErrorCommon::InternalServerError));
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_create_usecase = UserCreateUsecase {
+            repo: UserCreateRepository {
+                unit_of_work: Arc::new(create_mock_unit_of_work_with_outbox(
+                    mock_user_repo,
+                    mock_outbox_repo,
+                    ExpectedFinish::Rollback,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx(vec!["admin".to_string()]);
+        let res = user_create_usecase.exec(ctx, create_input("鈴木")).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,195 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::audit::audit_log_model::{AuditAction, AuditLog};
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::event::domain_event::DomainEvent;
+use crate::domain::outbox::outbox_model::OutboxMessage;
+use crate::domain::transaction::unit_of_work::{
+    TransactionTrait, UnitOfWorkTrait, finish_transaction,
+};
+use crate::domain::user::user_model::User;
+use crate::domain::user::user_repository::UserRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ドメインイベントのディスパッチャー
+use crate::application::usecase::event::domain_event_dispatcher::DomainEventDispatcherTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UserDeleteUsecaseTrait {
+    async fn exec(
+        &self,
+        ctx: ContextRequest,
+        uid: String,
+        if_match: Option<Vec<String>>,
+    ) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct UserDeleteRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+    pub unit_of_work: Arc<dyn UnitOfWorkTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct UserDeleteUsecase {
+    pub repo: UserDeleteRepository,
+    pub event_dispatcher: Arc<dyn DomainEventDispatcherTrait + 'static>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UserDeleteUsecase {
+    pub fn new(
+        repo: UserDeleteRepository,
+        event_dispatcher: Arc<dyn DomainEventDispatcherTrait + 'static>,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        UserDeleteUsecase {
+            repo,
+            event_dispatcher,
+            logger,
+        }
+    }
+
+    // ユーザー削除処理（論理削除、保存はトランザクション内で実行）
+    // ※対象ユーザーの取得とIf-Matchの比較はトランザクション外で行い、取得時のバージョンで更新する
+    // ※取得してから保存するまでに他の操作で更新された場合はリポジトリで409エラー
+    async fn delete(
+        &self,
+        ctx: &ContextRequest,
+        uid: &str,
+        if_match: Option<Vec<String>>,
+    ) -> Result<(), ErrorCommon> {
+        // 対象ユーザーの取得
+        let mut user = match self.repo.user_repository.find_by_uid(ctx, uid).await? {
+            Some(user) => user,
+            None => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::NOT_FOUND,
+                    message: "対象のユーザーが存在しません。".to_string(),
+                });
+            }
+        };
+
+        // If-Matchで指定されたETagと現在のETagの比較（強い比較）
+        if if_match.is_some_and(|etags| !user.matches_etag(&etags)) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::PRECONDITION_FAILED,
+                message:
+                    "対象のリソースが更新されています。最新の情報を取得してから再度実行して下さい。"
+                        .to_string(),
+            });
+        }
+
+        // 論理削除設定
+        let before = user.clone();
+        user.set_delete();
+
+        let tx = self.repo.unit_of_work.begin(ctx).await?;
+        let result = self.delete_in_tx(ctx, tx.as_ref(), &before, user).await;
+        let events = finish_transaction(tx, result).await?;
+
+        // ドメインイベントの通知（コミット後）
+        self.event_dispatcher.dispatch(ctx, events).await;
+
+        Ok(())
+    }
+
+    async fn delete_in_tx(
+        &self,
+        ctx: &ContextRequest,
+        tx: &dyn TransactionTrait,
+        before: &User,
+        mut user: User,
+    ) -> Result<Vec<DomainEvent>, ErrorCommon> {
+        // 取得時のバージョンで更新
+        tx.user_repository()
+            .update(ctx, &user, before.version)
+            .await?;
+
+        // ドメインイベントをアウトボックスに保存（ユーザー削除と同じトランザクション）
+        tx.outbox_repository()
+            .add(ctx, &OutboxMessage::from_events(&user.events))
+            .await?;
+
+        // 監査ログの保存（ユーザー削除と同じトランザクション）
+        let audit_log = AuditLog::for_user(
+            ctx.actor_id(),
+            ctx.request_id(),
+            AuditAction::Delete,
+            Some(before),
+            &user,
+        );
+        tx.audit_log_repository().add(ctx, &audit_log).await?;
+
+        Ok(user.take_events())
+    }
+}
+
+#[async_trait::async_trait]
+impl UserDeleteUsecaseTrait for UserDeleteUsecase {
+    async fn exec(
+        &self,
+        ctx: ContextRequest,
+        uid: String,
+        if_match: Option<Vec<String>>,
+    ) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::UserDelete, Some(&uid)) {
+            // ログ出力
+            let err_msg = format!("UserDeleteUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // ユーザー削除処理
+        match self.delete(&ctx, &uid, if_match).await {
+            Ok(_) => (StatusCode::NO_CONTENT, res_header).into_response(),
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("UserDeleteUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,372 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::audit::audit_log_model::AuditAction;
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::user::user_model::User;
+
+    // ドメインイベントのディスパッチャーのモック
+    use crate::application::usecase::event::domain_event_dispatcher::MockDomainEventDispatcherTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::audit::audit_log_repository::MockAuditLogRepositoryTrait;
+    use crate::domain::outbox::outbox_repository::MockOutboxRepositoryTrait;
+    use crate::domain::transaction::unit_of_work_test_helper::{
+        ExpectedFinish, create_mock_unit_of_work, create_mock_unit_of_work_with_audit_log,
+        create_mock_unit_of_work_with_outbox,
+    };
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // ユースケース
+    use crate::application::usecase::user::user_delete::UserDeleteRepository;
+    use crate::application::usecase::user::user_delete::UserDeleteUsecase;
+    use crate::application::usecase::user::user_delete::UserDeleteUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(subject: &str, roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "DELETE".to_string(),
+            uri: "/api/v1/users/xxxx-xxxx-xxxx-0001".to_string(),
+            auth: Some(AuthContext {
+                subject: subject.to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo
+            .expect_find_by_uid()
+            .returning(|_, uid| {
+                // 保存済みのユーザー（記録済みのドメインイベントなし）
+                let mut user = User::new(
+                    uid.to_string(),
+                    "田中".to_string(),
+                    "太郎".to_string(),
+                    "t.tanaka@example.com".to_string(),
+                );
+                user.take_events();
+                Ok(Some(user))
+            });
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_update()
+            .withf(|_, user, expected_version| {
+                user.deleted_at.is_some() && user.version == 2 && *expected_version == 1
+            })
+            .returning(|_, user, _| Ok(user.clone()));
+
+        // アウトボックスのモック化（同じトランザクションで保存されること）
+        let mut mock_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_outbox_repo
+            .expect_add()
+            .withf(|_, messages| messages.len() == 1 && messages[0].event_type == "user.deleted")
+            .times(1)
+            .returning(|_, messages| Ok(messages.to_vec()));
+
+        // ドメインイベントのディスパッチャーのモック化（コミット後に通知されること）
+        let mut mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+        mock_event_dispatcher
+            .expect_dispatch()
+            .withf(|_, events| events.len() == 1 && events[0].event_type() == "user.deleted")
+            .times(1)
+            .return_const(());
+
+        // ユースケースのインスタンス化
+        let user_delete_usecase = UserDeleteUsecase {
+            repo: UserDeleteRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work_with_outbox(
+                    mock_user_repo,
+                    mock_outbox_repo,
+                    ExpectedFinish::Commit,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_delete_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), None)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_audit_log() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo
+            .expect_find_by_uid()
+            .returning(|_, uid| {
+                let mut user = User::new(
+                    uid.to_string(),
+                    "田中".to_string(),
+                    "太郎".to_string(),
+                    "t.tanaka@example.com".to_string(),
+                );
+                user.take_events();
+                Ok(Some(user))
+            });
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_update()
+            .returning(|_, user, _| Ok(user.clone()));
+
+        // 監査ログのモック化（実行者・リクエストID・削除日時の変更が記録されること）
+        let mut mock_audit_log_repo = MockAuditLogRepositoryTrait::new();
+        mock_audit_log_repo
+            .expect_add()
+            .withf(|_, audit_log| {
+                audit_log.actor == "xxxx-xxxx-xxxx-0009"
+                    && audit_log.action == AuditAction::Delete
+                    && audit_log.target_uid == "xxxx-xxxx-xxxx-0001"
+                    && audit_log.request_id == "xxx-yyy-zzz-001"
+                    && audit_log.changes.len() == 1
+                    && audit_log.changes[0].field == "deleted_at"
+                    && audit_log.changes[0].before.is_null()
+                    && !audit_log.changes[0].after.is_null()
+            })
+            .times(1)
+            .returning(|_, audit_log| Ok(audit_log.clone()));
+
+        // ドメインイベントのディスパッチャーのモック化
+        let mut mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+        mock_event_dispatcher.expect_dispatch().return_const(());
+
+        // ユースケースのインスタンス化
+        let user_delete_usecase = UserDeleteUsecase {
+            repo: UserDeleteRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work_with_audit_log(
+                    mock_user_repo,
+                    mock_audit_log_repo,
+                    ExpectedFinish::Commit,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_delete_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), None)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        // リポジトリのモック化（呼ばれないこと）
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo.expect_find_by_uid().never();
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_update().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_delete_usecase = UserDeleteUsecase {
+            repo: UserDeleteRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行（本人でも削除は不可）
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0001", vec![]);
+        let res = user_delete_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), None)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo
+            .expect_find_by_uid()
+            .returning(|_, _| Ok(None));
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_update().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_delete_usecase = UserDeleteUsecase {
+            repo: UserDeleteRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_delete_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), None)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_conflict() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化（他の操作によりバージョンが更新済み）
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo
+            .expect_find_by_uid()
+            .returning(|_, uid| {
+                // 保存済みのユーザー（記録済みのドメインイベントなし）
+                let mut user = User::new(
+                    uid.to_string(),
+                    "田中".to_string(),
+                    "太郎".to_string(),
+                    "t.tanaka@example.com".to_string(),
+                );
+                user.take_events();
+                Ok(Some(user))
+            });
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_update().returning(|_, _, _| {
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::CONFLICT,
+                message: "対象のユーザーは他の操作により更新されています。".to_string(),
+            })
+        });
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_delete_usecase = UserDeleteUsecase {
+            repo: UserDeleteRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::Rollback,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_delete_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), None)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_precondition_failed() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化（If-MatchのETagが古い場合は削除しないこと）
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo
+            .expect_find_by_uid()
+            .returning(|_, uid| {
+                // 保存済みのユーザー（更新によりバージョンが2）
+                let mut user = User::new(
+                    uid.to_string(),
+                    "田中".to_string(),
+                    "太郎".to_string(),
+                    "t.tanaka@example.com".to_string(),
+                );
+                user.version = 2;
+                user.take_events();
+                Ok(Some(user))
+            });
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_update().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_delete_usecase = UserDeleteUsecase {
+            repo: UserDeleteRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let if_match = vec!["\"1-00000000000000000000000000000000\"".to_string()];
+        let res = user_delete_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), Some(if_match))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+    }
+}
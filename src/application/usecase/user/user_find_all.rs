@@ -15,9 +15,13 @@ use serde_json::json;
 use crate::application::usecase::context::context_request::ContextRequest;
 
 // ドメイン
-use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::user::user_model::User;
 use crate::domain::user::user_repository::UserRepositoryTrait;
 
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
 // ロガー
 use crate::application::usecase::logger::logger_trait::LoggerTrait;
 
@@ -57,6 +61,19 @@ impl UserFindAllUsecaseTrait for UserFindAllUsecase {
         let request_id = x_request_id.expect("-").to_str().unwrap();
         let res_header = [("X-Request-Id", request_id)];
 
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::UserReadAll, None) {
+            // ログ出力
+            let err_msg = format!("UserFindAllUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
         // 全てのユーザー取得処理
         let users = match self.repo.user_repository.find_all(&ctx).await {
             Ok(users) => users,
@@ -69,10 +86,7 @@ impl UserFindAllUsecaseTrait for UserFindAllUsecase {
                 let json_msg = Json(json!({ "message": err.to_string()}));
 
                 // ステータスコードの設定
-                let status_code = match err {
-                    ErrorCommon::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
-                    ErrorCommon::CustomError { status_code, .. } => status_code,
-                };
+                let status_code = err.status_code();
 
                 // レスポンス結果の設定
                 let res = (status_code, res_header, json_msg).into_response();
@@ -85,6 +99,10 @@ impl UserFindAllUsecaseTrait for UserFindAllUsecase {
         // レスポンスボディの設定
         let res_body = Json(json!(users));
 
+        // ETagはドメインモデルから算出して付与
+        let etag = User::list_etag(&users);
+        let res_header = [("X-Request-Id", request_id), ("ETag", etag.as_str())];
+
         // レスポンス結果を設定して戻り値として返す
         (StatusCode::OK, res_header, res_body).into_response()
     }
@@ -8,7 +8,7 @@ mod tests {
     use std::{str, sync::Arc};
 
     // 共通コンテキスト
-    use crate::application::usecase::context::context_request::ContextRequest;
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
 
     // ドメイン
     use crate::domain::{error::error_common::ErrorCommon, user::user_model::User};
@@ -40,9 +40,12 @@ mod tests {
                 last_name: "田中".to_string(),
                 first_name: "太郎".to_string(),
                 email: "t.tanaka@example.com".to_string(),
+                version: 1,
                 created_at: now,
                 updated_at: now,
                 deleted_at: None,
+                anonymized_at: None,
+                events: Vec::new(),
             },
             User {
                 id: 2,
@@ -50,9 +53,12 @@ mod tests {
                 last_name: "佐藤".to_string(),
                 first_name: "二郎".to_string(),
                 email: "z.satou@example.com".to_string(),
+                version: 1,
                 created_at: now,
                 updated_at: now,
                 deleted_at: None,
+                anonymized_at: None,
+                events: Vec::new(),
             },
         ];
         mock_user_repo
@@ -75,6 +81,11 @@ mod tests {
             header: h,
             method: "GET".to_string(),
             uri: "/api/v1/users".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles: vec!["admin".to_string()],
+            }),
         };
 
         // テスト実行
@@ -82,12 +93,14 @@ mod tests {
 
         // 検証
         assert_eq!(res.status(), StatusCode::OK);
+        let etag = res.headers()["ETag"].to_str().unwrap().to_string();
 
         // レスポンスボディの検証
         let body = res.into_body();
         let bytes = to_bytes(body, usize::MAX).await.unwrap();
         let body_str = std::str::from_utf8(&bytes).unwrap();
         let res_data: Vec<User> = serde_json::from_str(body_str).unwrap();
+        assert_eq!(etag, User::list_etag(&res_data));
         assert_eq!(res_data.len(), 2);
 
         assert_eq!(res_data[0].id, 1);
@@ -144,6 +157,11 @@ mod tests {
             header: h,
             method: "GET".to_string(),
             uri: "/api/v1/users".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles: vec!["admin".to_string()],
+            }),
         };
 
         // テスト実行
@@ -158,4 +176,44 @@ mod tests {
         let body_str = str::from_utf8(&bytes).unwrap();
         assert!(body_str.contains("Internal Server Error"));
     }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        // リポジトリのモック化（呼ばれないこと）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_find_all().never();
+
+        // ユースケースのインスタンス化
+        let user_find_all_usecase = UserFindAllUsecase {
+            repo: UserFindAllRepository {
+                user_repository: Arc::new(mock_user_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // 共通コンテキスト設定（ロールなし）
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        let ctx = ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/users".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles: vec![],
+            }),
+        };
+
+        // テスト実行
+        let res = user_find_all_usecase.exec(ctx).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
 }
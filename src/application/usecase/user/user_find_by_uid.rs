@@ -0,0 +1,109 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::user::user_repository::UserRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UserFindByUidUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, uid: String) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct UserFindByUidRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct UserFindByUidUsecase {
+    pub repo: UserFindByUidRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UserFindByUidUsecase {
+    pub fn new(repo: UserFindByUidRepository, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        UserFindByUidUsecase { repo, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl UserFindByUidUsecaseTrait for UserFindByUidUsecase {
+    async fn exec(&self, ctx: ContextRequest, uid: String) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::UserRead, Some(&uid)) {
+            // ログ出力
+            let err_msg = format!("UserFindByUidUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // ユーザー取得処理
+        let result = match self.repo.user_repository.find_by_uid(&ctx, &uid).await {
+            Ok(Some(user)) => Ok(user),
+            Ok(None) => Err(ErrorCommon::CustomError {
+                status_code: StatusCode::NOT_FOUND,
+                message: "対象のユーザーが存在しません。".to_string(),
+            }),
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("UserFindByUidUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                Err(err)
+            }
+        };
+
+        match result {
+            Ok(user) => {
+                // ETagはドメインモデルから算出して付与
+                let etag = user.etag();
+                let res_header = [("X-Request-Id", request_id), ("ETag", etag.as_str())];
+
+                // レスポンス結果を設定して戻り値として返す
+                (StatusCode::OK, res_header, Json(json!(user))).into_response()
+            }
+            Err(err) => {
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
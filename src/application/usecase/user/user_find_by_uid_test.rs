@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+
+    // ユースケース
+    use crate::application::usecase::user::user_find_by_uid::UserFindByUidRepository;
+    use crate::application::usecase::user::user_find_by_uid::UserFindByUidUsecase;
+    use crate::application::usecase::user::user_find_by_uid::UserFindByUidUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(subject: &str, roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/users/xxxx-xxxx-xxxx-0001".to_string(),
+            auth: Some(AuthContext {
+                subject: subject.to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_find_by_uid()
+            .withf(|_, uid| uid == "xxxx-xxxx-xxxx-0001")
+            .returning(|_, uid| {
+                Ok(Some(User::new(
+                    uid.to_string(),
+                    "田中".to_string(),
+                    "太郎".to_string(),
+                    "t.tanaka@example.com".to_string(),
+                )))
+            });
+
+        // ユースケースのインスタンス化
+        let user_find_by_uid_usecase = UserFindByUidUsecase {
+            repo: UserFindByUidRepository {
+                user_repository: Arc::new(mock_user_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行（本人による参照）
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0001", vec![]);
+        let res = user_find_by_uid_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        let etag = res.headers()["ETag"].to_str().unwrap().to_string();
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: User = serde_json::from_str(body_str).unwrap();
+        assert_eq!(etag, res_data.etag());
+        assert_eq!(res_data.uid, "xxxx-xxxx-xxxx-0001");
+        assert_eq!(res_data.last_name, "田中");
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_find_by_uid()
+            .returning(|_, _| Ok(None));
+
+        // ユースケースのインスタンス化
+        let user_find_by_uid_usecase = UserFindByUidUsecase {
+            repo: UserFindByUidRepository {
+                user_repository: Arc::new(mock_user_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_find_by_uid_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        // リポジトリのモック化（呼ばれないこと）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_find_by_uid().never();
+
+        // ユースケースのインスタンス化
+        let user_find_by_uid_usecase = UserFindByUidUsecase {
+            repo: UserFindByUidRepository {
+                user_repository: Arc::new(mock_user_repo),
+            },
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行（他人のユーザーを参照）
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0002", vec![]);
+        let res = user_find_by_uid_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,356 @@
+// axum
+use axum::{
+    Json,
+    body::Bytes,
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// 購読中のユーザーの保持用（順序を固定）
+use std::collections::BTreeSet;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 時間
+use std::time::Duration;
+
+// シリアライズ・デシリアライズ
+use serde::{Deserialize, Serialize};
+
+// json変換用マクロ
+use serde_json::json;
+
+// 接続への配信用のチャネル
+use tokio::sync::broadcast;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::event::domain_event::DomainEvent;
+
+// ユーザーの変更と接続状態の配信
+use crate::application::usecase::event::user_live_hub::{LiveMessage, UserLiveHubTrait};
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 接続時に選択するサブプロトコル
+pub const LIVE_PROTOCOL: &str = "users.v1";
+
+// クライアントから受信するメッセージ
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveClientMessage {
+    // ユーザーの購読
+    Subscribe { uids: Vec<String> },
+    // ユーザーの購読の解除
+    Unsubscribe { uids: Vec<String> },
+}
+
+// クライアントに送信するメッセージ
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveServerMessage {
+    // 購読・購読の解除の結果（購読中の全てのユーザー）
+    Subscriptions {
+        uids: Vec<String>,
+    },
+    // 購読中のユーザーの変更
+    Event {
+        uid: String,
+        event_type: String,
+        event: DomainEvent,
+    },
+    // 購読中のユーザーの接続状態（購読時は現在の状態を送信）
+    Presence {
+        uid: String,
+        online: bool,
+    },
+    // 受信が遅れて破棄されたメッセージがある（購読中のユーザーを再取得して下さい）
+    Lagged {
+        missed: u64,
+    },
+    // エラー（接続は継続）
+    Error {
+        message: String,
+    },
+}
+
+// 接続ごとの購読の状態
+pub struct UserLiveSession {
+    ctx: ContextRequest,
+    hub: Arc<dyn UserLiveHubTrait + 'static>,
+    max_subscriptions: usize,
+    subscriptions: BTreeSet<String>,
+}
+
+impl UserLiveSession {
+    pub fn new(
+        ctx: ContextRequest,
+        hub: Arc<dyn UserLiveHubTrait + 'static>,
+        max_subscriptions: usize,
+    ) -> Self {
+        UserLiveSession {
+            ctx,
+            hub,
+            max_subscriptions,
+            subscriptions: BTreeSet::new(),
+        }
+    }
+
+    // 購読中の全てのユーザー
+    fn subscriptions(&self) -> LiveServerMessage {
+        LiveServerMessage::Subscriptions {
+            uids: self.subscriptions.iter().cloned().collect(),
+        }
+    }
+
+    // エラーメッセージ
+    fn error(message: String) -> Vec<LiveServerMessage> {
+        vec![LiveServerMessage::Error { message }]
+    }
+
+    // クライアントから受信したメッセージの処理（送信するメッセージを返す）
+    pub fn handle_client_message(&mut self, text: &str) -> Vec<LiveServerMessage> {
+        let message = match serde_json::from_str::<LiveClientMessage>(text) {
+            Ok(message) => message,
+            Err(err) => return Self::error(format!("メッセージの形式が不正です。({})", err)),
+        };
+
+        match message {
+            LiveClientMessage::Subscribe { uids } => {
+                let new_uids: BTreeSet<String> = uids
+                    .into_iter()
+                    .filter(|uid| !self.subscriptions.contains(uid))
+                    .collect();
+
+                // 権限チェック（本人以外はユーザーの参照権限が必要、1件でもエラーの場合は購読しない）
+                for uid in new_uids.iter() {
+                    if let Err(err) = authorize(&self.ctx, Permission::UserRead, Some(uid)) {
+                        return Self::error(format!("{}（uid={}）", err, uid));
+                    }
+                }
+
+                // 購読数の上限チェック
+                if self.subscriptions.len() + new_uids.len() > self.max_subscriptions {
+                    return Self::error(format!(
+                        "購読数の上限（{}件）を超えています。",
+                        self.max_subscriptions
+                    ));
+                }
+
+                // 購読結果と購読したユーザーの現在の接続状態を送信
+                self.subscriptions.extend(new_uids.iter().cloned());
+                let mut replies = vec![self.subscriptions()];
+                replies.extend(new_uids.into_iter().map(|uid| LiveServerMessage::Presence {
+                    online: self.hub.is_online(&uid),
+                    uid,
+                }));
+                replies
+            }
+            LiveClientMessage::Unsubscribe { uids } => {
+                for uid in uids.iter() {
+                    self.subscriptions.remove(uid);
+                }
+                vec![self.subscriptions()]
+            }
+        }
+    }
+
+    // 配信されたメッセージの処理（購読していないユーザーの場合はNone）
+    pub fn handle_live_message(&self, message: LiveMessage) -> Option<LiveServerMessage> {
+        match message {
+            LiveMessage::Event(event) if self.subscriptions.contains(event.aggregate_id()) => {
+                Some(LiveServerMessage::Event {
+                    uid: event.aggregate_id().to_string(),
+                    event_type: event.event_type().to_string(),
+                    event,
+                })
+            }
+            LiveMessage::Presence { uid, online } if self.subscriptions.contains(&uid) => {
+                Some(LiveServerMessage::Presence { uid, online })
+            }
+            _ => None,
+        }
+    }
+}
+
+// 接続中の間、ユーザーの接続数を加算（切断時に減算）
+struct PresenceGuard {
+    hub: Arc<dyn UserLiveHubTrait + 'static>,
+    uid: String,
+}
+
+impl PresenceGuard {
+    fn new(hub: Arc<dyn UserLiveHubTrait + 'static>, uid: &str) -> Self {
+        hub.join(uid);
+        PresenceGuard {
+            hub,
+            uid: uid.to_string(),
+        }
+    }
+}
+
+impl Drop for PresenceGuard {
+    fn drop(&mut self) {
+        self.hub.leave(&self.uid);
+    }
+}
+
+// クライアントへのメッセージの送信
+async fn send_message(
+    socket: &mut WebSocket,
+    message: &LiveServerMessage,
+) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).unwrap_or_default();
+    socket.send(Message::Text(text.into())).await
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UserLiveUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, ws: WebSocketUpgrade) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct UserLiveRepository {
+    pub live_hub: Arc<dyn UserLiveHubTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct UserLiveUsecase {
+    pub repo: UserLiveRepository,
+    // 接続ごとの購読数の上限
+    pub max_subscriptions: usize,
+    // 受信するメッセージの最大サイズ（バイト）
+    pub max_message_bytes: usize,
+    // Pingの送信間隔
+    pub ping_interval: Duration,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UserLiveUsecase {
+    pub fn new(
+        repo: UserLiveRepository,
+        max_subscriptions: usize,
+        max_message_bytes: usize,
+        ping_interval: Duration,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        UserLiveUsecase {
+            repo,
+            max_subscriptions,
+            max_message_bytes,
+            ping_interval,
+            logger,
+        }
+    }
+
+    // 接続の処理（切断されるまで送受信）
+    async fn run(self, ctx: ContextRequest, subject: String, mut socket: WebSocket) {
+        // 配信の受信を開始してから接続数を加算
+        let mut receiver = self.repo.live_hub.subscribe();
+        let _presence = PresenceGuard::new(self.repo.live_hub.clone(), &subject);
+        let mut session = UserLiveSession::new(
+            ctx.clone(),
+            self.repo.live_hub.clone(),
+            self.max_subscriptions,
+        );
+
+        self.logger
+            .info(&ctx, &format!("WebSocketの接続（subject={}）", subject));
+
+        let mut ping = tokio::time::interval(self.ping_interval);
+        ping.tick().await;
+
+        'session: loop {
+            tokio::select! {
+                message = socket.recv() => match message {
+                    Some(Ok(Message::Text(text))) => {
+                        for reply in session.handle_client_message(text.as_str()) {
+                            if send_message(&mut socket, &reply).await.is_err() {
+                                break 'session;
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Binary(_))) => {
+                        let reply = LiveServerMessage::Error {
+                            message: "テキストメッセージのみ受信できます。".to_string(),
+                        };
+                        if send_message(&mut socket, &reply).await.is_err() {
+                            break 'session;
+                        }
+                    }
+                    // Ping・Pongは自動で応答
+                    Some(Ok(Message::Ping(_))) | Some(Ok(Message::Pong(_))) => {}
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break 'session,
+                },
+                live = receiver.recv() => match live {
+                    Ok(message) => {
+                        if let Some(reply) = session.handle_live_message(message)
+                            && send_message(&mut socket, &reply).await.is_err()
+                        {
+                            break 'session;
+                        }
+                    }
+                    // 送信が遅い接続は破棄された件数を通知して継続
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        let reply = LiveServerMessage::Lagged { missed };
+                        if send_message(&mut socket, &reply).await.is_err() {
+                            break 'session;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break 'session,
+                },
+                _ = ping.tick() => {
+                    if socket.send(Message::Ping(Bytes::new())).await.is_err() {
+                        break 'session;
+                    }
+                }
+            }
+        }
+
+        self.logger
+            .info(&ctx, &format!("WebSocketの切断（subject={}）", subject));
+    }
+}
+
+#[async_trait::async_trait]
+impl UserLiveUsecaseTrait for UserLiveUsecase {
+    async fn exec(&self, ctx: ContextRequest, ws: WebSocketUpgrade) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 認証チェック（接続時のみ、購読するユーザーごとの権限は購読時にチェック）
+        let subject = match &ctx.auth {
+            Some(auth) => auth.subject.clone(),
+            None => {
+                let json_msg = Json(json!({ "message": "認証されていません。" }));
+                return (StatusCode::UNAUTHORIZED, res_header, json_msg).into_response();
+            }
+        };
+
+        // 接続の開始
+        let usecase = self.clone();
+        let conn_ctx = ctx.clone();
+        let res = ws
+            .protocols([LIVE_PROTOCOL])
+            .max_message_size(self.max_message_bytes)
+            .on_upgrade(move |socket| usecase.run(conn_ctx, subject, socket));
+
+        // レスポンス結果を設定して戻り値として返す
+        (res_header, res).into_response()
+    }
+}
@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::user::user_model::User;
+
+    // ユーザーの変更と接続状態の配信のモック
+    use crate::application::usecase::event::user_live_hub::{LiveMessage, MockUserLiveHubTrait};
+
+    // ユースケース
+    use crate::application::usecase::user::user_live::{LiveServerMessage, UserLiveSession};
+
+    // 共通コンテキストの作成
+    fn create_ctx(subject: &str, roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/users/live".to_string(),
+            auth: Some(AuthContext {
+                subject: subject.to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 接続状態を返すモック（xxxx-xxxx-xxxx-0001のみ接続中）
+    fn create_mock_hub() -> MockUserLiveHubTrait {
+        let mut mock_hub = MockUserLiveHubTrait::new();
+        mock_hub
+            .expect_is_online()
+            .returning(|uid| uid == "xxxx-xxxx-xxxx-0001");
+        mock_hub
+    }
+
+    #[test]
+    fn test_subscribe_success() {
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["operator".to_string()]);
+        let mut session = UserLiveSession::new(ctx, Arc::new(create_mock_hub()), 10);
+
+        // テスト実行
+        let replies = session.handle_client_message(
+            r#"{"type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0002", "xxxx-xxxx-xxxx-0001"]}"#,
+        );
+
+        // 検証（購読中のユーザーと現在の接続状態を返すこと）
+        assert_eq!(
+            replies,
+            vec![
+                LiveServerMessage::Subscriptions {
+                    uids: vec![
+                        "xxxx-xxxx-xxxx-0001".to_string(),
+                        "xxxx-xxxx-xxxx-0002".to_string()
+                    ],
+                },
+                LiveServerMessage::Presence {
+                    uid: "xxxx-xxxx-xxxx-0001".to_string(),
+                    online: true,
+                },
+                LiveServerMessage::Presence {
+                    uid: "xxxx-xxxx-xxxx-0002".to_string(),
+                    online: false,
+                },
+            ]
+        );
+
+        // 購読の解除
+        let replies = session
+            .handle_client_message(r#"{"type": "unsubscribe", "uids": ["xxxx-xxxx-xxxx-0002"]}"#);
+        assert_eq!(
+            replies,
+            vec![LiveServerMessage::Subscriptions {
+                uids: vec!["xxxx-xxxx-xxxx-0001".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_subscribe_forbidden() {
+        // ロールなしの場合は本人のみ購読可能
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0001", vec![]);
+        let mut session = UserLiveSession::new(ctx, Arc::new(create_mock_hub()), 10);
+
+        // テスト実行（他人を含む場合は全て購読しないこと）
+        let replies = session.handle_client_message(
+            r#"{"type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0001", "xxxx-xxxx-xxxx-0002"]}"#,
+        );
+        assert!(matches!(replies[..], [LiveServerMessage::Error { .. }]));
+
+        // 本人のみの場合は購読可能
+        let replies = session
+            .handle_client_message(r#"{"type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0001"]}"#);
+        assert_eq!(
+            replies[0],
+            LiveServerMessage::Subscriptions {
+                uids: vec!["xxxx-xxxx-xxxx-0001".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_subscribe_limit() {
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let mut session = UserLiveSession::new(ctx, Arc::new(create_mock_hub()), 2);
+
+        // 上限以内
+        let replies = session.handle_client_message(
+            r#"{"type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0001", "xxxx-xxxx-xxxx-0002"]}"#,
+        );
+        assert!(matches!(
+            replies[0],
+            LiveServerMessage::Subscriptions { .. }
+        ));
+
+        // 購読済みのユーザーは上限の件数に含めないこと
+        let replies = session
+            .handle_client_message(r#"{"type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0001"]}"#);
+        assert!(matches!(
+            replies[0],
+            LiveServerMessage::Subscriptions { .. }
+        ));
+
+        // 上限を超える場合はエラー
+        let replies = session
+            .handle_client_message(r#"{"type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0003"]}"#);
+        assert!(matches!(replies[..], [LiveServerMessage::Error { .. }]));
+    }
+
+    #[test]
+    fn test_invalid_message() {
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let mut session = UserLiveSession::new(ctx, Arc::new(create_mock_hub()), 10);
+
+        for text in ["xxx", r#"{"type": "xxx"}"#, r#"{"type": "subscribe"}"#] {
+            let replies = session.handle_client_message(text);
+            assert!(matches!(replies[..], [LiveServerMessage::Error { .. }]));
+        }
+    }
+
+    #[test]
+    fn test_handle_live_message() {
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let mut session = UserLiveSession::new(ctx, Arc::new(create_mock_hub()), 10);
+        session.handle_client_message(r#"{"type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0001"]}"#);
+
+        // ドメインイベントの作成
+        let create_event = |uid: &str| {
+            let mut user = User::new(
+                uid.to_string(),
+                "田中".to_string(),
+                "太郎".to_string(),
+                "t.tanaka@example.com".to_string(),
+            );
+            user.take_events().remove(0)
+        };
+
+        // 購読中のユーザーの変更・接続状態は送信すること
+        let event = create_event("xxxx-xxxx-xxxx-0001");
+        assert_eq!(
+            session.handle_live_message(LiveMessage::Event(event.clone())),
+            Some(LiveServerMessage::Event {
+                uid: "xxxx-xxxx-xxxx-0001".to_string(),
+                event_type: "user.created".to_string(),
+                event,
+            })
+        );
+        assert_eq!(
+            session.handle_live_message(LiveMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0001".to_string(),
+                online: false,
+            }),
+            Some(LiveServerMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0001".to_string(),
+                online: false,
+            })
+        );
+
+        // 購読していないユーザーは送信しないこと
+        let event = create_event("xxxx-xxxx-xxxx-0002");
+        assert_eq!(session.handle_live_message(LiveMessage::Event(event)), None);
+        assert_eq!(
+            session.handle_live_message(LiveMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0002".to_string(),
+                online: true,
+            }),
+            None
+        );
+    }
+}
@@ -0,0 +1,264 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// axum
+use axum::http::StatusCode;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 日時
+use chrono::{DateTime, Duration, FixedOffset};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::audit::audit_log_model::AuditLog;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::job::job_model::Job;
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::transaction::unit_of_work::{
+    TransactionTrait, UnitOfWorkTrait, finish_transaction,
+};
+use crate::domain::user::user_model::User;
+use crate::domain::user::user_repository::UserRepositoryTrait;
+
+// ジョブのハンドラー
+use crate::application::usecase::job::job_handler_trait::JobHandlerTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ジョブの種類
+pub const USER_PURGE_JOB_TYPE: &str = "user.purge";
+
+// 監査ログに記録する実行者
+pub const USER_PURGE_ACTOR: &str = "system";
+
+// 保持期間が経過したユーザーの処理方法
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UserPurgeMode {
+    // 物理削除
+    Delete,
+    // 個人情報の匿名化（ユーザーは論理削除のまま保持）
+    Anonymize,
+}
+
+impl UserPurgeMode {
+    // 設定値から作成（delete、anonymize）
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "delete" => Ok(UserPurgeMode::Delete),
+            "anonymize" => Ok(UserPurgeMode::Anonymize),
+            _ => Err(format!(
+                "処理方法「{}」はdeleteまたはanonymizeで指定して下さい。",
+                value
+            )),
+        }
+    }
+}
+
+// 処理結果
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserPurgeResult {
+    // 対象のユーザー数
+    pub targets: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait]
+pub trait UserPurgeUsecaseTrait {
+    // 論理削除から保持期間が経過したユーザーの物理削除・匿名化（dry_run: 対象の確認のみ）
+    async fn purge(
+        &self,
+        ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        dry_run: bool,
+    ) -> Result<UserPurgeResult, ErrorCommon>;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct UserPurgeRepository {
+    pub user_repository: Arc<dyn UserRepositoryTrait + 'static>,
+    pub unit_of_work: Arc<dyn UnitOfWorkTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct UserPurgeUsecase {
+    pub repo: UserPurgeRepository,
+    // 論理削除から物理削除・匿名化までの保持期間（日）
+    pub retention_days: u64,
+    pub mode: UserPurgeMode,
+    // ジョブのペイロードで指定がない場合のドライラン
+    pub dry_run: bool,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UserPurgeUsecase {
+    pub fn new(
+        repo: UserPurgeRepository,
+        retention_days: u64,
+        mode: UserPurgeMode,
+        dry_run: bool,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        UserPurgeUsecase {
+            repo,
+            retention_days,
+            mode,
+            dry_run,
+            logger,
+        }
+    }
+
+    // ユーザーごとの処理（トランザクション内で実行）
+    async fn purge_user(&self, ctx: &ContextRequest, user: &User) -> Result<(), ErrorCommon> {
+        let tx = self.repo.unit_of_work.begin(ctx).await?;
+        let result = self.purge_user_in_tx(ctx, tx.as_ref(), user).await;
+        finish_transaction(tx, result).await
+    }
+
+    async fn purge_user_in_tx(
+        &self,
+        ctx: &ContextRequest,
+        tx: &dyn TransactionTrait,
+        user: &User,
+    ) -> Result<(), ErrorCommon> {
+        let user_repository = tx.user_repository();
+
+        match self.mode {
+            UserPurgeMode::Delete => user_repository.purge(ctx, &user.uid).await?,
+            UserPurgeMode::Anonymize => {
+                // 取得時のバージョンで更新
+                let mut user = user.clone();
+                let expected_version = user.version;
+                if let Err(msg) = user.anonymize(jst_now()) {
+                    return Err(ErrorCommon::CustomError {
+                        status_code: StatusCode::CONFLICT,
+                        message: msg,
+                    });
+                }
+                user_repository.update(ctx, &user, expected_version).await?;
+            }
+        }
+
+        // 認証情報・リフレッシュトークンの削除
+        tx.credential_repository()
+            .delete_by_user_uid(ctx, &user.uid)
+            .await?;
+        tx.refresh_token_repository()
+            .delete_all_by_user_uid(ctx, &user.uid)
+            .await?;
+
+        // 過去の監査ログの変更内容から個人情報を消去
+        tx.audit_log_repository()
+            .scrub_personal_data(ctx, &user.uid)
+            .await?;
+
+        // アウトボックスのメッセージとWebhookの配信（ボディ・配信ログ）から個人情報を消去
+        let messages = tx
+            .outbox_repository()
+            .scrub_personal_data(ctx, &user.uid)
+            .await?;
+        tx.webhook_delivery_repository()
+            .scrub_personal_data(ctx, &messages)
+            .await?;
+
+        // 監査ログの保存（物理削除・匿名化と同じトランザクション）
+        let audit_log = AuditLog::for_user_purge(USER_PURGE_ACTOR, ctx.request_id(), &user.uid);
+        tx.audit_log_repository().add(ctx, &audit_log).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserPurgeUsecaseTrait for UserPurgeUsecase {
+    async fn purge(
+        &self,
+        ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        dry_run: bool,
+    ) -> Result<UserPurgeResult, ErrorCommon> {
+        // 対象のユーザー取得
+        let cutoff = now - Duration::days(self.retention_days as i64);
+        let users = self
+            .repo
+            .user_repository
+            .find_purgeable(ctx, cutoff)
+            .await?;
+        let mut result = UserPurgeResult {
+            targets: users.len(),
+            ..Default::default()
+        };
+
+        // ドライランの場合は対象のログ出力のみ
+        if dry_run {
+            let uids: Vec<&str> = users.iter().map(|user| user.uid.as_str()).collect();
+            let msg = format!(
+                "【ドライラン】保持期間（{}日）が経過したユーザー: {}件（mode={:?}, uids={:?}）",
+                self.retention_days,
+                users.len(),
+                self.mode,
+                uids
+            );
+            self.logger.info(ctx, &msg);
+            return Ok(result);
+        }
+
+        // 1件の失敗で全体を中止しないようにユーザーごとに処理
+        for user in users.iter() {
+            match self.purge_user(ctx, user).await {
+                Ok(_) => result.succeeded += 1,
+                Err(err) => {
+                    result.failed += 1;
+                    let err_msg = format!(
+                        "ユーザーの物理削除・匿名化でエラー（uid={}）: {}",
+                        user.uid, err
+                    );
+                    self.logger.error(ctx, &err_msg);
+                }
+            }
+        }
+
+        let msg = format!(
+            "保持期間（{}日）が経過したユーザーを処理しました（mode={:?}, targets={}, succeeded={}, failed={}）",
+            self.retention_days, self.mode, result.targets, result.succeeded, result.failed
+        );
+        self.logger.info(ctx, &msg);
+
+        // 失敗したユーザーはジョブのリトライで再処理
+        if result.failed > 0 {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                message: format!("{}件のユーザーの処理に失敗しました。", result.failed),
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+// ジョブとして実行（ペイロードの「dry_run」でドライランを指定可能）
+#[async_trait]
+impl JobHandlerTrait for UserPurgeUsecase {
+    fn job_type(&self) -> &'static str {
+        USER_PURGE_JOB_TYPE
+    }
+
+    async fn handle(&self, ctx: &ContextRequest, job: &Job) -> Result<(), ErrorCommon> {
+        let dry_run = job
+            .payload
+            .get("dry_run")
+            .and_then(|value| value.as_bool())
+            .unwrap_or(self.dry_run);
+
+        self.purge(ctx, jst_now(), dry_run).await.map(|_| ())
+    }
+}
@@ -0,0 +1,416 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use chrono::Duration;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::audit::audit_log_model::AuditAction;
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::job::job_model::Job;
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::user::user_model::User;
+    use crate::domain::webhook::webhook_delivery_model::WebhookDelivery;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::audit::audit_log_repository::MockAuditLogRepositoryTrait;
+    use crate::domain::credential::credential_repository::MockCredentialRepositoryTrait;
+    use crate::domain::credential::refresh_token_repository::MockRefreshTokenRepositoryTrait;
+    use crate::domain::transaction::unit_of_work::MockUnitOfWorkTrait;
+    use crate::domain::transaction::unit_of_work_test_helper::{
+        ExpectedFinish, create_mock_unit_of_work, create_mock_unit_of_work_with_audit_log,
+        create_mock_unit_of_work_with_credential,
+    };
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+    use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
+    use crate::infrastructure::persistence::transaction::unit_of_work::UnitOfWork;
+    use crate::infrastructure::persistence::user::user_repository::UserRepository;
+
+    // ジョブのハンドラー
+    use crate::application::usecase::job::job_handler_trait::JobHandlerTrait;
+
+    // ユースケース
+    use crate::application::usecase::user::user_purge::{
+        USER_PURGE_JOB_TYPE, UserPurgeMode, UserPurgeRepository, UserPurgeResult, UserPurgeUsecase,
+        UserPurgeUsecaseTrait,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "job-worker".to_string(),
+            auth: None,
+        }
+    }
+
+    // 論理削除済みのユーザー
+    fn create_deleted_user() -> User {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        );
+        user.set_delete();
+        user.take_events();
+        user
+    }
+
+    // 対象のユーザーを返すリポジトリのモック
+    fn create_user_repo() -> MockUserRepositoryTrait {
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_find_purgeable()
+            .returning(|_, _| Ok(vec![create_deleted_user()]));
+        mock_user_repo
+    }
+
+    // ロガーのモック
+    fn create_logger() -> MockLoggerTrait {
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+        mock_logger
+    }
+
+    // ユースケースのインスタンス化
+    fn create_usecase(
+        unit_of_work: MockUnitOfWorkTrait,
+        mode: UserPurgeMode,
+        dry_run: bool,
+    ) -> UserPurgeUsecase {
+        UserPurgeUsecase::new(
+            UserPurgeRepository {
+                user_repository: Arc::new(create_user_repo()),
+                unit_of_work: Arc::new(unit_of_work),
+            },
+            30,
+            mode,
+            dry_run,
+            Arc::new(create_logger()),
+        )
+    }
+
+    #[test]
+    fn test_mode_parse() {
+        // 検証
+        assert_eq!(UserPurgeMode::parse("delete"), Ok(UserPurgeMode::Delete));
+        assert_eq!(
+            UserPurgeMode::parse("anonymize"),
+            Ok(UserPurgeMode::Anonymize)
+        );
+        assert!(UserPurgeMode::parse("archive").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_purge_delete() {
+        // トランザクション内のリポジトリのモック化（物理削除されること）
+        let mut mock_tx_user_repo = MockUserRepositoryTrait::new();
+        mock_tx_user_repo
+            .expect_purge()
+            .withf(|_, uid| uid == "xxxx-xxxx-xxxx-0001")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // 監査ログのモック化（実行者がsystemで、個人情報を記録しないこと）
+        let mut mock_audit_log_repo = MockAuditLogRepositoryTrait::new();
+        mock_audit_log_repo
+            .expect_add()
+            .withf(|_, audit_log| {
+                audit_log.action == AuditAction::Purge
+                    && audit_log.actor == "system"
+                    && audit_log.target_uid == "xxxx-xxxx-xxxx-0001"
+                    && audit_log.request_id == "xxx-yyy-zzz-001"
+                    && audit_log.changes.is_empty()
+            })
+            .times(1)
+            .returning(|_, audit_log| Ok(audit_log.clone()));
+        // 過去の監査ログから個人情報が消去されること
+        mock_audit_log_repo
+            .expect_scrub_personal_data()
+            .withf(|_, target_uid| target_uid == "xxxx-xxxx-xxxx-0001")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let usecase = create_usecase(
+            create_mock_unit_of_work_with_audit_log(
+                mock_tx_user_repo,
+                mock_audit_log_repo,
+                ExpectedFinish::Commit,
+            ),
+            UserPurgeMode::Delete,
+            false,
+        );
+
+        // テスト実行
+        let result = usecase
+            .purge(&create_ctx(), jst_now(), false)
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(
+            result,
+            UserPurgeResult {
+                targets: 1,
+                succeeded: 1,
+                failed: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_anonymize() {
+        // トランザクション内のリポジトリのモック化（匿名化して取得時のバージョンで更新されること）
+        let mut mock_tx_user_repo = MockUserRepositoryTrait::new();
+        mock_tx_user_repo
+            .expect_update()
+            .withf(|_, user, expected_version| {
+                user.anonymized_at.is_some()
+                    && user.email == "xxxx-xxxx-xxxx-0001@anonymized.invalid"
+                    && *expected_version == 2
+            })
+            .times(1)
+            .returning(|_, user, _| Ok(user.clone()));
+        mock_tx_user_repo.expect_purge().never();
+
+        let usecase = create_usecase(
+            create_mock_unit_of_work(mock_tx_user_repo, ExpectedFinish::Commit),
+            UserPurgeMode::Anonymize,
+            false,
+        );
+
+        // テスト実行
+        let result = usecase
+            .purge(&create_ctx(), jst_now(), false)
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(result.succeeded, 1);
+    }
+
+    #[tokio::test]
+    async fn test_purge_delete_credential() {
+        let mut mock_tx_user_repo = MockUserRepositoryTrait::new();
+        mock_tx_user_repo
+            .expect_update()
+            .returning(|_, user, _| Ok(user.clone()));
+
+        // 認証情報・リフレッシュトークンのモック化（匿名化でも削除されること）
+        let mut mock_credential_repo = MockCredentialRepositoryTrait::new();
+        mock_credential_repo
+            .expect_delete_by_user_uid()
+            .withf(|_, user_uid| user_uid == "xxxx-xxxx-xxxx-0001")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut mock_refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+        mock_refresh_token_repo
+            .expect_delete_all_by_user_uid()
+            .withf(|_, user_uid| user_uid == "xxxx-xxxx-xxxx-0001")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let usecase = create_usecase(
+            create_mock_unit_of_work_with_credential(
+                mock_tx_user_repo,
+                mock_credential_repo,
+                mock_refresh_token_repo,
+                ExpectedFinish::Commit,
+            ),
+            UserPurgeMode::Anonymize,
+            false,
+        );
+
+        // テスト実行
+        let result = usecase
+            .purge(&create_ctx(), jst_now(), false)
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(result.succeeded, 1);
+    }
+
+    #[tokio::test]
+    async fn test_purge_scrub_outbox_and_webhook() {
+        // 事前準備（保持期間が経過したユーザーとそのイベントのアウトボックス・Webhookの配信）
+        let db = new_db_dummy_connection().await.unwrap();
+        let now = jst_now();
+        let mut user = db.users.read().unwrap()[0].clone();
+        user.update_profile(
+            "山田".to_string(),
+            "花子".to_string(),
+            "h.yamada@example.com".to_string(),
+        )
+        .unwrap();
+        user.set_delete();
+        user.deleted_at = Some(now - Duration::days(31));
+        let messages = OutboxMessage::from_events(&user.take_events());
+        db.users.write().unwrap()[0] = user;
+        let mut delivery = WebhookDelivery::new("sub-0001", &messages[0], now);
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            backoff_base_secs: 10,
+            backoff_max_secs: 60,
+        };
+        delivery.record_failure(Some(400), "h.yamada@example.com is invalid", &policy, now);
+        db.webhook_deliveries.write().unwrap().push(delivery);
+        db.outbox_messages.write().unwrap().extend(messages);
+
+        // 事前準備（対象外のユーザーのアウトボックス）
+        let mut other_user = db.users.read().unwrap()[1].clone();
+        other_user
+            .update_profile(
+                "佐藤".to_string(),
+                "二郎".to_string(),
+                "z.satou2@example.com".to_string(),
+            )
+            .unwrap();
+        db.outbox_messages
+            .write()
+            .unwrap()
+            .extend(OutboxMessage::from_events(&other_user.take_events()));
+
+        // ユースケースのインスタンス化（ダミーDBのトランザクションで実行）
+        let logger = Arc::new(create_logger());
+        let usecase = UserPurgeUsecase::new(
+            UserPurgeRepository {
+                user_repository: Arc::new(UserRepository::new(db.clone(), logger.clone())),
+                unit_of_work: Arc::new(UnitOfWork::new(db.clone(), logger.clone())),
+            },
+            30,
+            UserPurgeMode::Delete,
+            false,
+            logger,
+        );
+
+        // テスト実行
+        let result = usecase.purge(&create_ctx(), now, false).await.unwrap();
+
+        // 検証（物理削除と同じトランザクションでアウトボックス・Webhookの配信から個人情報が消去される）
+        assert_eq!(result.succeeded, 1);
+        assert_eq!(db.users.read().unwrap().len(), 1);
+        let outbox_messages = db.outbox_messages.read().unwrap();
+        assert_eq!(outbox_messages.len(), 3);
+        for message in outbox_messages.iter().take(2) {
+            let payload = serde_json::to_string(&message.payload).unwrap();
+            assert!(!payload.contains("山田"));
+            assert!(!payload.contains("花子"));
+            assert!(!payload.contains("h.yamada@example.com"));
+        }
+        let payload = serde_json::to_string(&outbox_messages[2].payload).unwrap();
+        assert!(payload.contains("z.satou2@example.com"));
+        let webhook_deliveries = db.webhook_deliveries.read().unwrap();
+        assert!(!webhook_deliveries[0].body.contains("h.yamada@example.com"));
+        assert!(
+            webhook_deliveries[0]
+                .attempts
+                .iter()
+                .all(|a| a.error.as_deref() == Some("[REDACTED]"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_cutoff() {
+        let now = jst_now();
+
+        // リポジトリのモック化（保持期間を引いた日時で取得されること）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_find_purgeable()
+            .withf(move |_, cutoff| *cutoff == now - Duration::days(30))
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+
+        let usecase = UserPurgeUsecase::new(
+            UserPurgeRepository {
+                user_repository: Arc::new(mock_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    MockUserRepositoryTrait::new(),
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            30,
+            UserPurgeMode::Delete,
+            false,
+            Arc::new(create_logger()),
+        );
+
+        // テスト実行
+        let result = usecase.purge(&create_ctx(), now, false).await.unwrap();
+
+        // 検証
+        assert_eq!(result, UserPurgeResult::default());
+    }
+
+    #[tokio::test]
+    async fn test_purge_dry_run() {
+        // トランザクションを開始しないこと
+        let usecase = create_usecase(
+            create_mock_unit_of_work(MockUserRepositoryTrait::new(), ExpectedFinish::NotStarted),
+            UserPurgeMode::Delete,
+            false,
+        );
+
+        // テスト実行
+        let result = usecase.purge(&create_ctx(), jst_now(), true).await.unwrap();
+
+        // 検証（対象のみ）
+        assert_eq!(result.targets, 1);
+        assert_eq!(result.succeeded, 0);
+    }
+
+    #[tokio::test]
+    async fn test_purge_failed() {
+        // トランザクション内のリポジトリのモック化（物理削除でエラー）
+        let mut mock_tx_user_repo = MockUserRepositoryTrait::new();
+        mock_tx_user_repo
+            .expect_purge()
+            .returning(|_, _| Err(ErrorCommon::InternalServerError));
+
+        let usecase = create_usecase(
+            create_mock_unit_of_work(mock_tx_user_repo, ExpectedFinish::Rollback),
+            UserPurgeMode::Delete,
+            false,
+        );
+
+        // テスト実行
+        let result = usecase.purge(&create_ctx(), jst_now(), false).await;
+
+        // 検証（ジョブのリトライで再処理するためエラー）
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_handle_dry_run_payload() {
+        // 設定はドライランなしでもペイロードの指定を優先
+        let usecase = create_usecase(
+            create_mock_unit_of_work(MockUserRepositoryTrait::new(), ExpectedFinish::NotStarted),
+            UserPurgeMode::Delete,
+            false,
+        );
+        let job = Job::new(USER_PURGE_JOB_TYPE, json!({ "dry_run": true }), jst_now());
+
+        // テスト実行
+        let result = usecase.handle(&create_ctx(), &job).await;
+
+        // 検証
+        assert!(result.is_ok());
+        assert_eq!(usecase.job_type(), "user.purge");
+    }
+}
@@ -0,0 +1,134 @@
+// axum
+use axum::{
+    Json,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// エラーにならないストリームの型
+use std::convert::Infallible;
+
+// 時間
+use std::time::Duration;
+
+// ストリーム
+use futures_util::stream;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+
+// ドメインイベントの通知
+use crate::application::usecase::event::domain_event_stream::{DomainEventStreamTrait, StreamItem};
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UserStreamUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct UserStreamRepository {
+    pub event_stream: Arc<dyn DomainEventStreamTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct UserStreamUsecase {
+    pub repo: UserStreamRepository,
+    // キープアライブのコメントの送信間隔
+    pub keep_alive: Duration,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UserStreamUsecase {
+    pub fn new(
+        repo: UserStreamRepository,
+        keep_alive: Duration,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        UserStreamUsecase {
+            repo,
+            keep_alive,
+            logger,
+        }
+    }
+}
+
+// 通知をSSEのイベントに変換
+fn to_sse_event(item: StreamItem) -> Event {
+    match item {
+        StreamItem::Event { id, event } => Event::default()
+            .id(id)
+            .event(event.event_type())
+            .data(serde_json::to_string(&event).unwrap_or_default()),
+        StreamItem::Reset { id } => Event::default().id(id).event("reset").data(
+            json!({ "message": "再送できない通知があります。ユーザー一覧を再取得して下さい。" })
+                .to_string(),
+        ),
+    }
+}
+
+#[async_trait::async_trait]
+impl UserStreamUsecaseTrait for UserStreamUsecase {
+    async fn exec(&self, ctx: ContextRequest) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [
+            ("X-Request-Id", request_id),
+            // リバースプロキシでバッファリングしない
+            ("X-Accel-Buffering", "no"),
+        ];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::UserReadAll, None) {
+            // ログ出力
+            let err_msg = format!("UserStreamUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), [res_header[0]], json_msg).into_response();
+        }
+
+        // 購読の開始（再接続時はLast-Event-IDの後の通知から再送）
+        let last_event_id = ctx
+            .header
+            .get("Last-Event-ID")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        let subscription = self.repo.event_stream.subscribe(last_event_id);
+
+        // 接続が切断されるとストリームと一緒に購読も破棄される
+        let events = stream::unfold(subscription, |mut subscription| async move {
+            let item = subscription.next().await?;
+            Some((Ok::<Event, Infallible>(to_sse_event(item)), subscription))
+        });
+
+        let sse = Sse::new(events).keep_alive(KeepAlive::new().interval(self.keep_alive));
+
+        // レスポンス結果を設定して戻り値として返す
+        (res_header, sse).into_response()
+    }
+}
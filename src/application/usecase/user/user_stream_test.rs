@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use futures_util::StreamExt;
+    use std::{str, sync::Arc, time::Duration};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::user::user_model::User;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ドメインイベントの通知
+    use crate::application::usecase::event::domain_event_dispatcher::DomainEventHandlerTrait;
+    use crate::application::usecase::event::domain_event_stream::{
+        DomainEventStream, MockDomainEventStreamTrait,
+    };
+
+    // ユースケース
+    use crate::application::usecase::user::user_stream::UserStreamRepository;
+    use crate::application::usecase::user::user_stream::UserStreamUsecase;
+    use crate::application::usecase::user::user_stream::UserStreamUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(subject: &str, roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/users/stream".to_string(),
+            auth: Some(AuthContext {
+                subject: subject.to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // ドメインイベントの通知
+        let event_stream = Arc::new(DomainEventStream::new(10, 10));
+
+        // ユースケースのインスタンス化
+        let user_stream_usecase = UserStreamUsecase {
+            repo: UserStreamRepository {
+                event_stream: event_stream.clone(),
+            },
+            keep_alive: Duration::from_secs(60),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["operator".to_string()]);
+        let res = user_stream_usecase.exec(ctx.clone()).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()["Content-Type"], "text/event-stream");
+        assert_eq!(res.headers()["X-Request-Id"], "xxx-yyy-zzz-001");
+
+        // 接続後のドメインイベントが通知されること
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        );
+        for event in user.take_events() {
+            event_stream.handle(&ctx, &event).await.unwrap();
+        }
+
+        let mut body = res.into_body().into_data_stream();
+        let chunk = tokio::time::timeout(Duration::from_secs(5), body.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let text = str::from_utf8(&chunk).unwrap();
+        assert!(text.contains("event: user.created\n"));
+        assert!(text.contains("id: "));
+        assert!(text.contains("\"uid\":\"xxxx-xxxx-xxxx-0001\""));
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        // ドメインイベントの通知のモック化（購読されないこと）
+        let mut mock_event_stream = MockDomainEventStreamTrait::new();
+        mock_event_stream.expect_subscribe().never();
+
+        // ユースケースのインスタンス化
+        let user_stream_usecase = UserStreamUsecase {
+            repo: UserStreamRepository {
+                event_stream: Arc::new(mock_event_stream),
+            },
+            keep_alive: Duration::from_secs(60),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行（ロールなし）
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0001", vec![]);
+        let res = user_stream_usecase.exec(ctx).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
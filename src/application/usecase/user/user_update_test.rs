@@ -0,0 +1,429 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::user::user_model::User;
+
+    // ドメインイベントのディスパッチャーのモック
+    use crate::application::usecase::event::domain_event_dispatcher::MockDomainEventDispatcherTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // リポジトリのモック
+    use crate::domain::outbox::outbox_repository::MockOutboxRepositoryTrait;
+    use crate::domain::transaction::unit_of_work_test_helper::{
+        ExpectedFinish, create_mock_unit_of_work, create_mock_unit_of_work_with_outbox,
+    };
+    use crate::domain::user::user_repository::MockUserRepositoryTrait;
+    use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
+    use crate::infrastructure::persistence::transaction::unit_of_work::UnitOfWork;
+
+    // ユースケース
+    use crate::application::usecase::user::user_update::UserUpdateInput;
+    use crate::application::usecase::user::user_update::UserUpdateRepository;
+    use crate::application::usecase::user::user_update::UserUpdateUsecase;
+    use crate::application::usecase::user::user_update::UserUpdateUsecaseTrait;
+
+    // 共通コンテキストの作成
+    fn create_ctx(subject: &str, roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "PUT".to_string(),
+            uri: "/api/v1/users/xxxx-xxxx-xxxx-0001".to_string(),
+            auth: Some(AuthContext {
+                subject: subject.to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 入力値の作成
+    fn create_input(email: &str) -> UserUpdateInput {
+        UserUpdateInput {
+            last_name: "田中".to_string(),
+            first_name: "太郎".to_string(),
+            email: email.to_string(),
+            if_match: Some(vec![create_stored_user().etag()]),
+        }
+    }
+
+    // 保存済みのユーザー（記録済みのドメインイベントなし、ETagが一定になるよう更新日時を固定）
+    fn create_stored_user() -> User {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        );
+        user.updated_at =
+            chrono::DateTime::parse_from_rfc3339("2025-01-01T00:00:00+09:00").unwrap();
+        user.take_events();
+        user
+    }
+
+    // 更新対象ユーザーを返すリポジトリのモック（トランザクション外での取得用）
+    fn create_mock_user_repo() -> MockUserRepositoryTrait {
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_find_by_uid()
+            .returning(|_, _| Ok(Some(create_stored_user())));
+        mock_user_repo
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // ロガーのモック化
+        let mock_logger = MockLoggerTrait::new();
+
+        // リポジトリのモック化（取得時のバージョンで更新されること）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_update()
+            .withf(|_, user, expected_version| user.version == 2 && *expected_version == 1)
+            .returning(|_, user, _| Ok(user.clone()));
+
+        // アウトボックスのモック化（同じトランザクションで保存されること）
+        let mut mock_outbox_repo = MockOutboxRepositoryTrait::new();
+        mock_outbox_repo
+            .expect_add()
+            .withf(|_, messages| {
+                messages.len() == 1 && messages[0].event_type == "user.profile_updated"
+            })
+            .times(1)
+            .returning(|_, messages| Ok(messages.to_vec()));
+
+        // ドメインイベントのディスパッチャーのモック化（コミット後に通知されること）
+        let mut mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+        mock_event_dispatcher
+            .expect_dispatch()
+            .withf(|_, events| {
+                events.len() == 1 && events[0].event_type() == "user.profile_updated"
+            })
+            .times(1)
+            .return_const(());
+
+        // ユースケースのインスタンス化
+        let user_update_usecase = UserUpdateUsecase {
+            repo: UserUpdateRepository {
+                user_repository: Arc::new(create_mock_user_repo()),
+                unit_of_work: Arc::new(create_mock_unit_of_work_with_outbox(
+                    mock_user_repo,
+                    mock_outbox_repo,
+                    ExpectedFinish::Commit,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行（本人による更新）
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0001", vec![]);
+        let res = user_update_usecase
+            .exec(
+                ctx,
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("t.tanaka2@example.com"),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        let etag = res.headers()["ETag"].to_str().unwrap().to_string();
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: User = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data.uid, "xxxx-xxxx-xxxx-0001");
+        assert_eq!(res_data.email, "t.tanaka2@example.com");
+        assert!(res_data.updated_at > res_data.created_at);
+        assert_eq!(etag, res_data.etag());
+    }
+
+    #[tokio::test]
+    async fn test_exec_bad_request() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化（入力値エラーの場合はトランザクションを開始しないこと）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_update().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_update_usecase = UserUpdateUsecase {
+            repo: UserUpdateRepository {
+                user_repository: Arc::new(create_mock_user_repo()),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["operator".to_string()]);
+        let res = user_update_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), create_input(""))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+
+        // リポジトリのモック化（呼ばれないこと）
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo.expect_find_by_uid().never();
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_update().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_update_usecase = UserUpdateUsecase {
+            repo: UserUpdateRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行（他人のプロフィールを更新）
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0002", vec![]);
+        let res = user_update_usecase
+            .exec(
+                ctx,
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("t.tanaka2@example.com"),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo
+            .expect_find_by_uid()
+            .returning(|_, _| Ok(None));
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_update_usecase = UserUpdateUsecase {
+            repo: UserUpdateRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    MockUserRepositoryTrait::new(),
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_update_usecase
+            .exec(
+                ctx,
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("t.tanaka2@example.com"),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_conflict() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化（取得後に他の操作によりバージョンが更新済み）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo
+            .expect_update()
+            .withf(|_, _, expected_version| *expected_version == 1)
+            .returning(|_, _, _| {
+                Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::CONFLICT,
+                    message: "対象のユーザーは他の操作により更新されています。".to_string(),
+                })
+            });
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_update_usecase = UserUpdateUsecase {
+            repo: UserUpdateRepository {
+                user_repository: Arc::new(create_mock_user_repo()),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::Rollback,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let res = user_update_usecase
+            .exec(
+                ctx,
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                create_input("t.tanaka2@example.com"),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_precondition_failed() {
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+
+        // リポジトリのモック化（If-MatchのETagが古い場合はトランザクションを開始しないこと）
+        let mut mock_user_repo = MockUserRepositoryTrait::new();
+        mock_user_repo.expect_update().never();
+
+        // ドメインイベントのディスパッチャーのモック化（コミットしない場合は通知されないこと）
+        let mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+
+        // ユースケースのインスタンス化
+        let user_update_usecase = UserUpdateUsecase {
+            repo: UserUpdateRepository {
+                user_repository: Arc::new(create_mock_user_repo()),
+                unit_of_work: Arc::new(create_mock_unit_of_work(
+                    mock_user_repo,
+                    ExpectedFinish::NotStarted,
+                )),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        };
+
+        // テスト実行
+        let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+        let mut input = create_input("t.tanaka2@example.com");
+        input.if_match = Some(vec!["\"0-00000000000000000000000000000000\"".to_string()]);
+        let res = user_update_usecase
+            .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), input)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_exec_concurrent_conflict() {
+        // 事前準備（ダミーDBの保存済みユーザー）
+        let db = new_db_dummy_connection().await.unwrap();
+        let stored_user = db.users.read().unwrap()[0].clone();
+        let if_match = vec![stored_user.etag()];
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_error().returning(|_, _| ());
+        let mut mock_repo_logger = MockLoggerTrait::new();
+        mock_repo_logger.expect_info().returning(|_, _| ());
+        mock_repo_logger.expect_warn().returning(|_, _| ());
+
+        // リポジトリのモック化（両方のリクエストが保存前に同じユーザーを取得）
+        let mut mock_find_user_repo = MockUserRepositoryTrait::new();
+        mock_find_user_repo
+            .expect_find_by_uid()
+            .returning(move |_, _| Ok(Some(stored_user.clone())));
+
+        // ドメインイベントのディスパッチャーのモック化（コミットした1件のみ通知されること）
+        let mut mock_event_dispatcher = MockDomainEventDispatcherTrait::new();
+        mock_event_dispatcher
+            .expect_dispatch()
+            .times(1)
+            .return_const(());
+
+        // ユースケースのインスタンス化（保存はダミーDBのトランザクションで実行）
+        let user_update_usecase = Arc::new(UserUpdateUsecase {
+            repo: UserUpdateRepository {
+                user_repository: Arc::new(mock_find_user_repo),
+                unit_of_work: Arc::new(UnitOfWork::new(db.clone(), Arc::new(mock_repo_logger))),
+            },
+            event_dispatcher: Arc::new(mock_event_dispatcher),
+            logger: Arc::new(mock_logger),
+        });
+
+        // テスト実行（同じETagで同時に更新）
+        let handles: Vec<_> = ["t.tanaka2@example.com", "t.tanaka3@example.com"]
+            .into_iter()
+            .map(|email| {
+                let usecase = user_update_usecase.clone();
+                let mut input = create_input(email);
+                input.if_match = Some(if_match.clone());
+                tokio::spawn(async move {
+                    let ctx = create_ctx("xxxx-xxxx-xxxx-0009", vec!["admin".to_string()]);
+                    usecase
+                        .exec(ctx, "xxxx-xxxx-xxxx-0001".to_string(), input)
+                        .await
+                        .status()
+                })
+            })
+            .collect();
+        let mut statuses = Vec::new();
+        for handle in handles {
+            statuses.push(handle.await.unwrap());
+        }
+        statuses.sort();
+
+        // 検証（一方のみ更新され、もう一方はバージョンの不一致で409）
+        assert_eq!(statuses, vec![StatusCode::OK, StatusCode::CONFLICT]);
+        assert_eq!(db.users.read().unwrap()[0].version, 2);
+        assert_eq!(db.outbox_messages.read().unwrap().len(), 1);
+        assert_eq!(db.audit_logs.read().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,17 @@
+pub mod webhook_deliver;
+pub mod webhook_delivery_find_all;
+pub mod webhook_delivery_redeliver;
+pub mod webhook_fanout;
+pub mod webhook_sender_trait;
+pub mod webhook_subscription_create;
+pub mod webhook_subscription_delete;
+pub mod webhook_subscription_find_all;
+
+// テストコード用のモジュール
+mod webhook_deliver_test;
+mod webhook_delivery_find_all_test;
+mod webhook_delivery_redeliver_test;
+mod webhook_fanout_test;
+mod webhook_subscription_create_test;
+mod webhook_subscription_delete_test;
+mod webhook_subscription_find_all_test;
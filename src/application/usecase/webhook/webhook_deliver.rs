@@ -0,0 +1,270 @@
+// axum
+use axum::http::StatusCode;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+use std::time::Duration;
+
+// 停止の通知用のチャネル
+use tokio::sync::watch;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::{
+    ContextRequest, new_context_background,
+};
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::retry::retry_policy::RetryPolicy;
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::webhook::webhook_delivery_model::{WebhookDelivery, WebhookDeliveryStatus};
+use crate::domain::webhook::webhook_delivery_repository::WebhookDeliveryRepositoryTrait;
+use crate::domain::webhook::webhook_signature::sign;
+use crate::domain::webhook::webhook_subscription_repository::WebhookSubscriptionRepositoryTrait;
+
+// Webhookの送信
+use crate::application::usecase::webhook::webhook_sender_trait::{
+    WebhookRequest, WebhookSenderTrait,
+};
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 配信処理の結果
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WebhookDeliverResult {
+    pub succeeded: usize,
+    pub retrying: usize,
+    pub failed: usize,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookDeliverUsecaseTrait {
+    // 配信対象を配信して結果を保存
+    async fn deliver_due(&self, ctx: &ContextRequest) -> Result<WebhookDeliverResult, ErrorCommon>;
+    // 1件の配信を確保して試行し、結果を保存（他の処理で配信中の場合は409エラー）
+    async fn deliver(
+        &self,
+        ctx: &ContextRequest,
+        delivery: WebhookDelivery,
+    ) -> Result<WebhookDelivery, ErrorCommon>;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct WebhookDeliverRepository {
+    pub webhook_subscription_repository: Arc<dyn WebhookSubscriptionRepositoryTrait + 'static>,
+    pub webhook_delivery_repository: Arc<dyn WebhookDeliveryRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct WebhookDeliverUsecase {
+    pub repo: WebhookDeliverRepository,
+    pub sender: Arc<dyn WebhookSenderTrait + 'static>,
+    pub retry_policy: RetryPolicy,
+    pub batch_size: usize,
+    // 配信中の占有期間（秒）
+    pub lease_secs: u64,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookDeliverUsecase {
+    pub fn new(
+        repo: WebhookDeliverRepository,
+        sender: Arc<dyn WebhookSenderTrait + 'static>,
+        retry_policy: RetryPolicy,
+        batch_size: usize,
+        lease_secs: u64,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        WebhookDeliverUsecase {
+            repo,
+            sender,
+            retry_policy,
+            batch_size,
+            lease_secs,
+            logger,
+        }
+    }
+
+    // 配信の確保（取得後に他の処理で確保・更新された場合は409エラー）
+    async fn claim(
+        &self,
+        ctx: &ContextRequest,
+        mut delivery: WebhookDelivery,
+    ) -> Result<WebhookDelivery, ErrorCommon> {
+        let now = jst_now();
+        if delivery.is_locked(now) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::CONFLICT,
+                message: "対象の配信は他の処理で配信中です。".to_string(),
+            });
+        }
+
+        let expected_version = delivery.version;
+        delivery.claim(self.lease_secs, now);
+        self.repo
+            .webhook_delivery_repository
+            .update(ctx, &delivery, expected_version)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliverUsecaseTrait for WebhookDeliverUsecase {
+    async fn deliver_due(&self, ctx: &ContextRequest) -> Result<WebhookDeliverResult, ErrorCommon> {
+        let mut result = WebhookDeliverResult::default();
+
+        // 配信対象の取得
+        let deliveries = self
+            .repo
+            .webhook_delivery_repository
+            .find_due(ctx, jst_now(), self.batch_size)
+            .await?;
+
+        for delivery in deliveries.into_iter() {
+            let delivery = match self.deliver(ctx, delivery).await {
+                Ok(delivery) => delivery,
+                // 取得後に再配信等で確保された配信はスキップ
+                Err(err) if err.status_code() == StatusCode::CONFLICT => continue,
+                Err(err) => return Err(err),
+            };
+            match delivery.status {
+                WebhookDeliveryStatus::Succeeded => result.succeeded += 1,
+                WebhookDeliveryStatus::Pending => result.retrying += 1,
+                WebhookDeliveryStatus::Failed => result.failed += 1,
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn deliver(
+        &self,
+        ctx: &ContextRequest,
+        delivery: WebhookDelivery,
+    ) -> Result<WebhookDelivery, ErrorCommon> {
+        // 配信の確保（他の配信処理と同じ配信を重複して送信しない）
+        let mut delivery = self.claim(ctx, delivery).await?;
+        let expected_version = delivery.version;
+
+        // 購読設定の取得（削除済みの場合は配信を中止）
+        let subscription = match self
+            .repo
+            .webhook_subscription_repository
+            .find_by_subscription_id(ctx, &delivery.subscription_id)
+            .await?
+        {
+            Some(subscription) => subscription,
+            None => {
+                delivery.cancel("購読設定が削除されています。", jst_now());
+                return self
+                    .repo
+                    .webhook_delivery_repository
+                    .update(ctx, &delivery, expected_version)
+                    .await;
+            }
+        };
+
+        // 署名付きのリクエストを作成して送信
+        let timestamp = jst_now().timestamp();
+        let request = WebhookRequest {
+            url: subscription.url.clone(),
+            headers: vec![
+                ("Content-Type".to_string(), "application/json".to_string()),
+                ("Webhook-Id".to_string(), delivery.delivery_id.clone()),
+                ("Webhook-Event".to_string(), delivery.event_type.clone()),
+                ("Webhook-Timestamp".to_string(), timestamp.to_string()),
+                (
+                    "Webhook-Signature".to_string(),
+                    sign(subscription.secret.expose(), timestamp, &delivery.body),
+                ),
+            ],
+            body: delivery.body.clone(),
+        };
+        match self.sender.send(&request).await {
+            Ok(res) if (200..300).contains(&res.status_code) => {
+                delivery.record_success(res.status_code, jst_now());
+            }
+            Ok(res) => {
+                let error = format!("ステータスコード{}のレスポンス", res.status_code);
+                delivery.record_failure(
+                    Some(res.status_code),
+                    &error,
+                    &self.retry_policy,
+                    jst_now(),
+                );
+            }
+            Err(err) => {
+                delivery.record_failure(None, &err.to_string(), &self.retry_policy, jst_now());
+            }
+        }
+
+        // 配信失敗のログ出力
+        if let Some(attempt) = delivery.attempts.last().filter(|a| a.error.is_some()) {
+            let err_msg = format!(
+                "Webhookの配信エラー（delivery_id={}, attempt_count={}, status_code={:?}）: {}",
+                delivery.delivery_id,
+                delivery.attempt_count,
+                attempt.status_code,
+                attempt.error.as_deref().unwrap_or("-")
+            );
+            if delivery.status == WebhookDeliveryStatus::Failed {
+                self.logger.error(ctx, &err_msg);
+            } else {
+                self.logger.warn(ctx, &err_msg);
+            }
+        }
+
+        // 配信結果の保存（確保時のバージョンで更新）
+        self.repo
+            .webhook_delivery_repository
+            .update(ctx, &delivery, expected_version)
+            .await
+    }
+}
+
+// 配信対象を一定間隔で配信（main.rsでバックグラウンドタスクとして起動）
+pub async fn run_webhook_delivery<R>(
+    deliver: R,
+    interval: Duration,
+    mut shutdown: watch::Receiver<bool>,
+    logger: Arc<dyn LoggerTrait + 'static>,
+) where
+    R: WebhookDeliverUsecaseTrait + Send + Sync,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    while !*shutdown.borrow() {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = shutdown.changed() => break,
+        }
+
+        let ctx = new_context_background("webhook-delivery");
+        match deliver.deliver_due(&ctx).await {
+            Ok(result) => {
+                if result != WebhookDeliverResult::default() {
+                    let msg = format!(
+                        "Webhookを配信しました（succeeded={}, retrying={}, failed={}）",
+                        result.succeeded, result.retrying, result.failed
+                    );
+                    logger.info(&ctx, &msg);
+                }
+            }
+            Err(err) => {
+                let err_msg = format!("Webhookの配信処理でエラー: {}", err);
+                logger.error(&ctx, &err_msg);
+            }
+        }
+    }
+
+    logger.info(
+        &new_context_background("webhook-delivery"),
+        "Webhookの配信処理を停止しました",
+    );
+}
@@ -0,0 +1,332 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::user::user_model::User;
+    use crate::domain::webhook::webhook_delivery_model::{WebhookDelivery, WebhookDeliveryStatus};
+    use crate::domain::webhook::webhook_signature::verify;
+    use crate::domain::webhook::webhook_subscription_model::WebhookSubscription;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // 送信のモック
+    use crate::application::usecase::webhook::webhook_sender_trait::{
+        MockWebhookSenderTrait, WebhookResponse,
+    };
+
+    // リポジトリのモック
+    use crate::domain::webhook::webhook_delivery_repository::MockWebhookDeliveryRepositoryTrait;
+    use crate::domain::webhook::webhook_subscription_repository::MockWebhookSubscriptionRepositoryTrait;
+
+    // ユースケース
+    use crate::application::usecase::webhook::webhook_deliver::{
+        WebhookDeliverRepository, WebhookDeliverResult, WebhookDeliverUsecase,
+        WebhookDeliverUsecaseTrait,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "webhook-delivery".to_string(),
+            auth: None,
+        }
+    }
+
+    // 購読設定
+    fn create_subscription() -> WebhookSubscription {
+        let mut subscription = WebhookSubscription::new(
+            "https://example.com/hook".to_string(),
+            vec!["*".to_string()],
+        )
+        .unwrap();
+        subscription.id = 1;
+        subscription
+    }
+
+    // 配信対象
+    fn create_delivery(subscription_id: &str, attempt_count: u32) -> WebhookDelivery {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0003".to_string(),
+            "鈴木".to_string(),
+            "三郎".to_string(),
+            "s.suzuki@example.com".to_string(),
+        );
+        let message = OutboxMessage::from_events(&user.take_events()).remove(0);
+        let mut delivery = WebhookDelivery::new(subscription_id, &message, jst_now());
+        delivery.id = 1;
+        delivery.attempt_count = attempt_count;
+        delivery
+    }
+
+    // ユースケースのインスタンス化
+    fn create_usecase(
+        subscription: Option<WebhookSubscription>,
+        attempt_count: u32,
+        sender: MockWebhookSenderTrait,
+        expected_status: WebhookDeliveryStatus,
+    ) -> WebhookDeliverUsecase {
+        let subscription_id = subscription
+            .as_ref()
+            .map(|s| s.subscription_id.clone())
+            .unwrap_or("xxxx".to_string());
+
+        // リポジトリのモック化
+        let mut mock_subscription_repo = MockWebhookSubscriptionRepositoryTrait::new();
+        mock_subscription_repo
+            .expect_find_by_subscription_id()
+            .returning(move |_, _| Ok(subscription.clone()));
+        let mut mock_delivery_repo = MockWebhookDeliveryRepositoryTrait::new();
+        mock_delivery_repo
+            .expect_find_due()
+            .withf(|_, _, limit| *limit == 10)
+            .returning(move |_, _, _| Ok(vec![create_delivery(&subscription_id, attempt_count)]));
+        // 配信の確保（占有期限を設定して取得時のバージョンで更新）
+        mock_delivery_repo
+            .expect_update()
+            .withf(|_, delivery, expected_version| {
+                *expected_version == 1 && delivery.version == 2 && delivery.locked_until.is_some()
+            })
+            .times(1)
+            .returning(|_, delivery, _| Ok(delivery.clone()));
+        // 配信結果の保存（確保時のバージョンで更新して占有期限を解除）
+        mock_delivery_repo
+            .expect_update()
+            .withf(move |_, delivery, expected_version| {
+                *expected_version == 2
+                    && delivery.status == expected_status
+                    && delivery.locked_until.is_none()
+            })
+            .times(1)
+            .returning(|_, delivery, _| Ok(delivery.clone()));
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        WebhookDeliverUsecase {
+            repo: WebhookDeliverRepository {
+                webhook_subscription_repository: Arc::new(mock_subscription_repo),
+                webhook_delivery_repository: Arc::new(mock_delivery_repo),
+            },
+            sender: Arc::new(sender),
+            retry_policy: RetryPolicy {
+                max_attempts: 3,
+                backoff_base_secs: 10,
+                backoff_max_secs: 60,
+            },
+            batch_size: 10,
+            lease_secs: 60,
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    // ステータスコードを返す送信のモック
+    fn create_sender(status_code: u16) -> MockWebhookSenderTrait {
+        let mut mock_sender = MockWebhookSenderTrait::new();
+        mock_sender
+            .expect_send()
+            .times(1)
+            .returning(move |_| Ok(WebhookResponse { status_code }));
+        mock_sender
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_succeeded() {
+        let subscription = create_subscription();
+        let secret = subscription.secret.expose().to_string();
+
+        // 送信のモック化（署名付きのリクエストであることを検証）
+        let mut mock_sender = MockWebhookSenderTrait::new();
+        mock_sender
+            .expect_send()
+            .withf(move |req| {
+                let header = |name: &str| {
+                    req.headers
+                        .iter()
+                        .find(|(k, _)| k == name)
+                        .map(|(_, v)| v.clone())
+                        .unwrap()
+                };
+                let timestamp = header("Webhook-Timestamp").parse::<i64>().unwrap();
+                req.url == "https://example.com/hook"
+                    && header("Webhook-Event") == "user.created"
+                    && verify(&secret, timestamp, &req.body, &header("Webhook-Signature"))
+            })
+            .times(1)
+            .returning(|_| Ok(WebhookResponse { status_code: 204 }));
+
+        let usecase = create_usecase(
+            Some(subscription),
+            0,
+            mock_sender,
+            WebhookDeliveryStatus::Succeeded,
+        );
+
+        // テスト実行
+        let res = usecase.deliver_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(
+            res,
+            WebhookDeliverResult {
+                succeeded: 1,
+                retrying: 0,
+                failed: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_retrying() {
+        let usecase = create_usecase(
+            Some(create_subscription()),
+            0,
+            create_sender(500),
+            WebhookDeliveryStatus::Pending,
+        );
+
+        // テスト実行
+        let res = usecase.deliver_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(res.retrying, 1);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_failed() {
+        // 送信エラーで最大試行回数に到達
+        let mut mock_sender = MockWebhookSenderTrait::new();
+        mock_sender.expect_send().times(1).returning(|_| {
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_GATEWAY,
+                message: "connection refused".to_string(),
+            })
+        });
+        let usecase = create_usecase(
+            Some(create_subscription()),
+            2,
+            mock_sender,
+            WebhookDeliveryStatus::Failed,
+        );
+
+        // テスト実行
+        let res = usecase.deliver_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(res.failed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_subscription_deleted() {
+        // 購読設定が削除済みの場合は送信せずに中止
+        let mut mock_sender = MockWebhookSenderTrait::new();
+        mock_sender.expect_send().never();
+        let usecase = create_usecase(None, 0, mock_sender, WebhookDeliveryStatus::Failed);
+
+        // テスト実行
+        let res = usecase.deliver_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(res.failed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_skip_claimed() {
+        // リポジトリのモック化（取得後に再配信で確保された配信）
+        let mut mock_subscription_repo = MockWebhookSubscriptionRepositoryTrait::new();
+        mock_subscription_repo
+            .expect_find_by_subscription_id()
+            .never();
+        let mut mock_delivery_repo = MockWebhookDeliveryRepositoryTrait::new();
+        mock_delivery_repo
+            .expect_find_due()
+            .returning(|_, _, _| Ok(vec![create_delivery("xxxx", 0)]));
+        mock_delivery_repo
+            .expect_update()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::CONFLICT,
+                    message: "対象の配信は他の処理により更新されています。".to_string(),
+                })
+            });
+
+        // 送信のモック化（確保できない配信は送信しないこと）
+        let mut mock_sender = MockWebhookSenderTrait::new();
+        mock_sender.expect_send().never();
+
+        let usecase = WebhookDeliverUsecase {
+            repo: WebhookDeliverRepository {
+                webhook_subscription_repository: Arc::new(mock_subscription_repo),
+                webhook_delivery_repository: Arc::new(mock_delivery_repo),
+            },
+            sender: Arc::new(mock_sender),
+            retry_policy: RetryPolicy {
+                max_attempts: 3,
+                backoff_base_secs: 10,
+                backoff_max_secs: 60,
+            },
+            batch_size: 10,
+            lease_secs: 60,
+            logger: Arc::new(MockLoggerTrait::new()),
+        };
+
+        // テスト実行
+        let res = usecase.deliver_due(&create_ctx()).await.unwrap();
+
+        // 検証
+        assert_eq!(res, WebhookDeliverResult::default());
+    }
+
+    #[tokio::test]
+    async fn test_deliver_locked() {
+        // 送信のモック化（配信中の配信は送信しないこと）
+        let mut mock_sender = MockWebhookSenderTrait::new();
+        mock_sender.expect_send().never();
+
+        let mut mock_delivery_repo = MockWebhookDeliveryRepositoryTrait::new();
+        mock_delivery_repo.expect_update().never();
+
+        let usecase = WebhookDeliverUsecase {
+            repo: WebhookDeliverRepository {
+                webhook_subscription_repository: Arc::new(
+                    MockWebhookSubscriptionRepositoryTrait::new(),
+                ),
+                webhook_delivery_repository: Arc::new(mock_delivery_repo),
+            },
+            sender: Arc::new(mock_sender),
+            retry_policy: RetryPolicy {
+                max_attempts: 3,
+                backoff_base_secs: 10,
+                backoff_max_secs: 60,
+            },
+            batch_size: 10,
+            lease_secs: 60,
+            logger: Arc::new(MockLoggerTrait::new()),
+        };
+
+        // テスト実行（他の処理で配信中の配信）
+        let mut delivery = create_delivery("xxxx", 0);
+        delivery.claim(60, jst_now());
+        let res = usecase.deliver(&create_ctx(), delivery).await;
+
+        // 検証
+        assert_eq!(res.unwrap_err().status_code(), StatusCode::CONFLICT);
+    }
+}
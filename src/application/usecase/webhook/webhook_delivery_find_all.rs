@@ -0,0 +1,125 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::webhook::webhook_delivery_model::WebhookDelivery;
+use crate::domain::webhook::webhook_delivery_repository::WebhookDeliveryRepositoryTrait;
+use crate::domain::webhook::webhook_subscription_repository::WebhookSubscriptionRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookDeliveryFindAllUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, subscription_id: String) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct WebhookDeliveryFindAllRepository {
+    pub webhook_subscription_repository: Arc<dyn WebhookSubscriptionRepositoryTrait + 'static>,
+    pub webhook_delivery_repository: Arc<dyn WebhookDeliveryRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct WebhookDeliveryFindAllUsecase {
+    pub repo: WebhookDeliveryFindAllRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookDeliveryFindAllUsecase {
+    pub fn new(
+        repo: WebhookDeliveryFindAllRepository,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        WebhookDeliveryFindAllUsecase { repo, logger }
+    }
+
+    // 購読設定ごとの配信ログの取得処理
+    async fn find_all(
+        &self,
+        ctx: &ContextRequest,
+        subscription_id: &str,
+    ) -> Result<Vec<WebhookDelivery>, ErrorCommon> {
+        // 対象の購読設定の存在チェック
+        if self
+            .repo
+            .webhook_subscription_repository
+            .find_by_subscription_id(ctx, subscription_id)
+            .await?
+            .is_none()
+        {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::NOT_FOUND,
+                message: "対象の購読設定が存在しません。".to_string(),
+            });
+        }
+
+        self.repo
+            .webhook_delivery_repository
+            .find_by_subscription_id(ctx, subscription_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryFindAllUsecaseTrait for WebhookDeliveryFindAllUsecase {
+    async fn exec(&self, ctx: ContextRequest, subscription_id: String) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("WebhookDeliveryFindAllUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 配信ログの取得処理
+        match self.find_all(&ctx, &subscription_id).await {
+            Ok(deliveries) => (StatusCode::OK, res_header, Json(json!(deliveries))).into_response(),
+            Err(err) => {
+                // エラーログ出力（入力値のエラーは除く）
+                if !err.status_code().is_client_error() {
+                    let err_msg = format!("WebhookDeliveryFindAllUsecaseでエラー: {}", err);
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
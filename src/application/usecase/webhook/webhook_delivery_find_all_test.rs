@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::user::user_model::User;
+    use crate::domain::webhook::webhook_delivery_model::WebhookDelivery;
+    use crate::domain::webhook::webhook_delivery_repository::MockWebhookDeliveryRepositoryTrait;
+    use crate::domain::webhook::webhook_subscription_model::WebhookSubscription;
+    use crate::domain::webhook::webhook_subscription_repository::MockWebhookSubscriptionRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::webhook::webhook_delivery_find_all::{
+        WebhookDeliveryFindAllRepository, WebhookDeliveryFindAllUsecase,
+        WebhookDeliveryFindAllUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/admin/webhooks/xxxx/deliveries".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 管理者のロール
+    fn admin() -> Vec<String> {
+        vec!["admin".to_string()]
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase(subscription: Option<WebhookSubscription>) -> WebhookDeliveryFindAllUsecase {
+        // リポジトリのモック化
+        let mut mock_subscription_repo = MockWebhookSubscriptionRepositoryTrait::new();
+        mock_subscription_repo
+            .expect_find_by_subscription_id()
+            .returning(move |_, _| Ok(subscription.clone()));
+        let mut mock_delivery_repo = MockWebhookDeliveryRepositoryTrait::new();
+        mock_delivery_repo
+            .expect_find_by_subscription_id()
+            .returning(|_, subscription_id| {
+                let mut user = User::new(
+                    "xxxx-xxxx-xxxx-0003".to_string(),
+                    "鈴木".to_string(),
+                    "三郎".to_string(),
+                    "s.suzuki@example.com".to_string(),
+                );
+                let message = OutboxMessage::from_events(&user.take_events()).remove(0);
+                Ok(vec![WebhookDelivery::new(
+                    subscription_id,
+                    &message,
+                    jst_now(),
+                )])
+            });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        WebhookDeliveryFindAllUsecase {
+            repo: WebhookDeliveryFindAllRepository {
+                webhook_subscription_repository: Arc::new(mock_subscription_repo),
+                webhook_delivery_repository: Arc::new(mock_delivery_repo),
+            },
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let subscription = WebhookSubscription::new(
+            "https://example.com/hook".to_string(),
+            vec!["*".to_string()],
+        )
+        .unwrap();
+        let subscription_id = subscription.subscription_id.clone();
+
+        // テスト実行
+        let res = new_usecase(Some(subscription))
+            .exec(new_ctx(admin()), subscription_id.clone())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data[0]["subscription_id"], subscription_id);
+        assert_eq!(res_data[0]["event_type"], "user.created");
+        assert_eq!(res_data[0]["status"], "pending");
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // テスト実行
+        let res = new_usecase(None)
+            .exec(new_ctx(admin()), "xxxx".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行
+        let res = new_usecase(None)
+            .exec(new_ctx(vec!["operator".to_string()]), "xxxx".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
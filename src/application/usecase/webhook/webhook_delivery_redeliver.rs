@@ -0,0 +1,154 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::webhook::webhook_delivery_model::WebhookDelivery;
+use crate::domain::webhook::webhook_delivery_repository::WebhookDeliveryRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// 配信処理
+use crate::application::usecase::webhook::webhook_deliver::WebhookDeliverUsecaseTrait;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRedeliverUsecaseTrait {
+    async fn exec(
+        &self,
+        ctx: ContextRequest,
+        subscription_id: String,
+        delivery_id: String,
+    ) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct WebhookDeliveryRedeliverRepository {
+    pub webhook_delivery_repository: Arc<dyn WebhookDeliveryRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct WebhookDeliveryRedeliverUsecase {
+    pub repo: WebhookDeliveryRedeliverRepository,
+    // 配信に利用するユースケース
+    pub webhook_deliver: Arc<dyn WebhookDeliverUsecaseTrait + Send + Sync + 'static>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookDeliveryRedeliverUsecase {
+    pub fn new(
+        repo: WebhookDeliveryRedeliverRepository,
+        webhook_deliver: Arc<dyn WebhookDeliverUsecaseTrait + Send + Sync + 'static>,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        WebhookDeliveryRedeliverUsecase {
+            repo,
+            webhook_deliver,
+            logger,
+        }
+    }
+
+    // 再配信処理（試行回数をリセットして即時に配信）
+    async fn redeliver(
+        &self,
+        ctx: &ContextRequest,
+        subscription_id: &str,
+        delivery_id: &str,
+    ) -> Result<WebhookDelivery, ErrorCommon> {
+        // 対象の配信の取得
+        let mut delivery = match self
+            .repo
+            .webhook_delivery_repository
+            .find_by_delivery_id(ctx, delivery_id)
+            .await?
+        {
+            Some(delivery) if delivery.subscription_id == subscription_id => delivery,
+            _ => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::NOT_FOUND,
+                    message: "対象の配信が存在しません。".to_string(),
+                });
+            }
+        };
+
+        // 試行回数をリセットして配信（配信中の場合や取得後に他の処理で更新された場合は409エラー）
+        delivery.redeliver(jst_now());
+        self.webhook_deliver.deliver(ctx, delivery).await
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRedeliverUsecaseTrait for WebhookDeliveryRedeliverUsecase {
+    async fn exec(
+        &self,
+        ctx: ContextRequest,
+        subscription_id: String,
+        delivery_id: String,
+    ) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("WebhookDeliveryRedeliverUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 再配信処理（配信結果は配信ログで確認）
+        match self.redeliver(&ctx, &subscription_id, &delivery_id).await {
+            Ok(delivery) => {
+                let msg = format!(
+                    "Webhookを再配信しました: delivery_id={}, status={:?}",
+                    delivery.delivery_id, delivery.status
+                );
+                self.logger.info(&ctx, &msg);
+
+                (StatusCode::OK, res_header, Json(json!(delivery))).into_response()
+            }
+            Err(err) => {
+                // エラーログ出力（入力値のエラーは除く）
+                if !err.status_code().is_client_error() {
+                    let err_msg = format!("WebhookDeliveryRedeliverUsecaseでエラー: {}", err);
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::user::user_model::User;
+    use crate::domain::webhook::webhook_delivery_model::{WebhookDelivery, WebhookDeliveryStatus};
+    use crate::domain::webhook::webhook_delivery_repository::MockWebhookDeliveryRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // 配信処理のモック
+    use crate::application::usecase::webhook::webhook_deliver::MockWebhookDeliverUsecaseTrait;
+
+    // ユースケース
+    use crate::application::usecase::webhook::webhook_delivery_redeliver::{
+        WebhookDeliveryRedeliverRepository, WebhookDeliveryRedeliverUsecase,
+        WebhookDeliveryRedeliverUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/admin/webhooks/xxxx/deliveries/yyyy/redeliver".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 管理者のロール
+    fn admin() -> Vec<String> {
+        vec!["admin".to_string()]
+    }
+
+    // 配信に失敗した配信
+    fn new_delivery() -> WebhookDelivery {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0003".to_string(),
+            "鈴木".to_string(),
+            "三郎".to_string(),
+            "s.suzuki@example.com".to_string(),
+        );
+        let message = OutboxMessage::from_events(&user.take_events()).remove(0);
+        let mut delivery = WebhookDelivery::new("sub-0001", &message, jst_now());
+        delivery.status = WebhookDeliveryStatus::Failed;
+        delivery.attempt_count = 8;
+        delivery
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase(delivery: Option<WebhookDelivery>) -> WebhookDeliveryRedeliverUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockWebhookDeliveryRepositoryTrait::new();
+        mock_repo
+            .expect_find_by_delivery_id()
+            .returning(move |_, _| Ok(delivery.clone()));
+
+        // 配信処理のモック化（試行回数をリセットして配信）
+        let mut mock_deliver = MockWebhookDeliverUsecaseTrait::new();
+        mock_deliver.expect_deliver().returning(|_, mut delivery| {
+            assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+            assert_eq!(delivery.attempt_count, 0);
+            delivery.record_success(200, jst_now());
+            Ok(delivery)
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        WebhookDeliveryRedeliverUsecase {
+            repo: WebhookDeliveryRedeliverRepository {
+                webhook_delivery_repository: Arc::new(mock_repo),
+            },
+            webhook_deliver: Arc::new(mock_deliver),
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let delivery = new_delivery();
+        let delivery_id = delivery.delivery_id.clone();
+
+        // テスト実行
+        let res = new_usecase(Some(delivery))
+            .exec(new_ctx(admin()), "sub-0001".to_string(), delivery_id)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data["status"], "succeeded");
+        assert_eq!(res_data["attempt_count"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        let delivery = new_delivery();
+        let delivery_id = delivery.delivery_id.clone();
+
+        // テスト実行（別の購読設定の配信を指定）
+        let mismatch = new_usecase(Some(delivery))
+            .exec(new_ctx(admin()), "sub-0002".to_string(), delivery_id)
+            .await;
+        let missing = new_usecase(None)
+            .exec(new_ctx(admin()), "sub-0001".to_string(), "yyyy".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(mismatch.status(), StatusCode::NOT_FOUND);
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行
+        let res = new_usecase(None)
+            .exec(
+                new_ctx(vec!["operator".to_string()]),
+                "sub-0001".to_string(),
+                "yyyy".to_string(),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
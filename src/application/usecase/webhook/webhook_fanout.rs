@@ -0,0 +1,68 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::outbox::outbox_model::OutboxMessage;
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::webhook::webhook_delivery_model::WebhookDelivery;
+use crate::domain::webhook::webhook_delivery_repository::WebhookDeliveryRepositoryTrait;
+use crate::domain::webhook::webhook_subscription_repository::WebhookSubscriptionRepositoryTrait;
+
+// 送信先のトレイト
+use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct WebhookFanoutRepository {
+    pub webhook_subscription_repository: Arc<dyn WebhookSubscriptionRepositoryTrait + 'static>,
+    pub webhook_delivery_repository: Arc<dyn WebhookDeliveryRepositoryTrait + 'static>,
+}
+
+// アウトボックスのメッセージから購読設定ごとの配信を作成する送信先
+// ※配信はWebhookDeliverUsecaseがバックグラウンドで送信する
+pub struct WebhookFanoutPublisher {
+    pub repo: WebhookFanoutRepository,
+}
+
+impl WebhookFanoutPublisher {
+    pub fn new(repo: WebhookFanoutRepository) -> Self {
+        WebhookFanoutPublisher { repo }
+    }
+}
+
+#[async_trait]
+impl OutboxPublisherTrait for WebhookFanoutPublisher {
+    async fn publish(
+        &self,
+        ctx: &ContextRequest,
+        message: &OutboxMessage,
+    ) -> Result<(), ErrorCommon> {
+        let subscriptions = self
+            .repo
+            .webhook_subscription_repository
+            .find_all(ctx)
+            .await?;
+
+        // 購読対象の購読設定ごとに配信を作成（リトライ時は登録済みの配信を再利用）
+        let now = jst_now();
+        for subscription in subscriptions
+            .iter()
+            .filter(|s| s.matches(&message.event_type))
+        {
+            let delivery = WebhookDelivery::new(&subscription.subscription_id, message, now);
+            self.repo
+                .webhook_delivery_repository
+                .add(ctx, &delivery)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::user::user_model::User;
+    use crate::domain::webhook::webhook_subscription_model::WebhookSubscription;
+
+    // リポジトリのモック
+    use crate::domain::webhook::webhook_delivery_repository::MockWebhookDeliveryRepositoryTrait;
+    use crate::domain::webhook::webhook_subscription_repository::MockWebhookSubscriptionRepositoryTrait;
+
+    // 送信先
+    use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+    use crate::application::usecase::webhook::webhook_fanout::{
+        WebhookFanoutPublisher, WebhookFanoutRepository,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "outbox-relay".to_string(),
+            auth: None,
+        }
+    }
+
+    // 送信対象のメッセージ（user.created）
+    fn create_message() -> OutboxMessage {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0003".to_string(),
+            "鈴木".to_string(),
+            "三郎".to_string(),
+            "s.suzuki@example.com".to_string(),
+        );
+        OutboxMessage::from_events(&user.take_events()).remove(0)
+    }
+
+    // 購読設定
+    fn create_subscription(id: i64, event_types: &[&str]) -> WebhookSubscription {
+        let mut subscription = WebhookSubscription::new(
+            "https://example.com/hook".to_string(),
+            event_types.iter().map(|s| s.to_string()).collect(),
+        )
+        .unwrap();
+        subscription.id = id;
+        subscription
+    }
+
+    #[tokio::test]
+    async fn test_publish() {
+        let subscriptions = vec![
+            create_subscription(1, &["user.created"]),
+            create_subscription(2, &["user.deleted"]),
+            create_subscription(3, &["*"]),
+        ];
+        let expected: Vec<String> = vec![
+            subscriptions[0].subscription_id.clone(),
+            subscriptions[2].subscription_id.clone(),
+        ];
+
+        // リポジトリのモック化
+        let mut mock_subscription_repo = MockWebhookSubscriptionRepositoryTrait::new();
+        mock_subscription_repo
+            .expect_find_all()
+            .returning(move |_| Ok(subscriptions.clone()));
+
+        // 購読対象の購読設定の分だけ配信を作成
+        let message = create_message();
+        let event_id = message.event_id.clone();
+        let mut mock_delivery_repo = MockWebhookDeliveryRepositoryTrait::new();
+        mock_delivery_repo
+            .expect_add()
+            .withf(move |_, delivery| {
+                expected.contains(&delivery.subscription_id)
+                    && delivery.event_id == event_id
+                    && delivery.event_type == "user.created"
+            })
+            .times(2)
+            .returning(|_, delivery| Ok(delivery.clone()));
+
+        let publisher = WebhookFanoutPublisher::new(WebhookFanoutRepository {
+            webhook_subscription_repository: Arc::new(mock_subscription_repo),
+            webhook_delivery_repository: Arc::new(mock_delivery_repo),
+        });
+
+        // テスト実行
+        let res = publisher.publish(&create_ctx(), &message).await;
+
+        // 検証
+        assert!(res.is_ok());
+    }
+}
@@ -0,0 +1,27 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// Webhookの送信内容
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+// Webhookの送信結果
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebhookResponse {
+    pub status_code: u16,
+}
+
+// Webhookの送信用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait]
+pub trait WebhookSenderTrait: Send + Sync {
+    // 送信（接続エラー・タイムアウト等でレスポンスがない場合はエラー）
+    async fn send(&self, request: &WebhookRequest) -> Result<WebhookResponse, ErrorCommon>;
+}
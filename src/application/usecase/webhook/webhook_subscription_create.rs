@@ -0,0 +1,142 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// シリアライズ・デシリアライズ
+use serde::Deserialize;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::webhook::webhook_subscription_model::WebhookSubscription;
+use crate::domain::webhook::webhook_subscription_repository::WebhookSubscriptionRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 入力値の構造体
+#[derive(Clone, Deserialize, Debug)]
+pub struct WebhookSubscriptionCreateInput {
+    pub url: String,
+    // 購読するイベント種別（「*」で全て）
+    pub event_types: Vec<String>,
+}
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionCreateUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, input: WebhookSubscriptionCreateInput) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct WebhookSubscriptionCreateRepository {
+    pub webhook_subscription_repository: Arc<dyn WebhookSubscriptionRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct WebhookSubscriptionCreateUsecase {
+    pub repo: WebhookSubscriptionCreateRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookSubscriptionCreateUsecase {
+    pub fn new(
+        repo: WebhookSubscriptionCreateRepository,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        WebhookSubscriptionCreateUsecase { repo, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionCreateUsecaseTrait for WebhookSubscriptionCreateUsecase {
+    async fn exec(&self, ctx: ContextRequest, input: WebhookSubscriptionCreateInput) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("WebhookSubscriptionCreateUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 購読設定の作成
+        let subscription = match WebhookSubscription::new(input.url, input.event_types) {
+            Ok(subscription) => subscription,
+            Err(msg) => {
+                let err = ErrorCommon::CustomError {
+                    status_code: StatusCode::BAD_REQUEST,
+                    message: msg,
+                };
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // 購読設定の保存
+        let subscription = match self
+            .repo
+            .webhook_subscription_repository
+            .create(&ctx, &subscription)
+            .await
+        {
+            Ok(subscription) => subscription,
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("WebhookSubscriptionCreateUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // 作成内容のログ出力（シークレットは出力しない）
+        let msg = format!(
+            "Webhookの購読設定を作成しました: subscription_id={}",
+            subscription.subscription_id
+        );
+        self.logger.info(&ctx, &msg);
+
+        // レスポンスボディの設定（署名用のシークレットはこのレスポンスでのみ返す）
+        let res_body =
+            Json(json!({ "secret": subscription.secret.expose(), "subscription": subscription }));
+
+        // レスポンス結果を設定して戻り値として返す
+        (StatusCode::CREATED, res_header, res_body).into_response()
+    }
+}
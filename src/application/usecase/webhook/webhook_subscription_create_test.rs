@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::webhook::webhook_subscription_repository::MockWebhookSubscriptionRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::webhook::webhook_subscription_create::{
+        WebhookSubscriptionCreateInput, WebhookSubscriptionCreateRepository,
+        WebhookSubscriptionCreateUsecase, WebhookSubscriptionCreateUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/admin/webhooks".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 管理者のロール
+    fn admin() -> Vec<String> {
+        vec!["admin".to_string()]
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase() -> WebhookSubscriptionCreateUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockWebhookSubscriptionRepositoryTrait::new();
+        mock_repo.expect_create().returning(|_, subscription| {
+            let mut subscription = subscription.clone();
+            subscription.id = 1;
+            Ok(subscription)
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        WebhookSubscriptionCreateUsecase {
+            repo: WebhookSubscriptionCreateRepository {
+                webhook_subscription_repository: Arc::new(mock_repo),
+            },
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    // 入力値
+    fn new_input(url: &str, event_types: &[&str]) -> WebhookSubscriptionCreateInput {
+        WebhookSubscriptionCreateInput {
+            url: url.to_string(),
+            event_types: event_types.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let input = new_input("https://example.com/hook", &["user.created"]);
+
+        // テスト実行
+        let res = new_usecase().exec(new_ctx(admin()), input).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        // レスポンスボディの検証（シークレットは作成時のみ返却）
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert!(res_data["secret"].as_str().unwrap().starts_with("whsec_"));
+        assert_eq!(res_data["subscription"]["id"], 1);
+        assert_eq!(res_data["subscription"]["url"], "https://example.com/hook");
+        assert!(res_data["subscription"].get("secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exec_invalid_input() {
+        // テスト実行
+        let invalid_url = new_usecase()
+            .exec(new_ctx(admin()), new_input("ftp://example.com", &["*"]))
+            .await;
+        let invalid_event = new_usecase()
+            .exec(
+                new_ctx(admin()),
+                new_input("https://example.com/hook", &["user.unknown"]),
+            )
+            .await;
+
+        // 検証
+        assert_eq!(invalid_url.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(invalid_event.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        let input = new_input("https://example.com/hook", &["*"]);
+
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(vec!["operator".to_string()]), input)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,133 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::error::error_common::ErrorCommon;
+use crate::domain::webhook::webhook_subscription_repository::WebhookSubscriptionRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionDeleteUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest, subscription_id: String) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct WebhookSubscriptionDeleteRepository {
+    pub webhook_subscription_repository: Arc<dyn WebhookSubscriptionRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct WebhookSubscriptionDeleteUsecase {
+    pub repo: WebhookSubscriptionDeleteRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookSubscriptionDeleteUsecase {
+    pub fn new(
+        repo: WebhookSubscriptionDeleteRepository,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        WebhookSubscriptionDeleteUsecase { repo, logger }
+    }
+
+    // 購読設定の削除処理（未配信の配信は次回の配信時に中止）
+    async fn delete(&self, ctx: &ContextRequest, subscription_id: &str) -> Result<(), ErrorCommon> {
+        // 対象の購読設定の取得
+        let mut subscription = match self
+            .repo
+            .webhook_subscription_repository
+            .find_by_subscription_id(ctx, subscription_id)
+            .await?
+        {
+            Some(subscription) => subscription,
+            None => {
+                return Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::NOT_FOUND,
+                    message: "対象の購読設定が存在しません。".to_string(),
+                });
+            }
+        };
+
+        // 削除設定
+        subscription.set_delete();
+
+        self.repo
+            .webhook_subscription_repository
+            .update(ctx, &subscription)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionDeleteUsecaseTrait for WebhookSubscriptionDeleteUsecase {
+    async fn exec(&self, ctx: ContextRequest, subscription_id: String) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("WebhookSubscriptionDeleteUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 購読設定の削除処理
+        match self.delete(&ctx, &subscription_id).await {
+            Ok(_) => {
+                let msg = format!(
+                    "Webhookの購読設定を削除しました: subscription_id={}",
+                    subscription_id
+                );
+                self.logger.info(&ctx, &msg);
+
+                (StatusCode::NO_CONTENT, res_header).into_response()
+            }
+            Err(err) => {
+                // エラーログ出力（入力値のエラーは除く）
+                if !err.status_code().is_client_error() {
+                    let err_msg = format!("WebhookSubscriptionDeleteUsecaseでエラー: {}", err);
+                    self.logger.error(&ctx, &err_msg);
+                }
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                (err.status_code(), res_header, json_msg).into_response()
+            }
+        }
+    }
+}
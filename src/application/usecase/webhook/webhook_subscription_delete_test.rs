@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use std::sync::Arc;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::webhook::webhook_subscription_model::WebhookSubscription;
+    use crate::domain::webhook::webhook_subscription_repository::MockWebhookSubscriptionRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::webhook::webhook_subscription_delete::{
+        WebhookSubscriptionDeleteRepository, WebhookSubscriptionDeleteUsecase,
+        WebhookSubscriptionDeleteUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "DELETE".to_string(),
+            uri: "/api/v1/admin/webhooks/xxxx".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 管理者のロール
+    fn admin() -> Vec<String> {
+        vec!["admin".to_string()]
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase(subscription: Option<WebhookSubscription>) -> WebhookSubscriptionDeleteUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockWebhookSubscriptionRepositoryTrait::new();
+        mock_repo
+            .expect_find_by_subscription_id()
+            .returning(move |_, _| Ok(subscription.clone()));
+        mock_repo.expect_update().returning(|_, subscription| {
+            assert!(subscription.deleted_at.is_some());
+            Ok(subscription.clone())
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_info().returning(|_, _| ());
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        WebhookSubscriptionDeleteUsecase {
+            repo: WebhookSubscriptionDeleteRepository {
+                webhook_subscription_repository: Arc::new(mock_repo),
+            },
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        let subscription = WebhookSubscription::new(
+            "https://example.com/hook".to_string(),
+            vec!["*".to_string()],
+        )
+        .unwrap();
+        let subscription_id = subscription.subscription_id.clone();
+
+        // テスト実行
+        let res = new_usecase(Some(subscription))
+            .exec(new_ctx(admin()), subscription_id)
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_exec_not_found() {
+        // テスト実行
+        let res = new_usecase(None)
+            .exec(new_ctx(admin()), "xxxx".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行
+        let res = new_usecase(None)
+            .exec(new_ctx(vec!["operator".to_string()]), "xxxx".to_string())
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
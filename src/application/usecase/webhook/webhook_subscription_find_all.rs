@@ -0,0 +1,101 @@
+// axum
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::auth::auth_policy::Permission;
+use crate::domain::webhook::webhook_subscription_repository::WebhookSubscriptionRepositoryTrait;
+
+// 権限チェック
+use crate::application::usecase::auth::auth_authorize::authorize;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// ユースケース用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionFindAllUsecaseTrait {
+    async fn exec(&self, ctx: ContextRequest) -> Response;
+}
+
+// 使用するリポジトリをまとめる構造体
+#[derive(Clone)]
+pub struct WebhookSubscriptionFindAllRepository {
+    pub webhook_subscription_repository: Arc<dyn WebhookSubscriptionRepositoryTrait + 'static>,
+}
+
+// ユースケースの構造体
+#[derive(Clone)]
+pub struct WebhookSubscriptionFindAllUsecase {
+    pub repo: WebhookSubscriptionFindAllRepository,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookSubscriptionFindAllUsecase {
+    pub fn new(
+        repo: WebhookSubscriptionFindAllRepository,
+        logger: Arc<dyn LoggerTrait + 'static>,
+    ) -> Self {
+        WebhookSubscriptionFindAllUsecase { repo, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionFindAllUsecaseTrait for WebhookSubscriptionFindAllUsecase {
+    async fn exec(&self, ctx: ContextRequest) -> Response {
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let res_header = [("X-Request-Id", request_id)];
+
+        // 権限チェック
+        if let Err(err) = authorize(&ctx, Permission::AdminManage, None) {
+            // ログ出力
+            let err_msg = format!("WebhookSubscriptionFindAllUsecaseで権限エラー: {}", err);
+            self.logger.warn(&ctx, &err_msg);
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // レスポンス結果を設定して戻り値として返す
+            return (err.status_code(), res_header, json_msg).into_response();
+        }
+
+        // 全ての購読設定取得処理
+        let subscriptions = match self
+            .repo
+            .webhook_subscription_repository
+            .find_all(&ctx)
+            .await
+        {
+            Ok(subscriptions) => subscriptions,
+            Err(err) => {
+                // エラーログ出力
+                let err_msg = format!("WebhookSubscriptionFindAllUsecaseでエラー: {}", err);
+                self.logger.error(&ctx, &err_msg);
+
+                // json形式のメッセージを設定
+                let json_msg = Json(json!({ "message": err.to_string()}));
+
+                // レスポンス結果を設定して戻り値として返す
+                return (err.status_code(), res_header, json_msg).into_response();
+            }
+        };
+
+        // レスポンス結果を設定して戻り値として返す（シークレットはシリアライズ対象外）
+        (StatusCode::OK, res_header, Json(json!(subscriptions))).into_response()
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, StatusCode},
+    };
+    use std::{str, sync::Arc};
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::{AuthContext, ContextRequest};
+
+    // ドメイン
+    use crate::domain::webhook::webhook_subscription_model::WebhookSubscription;
+    use crate::domain::webhook::webhook_subscription_repository::MockWebhookSubscriptionRepositoryTrait;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // ユースケース
+    use crate::application::usecase::webhook::webhook_subscription_find_all::{
+        WebhookSubscriptionFindAllRepository, WebhookSubscriptionFindAllUsecase,
+        WebhookSubscriptionFindAllUsecaseTrait,
+    };
+
+    // 共通コンテキスト設定
+    fn new_ctx(roles: Vec<String>) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/admin/webhooks".to_string(),
+            auth: Some(AuthContext {
+                subject: "xxxx-xxxx-xxxx-0001".to_string(),
+                scopes: vec![],
+                roles,
+            }),
+        }
+    }
+
+    // 管理者のロール
+    fn admin() -> Vec<String> {
+        vec!["admin".to_string()]
+    }
+
+    // ユースケースのインスタンス化
+    fn new_usecase() -> WebhookSubscriptionFindAllUsecase {
+        // リポジトリのモック化
+        let mut mock_repo = MockWebhookSubscriptionRepositoryTrait::new();
+        mock_repo.expect_find_all().returning(|_| {
+            let mut subscription = WebhookSubscription::new(
+                "https://example.com/hook".to_string(),
+                vec!["*".to_string()],
+            )
+            .unwrap();
+            subscription.id = 1;
+            Ok(vec![subscription])
+        });
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_warn().returning(|_, _| ());
+        mock_logger.expect_error().returning(|_, _| ());
+
+        WebhookSubscriptionFindAllUsecase {
+            repo: WebhookSubscriptionFindAllRepository {
+                webhook_subscription_repository: Arc::new(mock_repo),
+            },
+            logger: Arc::new(mock_logger),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exec_success() {
+        // テスト実行
+        let res = new_usecase().exec(new_ctx(admin())).await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // レスポンスボディの検証（シークレットは返却しない）
+        let body = res.into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let body_str = str::from_utf8(&bytes).unwrap();
+        let res_data: serde_json::Value = serde_json::from_str(body_str).unwrap();
+        assert_eq!(res_data.as_array().unwrap().len(), 1);
+        assert_eq!(res_data[0]["event_types"][0], "*");
+        assert!(res_data[0].get("secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exec_forbidden() {
+        // テスト実行
+        let res = new_usecase()
+            .exec(new_ctx(vec!["operator".to_string()]))
+            .await;
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+}
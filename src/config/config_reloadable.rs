@@ -0,0 +1,259 @@
+use axum::http::HeaderValue;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+// tokio
+use tokio::signal::unix::{SignalKind, signal};
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// レート制限
+use crate::application::usecase::rate_limit::rate_limit_policy::{RateLimitPolicy, parse_rules};
+
+// 環境変数のデフォルト値を返す関数
+fn default_rust_log() -> String {
+    "info".to_string()
+}
+
+fn default_rate_limits() -> String {
+    "default=120/60,auth=20/60,pre_auth=600/60".to_string()
+}
+
+// 再起動なしで変更可能な設定の構造体
+#[derive(Clone, Deserialize, Debug)]
+pub struct ReloadableConfig {
+    #[serde(default = "default_rust_log")]
+    pub rust_log: String,
+    // 有効にする機能フラグ（カンマ区切り）
+    #[serde(default)]
+    pub feature_flags: Vec<String>,
+    // ルートグループ別のレート制限（「グループ名=回数/秒数」のカンマ区切り、空文字で無効）
+    #[serde(default = "default_rate_limits")]
+    pub rate_limits: String,
+    // レート制限の設定値から生成したグループ別のポリシー（prepareで設定）
+    #[serde(skip)]
+    pub rate_limit_policies: HashMap<String, RateLimitPolicy>,
+    // CORSで許可するオリジン（カンマ区切り、「*」で全て許可、空の場合は許可しない）
+    #[serde(default)]
+    pub cors_allowed_origins: Vec<String>,
+}
+
+impl Default for ReloadableConfig {
+    fn default() -> Self {
+        ReloadableConfig {
+            rust_log: default_rust_log(),
+            feature_flags: Vec::new(),
+            rate_limits: default_rate_limits(),
+            rate_limit_policies: parse_rules(&default_rate_limits()).unwrap(),
+            cors_allowed_origins: Vec::new(),
+        }
+    }
+}
+
+impl ReloadableConfig {
+    // 設定値のチェック
+    pub fn validate(&self) -> Result<(), String> {
+        let mut err_msgs = Vec::new();
+
+        if self.rust_log.trim().is_empty() {
+            err_msgs.push("RUST_LOGは必須です。".to_string());
+        } else if let Err(err) = env_filter::Builder::new().try_parse(&self.rust_log) {
+            err_msgs.push(format!("RUST_LOGが不正です（{}）。", err));
+        }
+
+        for flag in self.feature_flags.iter() {
+            let is_valid = flag
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            if !is_valid {
+                err_msgs.push(format!("FEATURE_FLAGSの値「{}」が不正です。", flag));
+            }
+        }
+
+        if let Err(err) = parse_rules(&self.rate_limits) {
+            err_msgs.push(format!("RATE_LIMITSが不正です（{}）。", err));
+        }
+
+        for origin in self.cors_allowed_origins.iter() {
+            if origin != "*" && HeaderValue::from_str(origin).is_err() {
+                err_msgs.push(format!(
+                    "CORS_ALLOWED_ORIGINSの値「{}」が不正です。",
+                    origin
+                ));
+            }
+        }
+
+        if !err_msgs.is_empty() {
+            return Err(err_msgs.join(", "));
+        }
+
+        Ok(())
+    }
+
+    // 設定値をチェックして設定値から生成する値を設定
+    pub fn prepare(mut self) -> Result<Self, String> {
+        self.validate()?;
+        self.rate_limit_policies = parse_rules(&self.rate_limits)?;
+        Ok(self)
+    }
+
+    // 機能フラグが有効かどうか
+    pub fn is_feature_enabled(&self, name: &str) -> bool {
+        self.feature_flags.iter().any(|flag| flag == name)
+    }
+}
+
+// カンマ区切りの設定値から空の値を除外
+fn trim_values(values: Vec<String>) -> Vec<String> {
+    values
+        .into_iter()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+// 設定ファイル（KEY=VALUE形式）の内容を読み込む
+fn read_config_file(file_path: &str) -> Result<HashMap<String, String>, String> {
+    let content = match std::fs::read_to_string(file_path) {
+        Ok(content) => content,
+        Err(err) => return Err(format!("設定ファイルの読み込みエラー: {}", err)),
+    };
+
+    let mut vars = HashMap::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match line.split_once('=') {
+            Some((key, value)) => {
+                vars.insert(key.trim().to_uppercase(), value.trim().to_string());
+            }
+            None => {
+                return Err(format!("設定ファイルの{}行目が不正です: {}", i + 1, line));
+            }
+        }
+    }
+
+    Ok(vars)
+}
+
+// 再起動なしで変更可能な設定の読み込み（環境変数を設定ファイルの値で上書き）
+pub fn load_reloadable_config(file_path: Option<&str>) -> Result<ReloadableConfig, String> {
+    let mut vars: HashMap<String, String> = std::env::vars().collect();
+    if let Some(path) = file_path {
+        vars.extend(read_config_file(path)?);
+    }
+
+    let mut config = match envy::from_iter::<_, ReloadableConfig>(vars) {
+        Ok(config) => config,
+        Err(err) => return Err(format!("設定値の変換エラー: {}", err)),
+    };
+
+    // 空の機能フラグとオリジンは除外
+    config.feature_flags = trim_values(config.feature_flags);
+    config.cors_allowed_origins = trim_values(config.cors_allowed_origins);
+
+    config.prepare()
+}
+
+// 再起動なしで変更可能な設定を保持するハンドル（AppStateで共有）
+#[derive(Clone)]
+pub struct ReloadableConfigHandle {
+    inner: Arc<RwLock<Arc<ReloadableConfig>>>,
+}
+
+impl ReloadableConfigHandle {
+    pub fn new(config: ReloadableConfig) -> Self {
+        ReloadableConfigHandle {
+            inner: Arc::new(RwLock::new(Arc::new(config))),
+        }
+    }
+
+    // 現在の設定を取得
+    pub fn get(&self) -> Arc<ReloadableConfig> {
+        self.inner.read().unwrap().clone()
+    }
+
+    // 設定を差し替え
+    pub fn replace(&self, config: ReloadableConfig) {
+        *self.inner.write().unwrap() = Arc::new(config);
+    }
+}
+
+// 設定ファイルの更新日時を取得
+fn get_modified(file_path: Option<&str>) -> Option<SystemTime> {
+    let path = file_path?;
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
+// 設定の再読み込み（チェックでエラーの場合は現在の設定を維持）
+fn reload(
+    file_path: Option<&str>,
+    handle: &ReloadableConfigHandle,
+    logger: &Arc<dyn LoggerTrait + 'static>,
+) {
+    let config = match load_reloadable_config(file_path) {
+        Ok(config) => config,
+        Err(err) => {
+            log::error!(
+                "設定の再読み込みに失敗したため現在の設定を維持します: {}",
+                err
+            );
+            return;
+        }
+    };
+
+    // ログフィルターの反映
+    if let Err(err) = logger.set_filters(&config.rust_log) {
+        log::error!(
+            "設定の再読み込みに失敗したため現在の設定を維持します: {}",
+            err
+        );
+        return;
+    }
+
+    log::info!("設定を再読み込みしました: {:?}", config);
+    handle.replace(config);
+}
+
+// SIGHUPの受信または設定ファイルの変更を監視して設定を再読み込み
+pub async fn watch_reloadable_config(
+    file_path: Option<String>,
+    interval: Duration,
+    handle: ReloadableConfigHandle,
+    logger: Arc<dyn LoggerTrait + 'static>,
+) {
+    let mut sighup = match signal(SignalKind::hangup()) {
+        Ok(sighup) => sighup,
+        Err(err) => {
+            log::error!("SIGHUPの監視設定エラー: {}", err);
+            return;
+        }
+    };
+
+    let mut ticker = tokio::time::interval(interval);
+    let mut last_modified = get_modified(file_path.as_deref());
+
+    loop {
+        tokio::select! {
+            _ = sighup.recv() => {
+                log::info!("SIGHUPを受信しました");
+                last_modified = get_modified(file_path.as_deref());
+                reload(file_path.as_deref(), &handle, &logger);
+            }
+            _ = ticker.tick() => {
+                let modified = get_modified(file_path.as_deref());
+                if modified != last_modified {
+                    last_modified = modified;
+                    log::info!("設定ファイルの変更を検知しました");
+                    reload(file_path.as_deref(), &handle, &logger);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    use crate::config::config_reloadable::{
+        ReloadableConfig, ReloadableConfigHandle, load_reloadable_config, watch_reloadable_config,
+    };
+
+    #[test]
+    fn test_validate_success() {
+        // 設定値
+        let config = ReloadableConfig {
+            rust_log: "info,rust_axum_domain::infrastructure=debug".to_string(),
+            feature_flags: vec!["new_user_api".to_string()],
+            rate_limits: "default=10/60".to_string(),
+            ..Default::default()
+        };
+
+        // テスト実行
+        let result = config.validate();
+
+        // 検証
+        assert!(result.is_ok());
+        assert!(config.is_feature_enabled("new_user_api"));
+        assert!(!config.is_feature_enabled("old_user_api"));
+    }
+
+    #[test]
+    fn test_validate_error() {
+        // 設定値
+        let config = ReloadableConfig {
+            rust_log: "info,rust_axum_domain=xxx".to_string(),
+            feature_flags: vec!["New-User-Api".to_string()],
+            rate_limits: "default=abc".to_string(),
+            cors_allowed_origins: vec!["http://local\nhost".to_string()],
+            ..Default::default()
+        };
+
+        // テスト実行
+        let result = config.validate();
+
+        // 検証
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err();
+        assert!(err_msg.contains("RUST_LOGが不正です"));
+        assert!(err_msg.contains("FEATURE_FLAGSの値「New-User-Api」が不正です。"));
+        assert!(err_msg.contains("RATE_LIMITSが不正です"));
+        assert!(err_msg.contains("CORS_ALLOWED_ORIGINSの値"));
+    }
+
+    #[test]
+    fn test_load_reloadable_config_from_file() {
+        // 設定ファイルの作成
+        let file_path = std::env::temp_dir().join("rust_axum_domain_reloadable_success.env");
+        let mut file = std::fs::File::create(&file_path).unwrap();
+        writeln!(file, "# コメント行").unwrap();
+        writeln!(file, "RUST_LOG=warn,rust_axum_domain::application=debug").unwrap();
+        writeln!(file, "FEATURE_FLAGS=new_user_api, beta_export").unwrap();
+        writeln!(file, "RATE_LIMITS=default=10/60").unwrap();
+        writeln!(file, "CORS_ALLOWED_ORIGINS=http://localhost:3000, ").unwrap();
+
+        // テスト実行
+        let result = load_reloadable_config(file_path.to_str());
+
+        // 検証
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(config.rust_log, "warn,rust_axum_domain::application=debug");
+        assert_eq!(
+            config.feature_flags,
+            vec!["new_user_api".to_string(), "beta_export".to_string()]
+        );
+        assert_eq!(config.rate_limit_policies["default"].capacity, 10);
+        assert_eq!(
+            config.cors_allowed_origins,
+            vec!["http://localhost:3000".to_string()]
+        );
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_reloadable_config_from_file_error() {
+        // 不正な値を含む設定ファイルの作成
+        let file_path = std::env::temp_dir().join("rust_axum_domain_reloadable_error.env");
+        let mut file = std::fs::File::create(&file_path).unwrap();
+        writeln!(file, "RUST_LOG=info,rust_axum_domain=xxx").unwrap();
+
+        // テスト実行
+        let result = load_reloadable_config(file_path.to_str());
+
+        // 検証
+        assert!(result.is_err());
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_handle_replace() {
+        // ハンドルの作成
+        let handle = ReloadableConfigHandle::new(ReloadableConfig::default());
+        let cloned_handle = handle.clone();
+
+        // テスト実行
+        handle.replace(ReloadableConfig {
+            rust_log: "debug".to_string(),
+            ..Default::default()
+        });
+
+        // 検証（クローンしたハンドルからも新しい設定を参照できること）
+        assert_eq!(cloned_handle.get().rust_log, "debug");
+    }
+
+    #[tokio::test]
+    async fn test_watch_reload_feature_flags() {
+        // 設定ファイルの作成
+        let file_path = std::env::temp_dir().join("rust_axum_domain_reloadable_watch.env");
+        std::fs::write(&file_path, "FEATURE_FLAGS=users_live\n").unwrap();
+        let handle =
+            ReloadableConfigHandle::new(load_reloadable_config(file_path.to_str()).unwrap());
+        assert!(handle.get().is_feature_enabled("users_live"));
+
+        // ロガーのモック化
+        let mut mock_logger = MockLoggerTrait::new();
+        mock_logger.expect_set_filters().returning(|_| Ok(()));
+
+        // テスト実行（設定ファイルの変更を監視）
+        let watcher = tokio::spawn(watch_reloadable_config(
+            file_path.to_str().map(|path| path.to_string()),
+            Duration::from_millis(20),
+            handle.clone(),
+            Arc::new(mock_logger),
+        ));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        std::fs::write(&file_path, "FEATURE_FLAGS=beta_export\n").unwrap();
+        let file = std::fs::File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        watcher.abort();
+
+        // 検証（再起動なしで機能フラグが差し替わる）
+        let config = handle.get();
+        assert!(!config.is_feature_enabled("users_live"));
+        assert!(config.is_feature_enabled("beta_export"));
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+use zeroize::Zeroize;
+
+// 秘密情報のラッパー
+// ※Debug・Display出力時はマスクし、破棄時にメモリ上の値をゼロクリアする
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: String) -> Self {
+        Secret(value)
+    }
+
+    // 秘密情報の値を参照（ログ出力等には使わないこと）
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret([REDACTED])")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+// 秘密情報を環境変数から読み込む
+// ※「<KEY>_FILE」が設定されている場合はファイル（Docker secrets等）から読み込む
+pub fn load_secret(key: &str) -> Result<Option<Secret>, String> {
+    load_secret_with(key, |name| std::env::var(name).ok())
+}
+
+// 秘密情報を指定した関数で取得した変数から読み込む（テストで環境変数を書き換えずに済むように分離）
+pub fn load_secret_with<F>(key: &str, lookup: F) -> Result<Option<Secret>, String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let file_key = format!("{}_FILE", key);
+    let value = lookup(key);
+    let file_path = lookup(&file_key);
+
+    match (value, file_path) {
+        (Some(_), Some(_)) => Err(format!("{}と{}の両方が設定されています。", key, file_key)),
+        (Some(value), None) => Ok(Some(Secret::new(value))),
+        (None, Some(file_path)) => {
+            let mut content = match std::fs::read_to_string(&file_path) {
+                Ok(content) => content,
+                Err(err) => {
+                    return Err(format!(
+                        "{}のファイル読み込みエラー（{}）: {}",
+                        file_key, file_path, err
+                    ));
+                }
+            };
+
+            // 末尾の改行を除いた値を設定し、読み込んだ文字列はゼロクリア
+            let secret = Secret::new(content.trim_end_matches(['\r', '\n']).to_string());
+            content.zeroize();
+
+            Ok(Some(secret))
+        }
+        (None, None) => Ok(None),
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::Write;
+
+    use crate::config::config_secret::{Secret, load_secret, load_secret_with};
+
+    // テスト用の変数から値を取得する関数を作成
+    fn new_lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> + use<> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_secret_redacted() {
+        // 秘密情報
+        let secret = Secret::new("p@ssw0rd".to_string());
+
+        // 検証
+        assert_eq!(format!("{:?}", secret), "Secret([REDACTED])");
+        assert_eq!(format!("{}", secret), "[REDACTED]");
+        assert_eq!(secret.expose(), "p@ssw0rd");
+    }
+
+    #[test]
+    fn test_load_secret_from_env() {
+        // 変数の設定
+        let lookup = new_lookup(&[("TEST_SECRET_FROM_ENV", "p@ssw0rd")]);
+
+        // テスト実行
+        let result = load_secret_with("TEST_SECRET_FROM_ENV", lookup);
+
+        // 検証
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().unwrap().expose(), "p@ssw0rd");
+    }
+
+    #[test]
+    fn test_load_secret_from_file() {
+        // 秘密情報ファイルの作成
+        let file_path = std::env::temp_dir().join("rust_axum_domain_secret_success.txt");
+        let mut file = std::fs::File::create(&file_path).unwrap();
+        writeln!(file, "p@ssw0rd").unwrap();
+
+        // 変数の設定
+        let lookup = new_lookup(&[("TEST_SECRET_FROM_FILE_FILE", file_path.to_str().unwrap())]);
+
+        // テスト実行
+        let result = load_secret_with("TEST_SECRET_FROM_FILE", lookup);
+
+        // 検証（末尾の改行は除かれること）
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().unwrap().expose(), "p@ssw0rd");
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_load_secret_not_set() {
+        // テスト実行
+        let result = load_secret("TEST_SECRET_NOT_SET");
+
+        // 検証
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_load_secret_error() {
+        // 変数の設定（値とファイルの両方を設定）
+        let lookup = new_lookup(&[
+            ("TEST_SECRET_BOTH", "p@ssw0rd"),
+            ("TEST_SECRET_BOTH_FILE", "/tmp/not_found.txt"),
+        ]);
+
+        // テスト実行
+        let result = load_secret_with("TEST_SECRET_BOTH", lookup);
+
+        // 検証
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "TEST_SECRET_BOTHとTEST_SECRET_BOTH_FILEの両方が設定されています。"
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use envy;
 use serde::Deserialize;
 
+// 秘密情報
+use crate::config::config_secret::{Secret, load_secret};
+
 // 環境変数のデフォルト値を返す関数
 fn default_env() -> String {
     "local".to_string()
@@ -10,35 +13,479 @@ fn default_port() -> u16 {
     8080
 }
 
-fn default_rust_log() -> String {
-    "info".to_string()
+fn default_config_reload_interval_secs() -> u64 {
+    5
+}
+
+fn default_jwt_access_token_ttl_secs() -> i64 {
+    900
+}
+
+fn default_refresh_token_ttl_secs() -> i64 {
+    14 * 24 * 3600
+}
+
+fn default_request_timeouts() -> String {
+    "default=30".to_string()
+}
+
+fn default_request_body_limit_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_idempotency_ttl_secs() -> u64 {
+    24 * 3600
+}
+
+fn default_outbox_relay_interval_secs() -> u64 {
+    1
+}
+
+fn default_outbox_relay_batch_size() -> usize {
+    100
+}
+
+fn default_outbox_max_attempts() -> u32 {
+    10
+}
+
+fn default_outbox_backoff_base_secs() -> u64 {
+    2
+}
+
+fn default_outbox_backoff_max_secs() -> u64 {
+    300
+}
+
+fn default_webhook_delivery_interval_secs() -> u64 {
+    1
+}
+
+fn default_webhook_delivery_batch_size() -> usize {
+    50
 }
 
-// 環境変数の構造体
-#[derive(Deserialize, Debug)]
+fn default_webhook_max_attempts() -> u32 {
+    8
+}
+
+fn default_webhook_backoff_base_secs() -> u64 {
+    10
+}
+
+fn default_webhook_backoff_max_secs() -> u64 {
+    3600
+}
+
+fn default_webhook_timeout_secs() -> u64 {
+    10
+}
+
+fn default_webhook_lease_secs() -> u64 {
+    60
+}
+
+fn default_event_broker() -> String {
+    "none".to_string()
+}
+
+fn default_event_broker_topic_prefix() -> String {
+    "events".to_string()
+}
+
+fn default_user_stream_buffer_size() -> usize {
+    1000
+}
+
+fn default_user_stream_channel_capacity() -> usize {
+    256
+}
+
+fn default_user_stream_keep_alive_secs() -> u64 {
+    15
+}
+
+fn default_user_live_max_subscriptions() -> usize {
+    100
+}
+
+fn default_user_live_channel_capacity() -> usize {
+    256
+}
+
+fn default_user_live_max_message_bytes() -> usize {
+    64 * 1024
+}
+
+fn default_user_live_ping_interval_secs() -> u64 {
+    30
+}
+
+fn default_job_worker_count() -> usize {
+    2
+}
+
+fn default_job_poll_interval_secs() -> u64 {
+    1
+}
+
+fn default_job_batch_size() -> usize {
+    10
+}
+
+fn default_job_lease_secs() -> u64 {
+    300
+}
+
+fn default_job_timeout_secs() -> u64 {
+    240
+}
+
+fn default_job_max_attempts() -> u32 {
+    5
+}
+
+fn default_job_backoff_base_secs() -> u64 {
+    10
+}
+
+fn default_job_backoff_max_secs() -> u64 {
+    3600
+}
+
+fn default_job_schedules() -> String {
+    "user.purge=0 3 * * *".to_string()
+}
+
+fn default_user_purge_retention_days() -> u64 {
+    30
+}
+
+fn default_user_purge_mode() -> String {
+    "delete".to_string()
+}
+
+fn default_shutdown_timeout_secs() -> u64 {
+    30
+}
+
+fn default_compression_algorithms() -> Vec<String> {
+    ["zstd", "br", "gzip"]
+        .iter()
+        .map(|v| v.to_string())
+        .collect()
+}
+
+fn default_compression_min_size_bytes() -> u16 {
+    1024
+}
+
+fn default_request_decompression() -> bool {
+    true
+}
+
+fn default_cors_allowed_methods() -> Vec<String> {
+    ["GET", "POST", "PUT", "DELETE"]
+        .iter()
+        .map(|v| v.to_string())
+        .collect()
+}
+
+fn default_cors_allowed_headers() -> Vec<String> {
+    [
+        "authorization",
+        "content-type",
+        "x-api-key",
+        "if-match",
+        "if-none-match",
+        "idempotency-key",
+    ]
+    .iter()
+    .map(|v| v.to_string())
+    .collect()
+}
+
+fn default_cors_max_age_secs() -> u64 {
+    600
+}
+
+// 環境変数の構造体（起動時のみ読み込む設定）
+// ※再起動なしで変更可能な設定はconfig_reloadableで定義
+#[derive(Clone, Deserialize, Debug)]
 pub struct Config {
     #[serde(default = "default_env")]
     pub env: String,
     #[serde(default = "default_port")]
     pub port: u16,
+    // 再起動なしで変更可能な設定のファイルパス（KEY=VALUE形式）
+    #[serde(default)]
+    pub config_file: Option<String>,
+    // 設定ファイルの変更を確認する間隔（秒）
+    #[serde(default = "default_config_reload_interval_secs")]
+    pub config_reload_interval_secs: u64,
+    // DB接続用のパスワード（DB_PASSWORDまたはDB_PASSWORD_FILEから読み込み）
     #[allow(dead_code)]
-    #[serde(default = "default_rust_log")]
-    pub rust_log: String,
+    #[serde(skip)]
+    pub db_password: Option<Secret>,
+    // JWT（HS256）の共通鍵（JWT_HS256_SECRETまたはJWT_HS256_SECRET_FILEから読み込み）
+    #[serde(skip)]
+    pub jwt_hs256_secret: Option<Secret>,
+    // JWT（RS256）の公開鍵ファイル（PEM形式）
+    #[serde(default)]
+    pub jwt_rs256_public_key_file: Option<String>,
+    // JWT（RS256）のJWKSファイル（kidで鍵を選択）
+    #[serde(default)]
+    pub jwt_jwks_file: Option<String>,
+    // JWTの発行者（issクレーム）のチェック用
+    #[serde(default)]
+    pub jwt_issuer: Option<String>,
+    // JWTの対象者（audクレーム）のチェック用
+    #[serde(default)]
+    pub jwt_audience: Option<String>,
+    // ログイン時に発行するアクセストークン（HS256）の有効期間（秒）
+    #[serde(default = "default_jwt_access_token_ttl_secs")]
+    pub jwt_access_token_ttl_secs: i64,
+    // ログイン時に発行するリフレッシュトークンの有効期間（秒）
+    #[serde(default = "default_refresh_token_ttl_secs")]
+    pub refresh_token_ttl_secs: i64,
+    // OIDCのIDプロバイダーの発行者URL（ディスカバリーに使用）
+    #[serde(default)]
+    pub oidc_issuer_url: Option<String>,
+    // OIDCのクライアントID
+    #[serde(default)]
+    pub oidc_client_id: Option<String>,
+    // OIDCのクライアントシークレット（OIDC_CLIENT_SECRETまたはOIDC_CLIENT_SECRET_FILEから読み込み）
+    #[serde(skip)]
+    pub oidc_client_secret: Option<Secret>,
+    // OIDCのリダイレクトURI（コールバック先）
+    #[serde(default)]
+    pub oidc_redirect_uri: Option<String>,
+    // CORSで許可するメソッド（カンマ区切り）
+    #[serde(default = "default_cors_allowed_methods")]
+    pub cors_allowed_methods: Vec<String>,
+    // CORSで許可するリクエストヘッダー（カンマ区切り）
+    #[serde(default = "default_cors_allowed_headers")]
+    pub cors_allowed_headers: Vec<String>,
+    // CORSでCookie等の資格情報の送信を許可するかどうか
+    #[serde(default)]
+    pub cors_allow_credentials: bool,
+    // プリフライトリクエストの結果をキャッシュする期間（秒）
+    #[serde(default = "default_cors_max_age_secs")]
+    pub cors_max_age_secs: u64,
+    // ルートグループ別のリクエストのタイムアウト（「グループ名=秒数」のカンマ区切り、空文字で無効）
+    #[serde(default = "default_request_timeouts")]
+    pub request_timeouts: String,
+    // リクエストボディの最大サイズ（バイト）
+    #[serde(default = "default_request_body_limit_bytes")]
+    pub request_body_limit_bytes: usize,
+    // レスポンスの圧縮方式（カンマ区切り、zstd・br・gzipから指定、空の場合は圧縮しない）
+    #[serde(default = "default_compression_algorithms")]
+    pub compression_algorithms: Vec<String>,
+    // レスポンスを圧縮する最小サイズ（バイト）
+    #[serde(default = "default_compression_min_size_bytes")]
+    pub compression_min_size_bytes: u16,
+    // 圧縮されたリクエストボディ（Content-Encoding）を受け付けるかどうか
+    #[serde(default = "default_request_decompression")]
+    pub request_decompression: bool,
+    // Idempotency-Keyごとの処理結果を保存する期間（秒）
+    #[serde(default = "default_idempotency_ttl_secs")]
+    pub idempotency_ttl_secs: u64,
+    // アウトボックスの送信処理の実行間隔（秒）
+    #[serde(default = "default_outbox_relay_interval_secs")]
+    pub outbox_relay_interval_secs: u64,
+    // アウトボックスの送信処理で1回に送信する最大件数
+    #[serde(default = "default_outbox_relay_batch_size")]
+    pub outbox_relay_batch_size: usize,
+    // アウトボックスのメッセージの最大試行回数（超えた場合は送信を中止）
+    #[serde(default = "default_outbox_max_attempts")]
+    pub outbox_max_attempts: u32,
+    // 送信失敗時のリトライ間隔の初期値と上限（秒、試行ごとに倍増）
+    #[serde(default = "default_outbox_backoff_base_secs")]
+    pub outbox_backoff_base_secs: u64,
+    #[serde(default = "default_outbox_backoff_max_secs")]
+    pub outbox_backoff_max_secs: u64,
+    // Webhookの配信処理の実行間隔（秒）
+    #[serde(default = "default_webhook_delivery_interval_secs")]
+    pub webhook_delivery_interval_secs: u64,
+    // Webhookの配信処理で1回に配信する最大件数
+    #[serde(default = "default_webhook_delivery_batch_size")]
+    pub webhook_delivery_batch_size: usize,
+    // Webhookの最大試行回数（超えた場合は配信を中止）
+    #[serde(default = "default_webhook_max_attempts")]
+    pub webhook_max_attempts: u32,
+    // 配信失敗時のリトライ間隔の初期値と上限（秒、試行ごとに倍増）
+    #[serde(default = "default_webhook_backoff_base_secs")]
+    pub webhook_backoff_base_secs: u64,
+    #[serde(default = "default_webhook_backoff_max_secs")]
+    pub webhook_backoff_max_secs: u64,
+    // Webhookの送信のタイムアウト（秒）
+    #[serde(default = "default_webhook_timeout_secs")]
+    pub webhook_timeout_secs: u64,
+    // 配信中のWebhookの占有期間（秒、送信のタイムアウトより長くする）
+    #[serde(default = "default_webhook_lease_secs")]
+    pub webhook_lease_secs: u64,
+    // ドメインイベントの送信先のメッセージブローカー（none、memory、nats、kafka）
+    #[serde(default = "default_event_broker")]
+    pub event_broker: String,
+    // メッセージブローカーの接続先（NATSのURL、Kafkaのブートストラップサーバー）
+    pub event_broker_url: Option<String>,
+    // 送信先のプレフィックス（「{プレフィックス}.{イベント種別}」に送信）
+    #[serde(default = "default_event_broker_topic_prefix")]
+    pub event_broker_topic_prefix: String,
+    // ユーザーの変更通知（SSE）で再送用に保持する最大件数（Last-Event-IDによる再開に利用）
+    #[serde(default = "default_user_stream_buffer_size")]
+    pub user_stream_buffer_size: usize,
+    // 接続ごとの未送信の通知の上限（超えた場合は保持している通知から再送）
+    #[serde(default = "default_user_stream_channel_capacity")]
+    pub user_stream_channel_capacity: usize,
+    // キープアライブのコメントの送信間隔（秒）
+    #[serde(default = "default_user_stream_keep_alive_secs")]
+    pub user_stream_keep_alive_secs: u64,
+    // WebSocketの接続ごとの購読数の上限
+    #[serde(default = "default_user_live_max_subscriptions")]
+    pub user_live_max_subscriptions: usize,
+    // WebSocketの接続ごとの未送信のメッセージの上限（超えた場合は破棄した件数を通知）
+    #[serde(default = "default_user_live_channel_capacity")]
+    pub user_live_channel_capacity: usize,
+    // WebSocketで受信するメッセージの最大サイズ（バイト）
+    #[serde(default = "default_user_live_max_message_bytes")]
+    pub user_live_max_message_bytes: usize,
+    // WebSocketのPingの送信間隔（秒）
+    #[serde(default = "default_user_live_ping_interval_secs")]
+    pub user_live_ping_interval_secs: u64,
+    // バックグラウンドジョブのワーカー数
+    #[serde(default = "default_job_worker_count")]
+    pub job_worker_count: usize,
+    // ワーカーが実行対象のジョブを確認する間隔（秒）
+    #[serde(default = "default_job_poll_interval_secs")]
+    pub job_poll_interval_secs: u64,
+    // ワーカーが1回に実行する最大件数
+    #[serde(default = "default_job_batch_size")]
+    pub job_batch_size: usize,
+    // 実行中のジョブの占有期間（秒）
+    #[serde(default = "default_job_lease_secs")]
+    pub job_lease_secs: u64,
+    // ジョブの実行タイムアウト（秒、占有期間より短くする）
+    #[serde(default = "default_job_timeout_secs")]
+    pub job_timeout_secs: u64,
+    // ジョブの最大試行回数（超えた場合はデッドレター）
+    #[serde(default = "default_job_max_attempts")]
+    pub job_max_attempts: u32,
+    // 実行失敗時のリトライ間隔の初期値と上限（秒、試行ごとに倍増）
+    #[serde(default = "default_job_backoff_base_secs")]
+    pub job_backoff_base_secs: u64,
+    #[serde(default = "default_job_backoff_max_secs")]
+    pub job_backoff_max_secs: u64,
+    // ジョブの実行スケジュール（「ジョブの種類=cron形式」をセミコロン区切りで指定）
+    #[serde(default = "default_job_schedules")]
+    pub job_schedules: String,
+    // 論理削除から物理削除・匿名化までの保持期間（日）
+    #[serde(default = "default_user_purge_retention_days")]
+    pub user_purge_retention_days: u64,
+    // 保持期間が経過したユーザーの処理方法（delete: 物理削除、anonymize: 個人情報の匿名化）
+    #[serde(default = "default_user_purge_mode")]
+    pub user_purge_mode: String,
+    // 対象のログ出力のみで削除・匿名化しない（ジョブのペイロードの「dry_run」で上書き可能）
+    #[serde(default)]
+    pub user_purge_dry_run: bool,
+    // 停止時にリクエストとジョブの完了を待つ最大時間（秒）
+    #[serde(default = "default_shutdown_timeout_secs")]
+    pub shutdown_timeout_secs: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            env: default_env(),
+            port: default_port(),
+            config_file: None,
+            config_reload_interval_secs: default_config_reload_interval_secs(),
+            db_password: None,
+            jwt_hs256_secret: None,
+            jwt_rs256_public_key_file: None,
+            jwt_jwks_file: None,
+            jwt_issuer: None,
+            jwt_audience: None,
+            jwt_access_token_ttl_secs: default_jwt_access_token_ttl_secs(),
+            refresh_token_ttl_secs: default_refresh_token_ttl_secs(),
+            oidc_issuer_url: None,
+            oidc_client_id: None,
+            oidc_client_secret: None,
+            oidc_redirect_uri: None,
+            cors_allowed_methods: default_cors_allowed_methods(),
+            cors_allowed_headers: default_cors_allowed_headers(),
+            cors_allow_credentials: false,
+            cors_max_age_secs: default_cors_max_age_secs(),
+            request_timeouts: default_request_timeouts(),
+            request_body_limit_bytes: default_request_body_limit_bytes(),
+            compression_algorithms: default_compression_algorithms(),
+            compression_min_size_bytes: default_compression_min_size_bytes(),
+            request_decompression: default_request_decompression(),
+            idempotency_ttl_secs: default_idempotency_ttl_secs(),
+            outbox_relay_interval_secs: default_outbox_relay_interval_secs(),
+            outbox_relay_batch_size: default_outbox_relay_batch_size(),
+            outbox_max_attempts: default_outbox_max_attempts(),
+            outbox_backoff_base_secs: default_outbox_backoff_base_secs(),
+            outbox_backoff_max_secs: default_outbox_backoff_max_secs(),
+            webhook_delivery_interval_secs: default_webhook_delivery_interval_secs(),
+            webhook_delivery_batch_size: default_webhook_delivery_batch_size(),
+            webhook_max_attempts: default_webhook_max_attempts(),
+            webhook_backoff_base_secs: default_webhook_backoff_base_secs(),
+            webhook_backoff_max_secs: default_webhook_backoff_max_secs(),
+            webhook_timeout_secs: default_webhook_timeout_secs(),
+            webhook_lease_secs: default_webhook_lease_secs(),
+            event_broker: default_event_broker(),
+            event_broker_url: None,
+            event_broker_topic_prefix: default_event_broker_topic_prefix(),
+            user_stream_buffer_size: default_user_stream_buffer_size(),
+            user_stream_channel_capacity: default_user_stream_channel_capacity(),
+            user_stream_keep_alive_secs: default_user_stream_keep_alive_secs(),
+            user_live_max_subscriptions: default_user_live_max_subscriptions(),
+            user_live_channel_capacity: default_user_live_channel_capacity(),
+            user_live_max_message_bytes: default_user_live_max_message_bytes(),
+            user_live_ping_interval_secs: default_user_live_ping_interval_secs(),
+            job_worker_count: default_job_worker_count(),
+            job_poll_interval_secs: default_job_poll_interval_secs(),
+            job_batch_size: default_job_batch_size(),
+            job_lease_secs: default_job_lease_secs(),
+            job_timeout_secs: default_job_timeout_secs(),
+            job_max_attempts: default_job_max_attempts(),
+            job_backoff_base_secs: default_job_backoff_base_secs(),
+            job_backoff_max_secs: default_job_backoff_max_secs(),
+            job_schedules: default_job_schedules(),
+            user_purge_retention_days: default_user_purge_retention_days(),
+            user_purge_mode: default_user_purge_mode(),
+            user_purge_dry_run: false,
+            shutdown_timeout_secs: default_shutdown_timeout_secs(),
+        }
+    }
+}
+
+// 秘密情報を返す関数
+fn get_secret(key: &str) -> Result<Option<Secret>, String> {
+    load_secret(key).map_err(|err| format!("秘密情報の読み込みエラー: {}", err))
 }
 
 // 環境変数を返す関数
-pub fn get_config() -> Config {
-    match envy::from_env::<Config>() {
+// ※デフォルト値で起動すると検証や秘密情報が無効のまま動作するため、変換・読み込みエラーの場合はErrを返す
+pub fn get_config() -> Result<Config, String> {
+    let mut config = match envy::from_env::<Config>() {
         Ok(config) => config,
-        Err(err) => {
-            println!("環境変数の初期化エラー: {}", err);
-
-            // 環境変数にデフォルト値を設定して返す
-            Config {
-                env: default_env(),
-                port: default_port(),
-                rust_log: default_rust_log(),
-            }
-        }
+        Err(err) => return Err(format!("環境変数の変換エラー: {}", err)),
+    };
+
+    // ジョブの実行タイムアウトは占有期間より短くする（占有期限内に実行結果を保存するため）
+    if config.job_timeout_secs >= config.job_lease_secs {
+        return Err(format!(
+            "環境変数の設定エラー: JOB_TIMEOUT_SECS（{}）はJOB_LEASE_SECS（{}）より短くして下さい。",
+            config.job_timeout_secs, config.job_lease_secs
+        ));
     }
+
+    // 秘密情報の設定
+    config.db_password = get_secret("DB_PASSWORD")?;
+    config.jwt_hs256_secret = get_secret("JWT_HS256_SECRET")?;
+    config.oidc_client_secret = get_secret("OIDC_CLIENT_SECRET")?;
+
+    Ok(config)
 }
@@ -1 +1,7 @@
+pub mod config_reloadable;
+pub mod config_secret;
 pub mod config_settings;
+
+// テストコード用のモジュール
+pub mod config_reloadable_test;
+pub mod config_secret_test;
@@ -0,0 +1,125 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+// ドメイン
+use crate::domain::time::time_jst::jst_now;
+
+// 発行するAPIキーの接頭辞
+const API_KEY_PREFIX: &str = "ak";
+
+// APIキーに付与できるスコープ
+pub const API_KEY_SCOPES: [&str; 3] = ["users:read", "users:write", "admin"];
+
+// APIキーモデルの定義
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ApiKey {
+    pub id: i64,
+    // キーの識別子（発行したキーに含まれる公開部分）
+    pub key_id: String,
+    pub name: String,
+    // キー全体のハッシュ値（平文のキーは保存しない）
+    #[serde(skip_serializing, default)]
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<FixedOffset>>,
+    pub last_used_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub revoked_at: Option<DateTime<FixedOffset>>,
+}
+
+impl ApiKey {
+    // 新規発行（平文のキーは戻り値でのみ返す）
+    pub fn issue(
+        name: String,
+        scopes: Vec<String>,
+        expires_at: Option<DateTime<FixedOffset>>,
+    ) -> Result<(Self, String), String> {
+        // パラメータチェック
+        let mut err_msgs = Vec::new();
+        if name.is_empty() {
+            err_msgs.push("nameは必須です。".to_string());
+        }
+        if scopes.is_empty() {
+            err_msgs.push("scopesは必須です。".to_string());
+        }
+        for scope in scopes.iter() {
+            if !API_KEY_SCOPES.contains(&scope.as_str()) {
+                err_msgs.push(format!("scopesの値「{}」が不正です。", scope));
+            }
+        }
+        let now = jst_now();
+        if expires_at.is_some_and(|expires_at| expires_at <= now) {
+            err_msgs.push("expires_atは未来の日時を指定して下さい。".to_string());
+        }
+        if !err_msgs.is_empty() {
+            return Err(err_msgs.join(", "));
+        }
+
+        // キーの生成（形式: ak_<key_id>_<secret>）
+        let key_id = Uuid::new_v4().simple().to_string()[..12].to_string();
+        let secret = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let plain_key = format!("{}_{}_{}", API_KEY_PREFIX, key_id, secret);
+
+        let api_key = Self {
+            id: 0,
+            key_id,
+            name,
+            key_hash: ApiKey::hash(&plain_key),
+            scopes,
+            expires_at,
+            last_used_at: None,
+            created_at: now,
+            revoked_at: None,
+        };
+
+        Ok((api_key, plain_key))
+    }
+
+    // キーのハッシュ値の作成
+    pub fn hash(plain_key: &str) -> String {
+        hex::encode(Sha256::digest(plain_key.as_bytes()))
+    }
+
+    // 平文のキーからkey_idを取得
+    pub fn parse_key_id(plain_key: &str) -> Option<&str> {
+        let mut parts = plain_key.splitn(3, '_');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(API_KEY_PREFIX), Some(key_id), Some(secret))
+                if !key_id.is_empty() && !secret.is_empty() =>
+            {
+                Some(key_id)
+            }
+            _ => None,
+        }
+    }
+
+    // 平文のキーが一致するかどうか（タイミング攻撃対策のため固定時間で比較）
+    pub fn verify(&self, plain_key: &str) -> bool {
+        let hash = ApiKey::hash(plain_key);
+        if hash.len() != self.key_hash.len() {
+            return false;
+        }
+        hash.bytes()
+            .zip(self.key_hash.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+    }
+
+    // 利用可能なキーかどうか（失効・有効期限切れでない）
+    pub fn is_active(&self, now: DateTime<FixedOffset>) -> bool {
+        if self.revoked_at.is_some() {
+            return false;
+        }
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+
+    // 失効設定
+    pub fn revoke(&mut self) {
+        self.revoked_at = Some(jst_now());
+    }
+}
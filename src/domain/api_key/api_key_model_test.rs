@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::api_key::api_key_model::ApiKey;
+    use chrono::{Duration, FixedOffset, Utc};
+
+    #[test]
+    fn test_issue_success() {
+        // テスト実行
+        let result = ApiKey::issue(
+            "batch-job".to_string(),
+            vec!["users:read".to_string()],
+            None,
+        );
+
+        // 検証
+        assert!(result.is_ok());
+        let (api_key, plain_key) = result.unwrap();
+        assert_eq!(api_key.id, 0);
+        assert_eq!(api_key.name, "batch-job");
+        assert_eq!(api_key.scopes, vec!["users:read".to_string()]);
+        assert!(plain_key.starts_with(&format!("ak_{}_", api_key.key_id)));
+        assert_ne!(api_key.key_hash, plain_key);
+        assert!(api_key.verify(&plain_key));
+        assert!(api_key.last_used_at.is_none());
+        assert!(api_key.revoked_at.is_none());
+    }
+
+    #[test]
+    fn test_issue_error() {
+        // 有効期限（過去日時）
+        let expires_at = Utc::now().with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+            - Duration::hours(1);
+
+        // テスト実行
+        let result = ApiKey::issue(
+            "".to_string(),
+            vec!["users:delete".to_string()],
+            Some(expires_at),
+        );
+
+        // 検証
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            "nameは必須です。, scopesの値「users:delete」が不正です。, expires_atは未来の日時を指定して下さい。"
+        );
+    }
+
+    #[test]
+    fn test_parse_key_id() {
+        // 検証
+        assert_eq!(
+            ApiKey::parse_key_id("ak_0123456789ab_secret"),
+            Some("0123456789ab")
+        );
+        assert_eq!(ApiKey::parse_key_id("xx_0123456789ab_secret"), None);
+        assert_eq!(ApiKey::parse_key_id("ak_0123456789ab"), None);
+        assert_eq!(ApiKey::parse_key_id(""), None);
+    }
+
+    #[test]
+    fn test_verify_error() {
+        let (api_key, plain_key) =
+            ApiKey::issue("batch-job".to_string(), vec!["admin".to_string()], None).unwrap();
+
+        // 検証（異なるキーは一致しないこと）
+        assert!(!api_key.verify(&format!("{}x", plain_key)));
+        assert!(!api_key.verify(""));
+    }
+
+    #[test]
+    fn test_is_active() {
+        let now = Utc::now().with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+        let (mut api_key, _) = ApiKey::issue(
+            "batch-job".to_string(),
+            vec!["users:read".to_string()],
+            Some(now + Duration::hours(1)),
+        )
+        .unwrap();
+
+        // 検証
+        assert!(api_key.is_active(now));
+        assert!(!api_key.is_active(now + Duration::hours(2)));
+
+        // 失効後は利用不可
+        api_key.revoke();
+        assert!(api_key.revoked_at.is_some());
+        assert!(!api_key.is_active(now));
+    }
+}
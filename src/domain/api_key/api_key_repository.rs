@@ -0,0 +1,33 @@
+// 日時
+use chrono::{DateTime, FixedOffset};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{api_key::api_key_model::ApiKey, error::error_common::ErrorCommon};
+
+// APIキーリポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ApiKeyRepositoryTrait: Send + Sync {
+    // 全てのAPIキー取得
+    async fn find_all(&self, ctx: &ContextRequest) -> Result<Vec<ApiKey>, ErrorCommon>;
+    // key_idによるAPIキー取得
+    async fn find_by_key_id(
+        &self,
+        ctx: &ContextRequest,
+        key_id: &str,
+    ) -> Result<Option<ApiKey>, ErrorCommon>;
+    // APIキー作成（採番したidを設定して返す）
+    async fn create(&self, ctx: &ContextRequest, api_key: &ApiKey) -> Result<ApiKey, ErrorCommon>;
+    // APIキー更新
+    async fn update(&self, ctx: &ContextRequest, api_key: &ApiKey) -> Result<ApiKey, ErrorCommon>;
+    // 最終利用日時のみ更新（同時に実行された失効等の更新を上書きしないように他の項目は変更しない）
+    async fn touch_last_used(
+        &self,
+        ctx: &ContextRequest,
+        key_id: &str,
+        at: DateTime<FixedOffset>,
+    ) -> Result<(), ErrorCommon>;
+}
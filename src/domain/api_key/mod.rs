@@ -0,0 +1,5 @@
+pub mod api_key_model;
+pub mod api_key_repository;
+
+// テストコード用のモジュール
+pub mod api_key_model_test;
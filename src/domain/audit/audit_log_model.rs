@@ -0,0 +1,117 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+// ドメイン
+use crate::domain::time::time_jst::jst_now;
+use crate::domain::user::user_model::User;
+
+// 操作の種類
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    // 論理削除から保持期間が経過したユーザーの物理削除・匿名化
+    Purge,
+}
+
+// 項目ごとの変更内容（作成時のbefore、削除で値がない項目のafterはnull）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct AuditFieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+// 監査ログモデルの定義（追記のみで更新・削除しない、ユーザーの物理削除・匿名化時の個人情報の消去を除く）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct AuditLog {
+    pub id: i64,
+    // 実行者（トークンのsub、APIキーの場合はキーID）
+    pub actor: String,
+    pub action: AuditAction,
+    // 操作対象のユーザーuid
+    pub target_uid: String,
+    pub changes: Vec<AuditFieldChange>,
+    pub request_id: String,
+    pub occurred_at: DateTime<FixedOffset>,
+}
+
+// 監査対象のユーザーの項目（id・バージョン・更新日時等の管理項目は除く）
+const AUDITED_USER_FIELDS: [&str; 4] = ["last_name", "first_name", "email", "deleted_at"];
+
+// 監査対象の項目のうち個人情報に該当する項目
+const PERSONAL_USER_FIELDS: [&str; 3] = ["last_name", "first_name", "email"];
+
+// 個人情報を消去した値
+pub const REDACTED_VALUE: &str = "[REDACTED]";
+
+impl AuditLog {
+    // ユーザーの変更から作成（before: 変更前、after: 変更後、作成時はbeforeがNone）
+    pub fn for_user(
+        actor: &str,
+        request_id: &str,
+        action: AuditAction,
+        before: Option<&User>,
+        after: &User,
+    ) -> Self {
+        Self {
+            id: 0,
+            actor: actor.to_string(),
+            action,
+            target_uid: after.uid.clone(),
+            changes: diff_user(before, after),
+            request_id: request_id.to_string(),
+            occurred_at: jst_now(),
+        }
+    }
+
+    // ユーザーの物理削除・匿名化から作成（個人情報を残さないため変更内容は記録しない）
+    pub fn for_user_purge(actor: &str, request_id: &str, target_uid: &str) -> Self {
+        Self {
+            id: 0,
+            actor: actor.to_string(),
+            action: AuditAction::Purge,
+            target_uid: target_uid.to_string(),
+            changes: Vec::new(),
+            request_id: request_id.to_string(),
+            occurred_at: jst_now(),
+        }
+    }
+
+    // 変更内容から個人情報を消去（値がない項目はnullのまま）
+    pub fn scrub_personal_data(&mut self) {
+        for change in self
+            .changes
+            .iter_mut()
+            .filter(|c| PERSONAL_USER_FIELDS.contains(&c.field.as_str()))
+        {
+            for value in [&mut change.before, &mut change.after] {
+                if !value.is_null() {
+                    *value = json!(REDACTED_VALUE);
+                }
+            }
+        }
+    }
+}
+
+// ユーザーの変更前後の差分（値が変わった項目のみ）
+pub fn diff_user(before: Option<&User>, after: &User) -> Vec<AuditFieldChange> {
+    let before = before.map(|user| json!(user)).unwrap_or(Value::Null);
+    let after = json!(after);
+
+    AUDITED_USER_FIELDS
+        .iter()
+        .filter_map(|field| {
+            let before_value = before.get(field).cloned().unwrap_or(Value::Null);
+            let after_value = after.get(field).cloned().unwrap_or(Value::Null);
+            (before_value != after_value).then(|| AuditFieldChange {
+                field: field.to_string(),
+                before: before_value,
+                after: after_value,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::domain::audit::audit_log_model::{AuditAction, AuditLog, REDACTED_VALUE, diff_user};
+    use crate::domain::user::user_model::User;
+
+    // テスト用のユーザー
+    fn create_user() -> User {
+        let mut user = User::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            "テスト".to_string(),
+            "太郎".to_string(),
+            "t.test@example.com".to_string(),
+        );
+        user.take_events();
+        user
+    }
+
+    #[test]
+    fn test_diff_user_create() {
+        let user = create_user();
+
+        // テスト実行
+        let changes = diff_user(None, &user);
+
+        // 検証（作成時は値のある項目のみ）
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["last_name", "first_name", "email"]);
+        assert_eq!(changes[0].before, Value::Null);
+        assert_eq!(changes[0].after, "テスト");
+    }
+
+    #[test]
+    fn test_diff_user_update() {
+        let before = create_user();
+        let mut after = before.clone();
+        after
+            .update_profile(
+                "テスト".to_string(),
+                "次郎".to_string(),
+                "t.test@example.com".to_string(),
+            )
+            .unwrap();
+
+        // テスト実行
+        let changes = diff_user(Some(&before), &after);
+
+        // 検証（変更した項目のみで、バージョン等の管理項目は含まない）
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "first_name");
+        assert_eq!(changes[0].before, "太郎");
+        assert_eq!(changes[0].after, "次郎");
+    }
+
+    #[test]
+    fn test_for_user_delete() {
+        let before = create_user();
+        let mut after = before.clone();
+        after.set_delete();
+
+        // テスト実行
+        let audit_log = AuditLog::for_user(
+            "xxx-xxx-xxx-0002",
+            "req-0001",
+            AuditAction::Delete,
+            Some(&before),
+            &after,
+        );
+
+        // 検証
+        assert_eq!(audit_log.actor, "xxx-xxx-xxx-0002");
+        assert_eq!(audit_log.action, AuditAction::Delete);
+        assert_eq!(audit_log.target_uid, "xxx-xxx-xxx-0001");
+        assert_eq!(audit_log.request_id, "req-0001");
+        assert_eq!(audit_log.changes.len(), 1);
+        assert_eq!(audit_log.changes[0].field, "deleted_at");
+        assert_eq!(audit_log.changes[0].before, Value::Null);
+        assert!(audit_log.changes[0].after.is_string());
+    }
+
+    #[test]
+    fn test_for_user_purge() {
+        // テスト実行
+        let audit_log = AuditLog::for_user_purge("system", "req-0001", "xxx-xxx-xxx-0001");
+
+        // 検証（個人情報を残さないため変更内容は記録しない）
+        assert_eq!(audit_log.action, AuditAction::Purge);
+        assert_eq!(audit_log.actor, "system");
+        assert_eq!(audit_log.target_uid, "xxx-xxx-xxx-0001");
+        assert_eq!(audit_log.request_id, "req-0001");
+        assert!(audit_log.changes.is_empty());
+    }
+
+    #[test]
+    fn test_scrub_personal_data() {
+        let before = create_user();
+        let mut after = before.clone();
+        after.set_delete();
+        let mut create_log =
+            AuditLog::for_user("sub", "req-0001", AuditAction::Create, None, &before);
+        let mut delete_log = AuditLog::for_user(
+            "sub",
+            "req-0002",
+            AuditAction::Delete,
+            Some(&before),
+            &after,
+        );
+
+        // テスト実行
+        create_log.scrub_personal_data();
+        delete_log.scrub_personal_data();
+
+        // 検証（個人情報の値のみ消去し、値がない項目と個人情報以外の項目はそのまま）
+        assert!(create_log.changes.iter().all(|c| c.before == Value::Null));
+        assert!(create_log.changes.iter().all(|c| c.after == REDACTED_VALUE));
+        assert_eq!(delete_log.changes.len(), 1);
+        assert_eq!(delete_log.changes[0].field, "deleted_at");
+        assert_eq!(delete_log.changes[0].before, Value::Null);
+        assert_ne!(delete_log.changes[0].after, REDACTED_VALUE);
+        assert_eq!(create_log.target_uid, "xxx-xxx-xxx-0001");
+    }
+}
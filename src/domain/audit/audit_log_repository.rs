@@ -0,0 +1,30 @@
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{audit::audit_log_model::AuditLog, error::error_common::ErrorCommon};
+
+// 監査ログリポジトリ用のトレイト（モック化もできるように定義）
+// ※追記のみのため、更新・削除のメソッドは定義しない（ユーザーの物理削除・匿名化時の個人情報の消去を除く）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuditLogRepositoryTrait: Send + Sync {
+    // 監査ログの追加
+    async fn add(
+        &self,
+        ctx: &ContextRequest,
+        audit_log: &AuditLog,
+    ) -> Result<AuditLog, ErrorCommon>;
+    // 操作対象のユーザーの監査ログ取得（古い順）
+    async fn find_by_target_uid(
+        &self,
+        ctx: &ContextRequest,
+        target_uid: &str,
+    ) -> Result<Vec<AuditLog>, ErrorCommon>;
+    // 操作対象のユーザーの監査ログから個人情報を消去
+    async fn scrub_personal_data(
+        &self,
+        ctx: &ContextRequest,
+        target_uid: &str,
+    ) -> Result<(), ErrorCommon>;
+}
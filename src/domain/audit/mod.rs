@@ -0,0 +1,5 @@
+pub mod audit_log_model;
+pub mod audit_log_repository;
+
+// テストコード用のモジュール
+pub mod audit_log_model_test;
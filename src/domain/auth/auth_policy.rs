@@ -0,0 +1,134 @@
+use axum::http::StatusCode;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// ロールの定義
+#[derive(Clone, Debug, PartialEq)]
+pub enum Role {
+    // 全ての操作が可能
+    Admin,
+    // ユーザーの参照・更新が可能
+    Operator,
+}
+
+impl Role {
+    // 文字列からロールを取得（未定義のロールはNone）
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "admin" => Some(Role::Admin),
+            "operator" => Some(Role::Operator),
+            _ => None,
+        }
+    }
+
+    // ロールに付与されている権限かどうか
+    fn has_permission(&self, permission: &Permission) -> bool {
+        match self {
+            Role::Admin => true,
+            Role::Operator => matches!(
+                permission,
+                Permission::UserRead | Permission::UserReadAll | Permission::UserUpdate
+            ),
+        }
+    }
+}
+
+// 権限の定義
+#[derive(Clone, Debug, PartialEq)]
+pub enum Permission {
+    // ユーザーの参照
+    UserRead,
+    // 全てのユーザーの参照
+    UserReadAll,
+    // ユーザーの作成
+    UserCreate,
+    // ユーザーの更新
+    UserUpdate,
+    // ユーザーの削除
+    UserDelete,
+    // ユーザーの監査ログの参照
+    UserAuditRead,
+    // パスワードの変更（本人は現在のパスワードの確認が必要）
+    PasswordUpdate,
+    // 管理用機能の利用
+    AdminManage,
+}
+
+impl Permission {
+    // 権限を付与するスコープ（APIキー等のスコープによる認可に利用）
+    fn required_scope(&self) -> &'static str {
+        match self {
+            Permission::UserRead | Permission::UserReadAll => "users:read",
+            Permission::UserCreate | Permission::UserUpdate | Permission::UserDelete => {
+                "users:write"
+            }
+            Permission::UserAuditRead | Permission::PasswordUpdate | Permission::AdminManage => {
+                "admin"
+            }
+        }
+    }
+
+    // 本人であれば許可される権限かどうか
+    fn is_allowed_for_self(&self) -> bool {
+        matches!(
+            self,
+            Permission::UserRead | Permission::UserUpdate | Permission::PasswordUpdate
+        )
+    }
+}
+
+// 操作の実行者
+#[derive(Clone, Debug, PartialEq)]
+pub struct Principal {
+    // 実行者のユーザーuid
+    pub subject: String,
+    pub roles: Vec<Role>,
+    pub scopes: Vec<String>,
+}
+
+impl Principal {
+    pub fn new(subject: String, role_names: &[String], scopes: &[String]) -> Self {
+        Self {
+            subject,
+            roles: role_names
+                .iter()
+                .filter_map(|name| Role::from_name(name))
+                .collect(),
+            scopes: scopes.to_vec(),
+        }
+    }
+
+    // 権限チェック
+    // owner_uid: 操作対象のユーザーuid（本人の操作かどうかの判定に利用）
+    pub fn authorize(
+        &self,
+        permission: Permission,
+        owner_uid: Option<&str>,
+    ) -> Result<(), ErrorCommon> {
+        // ロールによる判定
+        if self
+            .roles
+            .iter()
+            .any(|role| role.has_permission(&permission))
+        {
+            return Ok(());
+        }
+
+        // スコープによる判定
+        let required_scope = permission.required_scope();
+        if self.scopes.iter().any(|scope| scope == required_scope) {
+            return Ok(());
+        }
+
+        // 本人による操作の判定
+        if permission.is_allowed_for_self() && owner_uid == Some(self.subject.as_str()) {
+            return Ok(());
+        }
+
+        Err(ErrorCommon::CustomError {
+            status_code: StatusCode::FORBIDDEN,
+            message: "この操作を実行する権限がありません。".to_string(),
+        })
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+
+    use crate::domain::auth::auth_policy::{Permission, Principal, Role};
+    use crate::domain::error::error_common::ErrorCommon;
+
+    // 403エラーかどうか
+    fn is_forbidden(result: Result<(), ErrorCommon>) -> bool {
+        matches!(
+            result,
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::FORBIDDEN,
+                ..
+            })
+        )
+    }
+
+    #[test]
+    fn test_new_principal() {
+        // テスト実行（未定義のロールは除外されること）
+        let principal = Principal::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            &[
+                "admin".to_string(),
+                "operator".to_string(),
+                "unknown".to_string(),
+            ],
+            &["users:read".to_string()],
+        );
+
+        // 検証
+        assert_eq!(principal.subject, "xxx-xxx-xxx-0001");
+        assert_eq!(principal.roles, vec![Role::Admin, Role::Operator]);
+        assert_eq!(principal.scopes, vec!["users:read".to_string()]);
+    }
+
+    #[test]
+    fn test_authorize_admin() {
+        let principal = Principal::new("xxx-xxx-xxx-0001".to_string(), &["admin".to_string()], &[]);
+
+        // 検証（全ての権限が許可されること）
+        assert!(principal.authorize(Permission::UserReadAll, None).is_ok());
+        assert!(principal.authorize(Permission::UserCreate, None).is_ok());
+        assert!(
+            principal
+                .authorize(Permission::UserUpdate, Some("xxx-xxx-xxx-0002"))
+                .is_ok()
+        );
+        assert!(
+            principal
+                .authorize(Permission::UserDelete, Some("xxx-xxx-xxx-0002"))
+                .is_ok()
+        );
+        assert!(principal.authorize(Permission::AdminManage, None).is_ok());
+        assert!(
+            principal
+                .authorize(Permission::UserAuditRead, Some("xxx-xxx-xxx-0002"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_authorize_operator() {
+        let principal = Principal::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            &["operator".to_string()],
+            &[],
+        );
+
+        // 検証（参照・更新のみ許可されること）
+        assert!(principal.authorize(Permission::UserReadAll, None).is_ok());
+        assert!(
+            principal
+                .authorize(Permission::UserUpdate, Some("xxx-xxx-xxx-0002"))
+                .is_ok()
+        );
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserCreate, None)
+        ));
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserDelete, Some("xxx-xxx-xxx-0002"))
+        ));
+        assert!(is_forbidden(
+            principal.authorize(Permission::AdminManage, None)
+        ));
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserAuditRead, Some("xxx-xxx-xxx-0002"))
+        ));
+        assert!(is_forbidden(principal.authorize(
+            Permission::PasswordUpdate,
+            Some("xxx-xxx-xxx-0002")
+        )));
+    }
+
+    #[test]
+    fn test_authorize_self() {
+        let principal = Principal::new("xxx-xxx-xxx-0001".to_string(), &[], &[]);
+
+        // 検証（本人のプロフィールの参照・更新のみ許可されること）
+        assert!(
+            principal
+                .authorize(Permission::UserRead, Some("xxx-xxx-xxx-0001"))
+                .is_ok()
+        );
+        assert!(
+            principal
+                .authorize(Permission::UserUpdate, Some("xxx-xxx-xxx-0001"))
+                .is_ok()
+        );
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserUpdate, Some("xxx-xxx-xxx-0002"))
+        ));
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserDelete, Some("xxx-xxx-xxx-0001"))
+        ));
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserReadAll, None)
+        ));
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserAuditRead, Some("xxx-xxx-xxx-0001"))
+        ));
+        assert!(
+            principal
+                .authorize(Permission::PasswordUpdate, Some("xxx-xxx-xxx-0001"))
+                .is_ok()
+        );
+        assert!(is_forbidden(principal.authorize(
+            Permission::PasswordUpdate,
+            Some("xxx-xxx-xxx-0002")
+        )));
+    }
+
+    #[test]
+    fn test_authorize_scopes() {
+        let principal = Principal::new(
+            "api_key:xxxxxxxxxxxx".to_string(),
+            &[],
+            &["users:read".to_string()],
+        );
+
+        // 検証（スコープに対応する権限のみ許可されること）
+        assert!(principal.authorize(Permission::UserReadAll, None).is_ok());
+        assert!(
+            principal
+                .authorize(Permission::UserRead, Some("xxx-xxx-xxx-0002"))
+                .is_ok()
+        );
+        assert!(is_forbidden(
+            principal.authorize(Permission::UserCreate, None)
+        ));
+        assert!(is_forbidden(
+            principal.authorize(Permission::AdminManage, None)
+        ));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod auth_policy;
+
+// テストコード用のモジュール
+pub mod auth_policy_test;
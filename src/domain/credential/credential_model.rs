@@ -0,0 +1,91 @@
+use chrono::{DateTime, Duration, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+// ドメイン
+use crate::domain::time::time_jst::jst_now;
+
+// アカウントをロックするまでのログイン失敗回数
+pub const MAX_FAILED_ATTEMPTS: u32 = 5;
+
+// アカウントのロック期間（分）
+pub const LOCK_DURATION_MINUTES: i64 = 15;
+
+// パスワードの文字数制限
+const PASSWORD_MIN_LENGTH: usize = 8;
+const PASSWORD_MAX_LENGTH: usize = 128;
+
+// 認証情報モデルの定義（User.uidに紐づくパスワード）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Credential {
+    pub id: i64,
+    pub user_uid: String,
+    // パスワードのハッシュ値（平文のパスワードは保存しない）
+    #[serde(skip_serializing, default)]
+    pub password_hash: String,
+    // 連続したログイン失敗回数
+    pub failed_attempts: u32,
+    pub locked_until: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl Credential {
+    // 新規作成
+    pub fn new(user_uid: String, password_hash: String) -> Self {
+        let now = jst_now();
+
+        Self {
+            id: 0,
+            user_uid,
+            password_hash,
+            failed_attempts: 0,
+            locked_until: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    // パスワードのチェック（ハッシュ化前の平文に対して行う）
+    pub fn validate_password(password: &str) -> Result<(), String> {
+        let len = password.chars().count();
+        if !(PASSWORD_MIN_LENGTH..=PASSWORD_MAX_LENGTH).contains(&len) {
+            return Err(format!(
+                "passwordは{}文字以上{}文字以下で入力して下さい。",
+                PASSWORD_MIN_LENGTH, PASSWORD_MAX_LENGTH
+            ));
+        }
+
+        Ok(())
+    }
+
+    // パスワードの変更（ロックも解除）
+    pub fn change_password(&mut self, password_hash: String) {
+        self.password_hash = password_hash;
+        self.failed_attempts = 0;
+        self.locked_until = None;
+        self.updated_at = jst_now();
+    }
+
+    // ロック中かどうか
+    pub fn is_locked(&self, now: DateTime<FixedOffset>) -> bool {
+        self.locked_until
+            .is_some_and(|locked_until| now < locked_until)
+    }
+
+    // ログイン失敗の記録（上限に達した場合はロック）
+    pub fn record_failure(&mut self, now: DateTime<FixedOffset>) {
+        self.failed_attempts += 1;
+        if self.failed_attempts >= MAX_FAILED_ATTEMPTS {
+            self.failed_attempts = 0;
+            self.locked_until = Some(now + Duration::minutes(LOCK_DURATION_MINUTES));
+        }
+        self.updated_at = now;
+    }
+
+    // ログイン成功の記録
+    pub fn record_success(&mut self, now: DateTime<FixedOffset>) {
+        self.failed_attempts = 0;
+        self.locked_until = None;
+        self.updated_at = now;
+    }
+}
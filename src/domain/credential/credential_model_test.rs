@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::credential::credential_model::{
+        Credential, LOCK_DURATION_MINUTES, MAX_FAILED_ATTEMPTS,
+    };
+    use chrono::{Duration, FixedOffset, Utc};
+
+    #[test]
+    fn test_new_credential() {
+        // テスト実行
+        let credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+
+        // 検証
+        assert_eq!(credential.id, 0);
+        assert_eq!(credential.user_uid, "xxxx-xxxx-xxxx-0001");
+        assert_eq!(credential.password_hash, "hash");
+        assert_eq!(credential.failed_attempts, 0);
+        assert!(credential.locked_until.is_none());
+    }
+
+    #[test]
+    fn test_validate_password() {
+        assert!(Credential::validate_password("password").is_ok());
+        assert!(Credential::validate_password("short").is_err());
+        assert!(Credential::validate_password(&"x".repeat(129)).is_err());
+    }
+
+    #[test]
+    fn test_record_failure_and_lock() {
+        let now = Utc::now().with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+        let mut credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+
+        // 上限の直前まではロックされない
+        for _ in 0..MAX_FAILED_ATTEMPTS - 1 {
+            credential.record_failure(now);
+        }
+        assert_eq!(credential.failed_attempts, MAX_FAILED_ATTEMPTS - 1);
+        assert!(!credential.is_locked(now));
+
+        // 上限に達した場合はロック
+        credential.record_failure(now);
+        assert!(credential.is_locked(now));
+        assert_eq!(
+            credential.locked_until,
+            Some(now + Duration::minutes(LOCK_DURATION_MINUTES))
+        );
+
+        // ロック期間が過ぎた場合は解除
+        let after = now + Duration::minutes(LOCK_DURATION_MINUTES);
+        assert!(!credential.is_locked(after));
+    }
+
+    #[test]
+    fn test_record_success_and_change_password() {
+        let now = Utc::now().with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+        let mut credential = Credential::new("xxxx-xxxx-xxxx-0001".to_string(), "hash".to_string());
+
+        // ログイン成功で失敗回数をリセット
+        credential.record_failure(now);
+        credential.record_success(now);
+        assert_eq!(credential.failed_attempts, 0);
+
+        // パスワード変更でロックを解除
+        for _ in 0..MAX_FAILED_ATTEMPTS {
+            credential.record_failure(now);
+        }
+        credential.change_password("new-hash".to_string());
+        assert_eq!(credential.password_hash, "new-hash");
+        assert!(!credential.is_locked(now));
+    }
+}
@@ -0,0 +1,53 @@
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// 日時
+use chrono::{DateTime, FixedOffset};
+
+// ドメイン
+use crate::domain::{credential::credential_model::Credential, error::error_common::ErrorCommon};
+
+// 認証情報リポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait CredentialRepositoryTrait: Send + Sync {
+    // ユーザーのuidによる認証情報取得
+    async fn find_by_user_uid(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<Option<Credential>, ErrorCommon>;
+    // 認証情報作成（採番したidを設定して返す）
+    async fn create(
+        &self,
+        ctx: &ContextRequest,
+        credential: &Credential,
+    ) -> Result<Credential, ErrorCommon>;
+    // 認証情報更新
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        credential: &Credential,
+    ) -> Result<Credential, ErrorCommon>;
+    // ログイン失敗の記録（保存済みの値に対して失敗回数の加算とロックを1回の更新で行い、更新後の認証情報を返す）
+    // ※同時にログインに失敗した場合も失敗回数が失われないように、取得済みの値を書き戻さない
+    async fn record_failure(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+        now: DateTime<FixedOffset>,
+    ) -> Result<Credential, ErrorCommon>;
+    // ログイン成功の記録（保存済みの値の失敗回数とロックのみリセット）
+    async fn record_success(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+        now: DateTime<FixedOffset>,
+    ) -> Result<(), ErrorCommon>;
+    // ユーザーのuidによる認証情報削除（ユーザーの物理削除・匿名化用）
+    async fn delete_by_user_uid(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<(), ErrorCommon>;
+}
@@ -0,0 +1,11 @@
+pub mod credential_model;
+pub mod credential_repository;
+pub mod oidc_session_model;
+pub mod oidc_session_repository;
+pub mod refresh_token_model;
+pub mod refresh_token_repository;
+
+// テストコード用のモジュール
+pub mod credential_model_test;
+pub mod oidc_session_model_test;
+pub mod refresh_token_model_test;
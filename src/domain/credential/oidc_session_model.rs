@@ -0,0 +1,56 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Duration, FixedOffset};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+// ドメイン
+use crate::domain::time::time_jst::jst_now;
+
+// ログインセッションの有効期間（分）
+pub const OIDC_SESSION_TTL_MINUTES: i64 = 10;
+
+// OIDCのログインセッションモデルの定義（認可リクエストからコールバックまでの一時的な状態）
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct OidcSession {
+    // CSRF対策用のstateパラメータ
+    pub state: String,
+    // PKCEのcode_verifier（IDプロバイダーにはハッシュ値のみ送信）
+    pub code_verifier: String,
+    // IDトークンのリプレイ対策用のnonce
+    pub nonce: String,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+// ランダムな文字列の生成（PKCEで使用可能な文字のみ）
+fn random_string() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+impl OidcSession {
+    // 新規作成
+    pub fn new() -> Self {
+        Self {
+            state: random_string(),
+            code_verifier: random_string(),
+            nonce: random_string(),
+            created_at: jst_now(),
+        }
+    }
+
+    // PKCEのcode_challenge（S256）の作成
+    pub fn code_challenge(&self) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(self.code_verifier.as_bytes()))
+    }
+
+    // 有効期限切れかどうか
+    pub fn is_expired(&self, now: DateTime<FixedOffset>) -> bool {
+        now >= self.created_at + Duration::minutes(OIDC_SESSION_TTL_MINUTES)
+    }
+}
+
+impl Default for OidcSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::credential::oidc_session_model::{OIDC_SESSION_TTL_MINUTES, OidcSession};
+    use chrono::Duration;
+
+    #[test]
+    fn test_new_oidc_session() {
+        // テスト実行
+        let session_1 = OidcSession::new();
+        let session_2 = OidcSession::new();
+
+        // 検証
+        assert_eq!(session_1.code_verifier.len(), 64);
+        assert_ne!(session_1.state, session_2.state);
+        assert_ne!(session_1.nonce, session_2.nonce);
+        assert_ne!(session_1.code_verifier, session_2.code_verifier);
+    }
+
+    #[test]
+    fn test_code_challenge() {
+        // RFC 7636 Appendix Bの例
+        let session = OidcSession {
+            code_verifier: "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string(),
+            ..OidcSession::new()
+        };
+
+        // テスト実行・検証
+        assert_eq!(
+            session.code_challenge(),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let session = OidcSession::new();
+
+        // テスト実行・検証
+        assert!(!session.is_expired(session.created_at));
+        assert!(
+            session.is_expired(session.created_at + Duration::minutes(OIDC_SESSION_TTL_MINUTES))
+        );
+    }
+}
@@ -0,0 +1,21 @@
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{
+    credential::oidc_session_model::OidcSession, error::error_common::ErrorCommon,
+};
+
+// OIDCのログインセッションリポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait OidcSessionRepositoryTrait: Send + Sync {
+    // ログインセッション作成
+    async fn create(&self, ctx: &ContextRequest, session: &OidcSession) -> Result<(), ErrorCommon>;
+    // stateによるログインセッションの取得と削除（1回のみ使用可能）
+    async fn take_by_state(
+        &self,
+        ctx: &ContextRequest,
+        state: &str,
+    ) -> Result<Option<OidcSession>, ErrorCommon>;
+}
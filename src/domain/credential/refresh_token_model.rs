@@ -0,0 +1,67 @@
+use chrono::{DateTime, Duration, FixedOffset};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+// ドメイン
+use crate::domain::time::time_jst::jst_now;
+
+// 発行するリフレッシュトークンの接頭辞
+const REFRESH_TOKEN_PREFIX: &str = "rt";
+
+// リフレッシュトークンモデルの定義
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct RefreshToken {
+    pub id: i64,
+    // トークンのハッシュ値（平文のトークンは保存しない）
+    pub token_hash: String,
+    pub user_uid: String,
+    // ローテーションで発行されたトークンをまとめる識別子（再利用検知時に一括で失効）
+    pub family_id: String,
+    pub expires_at: DateTime<FixedOffset>,
+    pub created_at: DateTime<FixedOffset>,
+    // ローテーション済みまたは失効済みの日時
+    pub revoked_at: Option<DateTime<FixedOffset>>,
+}
+
+impl RefreshToken {
+    // 新規発行（family_idが未指定の場合は新しいファミリーを作成、平文のトークンは戻り値でのみ返す）
+    pub fn issue(user_uid: String, family_id: Option<String>, ttl_secs: i64) -> (Self, String) {
+        let now = jst_now();
+        let plain_token = format!(
+            "{}_{}{}",
+            REFRESH_TOKEN_PREFIX,
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        );
+
+        let refresh_token = Self {
+            id: 0,
+            token_hash: RefreshToken::hash(&plain_token),
+            user_uid,
+            family_id: family_id.unwrap_or_else(|| Uuid::new_v4().to_string()),
+            expires_at: now + Duration::seconds(ttl_secs),
+            created_at: now,
+            revoked_at: None,
+        };
+
+        (refresh_token, plain_token)
+    }
+
+    // トークンのハッシュ値の作成
+    pub fn hash(plain_token: &str) -> String {
+        hex::encode(Sha256::digest(plain_token.as_bytes()))
+    }
+
+    // 利用可能なトークンかどうか（失効・有効期限切れでない）
+    pub fn is_active(&self, now: DateTime<FixedOffset>) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+
+    // 失効設定
+    pub fn revoke(&mut self) {
+        if self.revoked_at.is_none() {
+            self.revoked_at = Some(jst_now());
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::credential::refresh_token_model::RefreshToken;
+    use chrono::{Duration, FixedOffset, Utc};
+
+    #[test]
+    fn test_issue() {
+        // テスト実行
+        let (refresh_token, plain_token) =
+            RefreshToken::issue("xxxx-xxxx-xxxx-0001".to_string(), None, 3600);
+
+        // 検証
+        assert_eq!(refresh_token.id, 0);
+        assert_eq!(refresh_token.user_uid, "xxxx-xxxx-xxxx-0001");
+        assert!(plain_token.starts_with("rt_"));
+        assert_eq!(refresh_token.token_hash, RefreshToken::hash(&plain_token));
+        assert!(refresh_token.revoked_at.is_none());
+
+        // ファミリーの引き継ぎ
+        let (rotated, _) = RefreshToken::issue(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            Some(refresh_token.family_id.clone()),
+            3600,
+        );
+        assert_eq!(rotated.family_id, refresh_token.family_id);
+    }
+
+    #[test]
+    fn test_is_active() {
+        let now = Utc::now().with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+        let (mut refresh_token, _) =
+            RefreshToken::issue("xxxx-xxxx-xxxx-0001".to_string(), None, 3600);
+
+        // 有効期限内
+        assert!(refresh_token.is_active(now));
+
+        // 有効期限切れ
+        assert!(!refresh_token.is_active(now + Duration::hours(2)));
+
+        // 失効済み
+        refresh_token.revoke();
+        assert!(!refresh_token.is_active(now));
+    }
+}
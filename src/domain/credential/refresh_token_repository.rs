@@ -0,0 +1,47 @@
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{
+    credential::refresh_token_model::RefreshToken, error::error_common::ErrorCommon,
+};
+
+// リフレッシュトークンリポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait RefreshTokenRepositoryTrait: Send + Sync {
+    // ハッシュ値によるリフレッシュトークン取得（失効済みを含む）
+    async fn find_by_token_hash(
+        &self,
+        ctx: &ContextRequest,
+        token_hash: &str,
+    ) -> Result<Option<RefreshToken>, ErrorCommon>;
+    // リフレッシュトークン作成（採番したidを設定して返す）
+    async fn create(
+        &self,
+        ctx: &ContextRequest,
+        refresh_token: &RefreshToken,
+    ) -> Result<RefreshToken, ErrorCommon>;
+    // 失効していない場合のみ失効（失効した場合はtrue、失効済みまたは存在しない場合はfalse）
+    // ※同じトークンでの同時リクエストで再発行が1回になるように、条件付きで更新する
+    async fn revoke_if_active(
+        &self,
+        ctx: &ContextRequest,
+        token_hash: &str,
+    ) -> Result<bool, ErrorCommon>;
+    // ファミリー単位での一括失効
+    async fn revoke_family(&self, ctx: &ContextRequest, family_id: &str)
+    -> Result<(), ErrorCommon>;
+    // ユーザー単位での一括失効（全てのファミリーが対象）
+    async fn revoke_all_by_user_uid(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<(), ErrorCommon>;
+    // ユーザー単位での一括削除（ユーザーの物理削除・匿名化用）
+    async fn delete_all_by_user_uid(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<(), ErrorCommon>;
+}
@@ -13,3 +13,13 @@ pub enum ErrorCommon {
         message: String,
     },
 }
+
+impl ErrorCommon {
+    // レスポンス用のステータスコード
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            ErrorCommon::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
+            ErrorCommon::CustomError { status_code, .. } => *status_code,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+// ドメイン
+use crate::domain::audit::audit_log_model::REDACTED_VALUE;
+use crate::domain::user::user_event::{UserCreated, UserDeleted, UserProfileUpdated};
+
+// イベント種別の一覧（Webhookの購読対象の指定等に利用）
+pub const EVENT_TYPES: [&str; 3] = ["user.created", "user.profile_updated", "user.deleted"];
+
+// ドメインイベントの定義（集約の状態変更時に記録）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", content = "data")]
+#[allow(clippy::enum_variant_names)]
+pub enum DomainEvent {
+    UserCreated(UserCreated),
+    UserProfileUpdated(UserProfileUpdated),
+    UserDeleted(UserDeleted),
+}
+
+impl DomainEvent {
+    // イベント種別
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::UserCreated(_) => "user.created",
+            DomainEvent::UserProfileUpdated(_) => "user.profile_updated",
+            DomainEvent::UserDeleted(_) => "user.deleted",
+        }
+    }
+
+    // イベントが発生した集約の識別子
+    pub fn aggregate_id(&self) -> &str {
+        match self {
+            DomainEvent::UserCreated(event) => &event.uid,
+            DomainEvent::UserProfileUpdated(event) => &event.uid,
+            DomainEvent::UserDeleted(event) => &event.uid,
+        }
+    }
+
+    // イベントの発生日時
+    pub fn occurred_at(&self) -> DateTime<FixedOffset> {
+        match self {
+            DomainEvent::UserCreated(event) => event.occurred_at,
+            DomainEvent::UserProfileUpdated(event) => event.occurred_at,
+            DomainEvent::UserDeleted(event) => event.occurred_at,
+        }
+    }
+
+    // イベントのデータから個人情報（氏名・メールアドレス）を消去
+    pub fn scrub_personal_data(&mut self) {
+        let (last_name, first_name, email) = match self {
+            DomainEvent::UserCreated(event) => (
+                &mut event.last_name,
+                &mut event.first_name,
+                &mut event.email,
+            ),
+            DomainEvent::UserProfileUpdated(event) => (
+                &mut event.last_name,
+                &mut event.first_name,
+                &mut event.email,
+            ),
+            DomainEvent::UserDeleted(_) => return,
+        };
+        for value in [last_name, first_name, email] {
+            *value = REDACTED_VALUE.to_string();
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Duration, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+// ドメイン
+use crate::domain::retry::retry_policy::RetryPolicy;
+
+// ジョブの状態
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    // 実行待ち（実行失敗後のリトライ待ちを含む）
+    Pending,
+    // 実行中（ワーカーが取得済み）
+    Running,
+    // 実行成功
+    Succeeded,
+    // 最大試行回数を超えて実行を中止（デッドレター）
+    DeadLettered,
+}
+
+// ジョブモデルの定義（永続化したキューから取得して実行）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Job {
+    pub id: i64,
+    pub job_id: String,
+    // ジョブの種類（実行するハンドラーの判別に利用）
+    pub job_type: String,
+    pub payload: Value,
+    // 重複登録の防止用のキー（同じキーのジョブは登録しない）
+    pub unique_key: Option<String>,
+    pub status: JobStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    // 次回の実行可能日時
+    pub run_at: DateTime<FixedOffset>,
+    // 実行中のジョブの占有期限（ワーカーが停止した場合は期限後に再実行）
+    pub locked_until: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub finished_at: Option<DateTime<FixedOffset>>,
+}
+
+impl Job {
+    // ジョブの作成（run_at: 実行可能日時）
+    pub fn new(job_type: &str, payload: Value, run_at: DateTime<FixedOffset>) -> Self {
+        Self {
+            id: 0,
+            job_id: Uuid::new_v4().to_string(),
+            job_type: job_type.to_string(),
+            payload,
+            unique_key: None,
+            status: JobStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            run_at,
+            locked_until: None,
+            created_at: run_at,
+            finished_at: None,
+        }
+    }
+
+    // 重複登録の防止用のキーの設定
+    pub fn with_unique_key(mut self, unique_key: &str) -> Self {
+        self.unique_key = Some(unique_key.to_string());
+        self
+    }
+
+    // 実行対象かどうか（占有期限が切れた実行中のジョブを含む）
+    pub fn is_due(&self, now: DateTime<FixedOffset>) -> bool {
+        match self.status {
+            JobStatus::Pending => self.run_at <= now,
+            JobStatus::Running => self.locked_until.is_some_and(|locked| locked <= now),
+            JobStatus::Succeeded | JobStatus::DeadLettered => false,
+        }
+    }
+
+    // 実行開始（占有期限を設定）
+    pub fn mark_running(&mut self, lease_secs: u64, now: DateTime<FixedOffset>) {
+        self.status = JobStatus::Running;
+        self.locked_until = Some(now + Duration::seconds(lease_secs as i64));
+    }
+
+    // 実行成功
+    pub fn mark_succeeded(&mut self, now: DateTime<FixedOffset>) {
+        self.attempts += 1;
+        self.status = JobStatus::Succeeded;
+        self.last_error = None;
+        self.locked_until = None;
+        self.finished_at = Some(now);
+    }
+
+    // 実行失敗（最大試行回数に達した場合はデッドレター）
+    pub fn mark_failed(&mut self, error: &str, policy: &RetryPolicy, now: DateTime<FixedOffset>) {
+        self.attempts += 1;
+        self.last_error = Some(error.to_string());
+        self.locked_until = None;
+
+        if policy.is_exhausted(self.attempts) {
+            self.mark_dead_lettered(now);
+            return;
+        }
+
+        let backoff_secs = policy.backoff_secs(self.attempts);
+        self.status = JobStatus::Pending;
+        self.run_at = now + Duration::seconds(backoff_secs as i64);
+    }
+
+    // リトライせずにデッドレター（ハンドラーが未登録の場合等）
+    pub fn mark_dead_lettered(&mut self, now: DateTime<FixedOffset>) {
+        self.status = JobStatus::DeadLettered;
+        self.locked_until = None;
+        self.finished_at = Some(now);
+    }
+
+    // デッドレターのジョブの再実行（試行回数をリセット）
+    pub fn retry(&mut self, now: DateTime<FixedOffset>) -> Result<(), String> {
+        if self.status != JobStatus::DeadLettered {
+            return Err("デッドレターのジョブのみ再実行できます。".to_string());
+        }
+
+        self.status = JobStatus::Pending;
+        self.attempts = 0;
+        self.run_at = now;
+        self.finished_at = None;
+        Ok(())
+    }
+}
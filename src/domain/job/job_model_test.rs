@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use serde_json::json;
+
+    use crate::domain::job::job_model::{Job, JobStatus};
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::time::time_jst::jst_now;
+
+    // テスト用のジョブ
+    fn create_job() -> Job {
+        Job::new("test.job", json!({ "key": "value" }), jst_now())
+    }
+
+    // テスト用のリトライ設定
+    fn create_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 2,
+            backoff_base_secs: 10,
+            backoff_max_secs: 60,
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let job = create_job().with_unique_key("key-0001");
+
+        // 検証
+        assert_eq!(job.job_type, "test.job");
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.attempts, 0);
+        assert_eq!(job.unique_key.as_deref(), Some("key-0001"));
+    }
+
+    #[test]
+    fn test_is_due() {
+        let now = jst_now();
+        let mut job = Job::new("test.job", json!({}), now + Duration::seconds(10));
+
+        // 検証（実行可能日時の前後）
+        assert!(!job.is_due(now));
+        assert!(job.is_due(now + Duration::seconds(10)));
+
+        // 検証（実行中は占有期限が切れた場合のみ）
+        job.mark_running(30, now + Duration::seconds(10));
+        assert!(!job.is_due(now + Duration::seconds(20)));
+        assert!(job.is_due(now + Duration::seconds(40)));
+
+        // 検証（完了後は対象外）
+        job.mark_succeeded(now + Duration::seconds(40));
+        assert!(!job.is_due(now + Duration::seconds(100)));
+    }
+
+    #[test]
+    fn test_mark_succeeded() {
+        let now = jst_now();
+        let mut job = create_job();
+        job.mark_running(30, now);
+        job.mark_succeeded(now);
+
+        // 検証
+        assert_eq!(job.status, JobStatus::Succeeded);
+        assert_eq!(job.attempts, 1);
+        assert_eq!(job.locked_until, None);
+        assert_eq!(job.finished_at, Some(now));
+    }
+
+    #[test]
+    fn test_mark_failed_retry() {
+        let now = jst_now();
+        let mut job = create_job();
+        job.mark_running(30, now);
+        job.mark_failed("error", &create_policy(), now);
+
+        // 検証（バックオフ後に再実行）
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.attempts, 1);
+        assert_eq!(job.last_error.as_deref(), Some("error"));
+        assert_eq!(job.run_at, now + Duration::seconds(10));
+        assert_eq!(job.locked_until, None);
+    }
+
+    #[test]
+    fn test_mark_failed_dead_lettered() {
+        let now = jst_now();
+        let mut job = create_job();
+        job.mark_failed("error", &create_policy(), now);
+        job.mark_failed("error", &create_policy(), now);
+
+        // 検証（最大試行回数でデッドレター）
+        assert_eq!(job.status, JobStatus::DeadLettered);
+        assert_eq!(job.attempts, 2);
+        assert_eq!(job.finished_at, Some(now));
+        assert!(!job.is_due(now + Duration::days(1)));
+    }
+
+    #[test]
+    fn test_retry() {
+        let now = jst_now();
+        let mut job = create_job();
+        job.mark_dead_lettered(now);
+
+        // テスト実行
+        job.retry(now + Duration::seconds(5)).unwrap();
+
+        // 検証
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.attempts, 0);
+        assert_eq!(job.finished_at, None);
+        assert!(job.is_due(now + Duration::seconds(5)));
+    }
+
+    #[test]
+    fn test_retry_not_dead_lettered() {
+        let mut job = create_job();
+
+        // 検証（デッドレター以外はエラー）
+        assert!(job.retry(jst_now()).is_err());
+    }
+}
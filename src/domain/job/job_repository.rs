@@ -0,0 +1,37 @@
+use chrono::{DateTime, FixedOffset};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{error::error_common::ErrorCommon, job::job_model::Job};
+
+// ジョブリポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait JobRepositoryTrait: Send + Sync {
+    // ジョブの追加（採番したidを設定、同じunique_keyのジョブがある場合は409エラー）
+    async fn add(&self, ctx: &ContextRequest, job: &Job) -> Result<Job, ErrorCommon>;
+    // 実行対象のジョブを1件実行中にして取得（古い順、複数のワーカーで同じジョブを取得しない）
+    async fn claim_due(
+        &self,
+        ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        lease_secs: u64,
+    ) -> Result<Option<Job>, ErrorCommon>;
+    // デッドレターのジョブ取得
+    async fn find_dead_lettered(&self, ctx: &ContextRequest) -> Result<Vec<Job>, ErrorCommon>;
+    // ジョブIDからジョブ取得
+    async fn find_by_job_id(
+        &self,
+        ctx: &ContextRequest,
+        job_id: &str,
+    ) -> Result<Option<Job>, ErrorCommon>;
+    // ジョブの更新（実行結果の反映、占有期限が取得時と一致しない場合は409エラー）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        job: &Job,
+        expected_locked_until: Option<DateTime<FixedOffset>>,
+    ) -> Result<Job, ErrorCommon>;
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Timelike};
+
+// cron形式の実行スケジュール（「分 時 日 月 曜日」の5項目、曜日は0〜6で0が日曜日）
+// ※各項目は「*」「数値」「範囲（1-5）」「間隔（*/15、1-30/5）」「リスト（1,15,30）」に対応
+#[derive(Clone, Debug, PartialEq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    days: Vec<u32>,
+    months: Vec<u32>,
+    weekdays: Vec<u32>,
+    // 日と曜日の両方が指定された場合はいずれかに一致すればよい（cronの仕様）
+    day_or_weekday: bool,
+}
+
+// 次回の実行日時を探索する最大期間（分、うるう年の2月29日を含めて4年分）
+const MAX_SEARCH_MINUTES: i64 = 4 * 366 * 24 * 60;
+
+// 項目の値の解析（min〜maxの範囲の値の一覧）
+fn parse_field(field: &str, min: u32, max: u32) -> Result<Vec<u32>, String> {
+    let mut values = Vec::new();
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => match step.parse::<u32>() {
+                Ok(step) if step > 0 => (range, step),
+                _ => return Err(format!("間隔「{}」が不正です。", part)),
+            },
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            match (start.parse::<u32>(), end.parse::<u32>()) {
+                (Ok(start), Ok(end)) if start <= end => (start, end),
+                _ => return Err(format!("範囲「{}」が不正です。", part)),
+            }
+        } else {
+            match range.parse::<u32>() {
+                // 「5/10」は5から最大値まで10間隔
+                Ok(value) if step > 1 => (value, max),
+                Ok(value) => (value, value),
+                Err(_) => return Err(format!("値「{}」が不正です。", part)),
+            }
+        };
+
+        if start < min || end > max {
+            return Err(format!(
+                "値「{}」は{}〜{}で指定して下さい。",
+                part, min, max
+            ));
+        }
+
+        values.extend((start..=end).step_by(step as usize));
+    }
+
+    values.sort_unstable();
+    values.dedup();
+    Ok(values)
+}
+
+impl CronSchedule {
+    // cron形式の文字列から作成
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "cron形式「{}」は「分 時 日 月 曜日」の5項目で指定して下さい。",
+                expression
+            ));
+        }
+
+        let error = |err: String| format!("cron形式「{}」: {}", expression, err);
+
+        Ok(CronSchedule {
+            expression: fields.join(" "),
+            minutes: parse_field(fields[0], 0, 59).map_err(error)?,
+            hours: parse_field(fields[1], 0, 23).map_err(error)?,
+            days: parse_field(fields[2], 1, 31).map_err(error)?,
+            months: parse_field(fields[3], 1, 12).map_err(error)?,
+            // 曜日の7は日曜日として扱う
+            weekdays: parse_field(fields[4], 0, 7)
+                .map_err(error)?
+                .into_iter()
+                .map(|weekday| weekday % 7)
+                .collect(),
+            day_or_weekday: fields[2] != "*" && fields[4] != "*",
+        })
+    }
+
+    // cron形式の文字列
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    // 指定日時より後の次回の実行日時（分単位、見つからない場合はNone）
+    pub fn next_after(&self, after: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+
+        let mut dt = start;
+        while dt - start < Duration::minutes(MAX_SEARCH_MINUTES) {
+            if !self.months.contains(&dt.month()) {
+                // 翌月の1日0時0分まで進める
+                let (year, month) = if dt.month() == 12 {
+                    (dt.year() + 1, 1)
+                } else {
+                    (dt.year(), dt.month() + 1)
+                };
+                dt = dt
+                    .with_day(1)?
+                    .with_hour(0)?
+                    .with_minute(0)?
+                    .with_month(month)?
+                    .with_year(year)?;
+                continue;
+            }
+            if !self.matches_date(&dt) {
+                // 翌日の0時0分まで進める
+                dt = dt.with_hour(0)?.with_minute(0)? + Duration::days(1);
+                continue;
+            }
+            if !self.hours.contains(&dt.hour()) {
+                // 次の時の0分まで進める
+                dt = dt.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if self.minutes.contains(&dt.minute()) {
+                return Some(dt);
+            }
+            dt += Duration::minutes(1);
+        }
+
+        None
+    }
+
+    // 日付（日・曜日）が一致するかどうか
+    fn matches_date(&self, dt: &DateTime<FixedOffset>) -> bool {
+        let day_matches = self.days.contains(&dt.day());
+        let weekday_matches = self.weekdays.contains(&dt.weekday().num_days_from_sunday());
+        if self.day_or_weekday {
+            day_matches || weekday_matches
+        } else {
+            day_matches && weekday_matches
+        }
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset};
+
+    use crate::domain::job::job_schedule::CronSchedule;
+
+    // テスト用の日時
+    fn dt(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    // 次回の実行日時
+    fn next(expression: &str, after: &str) -> DateTime<FixedOffset> {
+        CronSchedule::parse(expression)
+            .unwrap()
+            .next_after(dt(after))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let schedule = CronSchedule::parse("*/15  3 * * 1-5").unwrap();
+
+        // 検証（空白は正規化）
+        assert_eq!(schedule.expression(), "*/15 3 * * 1-5");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        // 検証
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("* 24 * * *").is_err());
+        assert!(CronSchedule::parse("* * 0 * *").is_err());
+        assert!(CronSchedule::parse("* * * 13 *").is_err());
+        assert!(CronSchedule::parse("* * * * 8").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+        assert!(CronSchedule::parse("a * * * *").is_err());
+    }
+
+    #[test]
+    fn test_next_after_every_minute() {
+        // 検証（秒は切り捨てて次の分）
+        assert_eq!(
+            next("* * * * *", "2026-01-01T10:00:30+09:00"),
+            dt("2026-01-01T10:01:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_next_after_step() {
+        // 検証
+        assert_eq!(
+            next("*/15 * * * *", "2026-01-01T10:00:00+09:00"),
+            dt("2026-01-01T10:15:00+09:00")
+        );
+        assert_eq!(
+            next("*/15 * * * *", "2026-01-01T10:50:00+09:00"),
+            dt("2026-01-01T11:00:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_next_after_daily() {
+        // 検証（当日の時刻を過ぎた場合は翌日）
+        assert_eq!(
+            next("0 3 * * *", "2026-01-01T02:59:00+09:00"),
+            dt("2026-01-01T03:00:00+09:00")
+        );
+        assert_eq!(
+            next("0 3 * * *", "2026-01-01T03:00:00+09:00"),
+            dt("2026-01-02T03:00:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_next_after_weekday() {
+        // 検証（2026-01-01は木曜日、月曜日は2026-01-05）
+        assert_eq!(
+            next("30 9 * * 1", "2026-01-01T10:00:00+09:00"),
+            dt("2026-01-05T09:30:00+09:00")
+        );
+        // 検証（7は日曜日）
+        assert_eq!(
+            next("0 0 * * 7", "2026-01-01T10:00:00+09:00"),
+            dt("2026-01-04T00:00:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_next_after_day_or_weekday() {
+        // 検証（日と曜日の両方を指定した場合はいずれかに一致）
+        assert_eq!(
+            next("0 0 15 * 1", "2026-01-01T10:00:00+09:00"),
+            dt("2026-01-05T00:00:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_next_after_month_and_year() {
+        // 検証（月末・年末をまたぐ）
+        assert_eq!(
+            next("0 0 1 * *", "2026-01-31T23:59:00+09:00"),
+            dt("2026-02-01T00:00:00+09:00")
+        );
+        assert_eq!(
+            next("0 0 1 1 *", "2026-06-15T00:00:00+09:00"),
+            dt("2027-01-01T00:00:00+09:00")
+        );
+        // 検証（うるう年の2月29日）
+        assert_eq!(
+            next("0 0 29 2 *", "2026-03-01T00:00:00+09:00"),
+            dt("2028-02-29T00:00:00+09:00")
+        );
+    }
+
+    #[test]
+    fn test_next_after_not_found() {
+        // 検証（存在しない日付）
+        let schedule = CronSchedule::parse("0 0 31 2 *").unwrap();
+        assert_eq!(schedule.next_after(dt("2026-01-01T00:00:00+09:00")), None);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod job_model;
+pub mod job_repository;
+pub mod job_schedule;
+
+// テストコード用のモジュール
+pub mod job_model_test;
+pub mod job_schedule_test;
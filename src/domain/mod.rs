@@ -1,2 +1,13 @@
+pub mod api_key;
+pub mod audit;
+pub mod auth;
+pub mod credential;
 pub mod error;
+pub mod event;
+pub mod job;
+pub mod outbox;
+pub mod retry;
+pub mod time;
+pub mod transaction;
 pub mod user;
+pub mod webhook;
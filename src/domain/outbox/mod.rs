@@ -0,0 +1,5 @@
+pub mod outbox_model;
+pub mod outbox_repository;
+
+// テストコード用のモジュール
+pub mod outbox_model_test;
@@ -0,0 +1,117 @@
+use chrono::{DateTime, Duration, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+// ドメイン
+use crate::domain::event::domain_event::DomainEvent;
+use crate::domain::retry::retry_policy::RetryPolicy;
+use crate::domain::time::time_jst::jst_now;
+
+// 送信状態
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxStatus {
+    // 未送信（送信失敗後のリトライ待ちを含む）
+    Pending,
+    // 送信済み
+    Delivered,
+    // 最大試行回数を超えて送信を中止
+    Failed,
+}
+
+// アウトボックスのメッセージ（ドメインイベントと同じトランザクションで保存して後から送信）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct OutboxMessage {
+    pub id: i64,
+    // イベントの識別子（送信先での重複排除に利用）
+    pub event_id: String,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub payload: DomainEvent,
+    pub status: OutboxStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    // 次回の送信可能日時
+    pub next_attempt_at: DateTime<FixedOffset>,
+    pub created_at: DateTime<FixedOffset>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+}
+
+impl OutboxMessage {
+    // ドメインイベントからメッセージを作成
+    pub fn new(event: DomainEvent) -> Self {
+        let now = jst_now();
+
+        Self {
+            id: 0,
+            event_id: Uuid::new_v4().to_string(),
+            event_type: event.event_type().to_string(),
+            aggregate_id: event.aggregate_id().to_string(),
+            payload: event,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            created_at: now,
+            delivered_at: None,
+        }
+    }
+
+    // 複数のドメインイベントからメッセージを作成
+    pub fn from_events(events: &[DomainEvent]) -> Vec<Self> {
+        events.iter().cloned().map(OutboxMessage::new).collect()
+    }
+
+    // 外部に送信する形式（イベントID・種別・発生日時とイベントのデータ部分）
+    pub fn envelope(&self) -> serde_json::Value {
+        let payload = json!(self.payload);
+        json!({
+            "id": self.event_id,
+            "type": self.event_type,
+            "occurred_at": self.payload.occurred_at(),
+            "data": payload["data"],
+        })
+    }
+
+    // 送信対象かどうか
+    pub fn is_due(&self, now: DateTime<FixedOffset>) -> bool {
+        self.status == OutboxStatus::Pending && self.next_attempt_at <= now
+    }
+
+    // 滞留しているかどうか（送信を中止した、またはリトライ中）
+    pub fn is_stuck(&self) -> bool {
+        match self.status {
+            OutboxStatus::Failed => true,
+            OutboxStatus::Pending => self.attempts > 0,
+            OutboxStatus::Delivered => false,
+        }
+    }
+
+    // 送信成功
+    pub fn mark_delivered(&mut self, now: DateTime<FixedOffset>) {
+        self.attempts += 1;
+        self.status = OutboxStatus::Delivered;
+        self.last_error = None;
+        self.delivered_at = Some(now);
+    }
+
+    // 送信失敗（最大試行回数に達した場合は送信を中止）
+    pub fn mark_failed(&mut self, error: &str, policy: &RetryPolicy, now: DateTime<FixedOffset>) {
+        self.attempts += 1;
+        self.last_error = Some(error.to_string());
+
+        if policy.is_exhausted(self.attempts) {
+            self.status = OutboxStatus::Failed;
+            return;
+        }
+
+        let backoff_secs = policy.backoff_secs(self.attempts);
+        self.next_attempt_at = now + Duration::seconds(backoff_secs as i64);
+    }
+
+    // ペイロードから個人情報を消去（送信済みのメッセージを含む）
+    pub fn scrub_personal_data(&mut self) {
+        self.payload.scrub_personal_data();
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::domain::outbox::outbox_model::{OutboxMessage, OutboxStatus};
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::user::user_model::User;
+
+    // テスト用のメッセージ
+    fn create_message() -> OutboxMessage {
+        let mut user = User::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            "テスト".to_string(),
+            "太郎".to_string(),
+            "t.test@example.com".to_string(),
+        );
+        OutboxMessage::from_events(&user.take_events()).remove(0)
+    }
+
+    // テスト用のリトライ設定
+    fn create_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            backoff_base_secs: 2,
+            backoff_max_secs: 5,
+        }
+    }
+
+    #[test]
+    fn test_new_message() {
+        // テスト実行
+        let message = create_message();
+
+        // 検証
+        assert_eq!(message.id, 0);
+        assert!(!message.event_id.is_empty());
+        assert_eq!(message.event_type, "user.created");
+        assert_eq!(message.aggregate_id, "xxx-xxx-xxx-0001");
+        assert_eq!(message.status, OutboxStatus::Pending);
+        assert_eq!(message.attempts, 0);
+        assert!(message.is_due(jst_now()));
+        assert!(!message.is_stuck());
+    }
+
+    #[test]
+    fn test_mark_delivered() {
+        let mut message = create_message();
+        let now = jst_now();
+
+        // テスト実行
+        message.mark_delivered(now);
+
+        // 検証
+        assert_eq!(message.status, OutboxStatus::Delivered);
+        assert_eq!(message.attempts, 1);
+        assert_eq!(message.delivered_at, Some(now));
+        assert!(!message.is_due(now));
+        assert!(!message.is_stuck());
+    }
+
+    #[test]
+    fn test_mark_failed_retry() {
+        let mut message = create_message();
+        let policy = create_policy();
+        let now = jst_now();
+
+        // テスト実行
+        message.mark_failed("connection refused", &policy, now);
+
+        // 検証（バックオフ後に再送信の対象となること）
+        assert_eq!(message.status, OutboxStatus::Pending);
+        assert_eq!(message.attempts, 1);
+        assert_eq!(message.last_error.as_deref(), Some("connection refused"));
+        assert_eq!(message.next_attempt_at, now + Duration::seconds(2));
+        assert!(!message.is_due(now));
+        assert!(message.is_due(now + Duration::seconds(2)));
+        assert!(message.is_stuck());
+    }
+
+    #[test]
+    fn test_mark_failed_give_up() {
+        let mut message = create_message();
+        let policy = create_policy();
+        let now = jst_now();
+
+        // テスト実行（最大試行回数まで失敗）
+        for _ in 0..policy.max_attempts {
+            message.mark_failed("connection refused", &policy, now);
+        }
+
+        // 検証（送信を中止して滞留扱いとなること）
+        assert_eq!(message.status, OutboxStatus::Failed);
+        assert_eq!(message.attempts, 3);
+        assert!(!message.is_due(now + Duration::days(1)));
+        assert!(message.is_stuck());
+    }
+
+    #[test]
+    fn test_scrub_personal_data() {
+        let mut message = create_message();
+        message.mark_delivered(jst_now());
+
+        // テスト実行
+        message.scrub_personal_data();
+
+        // 検証（送信済みでも氏名・メールアドレスが消去され、識別子は保持される）
+        let payload = serde_json::to_string(&message.payload).unwrap();
+        assert!(!payload.contains("テスト"));
+        assert!(!payload.contains("太郎"));
+        assert!(!payload.contains("t.test@example.com"));
+        assert!(payload.contains("[REDACTED]"));
+        assert_eq!(message.payload.aggregate_id(), "xxx-xxx-xxx-0001");
+        assert_eq!(message.status, OutboxStatus::Delivered);
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, FixedOffset};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage};
+
+// アウトボックスリポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait OutboxRepositoryTrait: Send + Sync {
+    // メッセージの追加（採番したidを設定）
+    async fn add(
+        &self,
+        ctx: &ContextRequest,
+        messages: &[OutboxMessage],
+    ) -> Result<Vec<OutboxMessage>, ErrorCommon>;
+    // 送信対象のメッセージ取得（古い順に最大limit件）
+    async fn find_due(
+        &self,
+        ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        limit: usize,
+    ) -> Result<Vec<OutboxMessage>, ErrorCommon>;
+    // 滞留しているメッセージ取得（送信を中止した、またはリトライ中）
+    async fn find_stuck(&self, ctx: &ContextRequest) -> Result<Vec<OutboxMessage>, ErrorCommon>;
+    // メッセージの更新（送信結果の反映）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        message: &OutboxMessage,
+    ) -> Result<OutboxMessage, ErrorCommon>;
+    // 集約のメッセージのペイロードから個人情報を消去（消去したメッセージを返す）
+    async fn scrub_personal_data(
+        &self,
+        ctx: &ContextRequest,
+        aggregate_id: &str,
+    ) -> Result<Vec<OutboxMessage>, ErrorCommon>;
+}
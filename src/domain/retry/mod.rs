@@ -0,0 +1,4 @@
+pub mod retry_policy;
+
+// テストコード用のモジュール
+pub mod retry_policy_test;
@@ -0,0 +1,22 @@
+// 失敗時のリトライ設定（アウトボックス、Webhook等の送信処理で共通）
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub backoff_base_secs: u64,
+    pub backoff_max_secs: u64,
+}
+
+impl RetryPolicy {
+    // 試行回数に応じた待機秒数（指数バックオフ、上限あり）
+    pub fn backoff_secs(&self, attempts: u32) -> u64 {
+        let exponent = attempts.saturating_sub(1).min(32);
+        self.backoff_base_secs
+            .saturating_mul(1u64 << exponent)
+            .min(self.backoff_max_secs)
+    }
+
+    // 最大試行回数に達したかどうか
+    pub fn is_exhausted(&self, attempts: u32) -> bool {
+        attempts >= self.max_attempts
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::retry::retry_policy::RetryPolicy;
+
+    // テスト用のリトライ設定
+    fn create_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            backoff_base_secs: 2,
+            backoff_max_secs: 5,
+        }
+    }
+
+    #[test]
+    fn test_backoff_secs() {
+        let policy = create_policy();
+
+        // 検証（指数的に増加し、上限で止まること）
+        assert_eq!(policy.backoff_secs(1), 2);
+        assert_eq!(policy.backoff_secs(2), 4);
+        assert_eq!(policy.backoff_secs(3), 5);
+        assert_eq!(policy.backoff_secs(100), 5);
+    }
+
+    #[test]
+    fn test_is_exhausted() {
+        let policy = create_policy();
+
+        // 検証
+        assert!(!policy.is_exhausted(2));
+        assert!(policy.is_exhausted(3));
+    }
+}
@@ -0,0 +1,8 @@
+use chrono::{DateTime, FixedOffset, TimeZone};
+
+// 現在日時（JST）の取得
+pub fn jst_now() -> DateTime<FixedOffset> {
+    let jst_offset = FixedOffset::east_opt(9 * 3600).unwrap();
+    let utc_now = chrono::Utc::now();
+    jst_offset.from_utc_datetime(&utc_now.naive_utc())
+}
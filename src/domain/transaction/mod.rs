@@ -0,0 +1,5 @@
+pub mod unit_of_work;
+
+// テストコード用のモジュール
+pub mod unit_of_work_test;
+pub mod unit_of_work_test_helper;
@@ -0,0 +1,63 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{
+    audit::audit_log_repository::AuditLogRepositoryTrait,
+    credential::credential_repository::CredentialRepositoryTrait,
+    credential::refresh_token_repository::RefreshTokenRepositoryTrait,
+    error::error_common::ErrorCommon, outbox::outbox_repository::OutboxRepositoryTrait,
+    user::user_repository::UserRepositoryTrait,
+    webhook::webhook_delivery_repository::WebhookDeliveryRepositoryTrait,
+};
+
+// トランザクション用のトレイト（モック化もできるように定義）
+// ※トランザクション内で使用するリポジトリを返し、全ての変更をコミットまたはロールバックする
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait TransactionTrait: Send + Sync {
+    // トランザクションに参加するUserリポジトリ
+    fn user_repository(&self) -> Arc<dyn UserRepositoryTrait>;
+    // トランザクションに参加するアウトボックスリポジトリ
+    fn outbox_repository(&self) -> Arc<dyn OutboxRepositoryTrait>;
+    // トランザクションに参加する監査ログリポジトリ
+    fn audit_log_repository(&self) -> Arc<dyn AuditLogRepositoryTrait>;
+    // トランザクションに参加する認証情報リポジトリ
+    fn credential_repository(&self) -> Arc<dyn CredentialRepositoryTrait>;
+    // トランザクションに参加するリフレッシュトークンリポジトリ
+    fn refresh_token_repository(&self) -> Arc<dyn RefreshTokenRepositoryTrait>;
+    // トランザクションに参加するWebhookの配信リポジトリ
+    fn webhook_delivery_repository(&self) -> Arc<dyn WebhookDeliveryRepositoryTrait>;
+    // コミット
+    async fn commit(self: Box<Self>) -> Result<(), ErrorCommon>;
+    // ロールバック
+    async fn rollback(self: Box<Self>) -> Result<(), ErrorCommon>;
+}
+
+// ユニットオブワーク用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UnitOfWorkTrait: Send + Sync {
+    // トランザクション開始
+    async fn begin(&self, ctx: &ContextRequest) -> Result<Box<dyn TransactionTrait>, ErrorCommon>;
+}
+
+// 処理結果に応じてコミットまたはロールバックする
+pub async fn finish_transaction<T>(
+    tx: Box<dyn TransactionTrait>,
+    result: Result<T, ErrorCommon>,
+) -> Result<T, ErrorCommon> {
+    match result {
+        Ok(value) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Err(err) => {
+            tx.rollback().await?;
+            Err(err)
+        }
+    }
+}
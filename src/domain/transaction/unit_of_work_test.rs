@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+    use crate::domain::transaction::unit_of_work::{MockTransactionTrait, finish_transaction};
+
+    #[tokio::test]
+    async fn test_finish_transaction_commit() {
+        // トランザクションのモック化
+        let mut mock_tx = MockTransactionTrait::new();
+        mock_tx.expect_commit().times(1).returning(|| Ok(()));
+        mock_tx.expect_rollback().never();
+
+        // テスト実行
+        let result = finish_transaction(Box::new(mock_tx), Ok(1)).await;
+
+        // 検証
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_finish_transaction_rollback() {
+        // トランザクションのモック化
+        let mut mock_tx = MockTransactionTrait::new();
+        mock_tx.expect_commit().never();
+        mock_tx.expect_rollback().times(1).returning(|| Ok(()));
+
+        // テスト実行
+        let result: Result<i32, ErrorCommon> = finish_transaction(
+            Box::new(mock_tx),
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_REQUEST,
+                message: "error".to_string(),
+            }),
+        )
+        .await;
+
+        // 検証
+        assert_eq!(result.unwrap_err().status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_finish_transaction_commit_error() {
+        // トランザクションのモック化
+        let mut mock_tx = MockTransactionTrait::new();
+        mock_tx
+            .expect_commit()
+            .returning(|| Err(ErrorCommon::InternalServerError));
+
+        // テスト実行
+        let result = finish_transaction(Box::new(mock_tx), Ok(1)).await;
+
+        // 検証
+        assert_eq!(
+            result.unwrap_err().status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
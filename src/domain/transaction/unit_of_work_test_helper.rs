@@ -0,0 +1,215 @@
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+use crate::domain::{
+    audit::audit_log_repository::MockAuditLogRepositoryTrait,
+    credential::credential_repository::MockCredentialRepositoryTrait,
+    credential::refresh_token_repository::MockRefreshTokenRepositoryTrait,
+    outbox::outbox_repository::MockOutboxRepositoryTrait,
+    transaction::unit_of_work::{MockTransactionTrait, MockUnitOfWorkTrait},
+    user::user_repository::MockUserRepositoryTrait,
+    webhook::webhook_delivery_repository::MockWebhookDeliveryRepositoryTrait,
+};
+
+// 期待するトランザクションの結果（コミット・ロールバック・開始しない）
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExpectedFinish {
+    Commit,
+    Rollback,
+    NotStarted,
+}
+
+// テスト用のユニットオブワークのモックを作成
+// ※トランザクション内ではモックのUserリポジトリを使用し、期待する結果で終了することを検証する
+// ※アウトボックス・監査ログへの追加、認証情報・リフレッシュトークンの削除、個人情報の消去は常に成功する
+#[cfg(test)]
+pub fn create_mock_unit_of_work(
+    user_repo: MockUserRepositoryTrait,
+    expected: ExpectedFinish,
+) -> MockUnitOfWorkTrait {
+    new_mock_unit_of_work(
+        user_repo,
+        create_permissive_outbox_repo(),
+        create_permissive_audit_log_repo(),
+        create_permissive_credential_repo(),
+        create_permissive_refresh_token_repo(),
+        create_permissive_webhook_delivery_repo(),
+        expected,
+    )
+}
+
+// テスト用のユニットオブワークのモックを作成（アウトボックスのモックを指定）
+#[cfg(test)]
+pub fn create_mock_unit_of_work_with_outbox(
+    user_repo: MockUserRepositoryTrait,
+    outbox_repo: MockOutboxRepositoryTrait,
+    expected: ExpectedFinish,
+) -> MockUnitOfWorkTrait {
+    new_mock_unit_of_work(
+        user_repo,
+        outbox_repo,
+        create_permissive_audit_log_repo(),
+        create_permissive_credential_repo(),
+        create_permissive_refresh_token_repo(),
+        create_permissive_webhook_delivery_repo(),
+        expected,
+    )
+}
+
+// テスト用のユニットオブワークのモックを作成（監査ログのモックを指定）
+#[cfg(test)]
+pub fn create_mock_unit_of_work_with_audit_log(
+    user_repo: MockUserRepositoryTrait,
+    audit_log_repo: MockAuditLogRepositoryTrait,
+    expected: ExpectedFinish,
+) -> MockUnitOfWorkTrait {
+    new_mock_unit_of_work(
+        user_repo,
+        create_permissive_outbox_repo(),
+        audit_log_repo,
+        create_permissive_credential_repo(),
+        create_permissive_refresh_token_repo(),
+        create_permissive_webhook_delivery_repo(),
+        expected,
+    )
+}
+
+// テスト用のユニットオブワークのモックを作成（認証情報・リフレッシュトークンのモックを指定）
+#[cfg(test)]
+pub fn create_mock_unit_of_work_with_credential(
+    user_repo: MockUserRepositoryTrait,
+    credential_repo: MockCredentialRepositoryTrait,
+    refresh_token_repo: MockRefreshTokenRepositoryTrait,
+    expected: ExpectedFinish,
+) -> MockUnitOfWorkTrait {
+    new_mock_unit_of_work(
+        user_repo,
+        create_permissive_outbox_repo(),
+        create_permissive_audit_log_repo(),
+        credential_repo,
+        refresh_token_repo,
+        create_permissive_webhook_delivery_repo(),
+        expected,
+    )
+}
+
+// 追加・個人情報の消去が常に成功するアウトボックスのモック
+#[cfg(test)]
+fn create_permissive_outbox_repo() -> MockOutboxRepositoryTrait {
+    let mut outbox_repo = MockOutboxRepositoryTrait::new();
+    outbox_repo
+        .expect_add()
+        .returning(|_, messages| Ok(messages.to_vec()));
+    outbox_repo
+        .expect_scrub_personal_data()
+        .returning(|_, _| Ok(Vec::new()));
+    outbox_repo
+}
+
+// 追加・個人情報の消去が常に成功する監査ログのモック
+#[cfg(test)]
+fn create_permissive_audit_log_repo() -> MockAuditLogRepositoryTrait {
+    let mut audit_log_repo = MockAuditLogRepositoryTrait::new();
+    audit_log_repo
+        .expect_add()
+        .returning(|_, audit_log| Ok(audit_log.clone()));
+    audit_log_repo
+        .expect_scrub_personal_data()
+        .returning(|_, _| Ok(()));
+    audit_log_repo
+}
+
+// 削除が常に成功する認証情報のモック
+#[cfg(test)]
+fn create_permissive_credential_repo() -> MockCredentialRepositoryTrait {
+    let mut credential_repo = MockCredentialRepositoryTrait::new();
+    credential_repo
+        .expect_delete_by_user_uid()
+        .returning(|_, _| Ok(()));
+    credential_repo
+}
+
+// 削除が常に成功するリフレッシュトークンのモック
+#[cfg(test)]
+fn create_permissive_refresh_token_repo() -> MockRefreshTokenRepositoryTrait {
+    let mut refresh_token_repo = MockRefreshTokenRepositoryTrait::new();
+    refresh_token_repo
+        .expect_delete_all_by_user_uid()
+        .returning(|_, _| Ok(()));
+    refresh_token_repo
+}
+
+// 個人情報の消去が常に成功するWebhookの配信のモック
+#[cfg(test)]
+fn create_permissive_webhook_delivery_repo() -> MockWebhookDeliveryRepositoryTrait {
+    let mut webhook_delivery_repo = MockWebhookDeliveryRepositoryTrait::new();
+    webhook_delivery_repo
+        .expect_scrub_personal_data()
+        .returning(|_, _| Ok(()));
+    webhook_delivery_repo
+}
+
+#[cfg(test)]
+fn new_mock_unit_of_work(
+    user_repo: MockUserRepositoryTrait,
+    outbox_repo: MockOutboxRepositoryTrait,
+    audit_log_repo: MockAuditLogRepositoryTrait,
+    credential_repo: MockCredentialRepositoryTrait,
+    refresh_token_repo: MockRefreshTokenRepositoryTrait,
+    webhook_delivery_repo: MockWebhookDeliveryRepositoryTrait,
+    expected: ExpectedFinish,
+) -> MockUnitOfWorkTrait {
+    let user_repo = Arc::new(user_repo);
+    let outbox_repo = Arc::new(outbox_repo);
+    let audit_log_repo = Arc::new(audit_log_repo);
+    let credential_repo = Arc::new(credential_repo);
+    let refresh_token_repo = Arc::new(refresh_token_repo);
+    let webhook_delivery_repo = Arc::new(webhook_delivery_repo);
+
+    let mut mock_uow = MockUnitOfWorkTrait::new();
+    if expected == ExpectedFinish::NotStarted {
+        mock_uow.expect_begin().never();
+        return mock_uow;
+    }
+    mock_uow.expect_begin().times(1).returning(move |_| {
+        let mut mock_tx = MockTransactionTrait::new();
+        let user_repo = user_repo.clone();
+        mock_tx
+            .expect_user_repository()
+            .returning(move || user_repo.clone());
+        let outbox_repo = outbox_repo.clone();
+        mock_tx
+            .expect_outbox_repository()
+            .returning(move || outbox_repo.clone());
+        let audit_log_repo = audit_log_repo.clone();
+        mock_tx
+            .expect_audit_log_repository()
+            .returning(move || audit_log_repo.clone());
+        let credential_repo = credential_repo.clone();
+        mock_tx
+            .expect_credential_repository()
+            .returning(move || credential_repo.clone());
+        let refresh_token_repo = refresh_token_repo.clone();
+        mock_tx
+            .expect_refresh_token_repository()
+            .returning(move || refresh_token_repo.clone());
+        let webhook_delivery_repo = webhook_delivery_repo.clone();
+        mock_tx
+            .expect_webhook_delivery_repository()
+            .returning(move || webhook_delivery_repo.clone());
+        mock_tx
+            .expect_commit()
+            .times(usize::from(expected == ExpectedFinish::Commit))
+            .returning(|| Ok(()));
+        mock_tx
+            .expect_rollback()
+            .times(usize::from(expected == ExpectedFinish::Rollback))
+            .returning(|| Ok(()));
+
+        Ok(Box::new(mock_tx))
+    });
+
+    mock_uow
+}
@@ -1,3 +1,4 @@
+pub mod user_event;
 pub mod user_model;
 pub mod user_repository;
 
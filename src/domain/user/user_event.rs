@@ -0,0 +1,31 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+// ユーザー作成イベント
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct UserCreated {
+    pub uid: String,
+    pub last_name: String,
+    pub first_name: String,
+    pub email: String,
+    pub occurred_at: DateTime<FixedOffset>,
+}
+
+// プロフィール更新イベント
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct UserProfileUpdated {
+    pub uid: String,
+    pub version: i64,
+    pub last_name: String,
+    pub first_name: String,
+    pub email: String,
+    pub occurred_at: DateTime<FixedOffset>,
+}
+
+// ユーザー削除（論理削除）イベント
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct UserDeleted {
+    pub uid: String,
+    pub version: i64,
+    pub occurred_at: DateTime<FixedOffset>,
+}
@@ -1,22 +1,34 @@
 use chrono::{DateTime, FixedOffset, TimeZone};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+// ドメイン
+use crate::domain::event::domain_event::DomainEvent;
+use crate::domain::user::user_event::{UserCreated, UserDeleted, UserProfileUpdated};
 
 // ユーザーモデルの定義
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct User {
     pub id: i64,
     pub uid: String,
     pub last_name: String,
     pub first_name: String,
     pub email: String,
+    // 楽観的排他制御用のバージョン（更新の度に加算）
+    pub version: i64,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
     pub deleted_at: Option<DateTime<FixedOffset>>,
+    // 匿名化日時（論理削除から保持期間が経過して個人情報を匿名化した日時）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anonymized_at: Option<DateTime<FixedOffset>>,
+    // 記録したドメインイベント（永続化後にユースケースで取り出して通知）
+    #[serde(skip)]
+    pub events: Vec<DomainEvent>,
 }
 
 impl User {
     // 新規作成
-    #[allow(dead_code)]
     pub fn new(
         new_uid: String,
         new_last_name: String,
@@ -30,27 +42,39 @@ impl User {
         let utc_now = chrono::Utc::now();
         let jst_now = jst_offset.from_utc_datetime(&utc_now.naive_utc());
 
-        Self {
+        let mut user = Self {
             id: 0,
             uid: new_uid,
             last_name: new_last_name,
             first_name: new_first_name,
             email: new_email,
+            version: 1,
             created_at: jst_now,
             updated_at: jst_now,
             deleted_at: None,
-        }
+            anonymized_at: None,
+            events: Vec::new(),
+        };
+
+        // ドメインイベントの記録
+        user.events.push(DomainEvent::UserCreated(UserCreated {
+            uid: user.uid.clone(),
+            last_name: user.last_name.clone(),
+            first_name: user.first_name.clone(),
+            email: user.email.clone(),
+            occurred_at: jst_now,
+        }));
+
+        user
     }
 
-    // プロフィール更新
-    #[allow(dead_code)]
-    pub fn update_profile(
-        &mut self,
-        last_name: String,
-        first_name: String,
-        email: String,
-    ) -> Result<(), String> {
-        // パラメータチェック
+    // 記録したドメインイベントの取り出し
+    pub fn take_events(&mut self) -> Vec<DomainEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    // プロフィールのパラメータチェック
+    pub fn validate_profile(last_name: &str, first_name: &str, email: &str) -> Result<(), String> {
         let mut err_msgs = Vec::new();
         if last_name.is_empty() {
             err_msgs.push("last_nameは必須です。");
@@ -67,6 +91,19 @@ impl User {
             return Err(msg);
         }
 
+        Ok(())
+    }
+
+    // プロフィール更新
+    pub fn update_profile(
+        &mut self,
+        last_name: String,
+        first_name: String,
+        email: String,
+    ) -> Result<(), String> {
+        // パラメータチェック
+        User::validate_profile(&last_name, &first_name, &email)?;
+
         // 対象項目の更新
         self.last_name = last_name;
         self.first_name = first_name;
@@ -77,22 +114,95 @@ impl User {
         let utc_now = chrono::Utc::now();
         let jst_now = jst_offset.from_utc_datetime(&utc_now.naive_utc());
 
-        // 更新日時の更新
+        // 更新日時とバージョンの更新
         self.updated_at = jst_now;
+        self.version += 1;
+
+        // ドメインイベントの記録
+        self.events
+            .push(DomainEvent::UserProfileUpdated(UserProfileUpdated {
+                uid: self.uid.clone(),
+                version: self.version,
+                last_name: self.last_name.clone(),
+                first_name: self.first_name.clone(),
+                email: self.email.clone(),
+                occurred_at: jst_now,
+            }));
 
         Ok(())
     }
 
     // 論理削除設定
-    #[allow(dead_code)]
     pub fn set_delete(&mut self) {
         // 現在日時の設定
         let jst_offset = FixedOffset::east_opt(9 * 3600).unwrap();
         let utc_now = chrono::Utc::now();
         let jst_now = jst_offset.from_utc_datetime(&utc_now.naive_utc());
 
-        // 更新日時と削除日時の更新
+        // 更新日時と削除日時とバージョンの更新
         self.updated_at = jst_now;
         self.deleted_at = Some(jst_now);
+        self.version += 1;
+
+        // ドメインイベントの記録
+        self.events.push(DomainEvent::UserDeleted(UserDeleted {
+            uid: self.uid.clone(),
+            version: self.version,
+            occurred_at: jst_now,
+        }));
+    }
+
+    // 物理削除・匿名化の対象かどうか（論理削除日時がcutoff以前で未匿名化）
+    pub fn is_purgeable(&self, cutoff: DateTime<FixedOffset>) -> bool {
+        self.anonymized_at.is_none()
+            && self
+                .deleted_at
+                .is_some_and(|deleted_at| deleted_at <= cutoff)
+    }
+
+    // 個人情報の匿名化（論理削除済みのユーザーのみ、uidは監査ログとの紐付けのため保持）
+    pub fn anonymize(&mut self, now: DateTime<FixedOffset>) -> Result<(), String> {
+        if self.deleted_at.is_none() {
+            return Err("論理削除済みのユーザーのみ匿名化できます。".to_string());
+        }
+
+        self.last_name = "削除済み".to_string();
+        self.first_name = "ユーザー".to_string();
+        self.email = format!("{}@anonymized.invalid", self.uid);
+        self.updated_at = now;
+        self.anonymized_at = Some(now);
+        self.version += 1;
+
+        Ok(())
+    }
+
+    // 強いETag（「"バージョン-ハッシュ値"」の形式）
+    pub fn etag(&self) -> String {
+        let source = format!(
+            "{}:{}:{}",
+            self.uid,
+            self.version,
+            self.updated_at
+                .to_rfc3339_opts(chrono::SecondsFormat::Nanos, true)
+        );
+        let hash = hex::encode(&Sha256::digest(source.as_bytes())[..16]);
+        format!("\"{}-{}\"", self.version, hash)
+    }
+
+    // ユーザー一覧の強いETag（各ユーザーのETagを連結したハッシュ値）
+    pub fn list_etag(users: &[User]) -> String {
+        let source = users
+            .iter()
+            .map(|user| user.etag())
+            .collect::<Vec<String>>()
+            .join("\n");
+        let hash = hex::encode(&Sha256::digest(source.as_bytes())[..16]);
+        format!("\"{}\"", hash)
+    }
+
+    // 指定されたETagのいずれかと現在のETagが一致するかどうか（強い比較）
+    pub fn matches_etag(&self, etags: &[String]) -> bool {
+        let etag = self.etag();
+        etags.contains(&etag)
     }
 }
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use crate::domain::event::domain_event::DomainEvent;
     use crate::domain::user::user_model::User;
     use chrono::{FixedOffset, Utc};
 
@@ -25,11 +26,24 @@ mod tests {
         assert_eq!(user.last_name, last_name);
         assert_eq!(user.first_name, first_name);
         assert_eq!(user.email, email);
+        assert_eq!(user.version, 1);
         assert!(
             user.created_at <= Utc::now().with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
         );
         assert_eq!(user.created_at, user.updated_at);
         assert!(user.deleted_at.is_none());
+
+        // ドメインイベントの検証
+        let events = user.clone().take_events();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            DomainEvent::UserCreated(event) => {
+                assert_eq!(event.uid, uid);
+                assert_eq!(event.email, email);
+                assert_eq!(event.occurred_at, user.created_at);
+            }
+            other => panic!("想定外のイベント: {:?}", other),
+        }
     }
 
     #[test]
@@ -66,8 +80,23 @@ mod tests {
         assert_eq!(user.last_name, new_last_name);
         assert_eq!(user.first_name, new_first_name);
         assert_eq!(user.email, new_email);
+        assert_eq!(user.version, 2);
         assert!(user.updated_at > user.created_at);
         assert!(user.deleted_at.is_none());
+
+        // ドメインイベントの検証（取り出し後は空になること）
+        let events = user.take_events();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            DomainEvent::UserProfileUpdated(event) => {
+                assert_eq!(event.uid, uid);
+                assert_eq!(event.version, 2);
+                assert_eq!(event.email, new_email);
+                assert_eq!(event.occurred_at, user.updated_at);
+            }
+            other => panic!("想定外のイベント: {:?}", other),
+        }
+        assert!(user.take_events().is_empty());
     }
 
     #[test]
@@ -110,6 +139,11 @@ mod tests {
         assert_eq!(user.email, email);
         assert!(user.updated_at == user.created_at);
         assert!(user.deleted_at.is_none());
+
+        // ドメインイベントの検証（更新イベントは記録されないこと）
+        let events = user.take_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), "user.created");
     }
 
     #[test]
@@ -136,7 +170,129 @@ mod tests {
         assert_eq!(user.last_name, last_name);
         assert_eq!(user.first_name, first_name);
         assert_eq!(user.email, email);
+        assert_eq!(user.version, 2);
         assert!(user.updated_at > user.created_at);
         assert!(user.deleted_at.is_some());
+
+        // ドメインイベントの検証
+        let events = user.take_events();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            DomainEvent::UserDeleted(event) => {
+                assert_eq!(event.uid, uid);
+                assert_eq!(event.version, 2);
+                assert_eq!(Some(event.occurred_at), user.deleted_at);
+            }
+            other => panic!("想定外のイベント: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_is_purgeable() {
+        let mut user = User::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            "テスト".to_string(),
+            "太郎".to_string(),
+            "t.test@example.com".to_string(),
+        );
+
+        // 検証（論理削除前は対象外）
+        assert!(!user.is_purgeable(user.created_at + chrono::Duration::days(1)));
+
+        // 検証（論理削除日時がcutoff以前の場合のみ対象）
+        user.set_delete();
+        let deleted_at = user.deleted_at.unwrap();
+        assert!(!user.is_purgeable(deleted_at - chrono::Duration::seconds(1)));
+        assert!(user.is_purgeable(deleted_at));
+
+        // 検証（匿名化済みは対象外）
+        user.anonymize(deleted_at).unwrap();
+        assert!(!user.is_purgeable(deleted_at + chrono::Duration::days(1)));
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let mut user = User::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            "テスト".to_string(),
+            "太郎".to_string(),
+            "t.test@example.com".to_string(),
+        );
+
+        // 検証（論理削除前はエラー）
+        assert!(user.anonymize(user.created_at).is_err());
+
+        // テスト実行
+        user.set_delete();
+        let now = user.deleted_at.unwrap();
+        user.anonymize(now).unwrap();
+
+        // 検証（uidは保持して個人情報を置き換え）
+        assert_eq!(user.uid, "xxx-xxx-xxx-0001");
+        assert_ne!(user.last_name, "テスト");
+        assert_ne!(user.first_name, "太郎");
+        assert_eq!(user.email, "xxx-xxx-xxx-0001@anonymized.invalid");
+        assert_eq!(user.version, 3);
+        assert_eq!(user.anonymized_at, Some(now));
+    }
+
+    #[test]
+    fn test_etag() {
+        let mut user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        );
+        let etag = user.etag();
+
+        // 検証（バージョンを先頭に含む強いETag、同じ値からは同じETag）
+        assert!(etag.starts_with("\"1-") && etag.ends_with('"'));
+        assert_eq!(etag.len(), 36);
+        assert_eq!(user.clone().etag(), etag);
+
+        // 更新後はバージョンとETagが変わること
+        user.update_profile(
+            "田中".to_string(),
+            "次郎".to_string(),
+            "j.tanaka@example.com".to_string(),
+        )
+        .unwrap();
+        assert!(user.etag().starts_with("\"2-"));
+        assert_ne!(user.etag(), etag);
+    }
+
+    #[test]
+    fn test_matches_etag() {
+        let user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        );
+
+        // 検証（ETag全体で比較し、バージョンのみ一致する値は一致しない）
+        assert!(user.matches_etag(&["\"xxx\"".to_string(), user.etag()]));
+        assert!(!user.matches_etag(&["\"1-xxx\"".to_string()]));
+        assert!(!user.matches_etag(&[]));
+    }
+
+    #[test]
+    fn test_list_etag() {
+        let user = User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        );
+        let etag = User::list_etag(std::slice::from_ref(&user));
+
+        // 検証（同じ一覧からは同じETag、内容が変わると別のETag）
+        assert!(etag.starts_with('"') && etag.ends_with('"'));
+        assert_eq!(User::list_etag(std::slice::from_ref(&user)), etag);
+        assert_ne!(User::list_etag(&[]), etag);
+        let mut updated_user = user.clone();
+        updated_user.updated_at += chrono::Duration::nanoseconds(1);
+        assert_ne!(User::list_etag(&[updated_user]), etag);
     }
 }
@@ -1,6 +1,9 @@
 // 共通コンテキスト
 use crate::application::usecase::context::context_request::ContextRequest;
 
+// 日時
+use chrono::{DateTime, FixedOffset};
+
 // ドメイン
 use crate::domain::{error::error_common::ErrorCommon, user::user_model::User};
 
@@ -10,5 +13,36 @@ use crate::domain::{error::error_common::ErrorCommon, user::user_model::User};
 #[mockall::automock]
 #[async_trait::async_trait]
 pub trait UserRepositoryTrait: Send + Sync {
+    // 全てのユーザー取得（論理削除済みは除く）
     async fn find_all(&self, ctx: &ContextRequest) -> Result<Vec<User>, ErrorCommon>;
+    // uidによるユーザー取得（論理削除済みは除く）
+    async fn find_by_uid(
+        &self,
+        ctx: &ContextRequest,
+        uid: &str,
+    ) -> Result<Option<User>, ErrorCommon>;
+    // メールアドレスによるユーザー取得（論理削除済みは除く）
+    async fn find_by_email(
+        &self,
+        ctx: &ContextRequest,
+        email: &str,
+    ) -> Result<Option<User>, ErrorCommon>;
+    // ユーザー作成（採番したidを設定して返す）
+    async fn create(&self, ctx: &ContextRequest, user: &User) -> Result<User, ErrorCommon>;
+    // ユーザー更新（論理削除を含む）
+    // ※保存済みのバージョンがexpected_versionと異なる場合は409エラー
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        user: &User,
+        expected_version: i64,
+    ) -> Result<User, ErrorCommon>;
+    // 論理削除日時がcutoff以前で未匿名化のユーザー取得（物理削除・匿名化の対象）
+    async fn find_purgeable(
+        &self,
+        ctx: &ContextRequest,
+        cutoff: DateTime<FixedOffset>,
+    ) -> Result<Vec<User>, ErrorCommon>;
+    // ユーザーの物理削除（論理削除済みのユーザーのみ）
+    async fn purge(&self, ctx: &ContextRequest, uid: &str) -> Result<(), ErrorCommon>;
 }
@@ -0,0 +1,10 @@
+pub mod webhook_delivery_model;
+pub mod webhook_delivery_repository;
+pub mod webhook_signature;
+pub mod webhook_subscription_model;
+pub mod webhook_subscription_repository;
+
+// テストコード用のモジュール
+pub mod webhook_delivery_model_test;
+pub mod webhook_signature_test;
+pub mod webhook_subscription_model_test;
@@ -0,0 +1,169 @@
+use chrono::{DateTime, Duration, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// ドメイン
+use crate::domain::audit::audit_log_model::REDACTED_VALUE;
+use crate::domain::outbox::outbox_model::OutboxMessage;
+use crate::domain::retry::retry_policy::RetryPolicy;
+
+// 配信状態
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    // 未配信（配信失敗後のリトライ待ちを含む）
+    Pending,
+    // 配信成功（2xxのレスポンス）
+    Succeeded,
+    // 最大試行回数を超えて配信を中止
+    Failed,
+}
+
+// 配信の試行結果（配信ログ）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct WebhookDeliveryAttempt {
+    pub attempted_at: DateTime<FixedOffset>,
+    // レスポンスのステータスコード（接続エラー等でレスポンスがない場合はNone）
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+}
+
+// Webhookの配信モデルの定義（イベントと購読設定の組み合わせごとに作成）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub delivery_id: String,
+    pub subscription_id: String,
+    pub event_id: String,
+    pub event_type: String,
+    // 送信するボディ（署名対象のため作成時に確定）
+    pub body: String,
+    pub status: WebhookDeliveryStatus,
+    // 直近の配信（再配信）開始からの試行回数
+    pub attempt_count: u32,
+    pub attempts: Vec<WebhookDeliveryAttempt>,
+    pub next_attempt_at: DateTime<FixedOffset>,
+    pub created_at: DateTime<FixedOffset>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+    // 配信中の占有期限（期限までは他の配信処理で送信しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_until: Option<DateTime<FixedOffset>>,
+    // 楽観的排他制御用のバージョン（確保・配信結果の反映の度に加算）
+    #[serde(default)]
+    pub version: i64,
+}
+
+impl WebhookDelivery {
+    // アウトボックスのメッセージから配信を作成
+    pub fn new(subscription_id: &str, message: &OutboxMessage, now: DateTime<FixedOffset>) -> Self {
+        Self {
+            id: 0,
+            delivery_id: Uuid::new_v4().to_string(),
+            subscription_id: subscription_id.to_string(),
+            event_id: message.event_id.clone(),
+            event_type: message.event_type.clone(),
+            body: message.envelope().to_string(),
+            status: WebhookDeliveryStatus::Pending,
+            attempt_count: 0,
+            attempts: Vec::new(),
+            next_attempt_at: now,
+            created_at: now,
+            delivered_at: None,
+            locked_until: None,
+            version: 1,
+        }
+    }
+
+    // 配信対象かどうか（他の配信処理で配信中の場合は除く）
+    pub fn is_due(&self, now: DateTime<FixedOffset>) -> bool {
+        self.status == WebhookDeliveryStatus::Pending
+            && self.next_attempt_at <= now
+            && !self.is_locked(now)
+    }
+
+    // 他の配信処理で配信中かどうか
+    pub fn is_locked(&self, now: DateTime<FixedOffset>) -> bool {
+        self.locked_until.is_some_and(|locked| now < locked)
+    }
+
+    // 配信の確保（占有期限を設定、保存時にバージョンで他の配信処理との競合を検出）
+    pub fn claim(&mut self, lease_secs: u64, now: DateTime<FixedOffset>) {
+        self.locked_until = Some(now + Duration::seconds(lease_secs as i64));
+        self.version += 1;
+    }
+
+    // 配信の確保の解除（配信結果の反映時）
+    fn release(&mut self) {
+        self.locked_until = None;
+        self.version += 1;
+    }
+
+    // 配信成功
+    pub fn record_success(&mut self, status_code: u16, now: DateTime<FixedOffset>) {
+        self.attempt_count += 1;
+        self.attempts.push(WebhookDeliveryAttempt {
+            attempted_at: now,
+            status_code: Some(status_code),
+            error: None,
+        });
+        self.status = WebhookDeliveryStatus::Succeeded;
+        self.delivered_at = Some(now);
+        self.release();
+    }
+
+    // 配信失敗（最大試行回数に達した場合は配信を中止）
+    pub fn record_failure(
+        &mut self,
+        status_code: Option<u16>,
+        error: &str,
+        policy: &RetryPolicy,
+        now: DateTime<FixedOffset>,
+    ) {
+        self.attempt_count += 1;
+        self.attempts.push(WebhookDeliveryAttempt {
+            attempted_at: now,
+            status_code,
+            error: Some(error.to_string()),
+        });
+        self.release();
+
+        if policy.is_exhausted(self.attempt_count) {
+            self.status = WebhookDeliveryStatus::Failed;
+            return;
+        }
+
+        let backoff_secs = policy.backoff_secs(self.attempt_count);
+        self.next_attempt_at = now + Duration::seconds(backoff_secs as i64);
+    }
+
+    // 配信の中止（購読設定が削除された場合等）
+    pub fn cancel(&mut self, reason: &str, now: DateTime<FixedOffset>) {
+        self.attempts.push(WebhookDeliveryAttempt {
+            attempted_at: now,
+            status_code: None,
+            error: Some(reason.to_string()),
+        });
+        self.status = WebhookDeliveryStatus::Failed;
+        self.release();
+    }
+
+    // 手動での再配信（試行回数をリセットして即時に配信対象とする、配信ログは保持）
+    pub fn redeliver(&mut self, now: DateTime<FixedOffset>) {
+        self.status = WebhookDeliveryStatus::Pending;
+        self.attempt_count = 0;
+        self.next_attempt_at = now;
+        self.delivered_at = None;
+    }
+
+    // 個人情報を消去したメッセージからボディを作成し直し、配信ログのエラー内容を消去
+    // ※配信中の処理が消去前の内容で保存しないようにバージョンを加算
+    pub fn scrub_personal_data(&mut self, message: &OutboxMessage) {
+        self.body = message.envelope().to_string();
+        for attempt in self.attempts.iter_mut() {
+            if attempt.error.is_some() {
+                attempt.error = Some(REDACTED_VALUE.to_string());
+            }
+        }
+        self.version += 1;
+    }
+}
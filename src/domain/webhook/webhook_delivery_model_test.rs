@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::retry::retry_policy::RetryPolicy;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::user::user_model::User;
+    use crate::domain::webhook::webhook_delivery_model::{WebhookDelivery, WebhookDeliveryStatus};
+
+    // テスト用の配信
+    fn create_delivery() -> WebhookDelivery {
+        let mut user = User::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            "テスト".to_string(),
+            "太郎".to_string(),
+            "t.test@example.com".to_string(),
+        );
+        let message = OutboxMessage::from_events(&user.take_events()).remove(0);
+        WebhookDelivery::new("sub-0001", &message, jst_now())
+    }
+
+    // テスト用のリトライ設定
+    fn create_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 2,
+            backoff_base_secs: 10,
+            backoff_max_secs: 60,
+        }
+    }
+
+    #[test]
+    fn test_new_delivery() {
+        // テスト実行
+        let delivery = create_delivery();
+
+        // 検証
+        assert_eq!(delivery.subscription_id, "sub-0001");
+        assert_eq!(delivery.event_type, "user.created");
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert!(delivery.is_due(jst_now()));
+
+        // ボディの検証
+        let body: serde_json::Value = serde_json::from_str(&delivery.body).unwrap();
+        assert_eq!(body["id"], delivery.event_id);
+        assert_eq!(body["type"], "user.created");
+        assert_eq!(body["data"]["uid"], "xxx-xxx-xxx-0001");
+        assert_eq!(body["data"]["email"], "t.test@example.com");
+        assert!(body["occurred_at"].is_string());
+    }
+
+    #[test]
+    fn test_record_success() {
+        let mut delivery = create_delivery();
+        let now = jst_now();
+
+        // テスト実行
+        delivery.record_success(204, now);
+
+        // 検証
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.attempts.len(), 1);
+        assert_eq!(delivery.attempts[0].status_code, Some(204));
+        assert_eq!(delivery.delivered_at, Some(now));
+        assert!(!delivery.is_due(now));
+    }
+
+    #[test]
+    fn test_record_failure() {
+        let mut delivery = create_delivery();
+        let policy = create_policy();
+        let now = jst_now();
+
+        // テスト実行（1回目はリトライ）
+        delivery.record_failure(Some(500), "500 Internal Server Error", &policy, now);
+
+        // 検証
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, now + Duration::seconds(10));
+        assert!(!delivery.is_due(now));
+
+        // テスト実行（最大試行回数に達して中止）
+        delivery.record_failure(None, "connection refused", &policy, now);
+
+        // 検証
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(delivery.attempts.len(), 2);
+        assert_eq!(delivery.attempts[1].status_code, None);
+    }
+
+    #[test]
+    fn test_redeliver() {
+        let mut delivery = create_delivery();
+        let policy = create_policy();
+        let now = jst_now();
+        delivery.record_failure(Some(500), "error", &policy, now);
+        delivery.record_failure(Some(500), "error", &policy, now);
+
+        // テスト実行
+        delivery.redeliver(now);
+
+        // 検証（配信ログは保持して試行回数をリセット）
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempt_count, 0);
+        assert_eq!(delivery.attempts.len(), 2);
+        assert!(delivery.is_due(now));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut delivery = create_delivery();
+
+        // テスト実行
+        delivery.cancel("subscription deleted", jst_now());
+
+        // 検証
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(
+            delivery.attempts[0].error.as_deref(),
+            Some("subscription deleted")
+        );
+    }
+
+    #[test]
+    fn test_claim() {
+        let mut delivery = create_delivery();
+        let now = jst_now();
+
+        // テスト実行（確保中は他の配信処理の配信対象外）
+        delivery.claim(60, now);
+
+        // 検証
+        assert_eq!(delivery.version, 2);
+        assert!(delivery.is_locked(now));
+        assert!(!delivery.is_due(now));
+        assert!(!delivery.is_locked(now + Duration::seconds(60)));
+        assert!(delivery.is_due(now + Duration::seconds(60)));
+
+        // 配信結果の反映で確保を解除
+        delivery.record_success(204, now);
+        assert_eq!(delivery.version, 3);
+        assert!(delivery.locked_until.is_none());
+    }
+
+    #[test]
+    fn test_scrub_personal_data() {
+        let mut user = User::new(
+            "xxx-xxx-xxx-0001".to_string(),
+            "テスト".to_string(),
+            "太郎".to_string(),
+            "t.test@example.com".to_string(),
+        );
+        let mut message = OutboxMessage::from_events(&user.take_events()).remove(0);
+        let mut delivery = WebhookDelivery::new("sub-0001", &message, jst_now());
+        delivery.record_failure(
+            Some(400),
+            "invalid email: t.test@example.com",
+            &create_policy(),
+            jst_now(),
+        );
+        delivery.record_success(204, jst_now());
+
+        // テスト実行
+        message.scrub_personal_data();
+        delivery.scrub_personal_data(&message);
+
+        // 検証（ボディと配信ログのエラー内容から個人情報が消去され、配信結果は保持される）
+        assert!(!delivery.body.contains("テスト"));
+        assert!(!delivery.body.contains("t.test@example.com"));
+        assert_eq!(delivery.body, message.envelope().to_string());
+        assert_eq!(delivery.attempts.len(), 2);
+        assert_eq!(delivery.attempts[0].status_code, Some(400));
+        assert_eq!(delivery.attempts[0].error.as_deref(), Some("[REDACTED]"));
+        assert_eq!(delivery.attempts[1].error, None);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.version, 4);
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, FixedOffset};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{
+    error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage,
+    webhook::webhook_delivery_model::WebhookDelivery,
+};
+
+// Webhookの配信リポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRepositoryTrait: Send + Sync {
+    // 配信の追加（同じイベントと購読設定の配信が登録済みの場合は追加せずに登録済みの配信を返す）
+    async fn add(
+        &self,
+        ctx: &ContextRequest,
+        delivery: &WebhookDelivery,
+    ) -> Result<WebhookDelivery, ErrorCommon>;
+    // 配信対象の取得（古い順に最大limit件）
+    async fn find_due(
+        &self,
+        ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, ErrorCommon>;
+    // delivery_idによる配信取得
+    async fn find_by_delivery_id(
+        &self,
+        ctx: &ContextRequest,
+        delivery_id: &str,
+    ) -> Result<Option<WebhookDelivery>, ErrorCommon>;
+    // 購読設定ごとの配信取得（新しい順）
+    async fn find_by_subscription_id(
+        &self,
+        ctx: &ContextRequest,
+        subscription_id: &str,
+    ) -> Result<Vec<WebhookDelivery>, ErrorCommon>;
+    // 配信の更新（配信の確保・配信結果の反映、バージョンが一致しない場合は409エラー）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        delivery: &WebhookDelivery,
+        expected_version: i64,
+    ) -> Result<WebhookDelivery, ErrorCommon>;
+    // 個人情報を消去したメッセージのイベントの配信から個人情報を消去
+    async fn scrub_personal_data(
+        &self,
+        ctx: &ContextRequest,
+        messages: &[OutboxMessage],
+    ) -> Result<(), ErrorCommon>;
+}
@@ -0,0 +1,41 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+// 署名ヘッダーの値の接頭辞
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+// 送信する内容の署名（HMAC-SHA256、署名対象は「タイムスタンプ.ボディ」）
+// ※受信側は同じ方法で計算した値と「Webhook-Signature」ヘッダーを比較して検証する
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMACは任意の長さのキーを受け付ける");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+
+    format!(
+        "{}{}",
+        SIGNATURE_PREFIX,
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+// 署名の検証（タイミング攻撃対策のため固定時間で比較）
+// ※受信側の実装例・テスト用のため、アプリケーション内では未使用
+#[allow(dead_code)]
+pub fn verify(secret: &str, timestamp: i64, body: &str, signature: &str) -> bool {
+    let Some(signature) = signature.strip_prefix(SIGNATURE_PREFIX) else {
+        return false;
+    };
+    let Ok(signature) = hex::decode(signature) else {
+        return false;
+    };
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMACは任意の長さのキーを受け付ける");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+
+    mac.verify_slice(&signature).is_ok()
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::webhook::webhook_signature::{sign, verify};
+
+    #[test]
+    fn test_sign() {
+        // テスト実行
+        let signature = sign("secret", 1700000000, r#"{"id":"1"}"#);
+
+        // 検証（同じ入力からは同じ署名、RFC 2104のHMAC-SHA256で計算した値と一致すること）
+        assert_eq!(signature, sign("secret", 1700000000, r#"{"id":"1"}"#));
+        assert_eq!(
+            sign("key", 0, "The quick brown fox jumps over the lazy dog")
+                .trim_start_matches("sha256="),
+            // HMAC-SHA256("key", "0.The quick brown fox jumps over the lazy dog")
+            hmac_reference("key", "0.The quick brown fox jumps over the lazy dog")
+        );
+        assert!(signature.starts_with("sha256="));
+        assert_eq!(signature.len(), "sha256=".len() + 64);
+    }
+
+    #[test]
+    fn test_verify() {
+        let body = r#"{"id":"1"}"#;
+        let signature = sign("secret", 1700000000, body);
+
+        // 検証
+        assert!(verify("secret", 1700000000, body, &signature));
+        assert!(!verify("other", 1700000000, body, &signature));
+        assert!(!verify("secret", 1700000001, body, &signature));
+        assert!(!verify("secret", 1700000000, r#"{"id":"2"}"#, &signature));
+        assert!(!verify("secret", 1700000000, body, "sha256=zz"));
+        assert!(!verify("secret", 1700000000, body, "invalid"));
+    }
+
+    // 比較用のHMAC-SHA256（RFC 2104の定義どおりに計算）
+    fn hmac_reference(key: &str, message: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut block = [0u8; 64];
+        block[..key.len()].copy_from_slice(key.as_bytes());
+        let ipad: Vec<u8> = block.iter().map(|b| b ^ 0x36).collect();
+        let opad: Vec<u8> = block.iter().map(|b| b ^ 0x5c).collect();
+
+        let inner = Sha256::new()
+            .chain_update(&ipad)
+            .chain_update(message.as_bytes())
+            .finalize();
+        let outer = Sha256::new()
+            .chain_update(&opad)
+            .chain_update(inner)
+            .finalize();
+        hex::encode(outer)
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// 秘密情報（Debug出力時はマスク）
+use crate::config::config_secret::Secret;
+
+// ドメイン
+use crate::domain::event::domain_event::EVENT_TYPES;
+use crate::domain::time::time_jst::jst_now;
+
+// 全てのイベント種別を購読する場合の指定
+pub const WEBHOOK_ALL_EVENTS: &str = "*";
+
+// 署名用シークレットの接頭辞
+const WEBHOOK_SECRET_PREFIX: &str = "whsec";
+
+// Webhookの購読設定モデルの定義
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct WebhookSubscription {
+    pub id: i64,
+    pub subscription_id: String,
+    pub url: String,
+    pub event_types: Vec<String>,
+    // 署名用のシークレット（作成時のレスポンスでのみ返す）
+    #[serde(skip)]
+    pub secret: Secret,
+    pub created_at: DateTime<FixedOffset>,
+    pub deleted_at: Option<DateTime<FixedOffset>>,
+}
+
+impl WebhookSubscription {
+    // 新規作成（シークレットを生成）
+    pub fn new(url: String, event_types: Vec<String>) -> Result<Self, String> {
+        // パラメータチェック
+        let mut err_msgs = Vec::new();
+        let scheme_len = if url.starts_with("https://") {
+            "https://".len()
+        } else if url.starts_with("http://") {
+            "http://".len()
+        } else {
+            0
+        };
+        if url.is_empty() {
+            err_msgs.push("urlは必須です。".to_string());
+        } else if scheme_len == 0 || url.len() == scheme_len {
+            err_msgs.push("urlはhttpまたはhttpsのURLを指定して下さい。".to_string());
+        }
+        if event_types.is_empty() {
+            err_msgs.push("event_typesは必須です。".to_string());
+        }
+        for event_type in event_types.iter() {
+            if event_type != WEBHOOK_ALL_EVENTS && !EVENT_TYPES.contains(&event_type.as_str()) {
+                err_msgs.push(format!("event_typesの値「{}」が不正です。", event_type));
+            }
+        }
+        if !err_msgs.is_empty() {
+            return Err(err_msgs.join(", "));
+        }
+
+        Ok(Self {
+            id: 0,
+            subscription_id: Uuid::new_v4().to_string(),
+            url,
+            event_types,
+            secret: Secret::new(format!(
+                "{}_{}{}",
+                WEBHOOK_SECRET_PREFIX,
+                Uuid::new_v4().simple(),
+                Uuid::new_v4().simple()
+            )),
+            created_at: jst_now(),
+            deleted_at: None,
+        })
+    }
+
+    // 購読対象のイベントかどうか
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.deleted_at.is_none()
+            && self
+                .event_types
+                .iter()
+                .any(|t| t == WEBHOOK_ALL_EVENTS || t == event_type)
+    }
+
+    // 削除設定
+    pub fn set_delete(&mut self) {
+        self.deleted_at = Some(jst_now());
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::webhook::webhook_subscription_model::WebhookSubscription;
+
+    #[test]
+    fn test_new_subscription() {
+        // テスト実行
+        let subscription = WebhookSubscription::new(
+            "https://example.com/webhooks".to_string(),
+            vec!["user.created".to_string(), "user.deleted".to_string()],
+        )
+        .unwrap();
+
+        // 検証
+        assert_eq!(subscription.id, 0);
+        assert!(!subscription.subscription_id.is_empty());
+        assert!(subscription.secret.expose().starts_with("whsec_"));
+        assert!(!format!("{:?}", subscription).contains(subscription.secret.expose()));
+        assert!(subscription.deleted_at.is_none());
+
+        // シークレットはシリアライズしないこと
+        let json = serde_json::to_value(&subscription).unwrap();
+        assert!(json.get("secret").is_none());
+    }
+
+    #[test]
+    fn test_new_subscription_error() {
+        // テスト実行
+        let result = WebhookSubscription::new(
+            "ftp://example.com".to_string(),
+            vec!["user.renamed".to_string()],
+        );
+
+        // 検証
+        assert_eq!(
+            result.unwrap_err(),
+            "urlはhttpまたはhttpsのURLを指定して下さい。, event_typesの値「user.renamed」が不正です。"
+        );
+
+        // 必須チェック
+        let result = WebhookSubscription::new("".to_string(), vec![]);
+        assert_eq!(
+            result.unwrap_err(),
+            "urlは必須です。, event_typesは必須です。"
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let mut subscription = WebhookSubscription::new(
+            "http://localhost:3000/hook".to_string(),
+            vec!["user.created".to_string()],
+        )
+        .unwrap();
+        let all = WebhookSubscription::new(
+            "http://localhost:3000/hook".to_string(),
+            vec!["*".to_string()],
+        )
+        .unwrap();
+
+        // 検証
+        assert!(subscription.matches("user.created"));
+        assert!(!subscription.matches("user.deleted"));
+        assert!(all.matches("user.deleted"));
+
+        // 削除済みの購読設定は対象外
+        subscription.set_delete();
+        assert!(!subscription.matches("user.created"));
+    }
+}
@@ -0,0 +1,34 @@
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::{
+    error::error_common::ErrorCommon, webhook::webhook_subscription_model::WebhookSubscription,
+};
+
+// Webhookの購読設定リポジトリ用のトレイト（モック化もできるように定義）
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionRepositoryTrait: Send + Sync {
+    // 全ての購読設定取得（削除済みは除く）
+    async fn find_all(&self, ctx: &ContextRequest)
+    -> Result<Vec<WebhookSubscription>, ErrorCommon>;
+    // subscription_idによる購読設定取得（削除済みは除く）
+    async fn find_by_subscription_id(
+        &self,
+        ctx: &ContextRequest,
+        subscription_id: &str,
+    ) -> Result<Option<WebhookSubscription>, ErrorCommon>;
+    // 購読設定作成（採番したidを設定して返す）
+    async fn create(
+        &self,
+        ctx: &ContextRequest,
+        subscription: &WebhookSubscription,
+    ) -> Result<WebhookSubscription, ErrorCommon>;
+    // 購読設定更新（削除を含む）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        subscription: &WebhookSubscription,
+    ) -> Result<WebhookSubscription, ErrorCommon>;
+}
@@ -0,0 +1,225 @@
+use axum::http::StatusCode;
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
+    jwk::JwkSet,
+};
+use serde::{Deserialize, Serialize};
+
+// 認証トークン用のトレイト
+use crate::application::usecase::auth::auth_token_trait::{AccessToken, AuthTokenTrait};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::AuthContext;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// コンフィグ
+use crate::config::config_settings::Config;
+
+// JWTのクレーム
+#[derive(Deserialize, Debug)]
+struct Claims {
+    sub: String,
+    // スペース区切りのスコープ
+    #[serde(default)]
+    scope: String,
+    // ロール名の配列
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+// 発行するJWTのクレーム
+#[derive(Serialize, Debug)]
+struct IssueClaims<'a> {
+    sub: &'a str,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    scope: String,
+    roles: &'a [String],
+    iat: i64,
+    exp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iss: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aud: Option<&'a str>,
+}
+
+// JWT認証の構造体
+pub struct AuthJwt {
+    hs256_key: Option<DecodingKey>,
+    // アクセストークン発行用の鍵（HS256の共通鍵）
+    hs256_encoding_key: Option<EncodingKey>,
+    access_token_ttl_secs: i64,
+    rs256_key: Option<DecodingKey>,
+    jwks: Option<JwkSet>,
+    issuer: Option<String>,
+    audience: Option<String>,
+}
+
+impl AuthJwt {
+    // 初期化用メソッド（コンフィグの鍵設定を読み込む）
+    pub fn new(config: &Config) -> Result<Self, String> {
+        let hs256_key = config
+            .jwt_hs256_secret
+            .as_ref()
+            .map(|secret| DecodingKey::from_secret(secret.expose().as_bytes()));
+        let hs256_encoding_key = config
+            .jwt_hs256_secret
+            .as_ref()
+            .map(|secret| EncodingKey::from_secret(secret.expose().as_bytes()));
+
+        let rs256_key = match &config.jwt_rs256_public_key_file {
+            Some(file_path) => {
+                let pem = match std::fs::read(file_path) {
+                    Ok(pem) => pem,
+                    Err(err) => {
+                        return Err(format!("公開鍵ファイルの読み込みエラー: {}", err));
+                    }
+                };
+                match DecodingKey::from_rsa_pem(&pem) {
+                    Ok(key) => Some(key),
+                    Err(err) => return Err(format!("公開鍵の形式が不正です: {}", err)),
+                }
+            }
+            None => None,
+        };
+
+        let jwks = match &config.jwt_jwks_file {
+            Some(file_path) => {
+                let content = match std::fs::read_to_string(file_path) {
+                    Ok(content) => content,
+                    Err(err) => return Err(format!("JWKSファイルの読み込みエラー: {}", err)),
+                };
+                match serde_json::from_str::<JwkSet>(&content) {
+                    Ok(jwks) => Some(jwks),
+                    Err(err) => return Err(format!("JWKSの形式が不正です: {}", err)),
+                }
+            }
+            None => None,
+        };
+
+        if hs256_key.is_none() && rs256_key.is_none() && jwks.is_none() {
+            log::warn!("JWTの検証鍵が設定されていないため、全ての認証が失敗します");
+        }
+
+        Ok(AuthJwt {
+            hs256_key,
+            hs256_encoding_key,
+            access_token_ttl_secs: config.jwt_access_token_ttl_secs,
+            rs256_key,
+            jwks,
+            issuer: config.jwt_issuer.clone(),
+            audience: config.jwt_audience.clone(),
+        })
+    }
+
+    // 401エラーの作成
+    fn unauthorized(message: &str) -> ErrorCommon {
+        ErrorCommon::CustomError {
+            status_code: StatusCode::UNAUTHORIZED,
+            message: message.to_string(),
+        }
+    }
+
+    // ヘッダーの署名方式とkidから検証鍵を取得
+    fn get_decoding_key(&self, alg: Algorithm, kid: Option<&str>) -> Option<DecodingKey> {
+        match alg {
+            Algorithm::HS256 => self.hs256_key.clone(),
+            Algorithm::RS256 => {
+                // JWKSが設定されている場合はkidで鍵を検索
+                let jwk = match (&self.jwks, kid) {
+                    (Some(jwks), Some(kid)) => jwks.find(kid),
+                    _ => None,
+                };
+                if let Some(jwk) = jwk {
+                    return DecodingKey::from_jwk(jwk).ok();
+                }
+                self.rs256_key.clone()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl AuthTokenTrait for AuthJwt {
+    fn verify(&self, token: &str) -> Result<AuthContext, ErrorCommon> {
+        // ヘッダーの取得
+        let header = match decode_header(token) {
+            Ok(header) => header,
+            Err(_) => return Err(AuthJwt::unauthorized("トークンの形式が不正です。")),
+        };
+
+        // 検証鍵の取得
+        let key = match self.get_decoding_key(header.alg, header.kid.as_deref()) {
+            Some(key) => key,
+            None => {
+                return Err(AuthJwt::unauthorized(
+                    "トークンの署名方式または鍵が不正です。",
+                ));
+            }
+        };
+
+        // 検証内容の設定
+        let mut validation = Validation::new(header.alg);
+        match &self.issuer {
+            Some(issuer) => validation.set_issuer(&[issuer]),
+            None => validation.iss = None,
+        }
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        // トークンの検証
+        let data = match decode::<Claims>(token, &key, &validation) {
+            Ok(data) => data,
+            Err(_) => return Err(AuthJwt::unauthorized("トークンが無効です。")),
+        };
+
+        Ok(AuthContext {
+            subject: data.claims.sub,
+            scopes: data
+                .claims
+                .scope
+                .split_whitespace()
+                .map(|s| s.to_string())
+                .collect(),
+            roles: data.claims.roles,
+        })
+    }
+
+    fn issue(&self, auth: &AuthContext) -> Result<AccessToken, ErrorCommon> {
+        // 発行用の鍵の取得
+        let key = match &self.hs256_encoding_key {
+            Some(key) => key,
+            None => {
+                log::error!("JWT（HS256）の共通鍵が設定されていないため、トークンを発行できません");
+                return Err(ErrorCommon::InternalServerError);
+            }
+        };
+
+        // クレームの設定
+        let now = chrono::Utc::now().timestamp();
+        let claims = IssueClaims {
+            sub: &auth.subject,
+            scope: auth.scopes.join(" "),
+            roles: &auth.roles,
+            iat: now,
+            exp: now + self.access_token_ttl_secs,
+            iss: self.issuer.as_deref(),
+            aud: self.audience.as_deref(),
+        };
+
+        // トークンの発行
+        match encode(&Header::new(Algorithm::HS256), &claims, key) {
+            Ok(token) => Ok(AccessToken {
+                token,
+                expires_in: self.access_token_ttl_secs,
+            }),
+            Err(err) => {
+                log::error!("トークンの発行エラー: {}", err);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+}
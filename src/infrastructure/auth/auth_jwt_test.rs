@@ -0,0 +1,333 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+    use serde_json::json;
+
+    // 認証トークン用のトレイト
+    use crate::application::usecase::auth::auth_token_trait::AuthTokenTrait;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::AuthContext;
+
+    // コンフィグ
+    use crate::config::{config_secret::Secret, config_settings::Config};
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+
+    // JWT認証
+    use crate::infrastructure::auth::auth_jwt::AuthJwt;
+
+    // テスト用のヘルパー
+    use crate::infrastructure::auth::auth_test_helper::{
+        TEST_RSA_MODULUS, TEST_RSA_PRIVATE_KEY, TEST_RSA_PUBLIC_KEY,
+    };
+
+    // テスト用の共通鍵
+    const TEST_HS256_SECRET: &str = "test-hs256-secret";
+
+    // 有効期限（現在時刻から1時間後）
+    fn exp() -> i64 {
+        chrono::Utc::now().timestamp() + 3600
+    }
+
+    // テスト用ファイルの作成
+    fn write_temp_file(name: &str, content: &str) -> String {
+        let file_path = std::env::temp_dir().join(name);
+        std::fs::write(&file_path, content).unwrap();
+        file_path.to_str().unwrap().to_string()
+    }
+
+    // 401エラーかどうか
+    fn is_unauthorized(err: &ErrorCommon) -> bool {
+        matches!(
+            err,
+            ErrorCommon::CustomError {
+                status_code: StatusCode::UNAUTHORIZED,
+                ..
+            }
+        )
+    }
+
+    #[test]
+    fn test_verify_hs256_success() {
+        // JWT認証のインスタンス化
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new(TEST_HS256_SECRET.to_string())),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+
+        // トークンの作成
+        let claims = json!({
+            "sub": "xxxx-xxxx-xxxx-0001",
+            "scope": "users:read users:write",
+            "roles": ["admin"],
+            "exp": exp(),
+        });
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(TEST_HS256_SECRET.as_bytes()),
+        )
+        .unwrap();
+
+        // テスト実行
+        let result = auth_jwt.verify(&token);
+
+        // 検証
+        assert!(result.is_ok());
+        let auth = result.unwrap();
+        assert_eq!(auth.subject, "xxxx-xxxx-xxxx-0001");
+        assert_eq!(auth.scopes, vec!["users:read", "users:write"]);
+        assert_eq!(auth.roles, vec!["admin"]);
+    }
+
+    #[test]
+    fn test_verify_hs256_invalid_signature() {
+        // JWT認証のインスタンス化
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new(TEST_HS256_SECRET.to_string())),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+
+        // 異なる鍵でトークンを作成
+        let claims = json!({ "sub": "xxxx-xxxx-xxxx-0001", "exp": exp() });
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(b"other-secret"),
+        )
+        .unwrap();
+
+        // テスト実行
+        let result = auth_jwt.verify(&token);
+
+        // 検証
+        assert!(result.is_err());
+        assert!(is_unauthorized(&result.unwrap_err()));
+    }
+
+    #[test]
+    fn test_verify_hs256_expired() {
+        // JWT認証のインスタンス化
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new(TEST_HS256_SECRET.to_string())),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+
+        // 有効期限切れのトークンを作成
+        let claims =
+            json!({ "sub": "xxxx-xxxx-xxxx-0001", "exp": chrono::Utc::now().timestamp() - 3600 });
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(TEST_HS256_SECRET.as_bytes()),
+        )
+        .unwrap();
+
+        // テスト実行
+        let result = auth_jwt.verify(&token);
+
+        // 検証
+        assert!(result.is_err());
+        assert!(is_unauthorized(&result.unwrap_err()));
+    }
+
+    #[test]
+    fn test_verify_issuer_and_audience() {
+        // JWT認証のインスタンス化
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new(TEST_HS256_SECRET.to_string())),
+            jwt_issuer: Some("https://auth.example.com".to_string()),
+            jwt_audience: Some("rust-axum-domain".to_string()),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+        let key = EncodingKey::from_secret(TEST_HS256_SECRET.as_bytes());
+
+        // 発行者と対象者が一致するトークン
+        let claims = json!({
+            "sub": "xxxx-xxxx-xxxx-0001",
+            "iss": "https://auth.example.com",
+            "aud": "rust-axum-domain",
+            "exp": exp(),
+        });
+        let token = encode(&Header::new(Algorithm::HS256), &claims, &key).unwrap();
+        assert!(auth_jwt.verify(&token).is_ok());
+
+        // 発行者が異なるトークン
+        let claims = json!({
+            "sub": "xxxx-xxxx-xxxx-0001",
+            "iss": "https://other.example.com",
+            "aud": "rust-axum-domain",
+            "exp": exp(),
+        });
+        let token = encode(&Header::new(Algorithm::HS256), &claims, &key).unwrap();
+        assert!(auth_jwt.verify(&token).is_err());
+    }
+
+    #[test]
+    fn test_verify_rs256_success() {
+        // JWT認証のインスタンス化
+        let file_path = write_temp_file("rust_axum_domain_test_rsa_pub.pem", TEST_RSA_PUBLIC_KEY);
+        let config = Config {
+            jwt_rs256_public_key_file: Some(file_path),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+
+        // トークンの作成
+        let claims = json!({ "sub": "xxxx-xxxx-xxxx-0002", "exp": exp() });
+        let token = encode(
+            &Header::new(Algorithm::RS256),
+            &claims,
+            &EncodingKey::from_rsa_pem(TEST_RSA_PRIVATE_KEY.as_bytes()).unwrap(),
+        )
+        .unwrap();
+
+        // テスト実行
+        let result = auth_jwt.verify(&token);
+
+        // 検証
+        assert!(result.is_ok());
+        let auth = result.unwrap();
+        assert_eq!(auth.subject, "xxxx-xxxx-xxxx-0002");
+        assert!(auth.scopes.is_empty());
+        assert!(auth.roles.is_empty());
+    }
+
+    #[test]
+    fn test_verify_rs256_with_jwks_success() {
+        // JWT認証のインスタンス化
+        let jwks = json!({
+            "keys": [{
+                "kty": "RSA",
+                "kid": "test-key-1",
+                "alg": "RS256",
+                "use": "sig",
+                "n": TEST_RSA_MODULUS,
+                "e": "AQAB",
+            }]
+        });
+        let file_path = write_temp_file("rust_axum_domain_test_jwks.json", &jwks.to_string());
+        let config = Config {
+            jwt_jwks_file: Some(file_path),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+
+        // トークンの作成
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some("test-key-1".to_string());
+        let claims = json!({ "sub": "xxxx-xxxx-xxxx-0003", "exp": exp() });
+        let token = encode(
+            &header,
+            &claims,
+            &EncodingKey::from_rsa_pem(TEST_RSA_PRIVATE_KEY.as_bytes()).unwrap(),
+        )
+        .unwrap();
+
+        // テスト実行
+        let result = auth_jwt.verify(&token);
+
+        // 検証
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().subject, "xxxx-xxxx-xxxx-0003");
+    }
+
+    #[test]
+    fn test_verify_algorithm_not_configured() {
+        // HS256の鍵のみ設定
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new(TEST_HS256_SECRET.to_string())),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+
+        // RS256のトークンを作成
+        let claims = json!({ "sub": "xxxx-xxxx-xxxx-0001", "exp": exp() });
+        let token = encode(
+            &Header::new(Algorithm::RS256),
+            &claims,
+            &EncodingKey::from_rsa_pem(TEST_RSA_PRIVATE_KEY.as_bytes()).unwrap(),
+        )
+        .unwrap();
+
+        // テスト実行
+        let result = auth_jwt.verify(&token);
+
+        // 検証
+        assert!(result.is_err());
+        assert!(is_unauthorized(&result.unwrap_err()));
+    }
+
+    #[test]
+    fn test_verify_malformed_token() {
+        // JWT認証のインスタンス化
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new(TEST_HS256_SECRET.to_string())),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+
+        // テスト実行
+        let result = auth_jwt.verify("xxx.yyy.zzz");
+
+        // 検証
+        assert!(result.is_err());
+        assert!(is_unauthorized(&result.unwrap_err()));
+    }
+
+    #[test]
+    fn test_issue_and_verify() {
+        // JWT認証のインスタンス化
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new(TEST_HS256_SECRET.to_string())),
+            jwt_issuer: Some("rust-axum-domain".to_string()),
+            jwt_access_token_ttl_secs: 600,
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+        let auth = AuthContext {
+            subject: "xxxx-xxxx-xxxx-0001".to_string(),
+            scopes: vec!["users:read".to_string()],
+            roles: vec!["operator".to_string()],
+        };
+
+        // テスト実行
+        let access_token = auth_jwt.issue(&auth).unwrap();
+
+        // 検証（発行したトークンを検証できること）
+        assert_eq!(access_token.expires_in, 600);
+        let result = auth_jwt.verify(&access_token.token).unwrap();
+        assert_eq!(result, auth);
+    }
+
+    #[test]
+    fn test_issue_key_not_configured() {
+        // JWT認証のインスタンス化（共通鍵なし）
+        let config = Config {
+            jwt_rs256_public_key_file: Some(write_temp_file(
+                "auth_jwt_test_issue_public.pem",
+                TEST_RSA_PUBLIC_KEY,
+            )),
+            ..Default::default()
+        };
+        let auth_jwt = AuthJwt::new(&config).unwrap();
+        let auth = AuthContext {
+            subject: "xxxx-xxxx-xxxx-0001".to_string(),
+            scopes: vec![],
+            roles: vec![],
+        };
+
+        // テスト実行
+        let result = auth_jwt.issue(&auth);
+
+        // 検証
+        assert!(matches!(result, Err(ErrorCommon::InternalServerError)));
+    }
+}
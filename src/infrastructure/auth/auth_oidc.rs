@@ -0,0 +1,240 @@
+use axum::http::StatusCode;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header, jwk::JwkSet};
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+// OIDCクライアント用のトレイト
+use crate::application::usecase::auth::auth_oidc_trait::{AuthOidcTrait, OidcUserInfo};
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// コンフィグ
+use crate::config::{config_secret::Secret, config_settings::Config};
+
+// IDトークンの署名方式として許可するアルゴリズム
+const ALLOWED_ALGORITHMS: [Algorithm; 3] = [Algorithm::RS256, Algorithm::PS256, Algorithm::ES256];
+
+// ディスカバリー（/.well-known/openid-configuration）の内容
+#[derive(Deserialize, Clone, Debug)]
+struct OidcDiscovery {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+// トークンエンドポイントのレスポンス
+#[derive(Deserialize, Debug)]
+struct TokenResponse {
+    id_token: String,
+}
+
+// IDトークンのクレーム
+#[derive(Deserialize, Debug)]
+struct IdTokenClaims {
+    sub: String,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    email_verified: Option<bool>,
+    #[serde(default)]
+    given_name: Option<String>,
+    #[serde(default)]
+    family_name: Option<String>,
+    #[serde(default)]
+    nonce: Option<String>,
+}
+
+// OIDCクライアントの構造体
+pub struct AuthOidc {
+    issuer_url: String,
+    client_id: String,
+    client_secret: Option<Secret>,
+    redirect_uri: String,
+    http: reqwest::Client,
+    // ディスカバリーの内容（初回利用時に取得してキャッシュ）
+    discovery: OnceCell<OidcDiscovery>,
+}
+
+impl AuthOidc {
+    // 初期化用メソッド（必要な設定が揃っていない場合はNoneを返す）
+    pub fn new(config: &Config) -> Option<Self> {
+        match (
+            &config.oidc_issuer_url,
+            &config.oidc_client_id,
+            &config.oidc_redirect_uri,
+        ) {
+            (Some(issuer_url), Some(client_id), Some(redirect_uri)) => Some(AuthOidc {
+                issuer_url: issuer_url.trim_end_matches('/').to_string(),
+                client_id: client_id.clone(),
+                client_secret: config.oidc_client_secret.clone(),
+                redirect_uri: redirect_uri.clone(),
+                http: reqwest::Client::new(),
+                discovery: OnceCell::new(),
+            }),
+            _ => None,
+        }
+    }
+
+    // 502エラーの作成（IDプロバイダーとの通信エラー）
+    fn bad_gateway(err: impl std::fmt::Display) -> ErrorCommon {
+        log::error!("IDプロバイダーとの通信エラー: {}", err);
+        ErrorCommon::CustomError {
+            status_code: StatusCode::BAD_GATEWAY,
+            message: "IDプロバイダーとの通信に失敗しました。".to_string(),
+        }
+    }
+
+    // 401エラーの作成
+    fn unauthorized(message: &str) -> ErrorCommon {
+        ErrorCommon::CustomError {
+            status_code: StatusCode::UNAUTHORIZED,
+            message: message.to_string(),
+        }
+    }
+
+    // JSONの取得
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, ErrorCommon> {
+        let res = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .map_err(AuthOidc::bad_gateway)?;
+        let res = res.error_for_status().map_err(AuthOidc::bad_gateway)?;
+        res.json::<T>().await.map_err(AuthOidc::bad_gateway)
+    }
+
+    // ディスカバリーの取得（発行者が設定値と一致しない場合はエラー）
+    async fn discovery(&self) -> Result<&OidcDiscovery, ErrorCommon> {
+        self.discovery
+            .get_or_try_init(|| async {
+                let url = format!("{}/.well-known/openid-configuration", self.issuer_url);
+                let discovery = self.get_json::<OidcDiscovery>(&url).await?;
+                if discovery.issuer.trim_end_matches('/') != self.issuer_url {
+                    return Err(AuthOidc::bad_gateway(format!(
+                        "ディスカバリーのissuer「{}」が設定値「{}」と一致しません",
+                        discovery.issuer, self.issuer_url
+                    )));
+                }
+                Ok(discovery)
+            })
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthOidcTrait for AuthOidc {
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String, ErrorCommon> {
+        let discovery = self.discovery().await?;
+
+        let mut url = reqwest::Url::parse(&discovery.authorization_endpoint)
+            .map_err(AuthOidc::bad_gateway)?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("scope", "openid email profile")
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("code_challenge", code_challenge)
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(url.to_string())
+    }
+
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcUserInfo, ErrorCommon> {
+        let discovery = self.discovery().await?;
+
+        // 認可コードをトークンに交換
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.redirect_uri),
+            ("client_id", &self.client_id),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(client_secret) = &self.client_secret {
+            form.push(("client_secret", client_secret.expose()));
+        }
+        let res = self
+            .http
+            .post(&discovery.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(AuthOidc::bad_gateway)?;
+        if res.status().is_client_error() {
+            return Err(AuthOidc::unauthorized("認可コードが無効です。"));
+        }
+        let res = res.error_for_status().map_err(AuthOidc::bad_gateway)?;
+        let token = res
+            .json::<TokenResponse>()
+            .await
+            .map_err(AuthOidc::bad_gateway)?;
+
+        // IDトークンの検証鍵の取得（鍵のローテーションに対応するため毎回取得）
+        let header = match decode_header(&token.id_token) {
+            Ok(header) => header,
+            Err(_) => return Err(AuthOidc::unauthorized("IDトークンの形式が不正です。")),
+        };
+        if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+            return Err(AuthOidc::unauthorized("IDトークンの署名方式が不正です。"));
+        }
+        let jwks = self.get_json::<JwkSet>(&discovery.jwks_uri).await?;
+        let jwk = match header.kid.as_deref() {
+            Some(kid) => jwks.find(kid),
+            None => jwks.keys.first(),
+        };
+        let key = match jwk.and_then(|jwk| DecodingKey::from_jwk(jwk).ok()) {
+            Some(key) => key,
+            None => {
+                return Err(AuthOidc::unauthorized(
+                    "IDトークンの検証鍵が見つかりません。",
+                ));
+            }
+        };
+
+        // IDトークンの検証（署名・発行者・対象者・有効期限）
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&discovery.issuer]);
+        validation.set_audience(&[&self.client_id]);
+        let claims = match decode::<IdTokenClaims>(&token.id_token, &key, &validation) {
+            Ok(data) => data.claims,
+            Err(_) => return Err(AuthOidc::unauthorized("IDトークンが無効です。")),
+        };
+
+        // nonceの検証
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(AuthOidc::unauthorized("IDトークンのnonceが一致しません。"));
+        }
+
+        let email = match claims.email {
+            Some(email) => email,
+            None => {
+                return Err(AuthOidc::unauthorized(
+                    "IDトークンにメールアドレスが含まれていません。",
+                ));
+            }
+        };
+
+        Ok(OidcUserInfo {
+            subject: claims.sub,
+            email,
+            email_verified: claims.email_verified,
+            given_name: claims.given_name,
+            family_name: claims.family_name,
+        })
+    }
+}
@@ -0,0 +1,240 @@
+#[cfg(test)]
+mod tests {
+    use axum::{Form, Json, Router, extract::State, http::StatusCode, routing::get, routing::post};
+    use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    // OIDCクライアント用のトレイト
+    use crate::application::usecase::auth::auth_oidc_trait::AuthOidcTrait;
+
+    // コンフィグ
+    use crate::config::config_settings::Config;
+
+    // ドメイン
+    use crate::domain::error::error_common::ErrorCommon;
+
+    // OIDCクライアント
+    use crate::infrastructure::auth::auth_oidc::AuthOidc;
+
+    // テスト用のヘルパー
+    use crate::infrastructure::auth::auth_test_helper::{TEST_RSA_MODULUS, TEST_RSA_PRIVATE_KEY};
+
+    // テスト用の設定値
+    const TEST_CLIENT_ID: &str = "test-client";
+    const TEST_CODE: &str = "test-code";
+    const TEST_CODE_VERIFIER: &str = "test-code-verifier";
+    const TEST_NONCE: &str = "test-nonce";
+
+    // モックのIDプロバイダーを起動してベースURLを返す
+    async fn start_mock_provider() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/mismatch/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/token", post(token))
+            .with_state(base_url.clone());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        base_url
+    }
+
+    // ディスカバリー
+    async fn discovery(State(base_url): State<String>) -> Json<serde_json::Value> {
+        Json(json!({
+            "issuer": base_url,
+            "authorization_endpoint": format!("{}/authorize", base_url),
+            "token_endpoint": format!("{}/token", base_url),
+            "jwks_uri": format!("{}/jwks", base_url),
+        }))
+    }
+
+    // JWKS
+    async fn jwks() -> Json<serde_json::Value> {
+        Json(json!({
+            "keys": [{
+                "kty": "RSA",
+                "kid": "mock-key-1",
+                "alg": "RS256",
+                "use": "sig",
+                "n": TEST_RSA_MODULUS,
+                "e": "AQAB",
+            }]
+        }))
+    }
+
+    // トークンエンドポイント（認可コードとcode_verifierが一致する場合のみIDトークンを発行）
+    async fn token(
+        State(base_url): State<String>,
+        Form(form): Form<HashMap<String, String>>,
+    ) -> Result<Json<serde_json::Value>, StatusCode> {
+        if form.get("grant_type").map(String::as_str) != Some("authorization_code")
+            || form.get("code").map(String::as_str) != Some(TEST_CODE)
+            || form.get("code_verifier").map(String::as_str) != Some(TEST_CODE_VERIFIER)
+        {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some("mock-key-1".to_string());
+        let claims = json!({
+            "iss": base_url,
+            "aud": TEST_CLIENT_ID,
+            "sub": "idp-user-0001",
+            "email": "h.suzuki@example.com",
+            "email_verified": true,
+            "given_name": "花子",
+            "family_name": "鈴木",
+            "nonce": TEST_NONCE,
+            "exp": chrono::Utc::now().timestamp() + 3600,
+        });
+        let id_token = encode(
+            &header,
+            &claims,
+            &EncodingKey::from_rsa_pem(TEST_RSA_PRIVATE_KEY.as_bytes()).unwrap(),
+        )
+        .unwrap();
+
+        Ok(Json(json!({
+            "access_token": "idp-access-token",
+            "token_type": "Bearer",
+            "id_token": id_token,
+        })))
+    }
+
+    // OIDCクライアントの作成
+    fn create_auth_oidc(base_url: &str) -> AuthOidc {
+        let config = Config {
+            oidc_issuer_url: Some(base_url.to_string()),
+            oidc_client_id: Some(TEST_CLIENT_ID.to_string()),
+            oidc_redirect_uri: Some("http://localhost:8080/api/v1/auth/oidc/callback".to_string()),
+            ..Default::default()
+        };
+        AuthOidc::new(&config).unwrap()
+    }
+
+    #[test]
+    fn test_new_not_configured() {
+        // テスト実行・検証
+        assert!(AuthOidc::new(&Config::default()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_authorization_url() {
+        let base_url = start_mock_provider().await;
+        let auth_oidc = create_auth_oidc(&base_url);
+
+        // テスト実行
+        let url = auth_oidc
+            .authorization_url("test-state", TEST_NONCE, "test-challenge")
+            .await
+            .unwrap();
+
+        // 検証
+        let url = reqwest::Url::parse(&url).unwrap();
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert!(
+            url.as_str()
+                .starts_with(&format!("{}/authorize?", base_url))
+        );
+        assert_eq!(params["response_type"], "code");
+        assert_eq!(params["client_id"], TEST_CLIENT_ID);
+        assert_eq!(params["scope"], "openid email profile");
+        assert_eq!(params["state"], "test-state");
+        assert_eq!(params["nonce"], TEST_NONCE);
+        assert_eq!(params["code_challenge"], "test-challenge");
+        assert_eq!(params["code_challenge_method"], "S256");
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_success() {
+        let base_url = start_mock_provider().await;
+        let auth_oidc = create_auth_oidc(&base_url);
+
+        // テスト実行
+        let user_info = auth_oidc
+            .exchange_code(TEST_CODE, TEST_CODE_VERIFIER, TEST_NONCE)
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(user_info.subject, "idp-user-0001");
+        assert_eq!(user_info.email, "h.suzuki@example.com");
+        assert_eq!(user_info.email_verified, Some(true));
+        assert_eq!(user_info.given_name.as_deref(), Some("花子"));
+        assert_eq!(user_info.family_name.as_deref(), Some("鈴木"));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_nonce_mismatch() {
+        let base_url = start_mock_provider().await;
+        let auth_oidc = create_auth_oidc(&base_url);
+
+        // テスト実行
+        let result = auth_oidc
+            .exchange_code(TEST_CODE, TEST_CODE_VERIFIER, "other-nonce")
+            .await;
+
+        // 検証
+        assert_eq!(result.unwrap_err().status_code(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_invalid_code_verifier() {
+        let base_url = start_mock_provider().await;
+        let auth_oidc = create_auth_oidc(&base_url);
+
+        // テスト実行
+        let result = auth_oidc
+            .exchange_code(TEST_CODE, "wrong-code-verifier", TEST_NONCE)
+            .await;
+
+        // 検証
+        assert_eq!(result.unwrap_err().status_code(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code_provider_unavailable() {
+        // 起動していないIDプロバイダー
+        let auth_oidc = create_auth_oidc("http://127.0.0.1:1");
+
+        // テスト実行
+        let result = auth_oidc
+            .exchange_code(TEST_CODE, TEST_CODE_VERIFIER, TEST_NONCE)
+            .await;
+
+        // 検証
+        assert!(matches!(
+            result,
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_GATEWAY,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_discovery_issuer_mismatch() {
+        let base_url = start_mock_provider().await;
+        let auth_oidc = create_auth_oidc(&format!("{}/mismatch", base_url));
+
+        // テスト実行（ディスカバリーのissuerが設定値と異なる）
+        let result = auth_oidc
+            .authorization_url("test-state", TEST_NONCE, "test-challenge")
+            .await;
+
+        // 検証
+        assert!(matches!(
+            result,
+            Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_GATEWAY,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+};
+use std::sync::OnceLock;
+use tokio::task::spawn_blocking;
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+// パスワードハッシュ用のトレイト
+use crate::application::usecase::auth::auth_password_trait::AuthPasswordTrait;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// ダミーの検証に使用するパスワード
+const DUMMY_PASSWORD: &str = "dummy-password-for-timing";
+
+// ダミーの検証に使用するハッシュ値（初回利用時に同じパラメータで生成）
+static DUMMY_PASSWORD_HASH: OnceLock<String> = OnceLock::new();
+
+// argon2idによるパスワードハッシュの構造体
+#[derive(Default)]
+pub struct AuthPasswordArgon2 {
+    argon2: Argon2<'static>,
+}
+
+impl AuthPasswordArgon2 {
+    // 初期化用メソッド（argon2idの推奨パラメータを使用）
+    pub fn new() -> Self {
+        AuthPasswordArgon2 {
+            argon2: Argon2::default(),
+        }
+    }
+}
+
+// パスワードのハッシュ化（CPU・メモリの負荷が高いため、spawn_blocking内で実行する）
+fn hash_password(argon2: &Argon2<'static>, password: &str) -> Result<String, ErrorCommon> {
+    // ソルトの生成（UUID v4の乱数を利用）
+    let salt = match SaltString::encode_b64(Uuid::new_v4().as_bytes()) {
+        Ok(salt) => salt,
+        Err(err) => {
+            log::error!("ソルトの生成エラー: {}", err);
+            return Err(ErrorCommon::InternalServerError);
+        }
+    };
+
+    // PHC文字列形式でハッシュ値を返す
+    match argon2.hash_password(password.as_bytes(), &salt) {
+        Ok(password_hash) => Ok(password_hash.to_string()),
+        Err(err) => {
+            log::error!("パスワードのハッシュ化エラー: {}", err);
+            Err(ErrorCommon::InternalServerError)
+        }
+    }
+}
+
+// パスワードの検証（CPU・メモリの負荷が高いため、spawn_blocking内で実行する）
+fn verify_password(argon2: &Argon2<'static>, password: &str, password_hash: &str) -> bool {
+    match PasswordHash::new(password_hash) {
+        Ok(parsed_hash) => argon2
+            .verify_password(password.as_bytes(), &parsed_hash)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+// ※非同期ランタイムのワーカースレッドを占有しないように、argon2idの処理はブロッキング用のスレッドで実行する
+#[async_trait::async_trait]
+impl AuthPasswordTrait for AuthPasswordArgon2 {
+    async fn hash(&self, password: &str) -> Result<String, ErrorCommon> {
+        let argon2 = self.argon2.clone();
+        let password = Zeroizing::new(password.to_string());
+
+        match spawn_blocking(move || hash_password(&argon2, &password)).await {
+            Ok(result) => result,
+            Err(err) => {
+                log::error!("パスワードのハッシュ化処理の実行エラー: {}", err);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    async fn verify(&self, password: &str, password_hash: &str) -> bool {
+        let argon2 = self.argon2.clone();
+        let password = Zeroizing::new(password.to_string());
+        let password_hash = password_hash.to_string();
+
+        match spawn_blocking(move || verify_password(&argon2, &password, &password_hash)).await {
+            Ok(is_valid) => is_valid,
+            Err(err) => {
+                log::error!("パスワードの検証処理の実行エラー: {}", err);
+                false
+            }
+        }
+    }
+
+    async fn dummy_verify(&self, password: &str) {
+        let argon2 = self.argon2.clone();
+        let password = Zeroizing::new(password.to_string());
+
+        let result = spawn_blocking(move || {
+            let dummy_hash = DUMMY_PASSWORD_HASH
+                .get_or_init(|| hash_password(&argon2, DUMMY_PASSWORD).unwrap_or_default());
+            let _ = verify_password(&argon2, &password, dummy_hash);
+        })
+        .await;
+        if let Err(err) = result {
+            log::error!("ダミーの検証処理の実行エラー: {}", err);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    // パスワードハッシュ用のトレイト
+    use crate::application::usecase::auth::auth_password_trait::AuthPasswordTrait;
+
+    // パスワードハッシュ
+    use crate::infrastructure::auth::auth_password::AuthPasswordArgon2;
+
+    #[tokio::test]
+    async fn test_hash_and_verify() {
+        let auth_password = AuthPasswordArgon2::new();
+
+        // テスト実行
+        let password_hash = auth_password.hash("password-0001").await.unwrap();
+
+        // 検証
+        assert!(password_hash.starts_with("$argon2id$"));
+        assert!(auth_password.verify("password-0001", &password_hash).await);
+        assert!(!auth_password.verify("password-0002", &password_hash).await);
+    }
+
+    #[tokio::test]
+    async fn test_hash_with_different_salt() {
+        let auth_password = AuthPasswordArgon2::new();
+
+        // テスト実行
+        let hash_1 = auth_password.hash("password-0001").await.unwrap();
+        let hash_2 = auth_password.hash("password-0001").await.unwrap();
+
+        // 検証
+        assert_ne!(hash_1, hash_2);
+    }
+
+    #[tokio::test]
+    async fn test_verify_invalid_hash() {
+        let auth_password = AuthPasswordArgon2::new();
+
+        // テスト実行・検証
+        assert!(!auth_password.verify("password-0001", "invalid-hash").await);
+    }
+
+    #[tokio::test]
+    async fn test_dummy_verify() {
+        let auth_password = AuthPasswordArgon2::new();
+
+        // テスト実行（2回目以降も生成済みのハッシュ値で検証できること）
+        auth_password.dummy_verify("password-0001").await;
+        auth_password.dummy_verify("password-0002").await;
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_hash_without_blocking_runtime() {
+        let auth_password = AuthPasswordArgon2::new();
+
+        // 他のタスク（ハッシュ化中に実行されること）
+        let other_task = tokio::spawn(async {});
+
+        // テスト実行
+        let password_hash = auth_password.hash("password-0001").await.unwrap();
+
+        // 検証（ハッシュ化の完了待ちの間に、同じスレッドの他のタスクが実行されている）
+        assert!(other_task.is_finished());
+        assert!(password_hash.starts_with("$argon2id$"));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod auth_jwt;
+pub mod auth_oidc;
+pub mod auth_password;
+
+// テストコード用のモジュール
+pub mod auth_jwt_test;
+pub mod auth_oidc_test;
+pub mod auth_password_test;
+pub mod auth_test_helper;
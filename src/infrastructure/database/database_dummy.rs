@@ -1,4 +1,93 @@
+use chrono::{FixedOffset, TimeZone};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::{Arc, RwLock};
+
+// ドメイン
+use crate::domain::{
+    api_key::api_key_model::ApiKey,
+    audit::audit_log_model::AuditLog,
+    credential::{
+        credential_model::Credential, oidc_session_model::OidcSession,
+        refresh_token_model::RefreshToken,
+    },
+    job::job_model::Job,
+    outbox::outbox_model::OutboxMessage,
+    user::user_model::User,
+    webhook::{
+        webhook_delivery_model::WebhookDelivery, webhook_subscription_model::WebhookSubscription,
+    },
+};
+
+// ダミーのDB（インメモリでデータを保持）
+#[derive(Clone)]
+pub struct DbDummy {
+    pub users: Arc<RwLock<Vec<User>>>,
+    pub api_keys: Arc<RwLock<Vec<ApiKey>>>,
+    pub credentials: Arc<RwLock<Vec<Credential>>>,
+    pub refresh_tokens: Arc<RwLock<Vec<RefreshToken>>>,
+    pub oidc_sessions: Arc<RwLock<Vec<OidcSession>>>,
+    pub outbox_messages: Arc<RwLock<Vec<OutboxMessage>>>,
+    pub webhook_subscriptions: Arc<RwLock<Vec<WebhookSubscription>>>,
+    pub webhook_deliveries: Arc<RwLock<Vec<WebhookDelivery>>>,
+    pub audit_logs: Arc<RwLock<Vec<AuditLog>>>,
+    pub jobs: Arc<RwLock<Vec<Job>>>,
+    // トランザクションの排他制御用のロック（同時に1つのトランザクションのみ実行）
+    pub tx_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
 // ダミーのDB接続
-pub async fn new_db_dummy_connection() -> Result<String, ()> {
-    Ok("dummy".to_string())
+pub async fn new_db_dummy_connection() -> Result<DbDummy, ()> {
+    // jstの設定
+    let jst_offset = FixedOffset::east_opt(9 * 3600).unwrap();
+
+    // 固定日付１
+    let specific_datetime_1 = jst_offset.with_ymd_and_hms(2025, 7, 26, 7, 10, 10).unwrap();
+
+    // 固定日付２
+    let specific_datetime_2 = jst_offset.with_ymd_and_hms(2025, 7, 27, 8, 30, 0).unwrap();
+
+    // 初期データ
+    let users: Vec<User> = vec![
+        User {
+            id: 1,
+            uid: "xxxx-xxxx-xxxx-0001".to_string(),
+            last_name: "田中".to_string(),
+            first_name: "太郎".to_string(),
+            email: "t.tanaka@example.com".to_string(),
+            version: 1,
+            created_at: specific_datetime_1,
+            updated_at: specific_datetime_1,
+            deleted_at: None,
+            anonymized_at: None,
+            events: Vec::new(),
+        },
+        User {
+            id: 2,
+            uid: "xxxx-xxxx-xxxx-0002".to_string(),
+            last_name: "佐藤".to_string(),
+            first_name: "二郎".to_string(),
+            email: "z.satou@example.com".to_string(),
+            version: 1,
+            created_at: specific_datetime_2,
+            updated_at: specific_datetime_2,
+            deleted_at: None,
+            anonymized_at: None,
+            events: Vec::new(),
+        },
+    ];
+
+    Ok(DbDummy {
+        users: Arc::new(RwLock::new(users)),
+        api_keys: Arc::new(RwLock::new(Vec::new())),
+        credentials: Arc::new(RwLock::new(Vec::new())),
+        refresh_tokens: Arc::new(RwLock::new(Vec::new())),
+        oidc_sessions: Arc::new(RwLock::new(Vec::new())),
+        outbox_messages: Arc::new(RwLock::new(Vec::new())),
+        webhook_subscriptions: Arc::new(RwLock::new(Vec::new())),
+        webhook_deliveries: Arc::new(RwLock::new(Vec::new())),
+        audit_logs: Arc::new(RwLock::new(Vec::new())),
+        jobs: Arc::new(RwLock::new(Vec::new())),
+        tx_lock: Arc::new(tokio::sync::Mutex::new(())),
+    })
 }
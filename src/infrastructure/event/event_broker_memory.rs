@@ -0,0 +1,131 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// 両端キュー（送信済みメッセージの保持用）
+use std::collections::VecDeque;
+
+// Mutex（複数のスレッド間で安全に値を更新するためのロック）
+use std::sync::Mutex;
+
+// 購読者への配信用のチャネル
+use tokio::sync::broadcast;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// メッセージブローカー
+use crate::application::usecase::event::event_publisher_trait::{
+    EventMessage, EventPublisherTrait,
+};
+
+// 保持する送信済みメッセージの最大件数（超えた場合は古いものから削除）
+const HISTORY_CAPACITY: usize = 1_000;
+
+// 購読者ごとの未受信メッセージの最大件数
+const CHANNEL_CAPACITY: usize = 1_024;
+
+// プロセス内のメッセージブローカー（外部のブローカーを起動せずにローカル・テストで使用）
+pub struct EventBrokerMemory {
+    history: Mutex<VecDeque<EventMessage>>,
+    sender: broadcast::Sender<EventMessage>,
+}
+
+impl Default for EventBrokerMemory {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        EventBrokerMemory {
+            history: Mutex::new(VecDeque::new()),
+            sender,
+        }
+    }
+}
+
+impl EventBrokerMemory {
+    // 初期化用メソッド
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 送信済みメッセージの取得（古い順）
+    #[allow(dead_code)]
+    pub fn published(&self) -> Vec<EventMessage> {
+        self.history.lock().unwrap().iter().cloned().collect()
+    }
+
+    // 購読の開始（NATS形式のパターン、「*」は1トークン、末尾の「>」は以降の全てに一致）
+    #[allow(dead_code)]
+    pub fn subscribe(&self, pattern: &str) -> EventSubscriptionMemory {
+        EventSubscriptionMemory {
+            pattern: pattern.to_string(),
+            receiver: self.sender.subscribe(),
+        }
+    }
+}
+
+#[async_trait]
+impl EventPublisherTrait for EventBrokerMemory {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn publish(
+        &self,
+        _ctx: &ContextRequest,
+        message: &EventMessage,
+    ) -> Result<(), ErrorCommon> {
+        {
+            let mut history = self.history.lock().unwrap();
+            if history.len() >= HISTORY_CAPACITY {
+                history.pop_front();
+            }
+            history.push_back(message.clone());
+        }
+
+        // 購読者がいない場合のエラーは無視
+        let _ = self.sender.send(message.clone());
+
+        Ok(())
+    }
+}
+
+// プロセス内のメッセージブローカーの購読
+pub struct EventSubscriptionMemory {
+    pattern: String,
+    receiver: broadcast::Receiver<EventMessage>,
+}
+
+impl EventSubscriptionMemory {
+    // パターンに一致する次のメッセージの受信（ブローカーが破棄された場合はNone）
+    #[allow(dead_code)]
+    pub async fn next(&mut self) -> Option<EventMessage> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(message) if topic_matches(&self.pattern, &message.topic) => {
+                    return Some(message);
+                }
+                Ok(_) => continue,
+                // 受信が遅れて破棄されたメッセージは読み飛ばす
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+// 送信先がパターンに一致するかどうか
+pub fn topic_matches(pattern: &str, topic: &str) -> bool {
+    let mut topic_tokens = topic.split('.');
+    for pattern_token in pattern.split('.') {
+        if pattern_token == ">" {
+            return topic_tokens.next().is_some();
+        }
+        match topic_tokens.next() {
+            Some(token) if pattern_token == "*" || pattern_token == token => continue,
+            _ => return false,
+        }
+    }
+    topic_tokens.next().is_none()
+}
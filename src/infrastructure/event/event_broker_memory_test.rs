@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use std::time::Duration;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // メッセージブローカー
+    use crate::application::usecase::event::event_publisher_trait::{
+        EventMessage, EventPublisherTrait,
+    };
+    use crate::infrastructure::event::event_broker_memory::{EventBrokerMemory, topic_matches};
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "-".to_string(),
+            uri: "outbox-relay".to_string(),
+            auth: None,
+        }
+    }
+
+    // 送信するメッセージ
+    fn create_message(topic: &str) -> EventMessage {
+        EventMessage {
+            topic: topic.to_string(),
+            key: "xxxx-xxxx-xxxx-0001".to_string(),
+            headers: vec![],
+            payload: b"{}".to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_topic_matches() {
+        assert!(topic_matches("events.user.created", "events.user.created"));
+        assert!(topic_matches("events.*.created", "events.user.created"));
+        assert!(topic_matches("events.>", "events.user.created"));
+        assert!(!topic_matches("events.>", "events"));
+        assert!(!topic_matches("events.user", "events.user.created"));
+        assert!(!topic_matches("events.*", "events.user.created"));
+        assert!(!topic_matches("events.user.deleted", "events.user.created"));
+    }
+
+    #[tokio::test]
+    async fn test_publish_and_subscribe() {
+        let broker = EventBrokerMemory::new();
+        let mut subscription = broker.subscribe("events.user.deleted");
+
+        // テスト実行
+        let ctx = create_ctx();
+        broker
+            .publish(&ctx, &create_message("events.user.created"))
+            .await
+            .unwrap();
+        broker
+            .publish(&ctx, &create_message("events.user.deleted"))
+            .await
+            .unwrap();
+
+        // 検証（送信済みメッセージは全て保持し、購読者にはパターンに一致するメッセージのみ配信）
+        let published = broker.published();
+        assert_eq!(published.len(), 2);
+        assert_eq!(published[0].topic, "events.user.created");
+        let received = tokio::time::timeout(Duration::from_secs(1), subscription.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(received.topic, "events.user.deleted");
+    }
+}
@@ -0,0 +1,90 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// axum
+use axum::http::StatusCode;
+
+use std::time::Duration;
+
+// Kafka
+use rdkafka::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::util::Timeout;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// メッセージブローカー
+use crate::application::usecase::event::event_publisher_trait::{
+    EventMessage, EventPublisherTrait,
+};
+
+// 送信のタイムアウト（秒）
+const SEND_TIMEOUT_SECS: u64 = 10;
+
+// Kafkaに送信する構造体
+pub struct EventPublisherKafka {
+    producer: FutureProducer,
+}
+
+impl EventPublisherKafka {
+    // 初期化用メソッド（ブートストラップサーバーの例: localhost:9092）
+    pub fn new(bootstrap_servers: &str) -> Result<Self, String> {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", bootstrap_servers)
+            // リトライ時の重複・順序の入れ替わりを防止
+            .set("enable.idempotence", "true")
+            .set("message.timeout.ms", (SEND_TIMEOUT_SECS * 1000).to_string())
+            .create::<FutureProducer>()
+            .map_err(|err| format!("Kafkaのプロデューサーを作成できませんでした: {}", err))?;
+
+        Ok(EventPublisherKafka { producer })
+    }
+}
+
+#[async_trait]
+impl EventPublisherTrait for EventPublisherKafka {
+    fn name(&self) -> &'static str {
+        "kafka"
+    }
+
+    async fn publish(
+        &self,
+        _ctx: &ContextRequest,
+        message: &EventMessage,
+    ) -> Result<(), ErrorCommon> {
+        let headers = message
+            .headers
+            .iter()
+            .fold(OwnedHeaders::new(), |headers, (name, value)| {
+                headers.insert(Header {
+                    key: name,
+                    value: Some(value),
+                })
+            });
+        let record = FutureRecord::to(&message.topic)
+            .key(&message.key)
+            .payload(&message.payload)
+            .headers(headers);
+
+        // 送信してブローカーの受領確認を待つ
+        match self
+            .producer
+            .send(
+                record,
+                Timeout::After(Duration::from_secs(SEND_TIMEOUT_SECS)),
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err((err, _)) => Err(ErrorCommon::CustomError {
+                status_code: StatusCode::SERVICE_UNAVAILABLE,
+                message: format!("Kafkaへの送信に失敗しました: {}", err),
+            }),
+        }
+    }
+}
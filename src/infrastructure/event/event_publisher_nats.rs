@@ -0,0 +1,83 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// axum
+use axum::http::StatusCode;
+
+// NATS（JetStream）
+use async_nats::HeaderMap;
+use async_nats::jetstream::{self, context::Context};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// メッセージブローカー
+use crate::application::usecase::event::event_publisher_trait::{
+    EventMessage, EventPublisherTrait,
+};
+
+// NATS JetStreamに送信する構造体
+// ※送信先のsubjectを含むストリームを事前に作成しておくこと
+pub struct EventPublisherNats {
+    jetstream: Context,
+}
+
+impl EventPublisherNats {
+    // 初期化用メソッド（URLの例: nats://localhost:4222）
+    pub async fn connect(url: &str) -> Result<Self, String> {
+        let client = async_nats::connect(url)
+            .await
+            .map_err(|err| format!("NATSに接続できませんでした: {}", err))?;
+
+        Ok(EventPublisherNats {
+            jetstream: jetstream::new(client),
+        })
+    }
+}
+
+// 送信エラーの変換
+fn publish_error(err: impl std::fmt::Display) -> ErrorCommon {
+    ErrorCommon::CustomError {
+        status_code: StatusCode::SERVICE_UNAVAILABLE,
+        message: format!("NATSへの送信に失敗しました: {}", err),
+    }
+}
+
+#[async_trait]
+impl EventPublisherTrait for EventPublisherNats {
+    fn name(&self) -> &'static str {
+        "nats"
+    }
+
+    async fn publish(
+        &self,
+        _ctx: &ContextRequest,
+        message: &EventMessage,
+    ) -> Result<(), ErrorCommon> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &message.headers {
+            headers.insert(name.as_str(), value.as_str());
+        }
+        // JetStreamの重複排除用のID（リトライ時の重複送信を排除）
+        if let Some(event_id) = message.header("Event-Id") {
+            headers.insert("Nats-Msg-Id", event_id);
+        }
+
+        // 送信してJetStreamの受領確認を待つ
+        let ack = self
+            .jetstream
+            .publish_with_headers(
+                message.topic.clone(),
+                headers,
+                message.payload.clone().into(),
+            )
+            .await
+            .map_err(publish_error)?;
+        ack.await.map_err(publish_error)?;
+
+        Ok(())
+    }
+}
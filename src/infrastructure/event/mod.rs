@@ -0,0 +1,8 @@
+pub mod event_broker_memory;
+#[cfg(feature = "kafka")]
+pub mod event_publisher_kafka;
+#[cfg(feature = "nats")]
+pub mod event_publisher_nats;
+
+// テストコード用のモジュール
+mod event_broker_memory_test;
@@ -0,0 +1,105 @@
+// ハッシュマップ
+use std::collections::HashMap;
+
+// Mutex（複数のスレッド間で安全に値を更新するためのロック）
+use std::sync::Mutex;
+
+// 時刻（単調増加する時計）
+use std::time::{Duration, Instant};
+
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// 冪等キー
+use crate::application::usecase::idempotency::idempotency_store_trait::{
+    IdempotencyBegin, IdempotencyResponse, IdempotencyStoreTrait,
+};
+
+// 有効期限切れのキーを削除するしきい値（キー数）
+const PURGE_THRESHOLD: usize = 10_000;
+
+// 冪等キーごとの保存データ
+struct IdempotencyEntry {
+    request_hash: String,
+    response: Option<IdempotencyResponse>,
+    expires_at: Instant,
+}
+
+// インメモリの冪等キーストア（単一インスタンス用）
+#[derive(Default)]
+pub struct IdempotencyMemory {
+    entries: Mutex<HashMap<String, IdempotencyEntry>>,
+}
+
+impl IdempotencyMemory {
+    // 初期化用メソッド
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IdempotencyStoreTrait for IdempotencyMemory {
+    async fn begin(
+        &self,
+        key: &str,
+        request_hash: &str,
+        ttl_secs: u64,
+    ) -> Result<IdempotencyBegin, ErrorCommon> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+
+        // キー数が多い場合は有効期限切れのキーを削除
+        if entries.len() >= PURGE_THRESHOLD {
+            entries.retain(|_, entry| entry.expires_at > now);
+        }
+
+        if let Some(entry) = entries.get(key).filter(|entry| entry.expires_at > now) {
+            if entry.request_hash != request_hash {
+                return Ok(IdempotencyBegin::Mismatch);
+            }
+            return match &entry.response {
+                Some(response) => Ok(IdempotencyBegin::Completed(response.clone())),
+                None => Ok(IdempotencyBegin::InFlight),
+            };
+        }
+
+        entries.insert(
+            key.to_string(),
+            IdempotencyEntry {
+                request_hash: request_hash.to_string(),
+                response: None,
+                expires_at: now + Duration::from_secs(ttl_secs),
+            },
+        );
+
+        Ok(IdempotencyBegin::Started)
+    }
+
+    async fn complete(&self, key: &str, response: IdempotencyResponse) -> Result<(), ErrorCommon> {
+        let mut entries = self.entries.lock().unwrap();
+
+        if let Some(entry) = entries.get_mut(key) {
+            entry.response = Some(response);
+        }
+
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), ErrorCommon> {
+        let mut entries = self.entries.lock().unwrap();
+
+        // 処理済みのキーは削除しない
+        if entries
+            .get(key)
+            .is_some_and(|entry| entry.response.is_none())
+        {
+            entries.remove(key);
+        }
+
+        Ok(())
+    }
+}
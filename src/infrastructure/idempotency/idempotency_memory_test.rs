@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    // 冪等キー
+    use crate::application::usecase::idempotency::idempotency_store_trait::{
+        IdempotencyBegin, IdempotencyResponse, IdempotencyStoreTrait,
+    };
+    use crate::infrastructure::idempotency::idempotency_memory::IdempotencyMemory;
+
+    // テスト用のレスポンス
+    fn create_response() -> IdempotencyResponse {
+        IdempotencyResponse {
+            status: 201,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: b"{}".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_begin_and_complete() {
+        let store = IdempotencyMemory::new();
+
+        // テスト実行
+        let first = store.begin("key-0001", "hash-0001", 60).await.unwrap();
+        let in_flight = store.begin("key-0001", "hash-0001", 60).await.unwrap();
+        store.complete("key-0001", create_response()).await.unwrap();
+        let completed = store.begin("key-0001", "hash-0001", 60).await.unwrap();
+        let mismatch = store.begin("key-0001", "hash-0002", 60).await.unwrap();
+
+        // 検証
+        assert_eq!(first, IdempotencyBegin::Started);
+        assert_eq!(in_flight, IdempotencyBegin::InFlight);
+        assert_eq!(completed, IdempotencyBegin::Completed(create_response()));
+        assert_eq!(mismatch, IdempotencyBegin::Mismatch);
+
+        // 処理済みのキーは削除しない
+        store.release("key-0001").await.unwrap();
+        let completed = store.begin("key-0001", "hash-0001", 60).await.unwrap();
+        assert_eq!(completed, IdempotencyBegin::Completed(create_response()));
+    }
+
+    #[tokio::test]
+    async fn test_release() {
+        let store = IdempotencyMemory::new();
+
+        // テスト実行
+        store.begin("key-0001", "hash-0001", 60).await.unwrap();
+        store.release("key-0001").await.unwrap();
+        let retry = store.begin("key-0001", "hash-0002", 60).await.unwrap();
+
+        // 検証（削除後は別のリクエストとして処理できる）
+        assert_eq!(retry, IdempotencyBegin::Started);
+    }
+
+    #[tokio::test]
+    async fn test_expired() {
+        let store = IdempotencyMemory::new();
+
+        // テスト実行（有効期限0秒）
+        store.begin("key-0001", "hash-0001", 0).await.unwrap();
+        store.complete("key-0001", create_response()).await.unwrap();
+        let retry = store.begin("key-0001", "hash-0001", 60).await.unwrap();
+
+        // 検証
+        assert_eq!(retry, IdempotencyBegin::Started);
+    }
+}
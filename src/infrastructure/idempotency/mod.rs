@@ -0,0 +1,4 @@
+pub mod idempotency_memory;
+
+// テストコード用のモジュール
+pub mod idempotency_memory_test;
@@ -1,5 +1,6 @@
 use chrono::TimeZone;
 use std::io::Write;
+use std::sync::{OnceLock, RwLock};
 
 // ロガー用のトレイト
 use crate::application::usecase::logger::logger_trait::LoggerTrait;
@@ -7,21 +8,26 @@ use crate::application::usecase::logger::logger_trait::LoggerTrait;
 // 共通コンテキスト
 use crate::application::usecase::context::context_request::ContextRequest;
 
-// ロガーの構造体
-#[derive(Clone)]
-pub struct Logger {}
+// フィルター設定が不正な場合に使うデフォルト値
+pub const DEFAULT_FILTERS: &str = "info";
 
-impl Logger {
-    // ロガーの初期化処理
-    pub fn init() {
-        // 日本時間を取得
-        let jst = chrono::offset::FixedOffset::east_opt(9 * 3600)
-            .unwrap()
-            .from_utc_datetime(&chrono::Utc::now().naive_utc());
+// 実行時に差し替え可能なロガー本体
+struct LogHandle {
+    filters: String,
+    logger: env_logger::Logger,
+}
+
+impl LogHandle {
+    // フィルター設定からロガー本体を生成
+    fn build(filters: &str) -> Self {
+        let logger = env_logger::Builder::new()
+            .parse_filters(filters)
+            .format(|buf, record| {
+                // 日本時間を取得
+                let jst = chrono::offset::FixedOffset::east_opt(9 * 3600)
+                    .unwrap()
+                    .from_utc_datetime(&chrono::Utc::now().naive_utc());
 
-        // カスタムロガーの初期化
-        env_logger::builder()
-            .format(move |buf, record| {
                 writeln!(
                     buf,
                     "{} {} {}",
@@ -30,12 +36,88 @@ impl Logger {
                     record.args()
                 )
             })
-            .init();
+            .build();
+
+        LogHandle {
+            filters: filters.to_string(),
+            logger,
+        }
+    }
+}
+
+static LOG_HANDLE: OnceLock<RwLock<LogHandle>> = OnceLock::new();
+
+// logクレートに登録するロガー（処理は現在のロガー本体に委譲）
+struct ReloadableLog;
+
+static RELOADABLE_LOG: ReloadableLog = ReloadableLog;
+
+impl log::Log for ReloadableLog {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        match LOG_HANDLE.get() {
+            Some(handle) => handle.read().unwrap().logger.enabled(metadata),
+            None => false,
+        }
+    }
+
+    fn log(&self, record: &log::Record) {
+        if let Some(handle) = LOG_HANDLE.get() {
+            handle.read().unwrap().logger.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(handle) = LOG_HANDLE.get() {
+            handle.read().unwrap().logger.flush();
+        }
+    }
+}
+
+// ロガーの構造体
+#[derive(Clone)]
+pub struct Logger {
+    target: String,
+}
+
+impl Logger {
+    // ロガーの初期化処理
+    // 例）filters: "info,rust_axum_domain::infrastructure=debug"
+    pub fn init(filters: &str) {
+        // フィルター設定のチェック
+        let filters = match Logger::validate_filters(filters) {
+            Ok(_) => filters,
+            Err(err) => {
+                println!("ログフィルターの設定エラー: {}", err);
+                DEFAULT_FILTERS
+            }
+        };
+
+        // カスタムロガーの初期化
+        let handle = LogHandle::build(filters);
+        log::set_max_level(handle.logger.filter());
+        if LOG_HANDLE.set(RwLock::new(handle)).is_err() {
+            return;
+        }
+        log::set_logger(&RELOADABLE_LOG).unwrap();
     }
 
-    // インスタンス生成
-    pub fn new() -> Self {
-        Logger {}
+    // インスタンス生成（targetはモジュール単位のログフィルターに利用）
+    pub fn new(target: &str) -> Self {
+        Logger {
+            target: target.to_string(),
+        }
+    }
+
+    // フィルター設定のチェック
+    fn validate_filters(filters: &str) -> Result<(), String> {
+        if filters.trim().is_empty() {
+            return Err("フィルターが空です。".to_string());
+        }
+
+        match env_filter::Builder::new().try_parse(filters) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err.to_string()),
+        }
     }
 
     // コンテキストからリクエスト情報取得
@@ -45,8 +127,11 @@ impl Logger {
         let request_id = x_request_id.expect("-").to_str().unwrap();
 
         format!(
-            "request_id={} method={} uri={}",
-            request_id, ctx.method, ctx.uri
+            "request_id={} actor={} method={} uri={}",
+            request_id,
+            ctx.actor_id(),
+            ctx.method,
+            ctx.uri
         )
     }
 }
@@ -55,16 +140,38 @@ impl Logger {
 impl LoggerTrait for Logger {
     fn info(&self, ctx: &ContextRequest, msg: &str) {
         let req_info = Logger::get_req_info_from_ctx(ctx);
-        log::info!("[{}] {}", req_info, msg);
+        log::info!(target: &self.target, "[{}] {}", req_info, msg);
     }
 
     fn warn(&self, ctx: &ContextRequest, msg: &str) {
         let req_info = Logger::get_req_info_from_ctx(ctx);
-        log::warn!("[{}] {}", req_info, msg);
+        log::warn!(target: &self.target, "[{}] {}", req_info, msg);
     }
 
     fn error(&self, ctx: &ContextRequest, msg: &str) {
         let req_info = Logger::get_req_info_from_ctx(ctx);
-        log::error!("[{}] {}", req_info, msg);
+        log::error!(target: &self.target, "[{}] {}", req_info, msg);
+    }
+
+    fn get_filters(&self) -> String {
+        match LOG_HANDLE.get() {
+            Some(handle) => handle.read().unwrap().filters.clone(),
+            None => DEFAULT_FILTERS.to_string(),
+        }
+    }
+
+    fn set_filters(&self, filters: &str) -> Result<(), String> {
+        // フィルター設定のチェック
+        Logger::validate_filters(filters)?;
+
+        // ロガー本体の差し替え
+        let new_handle = LogHandle::build(filters);
+        log::set_max_level(new_handle.logger.filter());
+        match LOG_HANDLE.get() {
+            Some(handle) => *handle.write().unwrap() = new_handle,
+            None => return Err("ロガーが初期化されていません。".to_string()),
+        }
+
+        Ok(())
     }
 }
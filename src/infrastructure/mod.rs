@@ -1,3 +1,9 @@
+pub mod auth;
 pub mod database;
+pub mod event;
+pub mod idempotency;
 pub mod logger;
+pub mod outbox;
 pub mod persistence;
+pub mod rate_limit;
+pub mod webhook;
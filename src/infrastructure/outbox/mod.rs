@@ -0,0 +1 @@
+pub mod outbox_publisher_log;
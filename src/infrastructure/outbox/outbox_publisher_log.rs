@@ -0,0 +1,51 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 送信先のトレイト
+use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+
+// ドメイン
+use crate::domain::{error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage};
+
+// メッセージをログ出力する送信先（外部の送信先が未設定の場合に使用）
+pub struct OutboxPublisherLog {
+    logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl OutboxPublisherLog {
+    pub fn new(logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        OutboxPublisherLog { logger }
+    }
+}
+
+#[async_trait]
+impl OutboxPublisherTrait for OutboxPublisherLog {
+    async fn publish(
+        &self,
+        ctx: &ContextRequest,
+        message: &OutboxMessage,
+    ) -> Result<(), ErrorCommon> {
+        let msg = format!(
+            "アウトボックスのメッセージ: id={} event_id={} event_type={} payload={}",
+            message.id,
+            message.event_id,
+            message.event_type,
+            json!(message.payload)
+        );
+        self.logger.info(ctx, &msg);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 日時
+use chrono::{DateTime, FixedOffset};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    api_key::api_key_model::ApiKey, api_key::api_key_repository::ApiKeyRepositoryTrait,
+    error::error_common::ErrorCommon,
+};
+
+// APIキーリポジトリの構造体
+pub struct ApiKeyRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl ApiKeyRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        ApiKeyRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyRepositoryTrait for ApiKeyRepository {
+    // 全てのAPIキー取得
+    async fn find_all(&self, _ctx: &ContextRequest) -> Result<Vec<ApiKey>, ErrorCommon> {
+        let api_keys = self.db.api_keys.read().unwrap();
+
+        Ok(api_keys.clone())
+    }
+
+    // key_idによるAPIキー取得
+    async fn find_by_key_id(
+        &self,
+        _ctx: &ContextRequest,
+        key_id: &str,
+    ) -> Result<Option<ApiKey>, ErrorCommon> {
+        let api_keys = self.db.api_keys.read().unwrap();
+
+        Ok(api_keys.iter().find(|k| k.key_id == key_id).cloned())
+    }
+
+    // APIキー作成
+    async fn create(&self, ctx: &ContextRequest, api_key: &ApiKey) -> Result<ApiKey, ErrorCommon> {
+        let mut api_keys = self.db.api_keys.write().unwrap();
+
+        // key_idの重複チェック
+        if api_keys.iter().any(|k| k.key_id == api_key.key_id) {
+            let msg = format!("key_idが重複しています: {}", api_key.key_id);
+            self.logger.error(ctx, &msg);
+            return Err(ErrorCommon::InternalServerError);
+        }
+
+        // idの採番
+        let mut new_api_key = api_key.clone();
+        new_api_key.id = api_keys.iter().map(|k| k.id).max().unwrap_or(0) + 1;
+        api_keys.push(new_api_key.clone());
+
+        Ok(new_api_key)
+    }
+
+    // APIキー更新
+    async fn update(&self, ctx: &ContextRequest, api_key: &ApiKey) -> Result<ApiKey, ErrorCommon> {
+        let mut api_keys = self.db.api_keys.write().unwrap();
+
+        match api_keys.iter_mut().find(|k| k.key_id == api_key.key_id) {
+            Some(stored_api_key) => {
+                *stored_api_key = api_key.clone();
+                Ok(api_key.clone())
+            }
+            None => {
+                let msg = format!("更新対象のAPIキーが存在しません: {}", api_key.key_id);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    // 最終利用日時のみ更新
+    async fn touch_last_used(
+        &self,
+        ctx: &ContextRequest,
+        key_id: &str,
+        at: DateTime<FixedOffset>,
+    ) -> Result<(), ErrorCommon> {
+        let mut api_keys = self.db.api_keys.write().unwrap();
+
+        match api_keys.iter_mut().find(|k| k.key_id == key_id) {
+            Some(stored_api_key) => {
+                stored_api_key.last_used_at = Some(at);
+                Ok(())
+            }
+            None => {
+                let msg = format!("更新対象のAPIキーが存在しません: {}", key_id);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod api_key_repository;
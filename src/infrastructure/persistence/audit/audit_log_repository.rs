@@ -0,0 +1,92 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    audit::audit_log_model::AuditLog, audit::audit_log_repository::AuditLogRepositoryTrait,
+    error::error_common::ErrorCommon,
+};
+
+// 監査ログリポジトリの構造体
+pub struct AuditLogRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl AuditLogRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        AuditLogRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogRepositoryTrait for AuditLogRepository {
+    // 監査ログの追加
+    async fn add(
+        &self,
+        ctx: &ContextRequest,
+        audit_log: &AuditLog,
+    ) -> Result<AuditLog, ErrorCommon> {
+        let mut audit_logs = self.db.audit_logs.write().unwrap();
+
+        // idの採番
+        let mut new_audit_log = audit_log.clone();
+        new_audit_log.id = audit_logs.iter().map(|a| a.id).max().unwrap_or(0) + 1;
+        audit_logs.push(new_audit_log.clone());
+
+        // ログにも出力（変更内容は個人情報を含むため項目名のみ）
+        let fields: Vec<&str> = new_audit_log
+            .changes
+            .iter()
+            .map(|c| c.field.as_str())
+            .collect();
+        let msg = format!(
+            "監査ログ: actor={} action={:?} target_uid={} fields={:?}",
+            new_audit_log.actor, new_audit_log.action, new_audit_log.target_uid, fields
+        );
+        self.logger.info(ctx, &msg);
+
+        Ok(new_audit_log)
+    }
+
+    // 操作対象のユーザーの監査ログ取得（古い順）
+    async fn find_by_target_uid(
+        &self,
+        _ctx: &ContextRequest,
+        target_uid: &str,
+    ) -> Result<Vec<AuditLog>, ErrorCommon> {
+        let audit_logs = self.db.audit_logs.read().unwrap();
+
+        Ok(audit_logs
+            .iter()
+            .filter(|a| a.target_uid == target_uid)
+            .cloned()
+            .collect())
+    }
+
+    // 操作対象のユーザーの監査ログから個人情報を消去
+    async fn scrub_personal_data(
+        &self,
+        _ctx: &ContextRequest,
+        target_uid: &str,
+    ) -> Result<(), ErrorCommon> {
+        let mut audit_logs = self.db.audit_logs.write().unwrap();
+
+        audit_logs
+            .iter_mut()
+            .filter(|a| a.target_uid == target_uid)
+            .for_each(|a| a.scrub_personal_data());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// 日時
+use chrono::{DateTime, FixedOffset};
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    credential::credential_model::Credential,
+    credential::credential_repository::CredentialRepositoryTrait, error::error_common::ErrorCommon,
+};
+
+// 認証情報リポジトリの構造体
+pub struct CredentialRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl CredentialRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        CredentialRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl CredentialRepositoryTrait for CredentialRepository {
+    // ユーザーのuidによる認証情報取得
+    async fn find_by_user_uid(
+        &self,
+        _ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<Option<Credential>, ErrorCommon> {
+        let credentials = self.db.credentials.read().unwrap();
+
+        Ok(credentials.iter().find(|c| c.user_uid == user_uid).cloned())
+    }
+
+    // 認証情報作成
+    async fn create(
+        &self,
+        ctx: &ContextRequest,
+        credential: &Credential,
+    ) -> Result<Credential, ErrorCommon> {
+        let mut credentials = self.db.credentials.write().unwrap();
+
+        // user_uidの重複チェック
+        if credentials
+            .iter()
+            .any(|c| c.user_uid == credential.user_uid)
+        {
+            let msg = format!("認証情報が重複しています: {}", credential.user_uid);
+            self.logger.error(ctx, &msg);
+            return Err(ErrorCommon::InternalServerError);
+        }
+
+        // idの採番
+        let mut new_credential = credential.clone();
+        new_credential.id = credentials.iter().map(|c| c.id).max().unwrap_or(0) + 1;
+        credentials.push(new_credential.clone());
+
+        Ok(new_credential)
+    }
+
+    // 認証情報更新
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        credential: &Credential,
+    ) -> Result<Credential, ErrorCommon> {
+        let mut credentials = self.db.credentials.write().unwrap();
+
+        match credentials
+            .iter_mut()
+            .find(|c| c.user_uid == credential.user_uid)
+        {
+            Some(stored_credential) => {
+                *stored_credential = credential.clone();
+                Ok(credential.clone())
+            }
+            None => {
+                let msg = format!("更新対象の認証情報が存在しません: {}", credential.user_uid);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    // ログイン失敗の記録
+    async fn record_failure(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+        now: DateTime<FixedOffset>,
+    ) -> Result<Credential, ErrorCommon> {
+        let mut credentials = self.db.credentials.write().unwrap();
+
+        match credentials.iter_mut().find(|c| c.user_uid == user_uid) {
+            Some(stored_credential) => {
+                stored_credential.record_failure(now);
+                Ok(stored_credential.clone())
+            }
+            None => {
+                let msg = format!("更新対象の認証情報が存在しません: {}", user_uid);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    // ログイン成功の記録
+    async fn record_success(
+        &self,
+        ctx: &ContextRequest,
+        user_uid: &str,
+        now: DateTime<FixedOffset>,
+    ) -> Result<(), ErrorCommon> {
+        let mut credentials = self.db.credentials.write().unwrap();
+
+        match credentials.iter_mut().find(|c| c.user_uid == user_uid) {
+            Some(stored_credential) => {
+                stored_credential.record_success(now);
+                Ok(())
+            }
+            None => {
+                let msg = format!("更新対象の認証情報が存在しません: {}", user_uid);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    // ユーザーのuidによる認証情報削除
+    async fn delete_by_user_uid(
+        &self,
+        _ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<(), ErrorCommon> {
+        let mut credentials = self.db.credentials.write().unwrap();
+
+        credentials.retain(|c| c.user_uid != user_uid);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod credential_repository;
+pub mod oidc_session_repository;
+pub mod refresh_token_repository;
@@ -0,0 +1,67 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    credential::oidc_session_model::OidcSession,
+    credential::oidc_session_repository::OidcSessionRepositoryTrait,
+    error::error_common::ErrorCommon,
+};
+
+// OIDCのログインセッションリポジトリの構造体
+pub struct OidcSessionRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl OidcSessionRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        OidcSessionRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl OidcSessionRepositoryTrait for OidcSessionRepository {
+    // ログインセッション作成（有効期限切れのセッションは合わせて削除）
+    async fn create(
+        &self,
+        _ctx: &ContextRequest,
+        session: &OidcSession,
+    ) -> Result<(), ErrorCommon> {
+        let mut sessions = self.db.oidc_sessions.write().unwrap();
+
+        let now = chrono::Utc::now().fixed_offset();
+        sessions.retain(|s| !s.is_expired(now));
+        sessions.push(session.clone());
+
+        Ok(())
+    }
+
+    // stateによるログインセッションの取得と削除
+    async fn take_by_state(
+        &self,
+        ctx: &ContextRequest,
+        state: &str,
+    ) -> Result<Option<OidcSession>, ErrorCommon> {
+        let mut sessions = self.db.oidc_sessions.write().unwrap();
+
+        match sessions.iter().position(|s| s.state == state) {
+            Some(index) => Ok(Some(sessions.remove(index))),
+            None => {
+                self.logger
+                    .warn(ctx, "stateに対応するログインセッションが存在しません");
+                Ok(None)
+            }
+        }
+    }
+}
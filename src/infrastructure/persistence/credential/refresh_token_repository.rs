@@ -0,0 +1,139 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    credential::refresh_token_model::RefreshToken,
+    credential::refresh_token_repository::RefreshTokenRepositoryTrait,
+    error::error_common::ErrorCommon,
+};
+
+// リフレッシュトークンリポジトリの構造体
+pub struct RefreshTokenRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl RefreshTokenRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        RefreshTokenRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl RefreshTokenRepositoryTrait for RefreshTokenRepository {
+    // ハッシュ値によるリフレッシュトークン取得
+    async fn find_by_token_hash(
+        &self,
+        _ctx: &ContextRequest,
+        token_hash: &str,
+    ) -> Result<Option<RefreshToken>, ErrorCommon> {
+        let refresh_tokens = self.db.refresh_tokens.read().unwrap();
+
+        Ok(refresh_tokens
+            .iter()
+            .find(|t| t.token_hash == token_hash)
+            .cloned())
+    }
+
+    // リフレッシュトークン作成
+    async fn create(
+        &self,
+        _ctx: &ContextRequest,
+        refresh_token: &RefreshToken,
+    ) -> Result<RefreshToken, ErrorCommon> {
+        let mut refresh_tokens = self.db.refresh_tokens.write().unwrap();
+
+        // idの採番
+        let mut new_refresh_token = refresh_token.clone();
+        new_refresh_token.id = refresh_tokens.iter().map(|t| t.id).max().unwrap_or(0) + 1;
+        refresh_tokens.push(new_refresh_token.clone());
+
+        Ok(new_refresh_token)
+    }
+
+    // 失効していない場合のみ失効
+    async fn revoke_if_active(
+        &self,
+        _ctx: &ContextRequest,
+        token_hash: &str,
+    ) -> Result<bool, ErrorCommon> {
+        let mut refresh_tokens = self.db.refresh_tokens.write().unwrap();
+
+        match refresh_tokens
+            .iter_mut()
+            .find(|t| t.token_hash == token_hash && t.revoked_at.is_none())
+        {
+            Some(stored_refresh_token) => {
+                stored_refresh_token.revoke();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    // ファミリー単位での一括失効
+    async fn revoke_family(
+        &self,
+        ctx: &ContextRequest,
+        family_id: &str,
+    ) -> Result<(), ErrorCommon> {
+        let mut refresh_tokens = self.db.refresh_tokens.write().unwrap();
+
+        let mut count = 0;
+        for refresh_token in refresh_tokens
+            .iter_mut()
+            .filter(|t| t.family_id == family_id && t.revoked_at.is_none())
+        {
+            refresh_token.revoke();
+            count += 1;
+        }
+
+        let msg = format!(
+            "リフレッシュトークンをファミリー単位で失効しました: family_id={} count={}",
+            family_id, count
+        );
+        self.logger.info(ctx, &msg);
+
+        Ok(())
+    }
+
+    // ユーザー単位での一括失効
+    async fn revoke_all_by_user_uid(
+        &self,
+        _ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<(), ErrorCommon> {
+        let mut refresh_tokens = self.db.refresh_tokens.write().unwrap();
+
+        refresh_tokens
+            .iter_mut()
+            .filter(|t| t.user_uid == user_uid)
+            .for_each(|t| t.revoke());
+
+        Ok(())
+    }
+
+    // ユーザー単位での一括削除
+    async fn delete_all_by_user_uid(
+        &self,
+        _ctx: &ContextRequest,
+        user_uid: &str,
+    ) -> Result<(), ErrorCommon> {
+        let mut refresh_tokens = self.db.refresh_tokens.write().unwrap();
+
+        refresh_tokens.retain(|t| t.user_uid != user_uid);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, FixedOffset};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    error::error_common::ErrorCommon,
+    job::job_model::{Job, JobStatus},
+    job::job_repository::JobRepositoryTrait,
+};
+
+// ジョブリポジトリの構造体
+pub struct JobRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl JobRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        JobRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl JobRepositoryTrait for JobRepository {
+    // ジョブの追加
+    async fn add(&self, _ctx: &ContextRequest, job: &Job) -> Result<Job, ErrorCommon> {
+        let mut jobs = self.db.jobs.write().unwrap();
+
+        // 重複登録のチェック（DBの場合はユニーク制約）
+        if job.unique_key.is_some() && jobs.iter().any(|j| j.unique_key == job.unique_key) {
+            return Err(ErrorCommon::CustomError {
+                status_code: StatusCode::CONFLICT,
+                message: "同じキーのジョブが登録済みです。".to_string(),
+            });
+        }
+
+        // idの採番
+        let mut new_job = job.clone();
+        new_job.id = jobs.iter().map(|j| j.id).max().unwrap_or(0) + 1;
+        jobs.push(new_job.clone());
+
+        Ok(new_job)
+    }
+
+    // 実行対象のジョブを1件実行中にして取得（DBの場合はSELECT ... LIMIT 1 FOR UPDATE SKIP LOCKED）
+    async fn claim_due(
+        &self,
+        _ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        lease_secs: u64,
+    ) -> Result<Option<Job>, ErrorCommon> {
+        let mut jobs = self.db.jobs.write().unwrap();
+
+        Ok(jobs
+            .iter_mut()
+            .filter(|j| j.is_due(now))
+            .min_by_key(|j| j.run_at)
+            .map(|job| {
+                job.mark_running(lease_secs, now);
+                job.clone()
+            }))
+    }
+
+    // デッドレターのジョブ取得
+    async fn find_dead_lettered(&self, _ctx: &ContextRequest) -> Result<Vec<Job>, ErrorCommon> {
+        let jobs = self.db.jobs.read().unwrap();
+
+        Ok(jobs
+            .iter()
+            .filter(|j| j.status == JobStatus::DeadLettered)
+            .cloned()
+            .collect())
+    }
+
+    // ジョブIDからジョブ取得
+    async fn find_by_job_id(
+        &self,
+        _ctx: &ContextRequest,
+        job_id: &str,
+    ) -> Result<Option<Job>, ErrorCommon> {
+        let jobs = self.db.jobs.read().unwrap();
+
+        Ok(jobs.iter().find(|j| j.job_id == job_id).cloned())
+    }
+
+    // ジョブの更新（DBの場合はUPDATE ... WHERE locked_until = expected_locked_until）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        job: &Job,
+        expected_locked_until: Option<DateTime<FixedOffset>>,
+    ) -> Result<Job, ErrorCommon> {
+        let mut jobs = self.db.jobs.write().unwrap();
+
+        match jobs.iter_mut().find(|j| j.id == job.id) {
+            Some(stored_job) if stored_job.locked_until != expected_locked_until => {
+                let msg = format!(
+                    "ジョブの占有期限が一致しません: job_id={}, expected={:?}, stored={:?}",
+                    job.job_id, expected_locked_until, stored_job.locked_until
+                );
+                self.logger.warn(ctx, &msg);
+                Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::CONFLICT,
+                    message: "対象のジョブは他の処理により更新されています。".to_string(),
+                })
+            }
+            Some(stored_job) => {
+                *stored_job = job.clone();
+                Ok(job.clone())
+            }
+            None => {
+                let msg = format!("更新対象のジョブが存在しません: {}", job.job_id);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+}
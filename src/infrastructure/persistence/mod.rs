@@ -1 +1,8 @@
+pub mod api_key;
+pub mod audit;
+pub mod credential;
+pub mod job;
+pub mod outbox;
+pub mod transaction;
 pub mod user;
+pub mod webhook;
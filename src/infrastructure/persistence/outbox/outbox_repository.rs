@@ -0,0 +1,124 @@
+use chrono::{DateTime, FixedOffset};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage,
+    outbox::outbox_repository::OutboxRepositoryTrait,
+};
+
+// アウトボックスリポジトリの構造体
+pub struct OutboxRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl OutboxRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        OutboxRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxRepositoryTrait for OutboxRepository {
+    // メッセージの追加
+    async fn add(
+        &self,
+        _ctx: &ContextRequest,
+        messages: &[OutboxMessage],
+    ) -> Result<Vec<OutboxMessage>, ErrorCommon> {
+        let mut outbox_messages = self.db.outbox_messages.write().unwrap();
+
+        // idの採番
+        let mut next_id = outbox_messages.iter().map(|m| m.id).max().unwrap_or(0);
+        let mut new_messages = Vec::new();
+        for message in messages.iter() {
+            next_id += 1;
+            let mut new_message = message.clone();
+            new_message.id = next_id;
+            outbox_messages.push(new_message.clone());
+            new_messages.push(new_message);
+        }
+
+        Ok(new_messages)
+    }
+
+    // 送信対象のメッセージ取得
+    async fn find_due(
+        &self,
+        _ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        limit: usize,
+    ) -> Result<Vec<OutboxMessage>, ErrorCommon> {
+        let outbox_messages = self.db.outbox_messages.read().unwrap();
+
+        Ok(outbox_messages
+            .iter()
+            .filter(|m| m.is_due(now))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    // 滞留しているメッセージ取得
+    async fn find_stuck(&self, _ctx: &ContextRequest) -> Result<Vec<OutboxMessage>, ErrorCommon> {
+        let outbox_messages = self.db.outbox_messages.read().unwrap();
+
+        Ok(outbox_messages
+            .iter()
+            .filter(|m| m.is_stuck())
+            .cloned()
+            .collect())
+    }
+
+    // メッセージの更新
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        message: &OutboxMessage,
+    ) -> Result<OutboxMessage, ErrorCommon> {
+        let mut outbox_messages = self.db.outbox_messages.write().unwrap();
+
+        match outbox_messages.iter_mut().find(|m| m.id == message.id) {
+            Some(stored_message) => {
+                *stored_message = message.clone();
+                Ok(message.clone())
+            }
+            None => {
+                let msg = format!("更新対象のメッセージが存在しません: {}", message.id);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    // 集約のメッセージのペイロードから個人情報を消去
+    async fn scrub_personal_data(
+        &self,
+        _ctx: &ContextRequest,
+        aggregate_id: &str,
+    ) -> Result<Vec<OutboxMessage>, ErrorCommon> {
+        let mut outbox_messages = self.db.outbox_messages.write().unwrap();
+
+        Ok(outbox_messages
+            .iter_mut()
+            .filter(|m| m.aggregate_id == aggregate_id)
+            .map(|m| {
+                m.scrub_personal_data();
+                m.clone()
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod unit_of_work;
+
+// テストコード用のモジュール
+pub mod unit_of_work_test;
@@ -0,0 +1,234 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::{Arc, RwLock};
+
+// 排他制御用のロック
+use tokio::sync::OwnedMutexGuard;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// リポジトリ
+use crate::infrastructure::persistence::audit::audit_log_repository::AuditLogRepository;
+use crate::infrastructure::persistence::credential::credential_repository::CredentialRepository;
+use crate::infrastructure::persistence::credential::refresh_token_repository::RefreshTokenRepository;
+use crate::infrastructure::persistence::outbox::outbox_repository::OutboxRepository;
+use crate::infrastructure::persistence::user::user_repository::UserRepository;
+use crate::infrastructure::persistence::webhook::webhook_delivery_repository::WebhookDeliveryRepository;
+
+// ドメイン
+use crate::domain::{
+    audit::audit_log_repository::AuditLogRepositoryTrait,
+    credential::credential_repository::CredentialRepositoryTrait,
+    credential::refresh_token_repository::RefreshTokenRepositoryTrait,
+    error::error_common::ErrorCommon,
+    outbox::outbox_repository::OutboxRepositoryTrait,
+    transaction::unit_of_work::{TransactionTrait, UnitOfWorkTrait},
+    user::user_repository::UserRepositoryTrait,
+    webhook::webhook_delivery_repository::WebhookDeliveryRepositoryTrait,
+};
+
+// ユニットオブワークの構造体
+pub struct UnitOfWork {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl UnitOfWork {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        UnitOfWork { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWorkTrait for UnitOfWork {
+    // トランザクション開始（対象のテーブルをコピーして変更はコピーに対して行う）
+    async fn begin(&self, _ctx: &ContextRequest) -> Result<Box<dyn TransactionTrait>, ErrorCommon> {
+        let guard = self.db.tx_lock.clone().lock_owned().await;
+
+        // 開始時点のテーブル（コミット時に変更された行を判別するため）と変更用のコピー
+        let snapshot = copy_tables(&self.db);
+        let staged = copy_tables(&snapshot);
+
+        Ok(Box::new(Transaction {
+            db: self.db.clone(),
+            snapshot,
+            user_repository: Arc::new(UserRepository::new(staged.clone(), self.logger.clone())),
+            outbox_repository: Arc::new(OutboxRepository::new(staged.clone(), self.logger.clone())),
+            audit_log_repository: Arc::new(AuditLogRepository::new(
+                staged.clone(),
+                self.logger.clone(),
+            )),
+            credential_repository: Arc::new(CredentialRepository::new(
+                staged.clone(),
+                self.logger.clone(),
+            )),
+            refresh_token_repository: Arc::new(RefreshTokenRepository::new(
+                staged.clone(),
+                self.logger.clone(),
+            )),
+            webhook_delivery_repository: Arc::new(WebhookDeliveryRepository::new(
+                staged.clone(),
+                self.logger.clone(),
+            )),
+            staged,
+            _guard: guard,
+        }))
+    }
+}
+
+// トランザクション対象のテーブル（users、outbox_messages、audit_logs、credentials、refresh_tokens、webhook_deliveries）をコピー
+fn copy_tables(db: &DbDummy) -> DbDummy {
+    let mut copied = db.clone();
+    copied.users = Arc::new(RwLock::new(db.users.read().unwrap().clone()));
+    copied.outbox_messages = Arc::new(RwLock::new(db.outbox_messages.read().unwrap().clone()));
+    copied.audit_logs = Arc::new(RwLock::new(db.audit_logs.read().unwrap().clone()));
+    copied.credentials = Arc::new(RwLock::new(db.credentials.read().unwrap().clone()));
+    copied.refresh_tokens = Arc::new(RwLock::new(db.refresh_tokens.read().unwrap().clone()));
+    copied.webhook_deliveries =
+        Arc::new(RwLock::new(db.webhook_deliveries.read().unwrap().clone()));
+    copied
+}
+
+// トランザクション内で変更された行のみテーブルに反映（トランザクション外で変更された行は保持する）
+// ※開始時点から削除された行は削除、変更された行はキーで上書き、追加された行はidを採番し直して追加
+fn apply_changes<T, K>(
+    table: &RwLock<Vec<T>>,
+    snapshot: &RwLock<Vec<T>>,
+    staged: &RwLock<Vec<T>>,
+    key: fn(&T) -> K,
+    id: fn(&mut T) -> &mut i64,
+) where
+    T: Clone + PartialEq,
+    K: PartialEq,
+{
+    let snapshot = snapshot.read().unwrap();
+    let staged = staged.read().unwrap();
+    let mut rows = table.write().unwrap();
+
+    // 削除された行
+    for before in snapshot.iter() {
+        if !staged.iter().any(|row| key(row) == key(before)) {
+            rows.retain(|row| key(row) != key(before));
+        }
+    }
+
+    // 変更・追加された行
+    for row in staged.iter() {
+        match snapshot.iter().find(|before| key(before) == key(row)) {
+            Some(before) if before == row => {}
+            Some(_) => {
+                if let Some(stored) = rows.iter_mut().find(|stored| key(stored) == key(row)) {
+                    *stored = row.clone();
+                }
+            }
+            None => {
+                let mut new_row = row.clone();
+                *id(&mut new_row) =
+                    rows.iter_mut().map(|stored| *id(stored)).max().unwrap_or(0) + 1;
+                rows.push(new_row);
+            }
+        }
+    }
+}
+
+// トランザクションの構造体
+// ※TODO: ダミーDBのため、トランザクション対象のテーブル（users、outbox_messages、audit_logs、credentials、refresh_tokens、webhook_deliveries）のみコミット時に反映する
+pub struct Transaction {
+    db: DbDummy,
+    snapshot: DbDummy,
+    staged: DbDummy,
+    user_repository: Arc<UserRepository>,
+    outbox_repository: Arc<OutboxRepository>,
+    audit_log_repository: Arc<AuditLogRepository>,
+    credential_repository: Arc<CredentialRepository>,
+    refresh_token_repository: Arc<RefreshTokenRepository>,
+    webhook_delivery_repository: Arc<WebhookDeliveryRepository>,
+    _guard: OwnedMutexGuard<()>,
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for Transaction {
+    fn user_repository(&self) -> Arc<dyn UserRepositoryTrait> {
+        self.user_repository.clone()
+    }
+
+    fn outbox_repository(&self) -> Arc<dyn OutboxRepositoryTrait> {
+        self.outbox_repository.clone()
+    }
+
+    fn audit_log_repository(&self) -> Arc<dyn AuditLogRepositoryTrait> {
+        self.audit_log_repository.clone()
+    }
+
+    fn credential_repository(&self) -> Arc<dyn CredentialRepositoryTrait> {
+        self.credential_repository.clone()
+    }
+
+    fn refresh_token_repository(&self) -> Arc<dyn RefreshTokenRepositoryTrait> {
+        self.refresh_token_repository.clone()
+    }
+
+    fn webhook_delivery_repository(&self) -> Arc<dyn WebhookDeliveryRepositoryTrait> {
+        self.webhook_delivery_repository.clone()
+    }
+
+    // コミット（コピーしたテーブルで変更された行を反映）
+    async fn commit(self: Box<Self>) -> Result<(), ErrorCommon> {
+        apply_changes(
+            &self.db.users,
+            &self.snapshot.users,
+            &self.staged.users,
+            |u| u.uid.clone(),
+            |u| &mut u.id,
+        );
+        apply_changes(
+            &self.db.outbox_messages,
+            &self.snapshot.outbox_messages,
+            &self.staged.outbox_messages,
+            |m| m.event_id.clone(),
+            |m| &mut m.id,
+        );
+        apply_changes(
+            &self.db.audit_logs,
+            &self.snapshot.audit_logs,
+            &self.staged.audit_logs,
+            |a| a.id,
+            |a| &mut a.id,
+        );
+        apply_changes(
+            &self.db.credentials,
+            &self.snapshot.credentials,
+            &self.staged.credentials,
+            |c| c.user_uid.clone(),
+            |c| &mut c.id,
+        );
+        apply_changes(
+            &self.db.refresh_tokens,
+            &self.snapshot.refresh_tokens,
+            &self.staged.refresh_tokens,
+            |t| t.token_hash.clone(),
+            |t| &mut t.id,
+        );
+        apply_changes(
+            &self.db.webhook_deliveries,
+            &self.snapshot.webhook_deliveries,
+            &self.staged.webhook_deliveries,
+            |d| d.delivery_id.clone(),
+            |d| &mut d.id,
+        );
+
+        Ok(())
+    }
+
+    // ロールバック（コピーしたテーブルを破棄）
+    async fn rollback(self: Box<Self>) -> Result<(), ErrorCommon> {
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ロガーのモック
+    use crate::application::usecase::logger::logger_trait::MockLoggerTrait;
+
+    // DB
+    use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
+
+    // リポジトリ（トランザクション外での書き込み用）
+    use crate::domain::credential::credential_repository::CredentialRepositoryTrait;
+    use crate::domain::credential::refresh_token_repository::RefreshTokenRepositoryTrait;
+    use crate::infrastructure::persistence::credential::credential_repository::CredentialRepository;
+    use crate::infrastructure::persistence::credential::refresh_token_repository::RefreshTokenRepository;
+
+    // ドメイン
+    use crate::domain::audit::audit_log_model::{AuditAction, AuditLog, REDACTED_VALUE};
+    use crate::domain::credential::credential_model::Credential;
+    use crate::domain::credential::refresh_token_model::RefreshToken;
+    use crate::domain::outbox::outbox_model::OutboxMessage;
+    use crate::domain::time::time_jst::jst_now;
+    use crate::domain::transaction::unit_of_work::UnitOfWorkTrait;
+    use crate::domain::user::user_model::User;
+    use crate::domain::webhook::webhook_delivery_model::WebhookDelivery;
+
+    // ユニットオブワーク
+    use crate::infrastructure::persistence::transaction::unit_of_work::UnitOfWork;
+
+    // 共通コンテキストの作成
+    fn create_ctx() -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+
+        ContextRequest {
+            header: h,
+            method: "POST".to_string(),
+            uri: "/api/v1/users".to_string(),
+            auth: None,
+        }
+    }
+
+    // テスト用のユーザー
+    fn create_user() -> User {
+        User::new(
+            "xxxx-xxxx-xxxx-0003".to_string(),
+            "鈴木".to_string(),
+            "三郎".to_string(),
+            "s.suzuki@example.com".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_commit() {
+        let db = new_db_dummy_connection().await.unwrap();
+        let uow = UnitOfWork::new(db.clone(), Arc::new(MockLoggerTrait::new()));
+        let ctx = create_ctx();
+
+        // テスト実行
+        let tx = uow.begin(&ctx).await.unwrap();
+        tx.user_repository()
+            .create(&ctx, &create_user())
+            .await
+            .unwrap();
+
+        // 検証（コミット前は反映されず、トランザクション内では参照できる）
+        assert_eq!(db.users.read().unwrap().len(), 2);
+        let user = tx
+            .user_repository()
+            .find_by_uid(&ctx, "xxxx-xxxx-xxxx-0003")
+            .await
+            .unwrap();
+        assert!(user.is_some());
+
+        tx.commit().await.unwrap();
+        assert_eq!(db.users.read().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rollback() {
+        let db = new_db_dummy_connection().await.unwrap();
+        let uow = UnitOfWork::new(db.clone(), Arc::new(MockLoggerTrait::new()));
+        let ctx = create_ctx();
+
+        // テスト実行（ロールバック）
+        let tx = uow.begin(&ctx).await.unwrap();
+        tx.user_repository()
+            .create(&ctx, &create_user())
+            .await
+            .unwrap();
+        tx.rollback().await.unwrap();
+
+        // テスト実行（コミットせずに破棄）
+        let tx = uow.begin(&ctx).await.unwrap();
+        tx.user_repository()
+            .create(&ctx, &create_user())
+            .await
+            .unwrap();
+        drop(tx);
+
+        // 検証
+        assert_eq!(db.users.read().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_serialized() {
+        let db = new_db_dummy_connection().await.unwrap();
+        let uow = UnitOfWork::new(db.clone(), Arc::new(MockLoggerTrait::new()));
+        let ctx = create_ctx();
+
+        // テスト実行
+        let tx = uow.begin(&ctx).await.unwrap();
+
+        // 検証（実行中のトランザクションが終了するまで次のトランザクションは開始しない）
+        let waiting = tokio::time::timeout(Duration::from_millis(100), uow.begin(&ctx)).await;
+        assert!(waiting.is_err());
+
+        tx.commit().await.unwrap();
+        let next = tokio::time::timeout(Duration::from_millis(100), uow.begin(&ctx)).await;
+        assert!(next.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_outbox() {
+        let db = new_db_dummy_connection().await.unwrap();
+        let uow = UnitOfWork::new(db.clone(), Arc::new(MockLoggerTrait::new()));
+        let ctx = create_ctx();
+
+        // テスト実行（ロールバックした場合はアウトボックスにも反映されない）
+        let mut user = create_user();
+        let messages = OutboxMessage::from_events(&user.take_events());
+        let tx = uow.begin(&ctx).await.unwrap();
+        tx.outbox_repository().add(&ctx, &messages).await.unwrap();
+        tx.rollback().await.unwrap();
+        assert!(db.outbox_messages.read().unwrap().is_empty());
+
+        // テスト実行（コミットした場合はユーザーと同時に反映される）
+        let tx = uow.begin(&ctx).await.unwrap();
+        tx.user_repository().create(&ctx, &user).await.unwrap();
+        let added = tx.outbox_repository().add(&ctx, &messages).await.unwrap();
+        tx.commit().await.unwrap();
+
+        // 検証
+        assert_eq!(added[0].id, 1);
+        assert_eq!(db.users.read().unwrap().len(), 3);
+        let outbox_messages = db.outbox_messages.read().unwrap();
+        assert_eq!(outbox_messages.len(), 1);
+        assert_eq!(outbox_messages[0].event_type, "user.created");
+    }
+
+    #[tokio::test]
+    async fn test_purge_personal_data() {
+        let db = new_db_dummy_connection().await.unwrap();
+        let uow = UnitOfWork::new(db.clone(), Arc::new(MockLoggerTrait::new()));
+        let ctx = create_ctx();
+
+        // 事前準備（対象のユーザーの認証情報・リフレッシュトークン・監査ログ・アウトボックス・Webhookの配信）
+        let mut user = create_user();
+        let messages = OutboxMessage::from_events(&user.take_events());
+        db.webhook_deliveries
+            .write()
+            .unwrap()
+            .push(WebhookDelivery::new("sub-0001", &messages[0], jst_now()));
+        db.outbox_messages.write().unwrap().extend(messages);
+        let (refresh_token, _) = RefreshToken::issue(user.uid.clone(), None, 3600);
+        db.credentials
+            .write()
+            .unwrap()
+            .push(Credential::new(user.uid.clone(), "hash".to_string()));
+        db.refresh_tokens.write().unwrap().push(refresh_token);
+        db.audit_logs.write().unwrap().push(AuditLog::for_user(
+            "sub",
+            "req-0001",
+            AuditAction::Create,
+            None,
+            &user,
+        ));
+
+        // テスト実行
+        let tx = uow.begin(&ctx).await.unwrap();
+        tx.credential_repository()
+            .delete_by_user_uid(&ctx, &user.uid)
+            .await
+            .unwrap();
+        tx.refresh_token_repository()
+            .delete_all_by_user_uid(&ctx, &user.uid)
+            .await
+            .unwrap();
+        tx.audit_log_repository()
+            .scrub_personal_data(&ctx, &user.uid)
+            .await
+            .unwrap();
+        let scrubbed = tx
+            .outbox_repository()
+            .scrub_personal_data(&ctx, &user.uid)
+            .await
+            .unwrap();
+        tx.webhook_delivery_repository()
+            .scrub_personal_data(&ctx, &scrubbed)
+            .await
+            .unwrap();
+
+        // 検証（コミット前は反映されない）
+        assert_eq!(scrubbed.len(), 1);
+        assert!(
+            db.webhook_deliveries.read().unwrap()[0]
+                .body
+                .contains("s.suzuki@example.com")
+        );
+        assert_eq!(db.credentials.read().unwrap().len(), 1);
+        assert_eq!(db.refresh_tokens.read().unwrap().len(), 1);
+        assert_eq!(
+            db.audit_logs.read().unwrap()[0].changes[2].after,
+            "s.suzuki@example.com"
+        );
+
+        // 検証（コミット後は削除・消去が反映される）
+        tx.commit().await.unwrap();
+        assert!(db.credentials.read().unwrap().is_empty());
+        assert!(db.refresh_tokens.read().unwrap().is_empty());
+        let audit_logs = db.audit_logs.read().unwrap();
+        assert!(
+            audit_logs[0]
+                .changes
+                .iter()
+                .all(|c| c.after == REDACTED_VALUE)
+        );
+        let outbox_messages = db.outbox_messages.read().unwrap();
+        let payload = serde_json::to_string(&outbox_messages[0].payload).unwrap();
+        assert!(!payload.contains("s.suzuki@example.com"));
+        let webhook_deliveries = db.webhook_deliveries.read().unwrap();
+        assert!(!webhook_deliveries[0].body.contains("s.suzuki@example.com"));
+        assert!(webhook_deliveries[0].body.contains(REDACTED_VALUE));
+    }
+
+    #[tokio::test]
+    async fn test_commit_keeps_non_transactional_writes() {
+        let db = new_db_dummy_connection().await.unwrap();
+        let uow = UnitOfWork::new(db.clone(), Arc::new(MockLoggerTrait::new()));
+        let ctx = create_ctx();
+
+        // 事前準備（削除対象のユーザーと他のユーザーの認証情報・リフレッシュトークン）
+        let user = create_user();
+        let (refresh_token, _) = RefreshToken::issue(user.uid.clone(), None, 3600);
+        let (other_refresh_token, _) =
+            RefreshToken::issue("xxxx-xxxx-xxxx-0001".to_string(), None, 3600);
+        {
+            let mut credentials = db.credentials.write().unwrap();
+            credentials.push(Credential::new(user.uid.clone(), "hash".to_string()));
+            credentials.push(Credential::new(
+                "xxxx-xxxx-xxxx-0001".to_string(),
+                "hash".to_string(),
+            ));
+            let mut refresh_tokens = db.refresh_tokens.write().unwrap();
+            refresh_tokens.push(refresh_token);
+            refresh_tokens.push(other_refresh_token.clone());
+        }
+
+        // テスト実行（トランザクション内で削除対象のユーザーの認証情報・リフレッシュトークンを削除）
+        let tx = uow.begin(&ctx).await.unwrap();
+        tx.credential_repository()
+            .delete_by_user_uid(&ctx, &user.uid)
+            .await
+            .unwrap();
+        tx.refresh_token_repository()
+            .delete_all_by_user_uid(&ctx, &user.uid)
+            .await
+            .unwrap();
+
+        // テスト実行（トランザクション中にトランザクション外でログイン失敗の記録・リフレッシュトークンの再発行）
+        let mut mock_repo_logger = MockLoggerTrait::new();
+        mock_repo_logger.expect_info().returning(|_, _| ());
+        let repo_logger = Arc::new(mock_repo_logger);
+        let credential_repo = CredentialRepository::new(db.clone(), repo_logger.clone());
+        let refresh_token_repo = RefreshTokenRepository::new(db.clone(), repo_logger);
+        credential_repo
+            .record_failure(&ctx, "xxxx-xxxx-xxxx-0001", jst_now())
+            .await
+            .unwrap();
+        let (new_refresh_token, _) = RefreshToken::issue(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            Some(other_refresh_token.family_id.clone()),
+            3600,
+        );
+        refresh_token_repo
+            .revoke_if_active(&ctx, &other_refresh_token.token_hash)
+            .await
+            .unwrap();
+        refresh_token_repo
+            .create(&ctx, &new_refresh_token)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        // 検証（トランザクションの削除とトランザクション外の変更の両方が反映される）
+        let credentials = db.credentials.read().unwrap();
+        assert_eq!(credentials.len(), 1);
+        assert_eq!(credentials[0].user_uid, "xxxx-xxxx-xxxx-0001");
+        assert_eq!(credentials[0].failed_attempts, 1);
+        let refresh_tokens = db.refresh_tokens.read().unwrap();
+        assert_eq!(refresh_tokens.len(), 2);
+        assert!(
+            refresh_tokens
+                .iter()
+                .all(|t| t.user_uid == "xxxx-xxxx-xxxx-0001")
+        );
+        assert!(refresh_tokens[0].revoked_at.is_some());
+        assert_eq!(refresh_tokens[1].token_hash, new_refresh_token.token_hash);
+    }
+}
@@ -1,4 +1,8 @@
-use chrono::{FixedOffset, TimeZone};
+// axum
+use axum::http::StatusCode;
+
+// 日時
+use chrono::{DateTime, FixedOffset};
 
 // Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
 use std::sync::Arc;
@@ -9,6 +13,9 @@ use crate::application::usecase::context::context_request::ContextRequest;
 // ロガー
 use crate::application::usecase::logger::logger_trait::LoggerTrait;
 
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
 // ドメイン
 use crate::domain::{
     error::error_common::ErrorCommon, user::user_model::User,
@@ -17,19 +24,16 @@ use crate::domain::{
 
 // ユーザーリポジトリの構造体
 pub struct UserRepository {
-    pub _db: String, // TODO: 仮でString型にしているが、DBインスタンスに合わせた型に変更する
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
     // Arc<T>型で動的にメモリ領域確保（スレッドセーフな共有所有権）
     // 'static: オブジェクトのライフタイムがプログラムが終了するまで破棄されない
-    pub _logger: Arc<dyn LoggerTrait + 'static>,
+    pub logger: Arc<dyn LoggerTrait + 'static>,
 }
 
 impl UserRepository {
     // 初期化用メソッド
-    pub fn new(db: String, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
-        UserRepository {
-            _db: db,
-            _logger: logger,
-        }
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        UserRepository { db, logger }
     }
 }
 
@@ -37,41 +41,131 @@ impl UserRepository {
 impl UserRepositoryTrait for UserRepository {
     // 全てのユーザー取得
     async fn find_all(&self, _ctx: &ContextRequest) -> Result<Vec<User>, ErrorCommon> {
-        // *今回はDBを直接使わないため、固定値を返す*
-
-        // jstの設定
-        let jst_offset = FixedOffset::east_opt(9 * 3600).unwrap();
-
-        // 固定日付１
-        let specific_datetime_1 = jst_offset.with_ymd_and_hms(2025, 7, 26, 7, 10, 10).unwrap();
-
-        // 固定日付２
-        let specific_datetime_2 = jst_offset.with_ymd_and_hms(2025, 7, 27, 8, 30, 0).unwrap();
-
-        // ユーザーデータ
-        let users: Vec<User> = vec![
-            User {
-                id: 1,
-                uid: "xxxx-xxxx-xxxx-0001".to_string(),
-                last_name: "田中".to_string(),
-                first_name: "太郎".to_string(),
-                email: "t.tanaka@example.com".to_string(),
-                created_at: specific_datetime_1,
-                updated_at: specific_datetime_1,
-                deleted_at: None,
-            },
-            User {
-                id: 2,
-                uid: "xxxx-xxxx-xxxx-0002".to_string(),
-                last_name: "佐藤".to_string(),
-                first_name: "二郎".to_string(),
-                email: "z.satou@example.com".to_string(),
-                created_at: specific_datetime_2,
-                updated_at: specific_datetime_2,
-                deleted_at: None,
-            },
-        ];
-
-        Ok(users)
+        let users = self.db.users.read().unwrap();
+
+        Ok(users
+            .iter()
+            .filter(|user| user.deleted_at.is_none())
+            .cloned()
+            .collect())
+    }
+
+    // uidによるユーザー取得
+    async fn find_by_uid(
+        &self,
+        _ctx: &ContextRequest,
+        uid: &str,
+    ) -> Result<Option<User>, ErrorCommon> {
+        let users = self.db.users.read().unwrap();
+
+        Ok(users
+            .iter()
+            .find(|user| user.uid == uid && user.deleted_at.is_none())
+            .cloned())
+    }
+
+    // メールアドレスによるユーザー取得
+    async fn find_by_email(
+        &self,
+        _ctx: &ContextRequest,
+        email: &str,
+    ) -> Result<Option<User>, ErrorCommon> {
+        let users = self.db.users.read().unwrap();
+
+        Ok(users
+            .iter()
+            .find(|user| user.email == email && user.deleted_at.is_none())
+            .cloned())
+    }
+
+    // ユーザー作成
+    async fn create(&self, ctx: &ContextRequest, user: &User) -> Result<User, ErrorCommon> {
+        let mut users = self.db.users.write().unwrap();
+
+        // uidの重複チェック
+        if users.iter().any(|u| u.uid == user.uid) {
+            let msg = format!("uidが重複しています: {}", user.uid);
+            self.logger.error(ctx, &msg);
+            return Err(ErrorCommon::InternalServerError);
+        }
+
+        // idの採番（ドメインイベントは保存しない）
+        let mut new_user = user.clone();
+        new_user.take_events();
+        new_user.id = users.iter().map(|u| u.id).max().unwrap_or(0) + 1;
+        users.push(new_user.clone());
+
+        Ok(new_user)
+    }
+
+    // ユーザー更新（バージョンが一致する場合のみ）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        user: &User,
+        expected_version: i64,
+    ) -> Result<User, ErrorCommon> {
+        let mut users = self.db.users.write().unwrap();
+
+        match users.iter_mut().find(|u| u.uid == user.uid) {
+            Some(stored_user) if stored_user.version != expected_version => {
+                let msg = format!(
+                    "ユーザーのバージョンが一致しません: uid={}, expected={}, stored={}",
+                    user.uid, expected_version, stored_user.version
+                );
+                self.logger.warn(ctx, &msg);
+                Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::CONFLICT,
+                    message: "対象のユーザーは他の操作により更新されています。最新の情報を取得してから再度実行して下さい。".to_string(),
+                })
+            }
+            Some(stored_user) => {
+                // ドメインイベントは保存しない
+                let mut new_user = user.clone();
+                new_user.take_events();
+                *stored_user = new_user.clone();
+                Ok(new_user)
+            }
+            None => {
+                let msg = format!("更新対象のユーザーが存在しません: {}", user.uid);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    // 物理削除・匿名化の対象のユーザー取得
+    async fn find_purgeable(
+        &self,
+        _ctx: &ContextRequest,
+        cutoff: DateTime<FixedOffset>,
+    ) -> Result<Vec<User>, ErrorCommon> {
+        let users = self.db.users.read().unwrap();
+
+        Ok(users
+            .iter()
+            .filter(|user| user.is_purgeable(cutoff))
+            .cloned()
+            .collect())
+    }
+
+    // ユーザーの物理削除
+    async fn purge(&self, ctx: &ContextRequest, uid: &str) -> Result<(), ErrorCommon> {
+        let mut users = self.db.users.write().unwrap();
+
+        match users
+            .iter()
+            .position(|user| user.uid == uid && user.deleted_at.is_some())
+        {
+            Some(index) => {
+                users.remove(index);
+                Ok(())
+            }
+            None => {
+                let msg = format!("物理削除対象のユーザーが存在しません: {}", uid);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
     }
 }
@@ -0,0 +1,2 @@
+pub mod webhook_delivery_repository;
+pub mod webhook_subscription_repository;
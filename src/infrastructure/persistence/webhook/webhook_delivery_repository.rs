@@ -0,0 +1,159 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, FixedOffset};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    error::error_common::ErrorCommon, outbox::outbox_model::OutboxMessage,
+    webhook::webhook_delivery_model::WebhookDelivery,
+    webhook::webhook_delivery_repository::WebhookDeliveryRepositoryTrait,
+};
+
+// Webhookの配信リポジトリの構造体
+pub struct WebhookDeliveryRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookDeliveryRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        WebhookDeliveryRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRepositoryTrait for WebhookDeliveryRepository {
+    // 配信の追加（同じイベントと購読設定の配信は重複して追加しない）
+    async fn add(
+        &self,
+        _ctx: &ContextRequest,
+        delivery: &WebhookDelivery,
+    ) -> Result<WebhookDelivery, ErrorCommon> {
+        let mut deliveries = self.db.webhook_deliveries.write().unwrap();
+
+        if let Some(stored_delivery) = deliveries.iter().find(|d| {
+            d.event_id == delivery.event_id && d.subscription_id == delivery.subscription_id
+        }) {
+            return Ok(stored_delivery.clone());
+        }
+
+        // idの採番
+        let mut new_delivery = delivery.clone();
+        new_delivery.id = deliveries.iter().map(|d| d.id).max().unwrap_or(0) + 1;
+        deliveries.push(new_delivery.clone());
+
+        Ok(new_delivery)
+    }
+
+    // 配信対象の取得
+    async fn find_due(
+        &self,
+        _ctx: &ContextRequest,
+        now: DateTime<FixedOffset>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, ErrorCommon> {
+        let deliveries = self.db.webhook_deliveries.read().unwrap();
+
+        Ok(deliveries
+            .iter()
+            .filter(|d| d.is_due(now))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    // delivery_idによる配信取得
+    async fn find_by_delivery_id(
+        &self,
+        _ctx: &ContextRequest,
+        delivery_id: &str,
+    ) -> Result<Option<WebhookDelivery>, ErrorCommon> {
+        let deliveries = self.db.webhook_deliveries.read().unwrap();
+
+        Ok(deliveries
+            .iter()
+            .find(|d| d.delivery_id == delivery_id)
+            .cloned())
+    }
+
+    // 購読設定ごとの配信取得（新しい順）
+    async fn find_by_subscription_id(
+        &self,
+        _ctx: &ContextRequest,
+        subscription_id: &str,
+    ) -> Result<Vec<WebhookDelivery>, ErrorCommon> {
+        let deliveries = self.db.webhook_deliveries.read().unwrap();
+
+        Ok(deliveries
+            .iter()
+            .rev()
+            .filter(|d| d.subscription_id == subscription_id)
+            .cloned()
+            .collect())
+    }
+
+    // 配信の更新（DBの場合はUPDATE ... WHERE version = expected_version）
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        delivery: &WebhookDelivery,
+        expected_version: i64,
+    ) -> Result<WebhookDelivery, ErrorCommon> {
+        let mut deliveries = self.db.webhook_deliveries.write().unwrap();
+
+        match deliveries
+            .iter_mut()
+            .find(|d| d.delivery_id == delivery.delivery_id)
+        {
+            Some(stored_delivery) if stored_delivery.version != expected_version => {
+                let msg = format!(
+                    "配信のバージョンが一致しません: delivery_id={}, expected={}, stored={}",
+                    delivery.delivery_id, expected_version, stored_delivery.version
+                );
+                self.logger.warn(ctx, &msg);
+                Err(ErrorCommon::CustomError {
+                    status_code: StatusCode::CONFLICT,
+                    message: "対象の配信は他の処理により更新されています。".to_string(),
+                })
+            }
+            Some(stored_delivery) => {
+                *stored_delivery = delivery.clone();
+                Ok(delivery.clone())
+            }
+            None => {
+                let msg = format!("更新対象の配信が存在しません: {}", delivery.delivery_id);
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+
+    // 個人情報を消去したメッセージのイベントの配信から個人情報を消去
+    async fn scrub_personal_data(
+        &self,
+        _ctx: &ContextRequest,
+        messages: &[OutboxMessage],
+    ) -> Result<(), ErrorCommon> {
+        let mut deliveries = self.db.webhook_deliveries.write().unwrap();
+
+        for delivery in deliveries.iter_mut() {
+            if let Some(message) = messages.iter().find(|m| m.event_id == delivery.event_id) {
+                delivery.scrub_personal_data(message);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+
+// DB
+use crate::infrastructure::database::database_dummy::DbDummy;
+
+// ドメイン
+use crate::domain::{
+    error::error_common::ErrorCommon, webhook::webhook_subscription_model::WebhookSubscription,
+    webhook::webhook_subscription_repository::WebhookSubscriptionRepositoryTrait,
+};
+
+// Webhookの購読設定リポジトリの構造体
+pub struct WebhookSubscriptionRepository {
+    pub db: DbDummy, // TODO: 仮でインメモリのダミーDBにしているが、DBインスタンスに合わせた型に変更する
+    pub logger: Arc<dyn LoggerTrait + 'static>,
+}
+
+impl WebhookSubscriptionRepository {
+    // 初期化用メソッド
+    pub fn new(db: DbDummy, logger: Arc<dyn LoggerTrait + 'static>) -> Self {
+        WebhookSubscriptionRepository { db, logger }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionRepositoryTrait for WebhookSubscriptionRepository {
+    // 全ての購読設定取得（削除済みは除く）
+    async fn find_all(
+        &self,
+        _ctx: &ContextRequest,
+    ) -> Result<Vec<WebhookSubscription>, ErrorCommon> {
+        let subscriptions = self.db.webhook_subscriptions.read().unwrap();
+
+        Ok(subscriptions
+            .iter()
+            .filter(|s| s.deleted_at.is_none())
+            .cloned()
+            .collect())
+    }
+
+    // subscription_idによる購読設定取得（削除済みは除く）
+    async fn find_by_subscription_id(
+        &self,
+        _ctx: &ContextRequest,
+        subscription_id: &str,
+    ) -> Result<Option<WebhookSubscription>, ErrorCommon> {
+        let subscriptions = self.db.webhook_subscriptions.read().unwrap();
+
+        Ok(subscriptions
+            .iter()
+            .find(|s| s.subscription_id == subscription_id && s.deleted_at.is_none())
+            .cloned())
+    }
+
+    // 購読設定作成
+    async fn create(
+        &self,
+        _ctx: &ContextRequest,
+        subscription: &WebhookSubscription,
+    ) -> Result<WebhookSubscription, ErrorCommon> {
+        let mut subscriptions = self.db.webhook_subscriptions.write().unwrap();
+
+        // idの採番
+        let mut new_subscription = subscription.clone();
+        new_subscription.id = subscriptions.iter().map(|s| s.id).max().unwrap_or(0) + 1;
+        subscriptions.push(new_subscription.clone());
+
+        Ok(new_subscription)
+    }
+
+    // 購読設定更新
+    async fn update(
+        &self,
+        ctx: &ContextRequest,
+        subscription: &WebhookSubscription,
+    ) -> Result<WebhookSubscription, ErrorCommon> {
+        let mut subscriptions = self.db.webhook_subscriptions.write().unwrap();
+
+        match subscriptions
+            .iter_mut()
+            .find(|s| s.subscription_id == subscription.subscription_id)
+        {
+            Some(stored_subscription) => {
+                *stored_subscription = subscription.clone();
+                Ok(subscription.clone())
+            }
+            None => {
+                let msg = format!(
+                    "更新対象の購読設定が存在しません: {}",
+                    subscription.subscription_id
+                );
+                self.logger.error(ctx, &msg);
+                Err(ErrorCommon::InternalServerError)
+            }
+        }
+    }
+}
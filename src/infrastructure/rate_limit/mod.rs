@@ -0,0 +1,4 @@
+pub mod rate_limit_memory;
+
+// テストコード用のモジュール
+pub mod rate_limit_memory_test;
@@ -0,0 +1,110 @@
+// ハッシュマップ
+use std::collections::HashMap;
+
+// Mutex（複数のスレッド間で安全に値を更新するためのロック）
+use std::sync::Mutex;
+
+// 時刻（単調増加する時計）
+use std::time::Instant;
+
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// レート制限
+use crate::application::usecase::rate_limit::{
+    rate_limit_policy::RateLimitPolicy,
+    rate_limit_store_trait::{RateLimitDecision, RateLimitStoreTrait},
+};
+
+// 不要なバケットを削除するしきい値（バケット数）
+const PURGE_THRESHOLD: usize = 10_000;
+
+// トークンバケット
+#[derive(Clone, Debug)]
+pub struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+    period_secs: u64,
+}
+
+impl TokenBucket {
+    // 満タンの状態で初期化
+    pub fn new(policy: &RateLimitPolicy, now: Instant) -> Self {
+        TokenBucket {
+            tokens: policy.capacity as f64,
+            updated_at: now,
+            period_secs: policy.period_secs,
+        }
+    }
+
+    // 経過時間分のトークンを補充してから1つ取得する
+    pub fn take(&mut self, policy: &RateLimitPolicy, now: Instant) -> RateLimitDecision {
+        let capacity = policy.capacity as f64;
+        let refill_per_sec = policy.refill_per_sec();
+
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * refill_per_sec).min(capacity);
+        self.updated_at = now;
+        self.period_secs = policy.period_secs;
+
+        let allowed = self.tokens >= 1.0;
+        let retry_after_secs = if allowed {
+            self.tokens -= 1.0;
+            0
+        } else {
+            ((1.0 - self.tokens) / refill_per_sec).ceil() as u64
+        };
+
+        RateLimitDecision {
+            allowed,
+            limit: policy.capacity,
+            remaining: self.tokens.floor() as u32,
+            reset_secs: ((capacity - self.tokens) / refill_per_sec).ceil() as u64,
+            retry_after_secs,
+        }
+    }
+
+    // 期間以上アクセスがなく満タンに戻っているかどうか
+    fn is_idle(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.updated_at).as_secs() >= self.period_secs
+    }
+}
+
+// インメモリのレート制限ストア（単一インスタンス用）
+#[derive(Default)]
+pub struct RateLimitMemory {
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl RateLimitMemory {
+    // 初期化用メソッド
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RateLimitStoreTrait for RateLimitMemory {
+    async fn acquire(
+        &self,
+        key: &str,
+        policy: &RateLimitPolicy,
+    ) -> Result<RateLimitDecision, ErrorCommon> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+
+        // バケット数が多い場合は満タンに戻ったバケットを削除
+        if buckets.len() >= PURGE_THRESHOLD {
+            buckets.retain(|_, bucket| !bucket.is_idle(now));
+        }
+
+        let bucket = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::new(policy, now));
+
+        Ok(bucket.take(policy, now))
+    }
+}
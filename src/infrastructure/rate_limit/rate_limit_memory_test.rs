@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    // レート制限
+    use crate::application::usecase::rate_limit::{
+        rate_limit_policy::RateLimitPolicy, rate_limit_store_trait::RateLimitStoreTrait,
+    };
+    use crate::infrastructure::rate_limit::rate_limit_memory::{RateLimitMemory, TokenBucket};
+
+    #[test]
+    fn test_take_and_refill() {
+        let policy = RateLimitPolicy::parse("2/10").unwrap();
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(&policy, now);
+
+        // テスト実行
+        let first = bucket.take(&policy, now);
+        let second = bucket.take(&policy, now);
+        let third = bucket.take(&policy, now);
+
+        // 検証
+        assert!(first.allowed);
+        assert_eq!(first.limit, 2);
+        assert_eq!(first.remaining, 1);
+        assert_eq!(first.reset_secs, 5);
+        assert!(second.allowed);
+        assert_eq!(second.remaining, 0);
+        assert!(!third.allowed);
+        assert_eq!(third.remaining, 0);
+        assert_eq!(third.retry_after_secs, 5);
+        assert_eq!(third.reset_secs, 10);
+
+        // 5秒後にトークンが1つ補充される
+        let refilled = bucket.take(&policy, now + Duration::from_secs(5));
+        assert!(refilled.allowed);
+        assert_eq!(refilled.remaining, 0);
+        assert_eq!(refilled.retry_after_secs, 0);
+
+        // 補充は上限を超えない
+        let full = bucket.take(&policy, now + Duration::from_secs(100));
+        assert!(full.allowed);
+        assert_eq!(full.remaining, 1);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_by_key() {
+        let store = RateLimitMemory::new();
+        let policy = RateLimitPolicy::parse("1/60").unwrap();
+
+        // テスト実行
+        let first = store.acquire("ip:127.0.0.1", &policy).await.unwrap();
+        let second = store.acquire("ip:127.0.0.1", &policy).await.unwrap();
+        let other = store.acquire("ip:127.0.0.2", &policy).await.unwrap();
+
+        // 検証（キーごとに独立したバケット）
+        assert!(first.allowed);
+        assert!(!second.allowed);
+        assert_eq!(second.retry_after_secs, 60);
+        assert!(other.allowed);
+    }
+}
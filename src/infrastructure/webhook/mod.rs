@@ -0,0 +1,4 @@
+pub mod webhook_sender_reqwest;
+
+// テストコード用のモジュール
+mod webhook_sender_reqwest_test;
@@ -0,0 +1,52 @@
+// 非同期処理用のトレイト
+use async_trait::async_trait;
+
+// axum
+use axum::http::StatusCode;
+
+use std::time::Duration;
+
+// Webhookの送信
+use crate::application::usecase::webhook::webhook_sender_trait::{
+    WebhookRequest, WebhookResponse, WebhookSenderTrait,
+};
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// reqwestでWebhookを送信する構造体
+pub struct WebhookSenderReqwest {
+    client: reqwest::Client,
+}
+
+impl WebhookSenderReqwest {
+    // 初期化用メソッド（タイムアウトは秒数で指定）
+    pub fn new(timeout_secs: u64) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .expect("Webhook送信用のHTTPクライアントを作成できませんでした");
+
+        WebhookSenderReqwest { client }
+    }
+}
+
+#[async_trait]
+impl WebhookSenderTrait for WebhookSenderReqwest {
+    async fn send(&self, request: &WebhookRequest) -> Result<WebhookResponse, ErrorCommon> {
+        let mut builder = self.client.post(&request.url).body(request.body.clone());
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        match builder.send().await {
+            Ok(res) => Ok(WebhookResponse {
+                status_code: res.status().as_u16(),
+            }),
+            Err(err) => Err(ErrorCommon::CustomError {
+                status_code: StatusCode::BAD_GATEWAY,
+                message: format!("Webhookの送信に失敗しました: {}", err),
+            }),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use axum::{Router, body::Bytes, http::HeaderMap, http::StatusCode, routing::post};
+    use std::time::Duration;
+
+    // Webhookの送信
+    use crate::application::usecase::webhook::webhook_sender_trait::{
+        WebhookRequest, WebhookSenderTrait,
+    };
+    use crate::infrastructure::webhook::webhook_sender_reqwest::WebhookSenderReqwest;
+
+    // 署名
+    use crate::domain::webhook::webhook_signature::{sign, verify};
+
+    const SECRET: &str = "whsec_test";
+
+    // 署名を検証する受信側のスタンドインを起動してベースURLを返す
+    async fn start_receiver() -> String {
+        let app = Router::new()
+            .route(
+                "/hook",
+                post(|headers: HeaderMap, body: Bytes| async move {
+                    let timestamp = headers["Webhook-Timestamp"]
+                        .to_str()
+                        .unwrap()
+                        .parse::<i64>()
+                        .unwrap();
+                    let signature = headers["Webhook-Signature"].to_str().unwrap();
+                    let body = String::from_utf8(body.to_vec()).unwrap();
+                    if verify(SECRET, timestamp, &body, signature) {
+                        StatusCode::NO_CONTENT
+                    } else {
+                        StatusCode::UNAUTHORIZED
+                    }
+                }),
+            )
+            .route(
+                "/slow",
+                post(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    StatusCode::OK
+                }),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        base_url
+    }
+
+    // テスト用の送信内容
+    fn create_request(url: String, secret: &str) -> WebhookRequest {
+        let timestamp = 1_700_000_000;
+        let body = r#"{"type":"user.created"}"#.to_string();
+        WebhookRequest {
+            url,
+            headers: vec![
+                ("Content-Type".to_string(), "application/json".to_string()),
+                ("Webhook-Timestamp".to_string(), timestamp.to_string()),
+                (
+                    "Webhook-Signature".to_string(),
+                    sign(secret, timestamp, &body),
+                ),
+            ],
+            body,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send() {
+        let base_url = start_receiver().await;
+        let sender = WebhookSenderReqwest::new(3);
+
+        // 検証（署名が一致する場合）
+        let req = create_request(format!("{}/hook", base_url), SECRET);
+        let res = sender.send(&req).await.unwrap();
+        assert_eq!(res.status_code, 204);
+
+        // 検証（署名が一致しない場合はレスポンスのステータスコードを返す）
+        let req = create_request(format!("{}/hook", base_url), "whsec_other");
+        let res = sender.send(&req).await.unwrap();
+        assert_eq!(res.status_code, 401);
+    }
+
+    #[tokio::test]
+    async fn test_send_error() {
+        let base_url = start_receiver().await;
+        let sender = WebhookSenderReqwest::new(1);
+
+        // 検証（タイムアウト）
+        let req = create_request(format!("{}/slow", base_url), SECRET);
+        assert!(sender.send(&req).await.is_err());
+
+        // 検証（接続エラー）
+        let req = create_request("http://127.0.0.1:1/hook".to_string(), SECRET);
+        assert!(sender.send(&req).await.is_err());
+    }
+}
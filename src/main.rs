@@ -2,7 +2,13 @@
 use axum::serve;
 
 // Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
+
+// 停止の通知用のチャネルとシグナル
+use tokio::signal::unix::{SignalKind, signal};
+use tokio::sync::watch;
 
 // モジュールのインポート
 mod application;
@@ -13,32 +19,167 @@ mod presentation;
 mod registry;
 
 // コンフィグ設定
+use crate::config::config_reloadable::{
+    ReloadableConfigHandle, load_reloadable_config, watch_reloadable_config,
+};
 use crate::config::config_settings::get_config;
 
+// アウトボックスの送信処理
+use crate::application::usecase::outbox::outbox_relay::run_outbox_relay;
+
+// Webhookの配信処理
+use crate::application::usecase::webhook::webhook_deliver::run_webhook_delivery;
+
+// バックグラウンドジョブのワーカーとスケジューラー
+use crate::application::usecase::job::job_scheduler::run_job_scheduler;
+use crate::application::usecase::job::job_worker::run_job_worker;
+
 // ルーター設定
 use crate::presentation::router::router_settings::router;
 
 // ロガー設定
-use crate::infrastructure::logger::logger_log::Logger;
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::{DEFAULT_FILTERS, Logger};
 
 // レジストリ設定
 use crate::registry::registry_settings::AppState;
 
 #[tokio::main]
 async fn main() {
-    // 環境変数取得
-    let config = get_config();
+    // ロガーの初期化（設定の読み込みエラーを出力するため、環境変数のRUST_LOGで先に初期化）
+    let rust_log = std::env::var("RUST_LOG").unwrap_or_else(|_| DEFAULT_FILTERS.to_string());
+    Logger::init(&rust_log);
+
+    // 環境変数取得（エラーの場合は起動しない）
+    let config = match get_config() {
+        Ok(config) => config,
+        Err(err) => {
+            log::error!("設定の読み込みに失敗したため起動を中止します: {}", err);
+            std::process::exit(1);
+        }
+    };
 
-    // ロガーの初期化
-    Logger::init();
+    // 再起動なしで変更可能な設定の取得（エラーの場合は起動しない）
+    let reloadable_config = match load_reloadable_config(config.config_file.as_deref()) {
+        Ok(reloadable_config) => reloadable_config,
+        Err(err) => {
+            log::error!(
+                "再読み込み可能な設定の読み込みに失敗したため起動を中止します: {}",
+                err
+            );
+            std::process::exit(1);
+        }
+    };
+
+    // 設定ファイルのログフィルターを反映
+    if let Err(err) = Logger::new(module_path!()).set_filters(&reloadable_config.rust_log) {
+        log::error!(
+            "ログフィルターの設定に失敗したため起動を中止します: {}",
+            err
+        );
+        std::process::exit(1);
+    }
 
     // サーバー起動のログ出力
     log::info!("Start rust_axum_domain (ENV:{}) !!", config.env);
 
+    // 設定の再読み込み（SIGHUPまたは設定ファイルの変更時）
+    let reloadable_config = ReloadableConfigHandle::new(reloadable_config);
+    tokio::spawn(watch_reloadable_config(
+        config.config_file.clone(),
+        Duration::from_secs(config.config_reload_interval_secs),
+        reloadable_config.clone(),
+        Arc::new(Logger::new(module_path!())),
+    ));
+
     // サーバー起動
-    let state = Arc::new(AppState::new().await);
-    let app = router(state);
+    let state = Arc::new(AppState::new(&config, reloadable_config).await);
+
+    // 停止の通知（サーバーとバックグラウンドタスクで共有）
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let mut tasks = Vec::new();
+
+    // アウトボックスの送信処理（バックグラウンドタスク、停止時は送信中のメッセージの完了を待機）
+    tasks.push(tokio::spawn(run_outbox_relay(
+        state.outbox_relay.clone(),
+        Duration::from_secs(config.outbox_relay_interval_secs),
+        shutdown_rx.clone(),
+        Arc::new(Logger::new("rust_axum_domain::application::usecase")),
+    )));
+
+    // Webhookの配信処理（バックグラウンドタスク、停止時は配信中のWebhookの完了を待機）
+    tasks.push(tokio::spawn(run_webhook_delivery(
+        state.webhook_deliver.clone(),
+        Duration::from_secs(config.webhook_delivery_interval_secs),
+        shutdown_rx.clone(),
+        Arc::new(Logger::new("rust_axum_domain::application::usecase")),
+    )));
+
+    // ジョブのワーカーとスケジューラー（バックグラウンドタスク、停止時は実行中のジョブの完了を待機）
+    for _ in 0..config.job_worker_count {
+        tasks.push(tokio::spawn(run_job_worker(
+            state.job_worker.clone(),
+            Duration::from_secs(config.job_poll_interval_secs),
+            shutdown_rx.clone(),
+            Arc::new(Logger::new("rust_axum_domain::application::usecase")),
+        )));
+    }
+    tasks.push(tokio::spawn(run_job_scheduler(
+        state.job_scheduler.clone(),
+        Duration::from_secs(1),
+        shutdown_rx.clone(),
+        Arc::new(Logger::new("rust_axum_domain::application::usecase")),
+    )));
+
+    let app = router(state, &config);
     let addr = format!("0.0.0.0:{}", config.port);
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    serve(listener, app).await.unwrap();
+    // クライアントIPをレート制限で使用するため接続情報を付与
+    let mut server_shutdown = shutdown_rx.clone();
+    tasks.push(tokio::spawn(async move {
+        let result = serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(async move {
+            let _ = server_shutdown.wait_for(|shutdown| *shutdown).await;
+        })
+        .await;
+        if let Err(err) = result {
+            log::error!("サーバーのエラー: {}", err);
+        }
+    }));
+
+    // 停止シグナルを受信したら新規の受付を停止して処理中のリクエストとジョブの完了を待機
+    // ※SSE・WebSocketの接続は切断されないため、待機時間を超えた場合はそのまま停止
+    shutdown_signal().await;
+    log::info!(
+        "停止シグナルを受信しました。処理中のリクエストとバックグラウンドタスクの完了を待機します"
+    );
+    let _ = shutdown_tx.send(true);
+
+    let timeout = Duration::from_secs(config.shutdown_timeout_secs);
+    let wait = async {
+        for task in tasks {
+            let _ = task.await;
+        }
+    };
+    if tokio::time::timeout(timeout, wait).await.is_err() {
+        log::warn!(
+            "停止の待機がタイムアウトしました（{}秒）",
+            config.shutdown_timeout_secs
+        );
+    }
+
+    log::info!("Stop rust_axum_domain !!");
+}
+
+// 停止シグナル（Ctrl+CまたはSIGTERM）の受信待ち
+async fn shutdown_signal() {
+    let mut sigterm = signal(SignalKind::terminate()).expect("SIGTERMの監視設定エラー");
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = sigterm.recv() => {}
+    }
 }
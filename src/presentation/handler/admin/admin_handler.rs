@@ -0,0 +1,55 @@
+// axum
+use axum::{
+    Json,
+    extract::{Extension, State},
+    response::Response,
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ユースケースのトレイト
+use crate::application::usecase::admin::admin_log_level_find::AdminLogLevelFindUsecaseTrait;
+use crate::application::usecase::admin::admin_log_level_update::{
+    AdminLogLevelUpdateInput, AdminLogLevelUpdateUsecaseTrait,
+};
+use crate::application::usecase::admin::admin_outbox_find_stuck::AdminOutboxFindStuckUsecaseTrait;
+
+// ハンドラー
+// ログフィルター設定の取得
+pub async fn find_log_level(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+) -> Response {
+    // ユースケースを実行
+    state.admin_usecase.admin_log_level_find.exec(ctx).await
+}
+
+// ログフィルター設定の変更
+pub async fn update_log_level(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Json(input): Json<AdminLogLevelUpdateInput>,
+) -> Response {
+    // ユースケースを実行
+    state
+        .admin_usecase
+        .admin_log_level_update
+        .exec(ctx, input)
+        .await
+}
+
+// 滞留しているアウトボックスのメッセージ取得
+pub async fn find_stuck_outbox(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+) -> Response {
+    // ユースケースを実行
+    state.admin_usecase.admin_outbox_find_stuck.exec(ctx).await
+}
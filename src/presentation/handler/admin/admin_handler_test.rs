@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    // テスト用のヘルパー
+    use crate::presentation::handler::handler_test_helper::create_bearer_token;
+
+    #[tokio::test]
+    async fn test_find_log_level_response_ok() {
+        // リクエストを実行
+        let url = "http://localhost:8080/api/v1/admin/log-level";
+        let client = reqwest::Client::new();
+        let res = client
+            .get(url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]),
+            )
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 200);
+
+        // レスポンスボディの検証
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        assert!(res_body["filters"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_update_log_level_response_ok() {
+        // リクエストを実行
+        let url = "http://localhost:8080/api/v1/admin/log-level";
+        let client = reqwest::Client::new();
+        let req_body = json!({ "filters": "info" });
+        let res = client
+            .put(url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]),
+            )
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 200);
+
+        // レスポンスボディの検証
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        assert_eq!(res_body["filters"], "info");
+    }
+
+    #[tokio::test]
+    async fn test_update_log_level_response_bad_request() {
+        // リクエストを実行
+        let url = "http://localhost:8080/api/v1/admin/log-level";
+        let client = reqwest::Client::new();
+        let req_body = json!({ "filters": "info,rust_axum_domain=xxx" });
+        let res = client
+            .put(url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]),
+            )
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_find_stuck_outbox_response_ok() {
+        // リクエストを実行
+        let url = "http://localhost:8080/api/v1/admin/outbox/stuck";
+        let client = reqwest::Client::new();
+        let res = client
+            .get(url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]),
+            )
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 200);
+
+        // レスポンスボディの検証
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        assert!(res_body.is_array());
+    }
+
+    #[tokio::test]
+    async fn test_find_stuck_outbox_response_forbidden() {
+        // リクエストを実行
+        let url = "http://localhost:8080/api/v1/admin/outbox/stuck";
+        let client = reqwest::Client::new();
+        let res = client
+            .get(url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0002", &["operator"]),
+            )
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 403);
+    }
+}
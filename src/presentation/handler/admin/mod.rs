@@ -0,0 +1,4 @@
+pub mod admin_handler;
+
+// テストコード用のモジュール
+pub mod admin_handler_test;
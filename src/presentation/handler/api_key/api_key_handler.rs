@@ -0,0 +1,52 @@
+// axum
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+    response::Response,
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ユースケースのトレイト
+use crate::application::usecase::api_key::api_key_find_all::ApiKeyFindAllUsecaseTrait;
+use crate::application::usecase::api_key::api_key_issue::{
+    ApiKeyIssueInput, ApiKeyIssueUsecaseTrait,
+};
+use crate::application::usecase::api_key::api_key_revoke::ApiKeyRevokeUsecaseTrait;
+
+// ハンドラー
+// 全てのAPIキー取得
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+) -> Response {
+    // ユースケースを実行
+    state.api_key_usecase.api_key_find_all.exec(ctx).await
+}
+
+// APIキーの発行
+pub async fn issue(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Json(input): Json<ApiKeyIssueInput>,
+) -> Response {
+    // ユースケースを実行
+    state.api_key_usecase.api_key_issue.exec(ctx, input).await
+}
+
+// APIキーの失効
+pub async fn revoke(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(key_id): Path<String>,
+) -> Response {
+    // ユースケースを実行
+    state.api_key_usecase.api_key_revoke.exec(ctx, key_id).await
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    // テスト用のヘルパー
+    use crate::presentation::handler::handler_test_helper::create_bearer_token;
+
+    #[tokio::test]
+    async fn test_api_key_flow() {
+        let base_url = "http://localhost:8080/api/v1";
+        let client = reqwest::Client::new();
+        let admin_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+
+        // APIキーの発行
+        let req_body = json!({ "name": "batch", "scopes": ["users:read"] });
+        let res = client
+            .post(format!("{}/admin/api-keys", base_url))
+            .header("Authorization", &admin_token)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 201);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        let plain_key = res_body["key"].as_str().unwrap().to_string();
+        let key_id = res_body["api_key"]["key_id"].as_str().unwrap().to_string();
+
+        // APIキーでの参照（スコープ内）
+        let res = client
+            .get(format!("{}/users", base_url))
+            .header("X-Api-Key", &plain_key)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+
+        // APIキーでの作成（スコープ外）
+        let req_body = json!({
+            "first_name": "テスト",
+            "last_name": "APIキー",
+            "email": "api-key@example.com"
+        });
+        let res = client
+            .post(format!("{}/users", base_url))
+            .header("X-Api-Key", &plain_key)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 403);
+
+        // APIキー一覧の取得（最終利用日時が設定されていること）
+        let res = client
+            .get(format!("{}/admin/api-keys", base_url))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        let api_key = res_body
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|api_key| api_key["key_id"] == key_id.as_str())
+            .unwrap();
+        assert!(api_key["last_used_at"].is_string());
+
+        // APIキーの失効
+        let res = client
+            .delete(format!("{}/admin/api-keys/{}", base_url, key_id))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 204);
+
+        // 失効したAPIキーでの参照
+        let res = client
+            .get(format!("{}/users", base_url))
+            .header("X-Api-Key", &plain_key)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_api_key_response_unauthorized() {
+        // リクエストを実行
+        let url = "http://localhost:8080/api/v1/users";
+        let client = reqwest::Client::new();
+        let res = client
+            .get(url)
+            .header("X-Api-Key", "ak_xxxx_yyyy")
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 401);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod api_key_handler;
+
+// テストコード用のモジュール
+pub mod api_key_handler_test;
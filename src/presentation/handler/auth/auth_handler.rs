@@ -0,0 +1,81 @@
+// axum
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+    response::Response,
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ユースケースのトレイト
+use crate::application::usecase::auth::auth_login::{AuthLoginInput, AuthLoginUsecaseTrait};
+use crate::application::usecase::auth::auth_oidc_authorize::AuthOidcAuthorizeUsecaseTrait;
+use crate::application::usecase::auth::auth_oidc_callback::{
+    AuthOidcCallbackInput, AuthOidcCallbackUsecaseTrait,
+};
+use crate::application::usecase::auth::auth_password_update::{
+    AuthPasswordUpdateInput, AuthPasswordUpdateUsecaseTrait,
+};
+use crate::application::usecase::auth::auth_refresh::{AuthRefreshInput, AuthRefreshUsecaseTrait};
+
+// ハンドラー
+// ログイン
+pub async fn login(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Json(input): Json<AuthLoginInput>,
+) -> Response {
+    // ユースケースを実行
+    state.auth_usecase.auth_login.exec(ctx, input).await
+}
+
+// トークンの再発行
+pub async fn refresh(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Json(input): Json<AuthRefreshInput>,
+) -> Response {
+    // ユースケースを実行
+    state.auth_usecase.auth_refresh.exec(ctx, input).await
+}
+
+// OIDCによるログイン（IDプロバイダーへのリダイレクト）
+pub async fn oidc_authorize(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+) -> Response {
+    // ユースケースを実行
+    state.auth_usecase.auth_oidc_authorize.exec(ctx).await
+}
+
+// OIDCのコールバック
+pub async fn oidc_callback(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Query(input): Query<AuthOidcCallbackInput>,
+) -> Response {
+    // ユースケースを実行
+    state.auth_usecase.auth_oidc_callback.exec(ctx, input).await
+}
+
+// パスワードの設定
+pub async fn update_password(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(uid): Path<String>,
+    Json(input): Json<AuthPasswordUpdateInput>,
+) -> Response {
+    // ユースケースを実行
+    state
+        .auth_usecase
+        .auth_password_update
+        .exec(ctx, uid, input)
+        .await
+}
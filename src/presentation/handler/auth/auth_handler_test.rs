@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    // テスト用のヘルパー
+    use crate::presentation::handler::handler_test_helper::create_bearer_token;
+
+    #[tokio::test]
+    async fn test_login_refresh_flow() {
+        let base_url = "http://localhost:8080/api/v1";
+        let client = reqwest::Client::new();
+
+        // パスワードの設定（本人）
+        let req_body = json!({ "password": "password-0002" });
+        let res = client
+            .put(format!("{}/users/xxxx-xxxx-xxxx-0002/password", base_url))
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0002", &[]),
+            )
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 204);
+
+        // ログイン
+        let req_body = json!({ "email": "z.satou@example.com", "password": "password-0002" });
+        let res = client
+            .post(format!("{}/auth/login", base_url))
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        let access_token = res_body["access_token"].as_str().unwrap().to_string();
+        let refresh_token = res_body["refresh_token"].as_str().unwrap().to_string();
+
+        // 発行したアクセストークンで本人の情報を取得
+        let res = client
+            .get(format!("{}/users/xxxx-xxxx-xxxx-0002", base_url))
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+
+        // トークンの再発行（ローテーション）
+        let req_body = json!({ "refresh_token": refresh_token });
+        let res = client
+            .post(format!("{}/auth/refresh", base_url))
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        let rotated_refresh_token = res_body["refresh_token"].as_str().unwrap().to_string();
+
+        // ローテーション済みのトークンの再利用は拒否
+        let req_body = json!({ "refresh_token": refresh_token });
+        let res = client
+            .post(format!("{}/auth/refresh", base_url))
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 401);
+
+        // 再利用を検知した場合は新しいトークンも失効
+        let req_body = json!({ "refresh_token": rotated_refresh_token });
+        let res = client
+            .post(format!("{}/auth/refresh", base_url))
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn test_login_response_unauthorized() {
+        // リクエストを実行
+        let url = "http://localhost:8080/api/v1/auth/login";
+        let client = reqwest::Client::new();
+        let req_body = json!({ "email": "unknown@example.com", "password": "password-0001" });
+        let res = client.post(url).json(&req_body).send().await.unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn test_oidc_callback_response_invalid_state() {
+        // リクエストを実行（存在しないstate、OIDCが未設定の場合は503）
+        let url = "http://localhost:8080/api/v1/auth/oidc/callback?code=xxxx&state=xxxx";
+        let client = reqwest::Client::new();
+        let res = client.get(url).send().await.unwrap();
+
+        // レスポンスステータスの検証
+        assert!(res.status() == 401 || res.status() == 503);
+    }
+}
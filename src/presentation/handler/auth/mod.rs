@@ -0,0 +1,4 @@
+pub mod auth_handler;
+
+// テストコード用のモジュール
+pub mod auth_handler_test;
@@ -0,0 +1,100 @@
+// axum
+use axum::{
+    Json,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー設定
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::Logger;
+
+// If-Matchヘッダーのチェック（未指定の場合は428、比較できるETagがない場合は412のレスポンスを返す）
+// ※指定されたETagを返し（「*」の場合はNone）、現在のリソースのETagとの比較はユースケースで行う
+// ※強い比較のため、弱いETag（W/）は一致しない値として除外する
+pub fn check_if_match(ctx: &ContextRequest) -> Result<Option<Vec<String>>, Box<Response>> {
+    let if_match = ctx.header.get(header::IF_MATCH);
+    let (status_code, message) = match if_match.and_then(|v| v.to_str().ok()) {
+        Some(value) if value.split(',').any(|tag| tag.trim() == "*") => return Ok(None),
+        Some(value) => {
+            let etags: Vec<String> = value
+                .split(',')
+                .map(|tag| tag.trim())
+                .filter(|tag| tag.len() >= 2 && tag.starts_with('"') && tag.ends_with('"'))
+                .map(|tag| tag.to_string())
+                .collect();
+            if !etags.is_empty() {
+                return Ok(Some(etags));
+            }
+            (
+                StatusCode::PRECONDITION_FAILED,
+                "対象のリソースが更新されています。最新の情報を取得してから再度実行して下さい。",
+            )
+        }
+        None => (
+            StatusCode::PRECONDITION_REQUIRED,
+            "If-Matchヘッダーを指定して下さい。",
+        ),
+    };
+
+    // ログ出力
+    let logger = Logger::new(module_path!());
+    let err_msg = format!("条件付きリクエストのエラー: {}", message);
+    logger.warn(ctx, &err_msg);
+
+    // レスポンスヘッダーに付与する値の設定
+    let x_request_id = ctx.header.get("X-Request-Id");
+    let request_id = x_request_id.expect("-").to_str().unwrap();
+    let res_header = [("X-Request-Id", request_id)];
+
+    // json形式のメッセージを設定
+    let json_msg = Json(json!({ "message": message }));
+
+    Err(Box::new(
+        (status_code, res_header, json_msg).into_response(),
+    ))
+}
+
+// If-Match・If-None-Matchヘッダーの値とETagの比較（weak=trueの場合は弱い比較）
+pub fn etag_matches(header_value: &str, etag: &str, weak: bool) -> bool {
+    header_value.split(',').map(|tag| tag.trim()).any(|tag| {
+        if tag == "*" {
+            return true;
+        }
+        match tag.strip_prefix("W/") {
+            Some(weak_tag) => weak && weak_tag == etag,
+            None => tag == etag,
+        }
+    })
+}
+
+// 取得系のレスポンスのETag（ユースケースで付与）とIf-None-Matchを比較し、一致する場合は304
+pub fn etag_response(ctx: &ContextRequest, res: Response) -> Response {
+    let etag = match res.headers().get(header::ETAG) {
+        Some(etag) => etag.clone(),
+        None => return res,
+    };
+
+    let if_none_match = ctx.header.get(header::IF_NONE_MATCH);
+    let not_modified = match (etag.to_str(), if_none_match.and_then(|v| v.to_str().ok())) {
+        (Ok(etag), Some(value)) => etag_matches(value, etag, true),
+        _ => false,
+    };
+    if !not_modified {
+        return res;
+    }
+
+    // レスポンスヘッダーに付与する値の設定
+    let x_request_id = ctx.header.get("X-Request-Id");
+    let request_id = x_request_id.expect("-").to_str().unwrap();
+    let mut res = (StatusCode::NOT_MODIFIED, [("X-Request-Id", request_id)]).into_response();
+    res.headers_mut().insert(header::ETAG, etag);
+
+    res
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json,
+        body::to_bytes,
+        http::{HeaderMap, StatusCode, header},
+        response::{IntoResponse, Response},
+    };
+    use serde_json::json;
+
+    // 共通コンテキスト
+    use crate::application::usecase::context::context_request::ContextRequest;
+
+    // ドメイン
+    use crate::domain::user::user_model::User;
+
+    // 条件付きリクエスト（ETag）
+    use crate::presentation::handler::handler_conditional::{
+        check_if_match, etag_matches, etag_response,
+    };
+
+    // 共通コンテキストの作成
+    fn create_ctx(headers: &[(&'static str, &str)]) -> ContextRequest {
+        let mut h = HeaderMap::new();
+        h.insert("X-Request-Id", "xxx-yyy-zzz-001".parse().unwrap());
+        for (key, value) in headers {
+            h.insert(*key, value.parse().unwrap());
+        }
+
+        ContextRequest {
+            header: h,
+            method: "GET".to_string(),
+            uri: "/api/v1/users/xxxx-xxxx-xxxx-0001".to_string(),
+            auth: None,
+        }
+    }
+
+    // テスト用のユーザー
+    fn create_user() -> User {
+        User::new(
+            "xxxx-xxxx-xxxx-0001".to_string(),
+            "田中".to_string(),
+            "太郎".to_string(),
+            "t.tanaka@example.com".to_string(),
+        )
+    }
+
+    // ユーザーを返すレスポンス（ユースケースと同様にETagを付与）
+    fn user_response(user: &User) -> Response {
+        let mut res = (StatusCode::OK, Json(json!(user))).into_response();
+        res.headers_mut()
+            .insert(header::ETAG, user.etag().parse().unwrap());
+        res
+    }
+
+    #[test]
+    fn test_etag_matches() {
+        // 検証
+        assert!(etag_matches("\"abc\"", "\"abc\"", false));
+        assert!(etag_matches("\"xyz\", \"abc\"", "\"abc\"", false));
+        assert!(etag_matches("*", "\"abc\"", false));
+        assert!(!etag_matches("\"xyz\"", "\"abc\"", false));
+
+        // 弱いETagは弱い比較の場合のみ一致
+        assert!(!etag_matches("W/\"abc\"", "\"abc\"", false));
+        assert!(etag_matches("W/\"abc\"", "\"abc\"", true));
+    }
+
+    #[tokio::test]
+    async fn test_etag_response() {
+        let user = create_user();
+        let etag = user.etag();
+
+        // テスト実行（If-None-Matchなし）
+        let ctx = create_ctx(&[]);
+        let res = etag_response(&ctx, user_response(&user));
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()["ETag"], etag.as_str());
+        let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        let res_user: User = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res_user.uid, user.uid);
+
+        // テスト実行（If-None-Matchが一致しない）
+        let ctx = create_ctx(&[("If-None-Match", "\"1-xxx\"")]);
+        let res = etag_response(&ctx, user_response(&user));
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // テスト実行（If-None-Matchが一致）
+        let ctx = create_ctx(&[("If-None-Match", &etag)]);
+        let res = etag_response(&ctx, user_response(&user));
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.headers()["ETag"], etag.as_str());
+        assert_eq!(res.headers()["X-Request-Id"], "xxx-yyy-zzz-001");
+        let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_etag_response_error() {
+        // テスト実行（ETagがないエラーのレスポンスはそのまま返す）
+        let ctx = create_ctx(&[("If-None-Match", "*")]);
+        let res = (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "message": "対象のユーザーが存在しません。" })),
+        )
+            .into_response();
+        let res = etag_response(&ctx, res);
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        assert!(!res.headers().contains_key("ETag"));
+    }
+
+    #[test]
+    fn test_check_if_match() {
+        let etag = create_user().etag();
+
+        // 検証（指定されたETagの全体、「*」の場合はNone）
+        assert_eq!(
+            check_if_match(&create_ctx(&[("If-Match", &etag)])).unwrap(),
+            Some(vec![etag.clone()])
+        );
+        assert_eq!(
+            check_if_match(&create_ctx(&[("If-Match", "\"xxx\", W/\"yyy\"")])).unwrap(),
+            Some(vec!["\"xxx\"".to_string()])
+        );
+        assert_eq!(
+            check_if_match(&create_ctx(&[("If-Match", "*")])).unwrap(),
+            None
+        );
+
+        let res = check_if_match(&create_ctx(&[])).unwrap_err();
+        assert_eq!(res.status(), StatusCode::PRECONDITION_REQUIRED);
+
+        // 弱いETagのみの場合は一致しない
+        let res = check_if_match(&create_ctx(&[("If-Match", "W/\"xxx\"")])).unwrap_err();
+        assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(test)]
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+
+// テスト用のAuthorizationヘッダーの値を作成
+// ※テスト用の環境変数（JWT_HS256_SECRET）を設定したローカルサーバーと同じ鍵で署名する
+#[cfg(test)]
+pub fn create_bearer_token(subject: &str, roles: &[&str]) -> String {
+    let secret = std::env::var("JWT_HS256_SECRET")
+        .expect("テスト用の環境変数「JWT_HS256_SECRET」を設定して下さい");
+    let claims = serde_json::json!({
+        "sub": subject,
+        "roles": roles,
+        "exp": chrono::Utc::now().timestamp() + 3600,
+    });
+    let token = encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .unwrap();
+
+    format!("Bearer {}", token)
+}
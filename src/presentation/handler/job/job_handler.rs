@@ -0,0 +1,50 @@
+// axum
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+    response::Response,
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ユースケースのトレイト
+use crate::application::usecase::job::job_dead_letter_find_all::JobDeadLetterFindAllUsecaseTrait;
+use crate::application::usecase::job::job_enqueue::{JobEnqueueInput, JobEnqueueUsecaseTrait};
+use crate::application::usecase::job::job_retry::JobRetryUsecaseTrait;
+
+// ハンドラー
+// ジョブの登録
+pub async fn enqueue(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Json(input): Json<JobEnqueueInput>,
+) -> Response {
+    // ユースケースを実行
+    state.job_usecase.job_enqueue.exec(ctx, input).await
+}
+
+// デッドレターのジョブ取得
+pub async fn find_dead_letter(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+) -> Response {
+    // ユースケースを実行
+    state.job_usecase.job_dead_letter_find_all.exec(ctx).await
+}
+
+// デッドレターのジョブの再実行
+pub async fn retry(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(job_id): Path<String>,
+) -> Response {
+    // ユースケースを実行
+    state.job_usecase.job_retry.exec(ctx, job_id).await
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    // テスト用のヘルパー
+    use crate::presentation::handler::handler_test_helper::create_bearer_token;
+
+    #[tokio::test]
+    async fn test_job_admin() {
+        let base_url = "http://localhost:8080/api/v1";
+        let client = reqwest::Client::new();
+        let admin_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+
+        // デッドレターのジョブ一覧の取得
+        let res = client
+            .get(format!("{}/admin/jobs/dead-letter", base_url))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        assert!(res_body.is_array());
+
+        // ハンドラーが未登録のジョブの種類は登録できない
+        let res = client
+            .post(format!("{}/admin/jobs", base_url))
+            .header("Authorization", &admin_token)
+            .json(&json!({ "job_type": "unknown.job" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+
+        // 論理削除済みユーザーの物理削除のジョブの登録（ドライラン）
+        let res = client
+            .post(format!("{}/admin/jobs", base_url))
+            .header("Authorization", &admin_token)
+            .json(&json!({ "job_type": "user.purge", "payload": { "dry_run": true } }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 201);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        assert_eq!(res_body["job_type"], "user.purge");
+        assert_eq!(res_body["status"], "pending");
+
+        // 存在しないジョブの再実行
+        let res = client
+            .post(format!("{}/admin/jobs/xxxx/retry", base_url))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_job_admin_forbidden() {
+        let client = reqwest::Client::new();
+        let operator_token = create_bearer_token("xxxx-xxxx-xxxx-0002", &["operator"]);
+
+        // テスト実行
+        let res = client
+            .get("http://localhost:8080/api/v1/admin/jobs/dead-letter")
+            .header("Authorization", &operator_token)
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(res.status(), 403);
+    }
+}
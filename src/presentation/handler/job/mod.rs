@@ -0,0 +1,4 @@
+pub mod job_handler;
+
+// テストコード用のモジュール
+pub mod job_handler_test;
@@ -1 +1,11 @@
+pub mod admin;
+pub mod api_key;
+pub mod auth;
+pub mod handler_conditional;
+pub mod job;
 pub mod user;
+pub mod webhook;
+
+// テストコード用のモジュール
+pub mod handler_conditional_test;
+pub mod handler_test_helper;
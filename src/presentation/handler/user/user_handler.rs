@@ -1,12 +1,16 @@
 // axum
 use axum::{
-    extract::{Extension, State},
+    Json,
+    extract::{Extension, Path, State, ws::WebSocketUpgrade},
     response::Response,
 };
 
 // Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
 use std::sync::Arc;
 
+// 条件付きリクエスト（ETag）
+use crate::presentation::handler::handler_conditional::{check_if_match, etag_response};
+
 // レジストリ
 use crate::registry::registry_settings::AppState;
 
@@ -14,7 +18,14 @@ use crate::registry::registry_settings::AppState;
 use crate::application::usecase::context::context_request::ContextRequest;
 
 // ユースケースのトレイト
+use crate::application::usecase::user::user_audit_find_all::UserAuditFindAllUsecaseTrait;
+use crate::application::usecase::user::user_create::{UserCreateInput, UserCreateUsecaseTrait};
+use crate::application::usecase::user::user_delete::UserDeleteUsecaseTrait;
 use crate::application::usecase::user::user_find_all::UserFindAllUsecaseTrait;
+use crate::application::usecase::user::user_find_by_uid::UserFindByUidUsecaseTrait;
+use crate::application::usecase::user::user_live::UserLiveUsecaseTrait;
+use crate::application::usecase::user::user_stream::UserStreamUsecaseTrait;
+use crate::application::usecase::user::user_update::{UserUpdateInput, UserUpdateUsecaseTrait};
 
 // ハンドラー
 // 全てのユーザー取得
@@ -23,5 +34,101 @@ pub async fn find_all(
     Extension(ctx): Extension<ContextRequest>,
 ) -> Response {
     // ユースケースを実行
-    state.user_usecase.user_find_all.exec(ctx).await
+    let res = state.user_usecase.user_find_all.exec(ctx.clone()).await;
+
+    // If-None-Matchが一致する場合は304（ETagはユースケースで付与）
+    etag_response(&ctx, res)
+}
+
+// 対象ユーザー取得
+pub async fn find_by_uid(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(uid): Path<String>,
+) -> Response {
+    // ユースケースを実行
+    let res = state
+        .user_usecase
+        .user_find_by_uid
+        .exec(ctx.clone(), uid)
+        .await;
+
+    // If-None-Matchが一致する場合は304（ETagはユースケースで付与）
+    etag_response(&ctx, res)
+}
+
+// ユーザー作成
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Json(input): Json<UserCreateInput>,
+) -> Response {
+    // ユースケースを実行（ETagはユースケースで付与）
+    state.user_usecase.user_create.exec(ctx, input).await
+}
+
+// ユーザー更新
+pub async fn update(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(uid): Path<String>,
+    Json(mut input): Json<UserUpdateInput>,
+) -> Response {
+    // If-Matchヘッダーのチェック（現在のETagとの比較はユースケースで実施）
+    input.if_match = match check_if_match(&ctx) {
+        Ok(if_match) => if_match,
+        Err(res) => return *res,
+    };
+
+    // ユースケースを実行（ETagはユースケースで付与）
+    state.user_usecase.user_update.exec(ctx, uid, input).await
+}
+
+// ユーザー削除
+pub async fn delete(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(uid): Path<String>,
+) -> Response {
+    // If-Matchヘッダーのチェック（現在のETagとの比較はユースケースで実施）
+    let if_match = match check_if_match(&ctx) {
+        Ok(if_match) => if_match,
+        Err(res) => return *res,
+    };
+
+    // ユースケースを実行
+    state
+        .user_usecase
+        .user_delete
+        .exec(ctx, uid, if_match)
+        .await
+}
+
+// 対象ユーザーの監査ログ取得
+pub async fn find_audit(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(uid): Path<String>,
+) -> Response {
+    // ユースケースを実行
+    state.user_usecase.user_audit_find_all.exec(ctx, uid).await
+}
+
+// ユーザーの変更通知（SSE）
+pub async fn stream(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+) -> Response {
+    // ユースケースを実行
+    state.user_usecase.user_stream.exec(ctx).await
+}
+
+// ユーザーの変更と接続状態の購読（WebSocket）
+pub async fn live(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    // ユースケースを実行
+    state.user_usecase.user_live.exec(ctx, ws).await
 }
@@ -1,13 +1,33 @@
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+    use std::time::Duration;
+
+    use crate::domain::audit::audit_log_model::{AuditAction, AuditLog};
     use crate::domain::user::user_model::User;
 
+    // WebSocketのメッセージ
+    use crate::application::usecase::user::user_live::LiveServerMessage;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::{Message, client::IntoClientRequest};
+
+    // テスト用のヘルパー
+    use crate::presentation::handler::handler_test_helper::create_bearer_token;
+
     #[tokio::test]
     async fn test_response_ok() {
         // リクエストを実行
         let url = "http://localhost:8080/api/v1/users";
         let client = reqwest::Client::new();
-        let res = client.get(url).send().await.unwrap();
+        let res = client
+            .get(url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]),
+            )
+            .send()
+            .await
+            .unwrap();
 
         // レスポンスステータスの検証
         assert_eq!(res.status(), 200);
@@ -33,4 +53,550 @@ mod tests {
         assert_eq!(req_body[1].created_at, req_body[1].updated_at);
         assert!(req_body[1].deleted_at.is_none());
     }
+
+    #[tokio::test]
+    async fn test_response_unauthorized() {
+        // リクエストを実行（認証トークンなし）
+        let url = "http://localhost:8080/api/v1/users";
+        let client = reqwest::Client::new();
+        let res = client.get(url).send().await.unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 401);
+        assert_eq!(res.headers().get("WWW-Authenticate").unwrap(), "Bearer");
+    }
+
+    #[tokio::test]
+    async fn test_response_unauthorized_invalid_token() {
+        // リクエストを実行（不正な認証トークン）
+        let url = "http://localhost:8080/api/v1/users";
+        let client = reqwest::Client::new();
+        let res = client
+            .get(url)
+            .header("Authorization", "Bearer xxx.yyy.zzz")
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn test_response_unauthorized_websocket_protocol_token() {
+        // リクエストを実行（WebSocketのアップグレードリクエスト以外はサブプロトコルのトークンを受け付けない）
+        let url = "http://localhost:8080/api/v1/users";
+        let bearer_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+        let token = bearer_token.trim_start_matches("Bearer ");
+        let client = reqwest::Client::new();
+        let res = client
+            .get(url)
+            .header(
+                "Sec-WebSocket-Protocol",
+                format!("users.v1, bearer.{}", token),
+            )
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn test_response_forbidden() {
+        // リクエストを実行（ロールなし）
+        let url = "http://localhost:8080/api/v1/users";
+        let client = reqwest::Client::new();
+        let res = client
+            .get(url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0001", &[]),
+            )
+            .send()
+            .await
+            .unwrap();
+
+        // レスポンスステータスの検証
+        assert_eq!(res.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn test_create_update_delete_response_ok() {
+        let client = reqwest::Client::new();
+        let admin_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+
+        // ユーザー作成
+        let url = "http://localhost:8080/api/v1/users";
+        let req_body = json!({
+            "last_name": "鈴木",
+            "first_name": "三郎",
+            "email": "s.suzuki@example.com",
+        });
+        let res = client
+            .post(url)
+            .header("Authorization", &admin_token)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 201);
+        let created_etag = res.headers()["ETag"].to_str().unwrap().to_string();
+        let created_user: User = res.json().await.unwrap();
+        assert_eq!(created_user.last_name, "鈴木");
+
+        // 本人によるプロフィール更新
+        let url = format!("http://localhost:8080/api/v1/users/{}", created_user.uid);
+        let self_token = create_bearer_token(&created_user.uid, &[]);
+        let req_body = json!({
+            "last_name": "鈴木",
+            "first_name": "三郎",
+            "email": "s.suzuki2@example.com",
+        });
+        let res = client
+            .put(&url)
+            .header("Authorization", &self_token)
+            .header("If-Match", &created_etag)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let updated_etag = res.headers()["ETag"].to_str().unwrap().to_string();
+        assert_ne!(updated_etag, created_etag);
+        let updated_user: User = res.json().await.unwrap();
+        assert_eq!(updated_user.email, "s.suzuki2@example.com");
+
+        // 更新前のETagでは更新不可
+        let res = client
+            .put(&url)
+            .header("Authorization", &self_token)
+            .header("If-Match", &created_etag)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 412);
+
+        // 更新後のETagは取得時のETagと一致すること
+        let res = client
+            .get(&url)
+            .header("Authorization", &self_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.headers()["ETag"], updated_etag.as_str());
+
+        // 他人のプロフィール更新は不可
+        let other_url = "http://localhost:8080/api/v1/users/xxxx-xxxx-xxxx-0002";
+        let res = client
+            .put(other_url)
+            .header("Authorization", &self_token)
+            .header("If-Match", "*")
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 403);
+
+        // If-Matchヘッダーなしでは削除不可
+        let res = client
+            .delete(&url)
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 428);
+
+        // ユーザー削除
+        let res = client
+            .delete(&url)
+            .header("Authorization", &admin_token)
+            .header("If-Match", &updated_etag)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 204);
+
+        // 削除後は取得できないこと
+        let res = client
+            .get(&url)
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 404);
+
+        // 削除後も監査ログは取得できること
+        let audit_url = format!("{}/audit", url);
+        let res = client
+            .get(&audit_url)
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let audit_logs: Vec<AuditLog> = res.json().await.unwrap();
+        let actions: Vec<AuditAction> = audit_logs.iter().map(|log| log.action.clone()).collect();
+        assert_eq!(
+            actions,
+            vec![
+                AuditAction::Create,
+                AuditAction::Update,
+                AuditAction::Delete
+            ]
+        );
+        assert_eq!(audit_logs[0].actor, "xxxx-xxxx-xxxx-0001");
+        assert_eq!(audit_logs[1].actor, created_user.uid);
+        assert_eq!(audit_logs[1].changes.len(), 1);
+        assert_eq!(audit_logs[1].changes[0].field, "email");
+        assert_eq!(audit_logs[1].changes[0].before, "s.suzuki@example.com");
+        assert_eq!(audit_logs[1].changes[0].after, "s.suzuki2@example.com");
+
+        // 監査ログはadminロールのみ参照可能
+        let operator_token = create_bearer_token("xxxx-xxxx-xxxx-0002", &["operator"]);
+        let res = client
+            .get(&audit_url)
+            .header("Authorization", &operator_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 403);
+    }
+
+    // SSEのイベントの受信（条件に一致するイベントまで読み飛ばし、idとdataを返す）
+    async fn read_sse_event(
+        res: &mut reqwest::Response,
+        buf: &mut String,
+        matches: impl Fn(&str) -> bool,
+    ) -> (String, String) {
+        loop {
+            while let Some(pos) = buf.find("\n\n") {
+                let block: String = buf.drain(..pos + 2).collect();
+                if !matches(&block) {
+                    continue;
+                }
+                let field = |name: &str| {
+                    block
+                        .lines()
+                        .find_map(|line| line.strip_prefix(name))
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                return (field("id: "), field("data: "));
+            }
+            let chunk = tokio::time::timeout(Duration::from_secs(5), res.chunk())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            buf.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_response_ok() {
+        let client = reqwest::Client::new();
+        let admin_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+        let stream_url = "http://localhost:8080/api/v1/users/stream";
+
+        // 変更通知の接続
+        let mut stream_res = client
+            .get(stream_url)
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(stream_res.status(), 200);
+        assert_eq!(stream_res.headers()["Content-Type"], "text/event-stream");
+
+        // ユーザー作成
+        let req_body = json!({
+            "last_name": "高橋",
+            "first_name": "四郎",
+            "email": "s.takahashi@example.com",
+        });
+        let res = client
+            .post("http://localhost:8080/api/v1/users")
+            .header("Authorization", &admin_token)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 201);
+        let etag = res.headers()["ETag"].to_str().unwrap().to_string();
+        let created_user: User = res.json().await.unwrap();
+
+        // 作成の通知を受信
+        let mut buf = String::new();
+        let (created_event_id, data) = read_sse_event(&mut stream_res, &mut buf, |block| {
+            block.contains("event: user.created") && block.contains("s.takahashi@example.com")
+        })
+        .await;
+        assert!(data.contains(&created_user.uid));
+        drop(stream_res);
+
+        // 切断中にユーザーを更新
+        let url = format!("http://localhost:8080/api/v1/users/{}", created_user.uid);
+        let req_body = json!({
+            "last_name": "高橋",
+            "first_name": "四郎",
+            "email": "s.takahashi2@example.com",
+        });
+        let res = client
+            .put(&url)
+            .header("Authorization", &admin_token)
+            .header("If-Match", &etag)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let etag = res.headers()["ETag"].to_str().unwrap().to_string();
+
+        // Last-Event-IDを指定して再接続（切断中の通知が再送されること）
+        let mut stream_res = client
+            .get(stream_url)
+            .header("Authorization", &admin_token)
+            .header("Last-Event-ID", &created_event_id)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(stream_res.status(), 200);
+        let mut buf = String::new();
+        let (_, data) =
+            read_sse_event(&mut stream_res, &mut buf, |block| block.contains("id: ")).await;
+        assert!(data.contains("\"type\":\"UserProfileUpdated\""));
+        assert!(data.contains("s.takahashi2@example.com"));
+        drop(stream_res);
+
+        // テストデータの削除
+        let res = client
+            .delete(&url)
+            .header("Authorization", &admin_token)
+            .header("If-Match", &etag)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 204);
+
+        // ロールなしでは接続不可
+        let res = client
+            .get(stream_url)
+            .header(
+                "Authorization",
+                create_bearer_token("xxxx-xxxx-xxxx-0001", &[]),
+            )
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 403);
+    }
+
+    type LiveSocket = tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >;
+
+    // WebSocketの接続（protocol: Sec-WebSocket-Protocolヘッダー、authorization: Authorizationヘッダー）
+    async fn connect_live(protocol: &str, authorization: Option<&str>) -> LiveSocket {
+        let mut req = "ws://localhost:8080/api/v1/users/live"
+            .into_client_request()
+            .unwrap();
+        req.headers_mut()
+            .insert("Sec-WebSocket-Protocol", protocol.parse().unwrap());
+        if let Some(authorization) = authorization {
+            req.headers_mut()
+                .insert("Authorization", authorization.parse().unwrap());
+        }
+        let (socket, res) = tokio_tungstenite::connect_async(req).await.unwrap();
+        assert_eq!(res.headers()["Sec-WebSocket-Protocol"], "users.v1");
+        socket
+    }
+
+    // WebSocketのメッセージの受信（Ping・Pongは読み飛ばす）
+    async fn next_live_message(socket: &mut LiveSocket) -> LiveServerMessage {
+        loop {
+            let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            if let Message::Text(text) = message {
+                return serde_json::from_str(text.as_str()).unwrap();
+            }
+        }
+    }
+
+    // WebSocketのメッセージの送信
+    async fn send_live_message(socket: &mut LiveSocket, message: serde_json::Value) {
+        socket
+            .send(Message::Text(message.to_string().into()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_live_response_ok() {
+        let client = reqwest::Client::new();
+        let admin_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+
+        // 管理者の接続（Authorizationヘッダーで認証）
+        let mut admin_socket = connect_live("users.v1", Some(&admin_token)).await;
+        send_live_message(
+            &mut admin_socket,
+            json!({ "type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0002"] }),
+        )
+        .await;
+        assert_eq!(
+            next_live_message(&mut admin_socket).await,
+            LiveServerMessage::Subscriptions {
+                uids: vec!["xxxx-xxxx-xxxx-0002".to_string()],
+            }
+        );
+        assert_eq!(
+            next_live_message(&mut admin_socket).await,
+            LiveServerMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0002".to_string(),
+                online: false,
+            }
+        );
+
+        // ユーザーの接続（ブラウザ向けにサブプロトコルで認証）で接続状態が通知されること
+        let user_token = create_bearer_token("xxxx-xxxx-xxxx-0002", &[]);
+        let protocol = format!(
+            "users.v1, bearer.{}",
+            user_token.strip_prefix("Bearer ").unwrap()
+        );
+        let mut user_socket = connect_live(&protocol, None).await;
+        assert_eq!(
+            next_live_message(&mut admin_socket).await,
+            LiveServerMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0002".to_string(),
+                online: true,
+            }
+        );
+
+        // ロールなしでは他人を購読できないこと
+        send_live_message(
+            &mut user_socket,
+            json!({ "type": "subscribe", "uids": ["xxxx-xxxx-xxxx-0001"] }),
+        )
+        .await;
+        assert!(matches!(
+            next_live_message(&mut user_socket).await,
+            LiveServerMessage::Error { .. }
+        ));
+
+        // 切断で接続状態が通知されること
+        user_socket.close(None).await.unwrap();
+        assert_eq!(
+            next_live_message(&mut admin_socket).await,
+            LiveServerMessage::Presence {
+                uid: "xxxx-xxxx-xxxx-0002".to_string(),
+                online: false,
+            }
+        );
+
+        // ユーザー作成
+        let req_body = json!({
+            "last_name": "伊藤",
+            "first_name": "五郎",
+            "email": "g.ito@example.com",
+        });
+        let res = client
+            .post("http://localhost:8080/api/v1/users")
+            .header("Authorization", &admin_token)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 201);
+        let etag = res.headers()["ETag"].to_str().unwrap().to_string();
+        let created_user: User = res.json().await.unwrap();
+
+        // 作成したユーザーを購読して削除（購読中のユーザーの変更が通知されること）
+        send_live_message(
+            &mut admin_socket,
+            json!({ "type": "subscribe", "uids": [created_user.uid] }),
+        )
+        .await;
+        assert!(matches!(
+            next_live_message(&mut admin_socket).await,
+            LiveServerMessage::Subscriptions { .. }
+        ));
+        assert!(matches!(
+            next_live_message(&mut admin_socket).await,
+            LiveServerMessage::Presence { online: false, .. }
+        ));
+
+        let url = format!("http://localhost:8080/api/v1/users/{}", created_user.uid);
+        let res = client
+            .delete(&url)
+            .header("Authorization", &admin_token)
+            .header("If-Match", &etag)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 204);
+        match next_live_message(&mut admin_socket).await {
+            LiveServerMessage::Event {
+                uid, event_type, ..
+            } => {
+                assert_eq!(uid, created_user.uid);
+                assert_eq!(event_type, "user.deleted");
+            }
+            other => panic!("ユーザーの変更ではありません: {:?}", other),
+        }
+        admin_socket.close(None).await.unwrap();
+
+        // 認証なしでは接続できないこと
+        let req = "ws://localhost:8080/api/v1/users/live"
+            .into_client_request()
+            .unwrap();
+        assert!(tokio_tungstenite::connect_async(req).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_response_not_modified() {
+        let client = reqwest::Client::new();
+        let admin_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+
+        for url in [
+            "http://localhost:8080/api/v1/users",
+            "http://localhost:8080/api/v1/users/xxxx-xxxx-xxxx-0001",
+        ] {
+            // ETagの取得
+            let res = client
+                .get(url)
+                .header("Authorization", &admin_token)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 200);
+            let etag = res.headers()["ETag"].to_str().unwrap().to_string();
+
+            // If-None-Matchが一致する場合は304
+            let res = client
+                .get(url)
+                .header("Authorization", &admin_token)
+                .header("If-None-Match", &etag)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 304);
+            assert_eq!(res.headers()["ETag"], etag.as_str());
+            assert!(res.text().await.unwrap().is_empty());
+
+            // If-None-Matchが一致しない場合は200
+            let res = client
+                .get(url)
+                .header("Authorization", &admin_token)
+                .header("If-None-Match", "\"xxx\"")
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 200);
+        }
+    }
 }
@@ -0,0 +1,4 @@
+pub mod webhook_handler;
+
+// テストコード用のモジュール
+pub mod webhook_handler_test;
@@ -0,0 +1,94 @@
+// axum
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+    response::Response,
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ユースケースのトレイト
+use crate::application::usecase::webhook::webhook_delivery_find_all::WebhookDeliveryFindAllUsecaseTrait;
+use crate::application::usecase::webhook::webhook_delivery_redeliver::WebhookDeliveryRedeliverUsecaseTrait;
+use crate::application::usecase::webhook::webhook_subscription_create::{
+    WebhookSubscriptionCreateInput, WebhookSubscriptionCreateUsecaseTrait,
+};
+use crate::application::usecase::webhook::webhook_subscription_delete::WebhookSubscriptionDeleteUsecaseTrait;
+use crate::application::usecase::webhook::webhook_subscription_find_all::WebhookSubscriptionFindAllUsecaseTrait;
+
+// ハンドラー
+// 全ての購読設定取得
+pub async fn find_all(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+) -> Response {
+    // ユースケースを実行
+    state
+        .webhook_usecase
+        .webhook_subscription_find_all
+        .exec(ctx)
+        .await
+}
+
+// 購読設定の作成
+pub async fn create(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Json(input): Json<WebhookSubscriptionCreateInput>,
+) -> Response {
+    // ユースケースを実行
+    state
+        .webhook_usecase
+        .webhook_subscription_create
+        .exec(ctx, input)
+        .await
+}
+
+// 購読設定の削除
+pub async fn delete(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(subscription_id): Path<String>,
+) -> Response {
+    // ユースケースを実行
+    state
+        .webhook_usecase
+        .webhook_subscription_delete
+        .exec(ctx, subscription_id)
+        .await
+}
+
+// 購読設定ごとの配信ログ取得
+pub async fn find_deliveries(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path(subscription_id): Path<String>,
+) -> Response {
+    // ユースケースを実行
+    state
+        .webhook_usecase
+        .webhook_delivery_find_all
+        .exec(ctx, subscription_id)
+        .await
+}
+
+// 配信の再送
+pub async fn redeliver(
+    State(state): State<Arc<AppState>>,
+    Extension(ctx): Extension<ContextRequest>,
+    Path((subscription_id, delivery_id)): Path<(String, String)>,
+) -> Response {
+    // ユースケースを実行
+    state
+        .webhook_usecase
+        .webhook_delivery_redeliver
+        .exec(ctx, subscription_id, delivery_id)
+        .await
+}
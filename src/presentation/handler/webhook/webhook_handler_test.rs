@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::Bytes,
+        http::{HeaderMap, StatusCode},
+        routing::post,
+    };
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    // 署名
+    use crate::domain::webhook::webhook_signature::verify;
+
+    // テスト用のヘルパー
+    use crate::presentation::handler::handler_test_helper::create_bearer_token;
+
+    // 受信したWebhook（署名の検証結果、イベント種別、ボディ）
+    type Received = Arc<Mutex<Vec<(bool, String, serde_json::Value)>>>;
+
+    // Webhookの受信側のスタンドインを起動してURLを返す
+    async fn start_receiver(secret: Arc<Mutex<String>>, received: Received) -> String {
+        let app = Router::new().route(
+            "/hook",
+            post(move |headers: HeaderMap, body: Bytes| async move {
+                let header = |name: &str| headers[name].to_str().unwrap().to_string();
+                let timestamp = header("Webhook-Timestamp").parse::<i64>().unwrap();
+                let body = String::from_utf8(body.to_vec()).unwrap();
+                let verified = verify(
+                    &secret.lock().unwrap(),
+                    timestamp,
+                    &body,
+                    &header("Webhook-Signature"),
+                );
+                received.lock().unwrap().push((
+                    verified,
+                    header("Webhook-Event"),
+                    serde_json::from_str(&body).unwrap(),
+                ));
+                StatusCode::NO_CONTENT
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_webhook_flow() {
+        let base_url = "http://localhost:8080/api/v1";
+        let client = reqwest::Client::new();
+        let admin_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["admin"]);
+
+        // 受信側の起動
+        let secret = Arc::new(Mutex::new(String::new()));
+        let received: Received = Arc::new(Mutex::new(Vec::new()));
+        let hook_url = start_receiver(secret.clone(), received.clone()).await;
+
+        // 購読設定の作成
+        let req_body = json!({ "url": hook_url, "event_types": ["user.created"] });
+        let res = client
+            .post(format!("{}/admin/webhooks", base_url))
+            .header("Authorization", &admin_token)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 201);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        *secret.lock().unwrap() = res_body["secret"].as_str().unwrap().to_string();
+        let subscription_id = res_body["subscription"]["subscription_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        // 購読設定一覧の取得（シークレットは返却しない）
+        let res = client
+            .get(format!("{}/admin/webhooks", base_url))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        let subscription = res_body
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["subscription_id"] == subscription_id.as_str())
+            .unwrap();
+        assert!(subscription.get("secret").is_none());
+
+        // ユーザーの作成（user.createdイベントが配信される）
+        let email = format!("webhook-{}@example.com", &subscription_id[..8]);
+        let req_body = json!({
+            "first_name": "テスト",
+            "last_name": "Webhook",
+            "email": email
+        });
+        let res = client
+            .post(format!("{}/users", base_url))
+            .header("Authorization", &admin_token)
+            .json(&req_body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 201);
+
+        // 配信の完了待ち（アウトボックスの送信処理とWebhookの配信処理はバックグラウンドで実行）
+        // ※他のテストで作成したユーザーのイベントも配信されるため、作成したユーザーの配信で検証
+        let mut delivery = serde_json::Value::Null;
+        for _ in 0..20 {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            let res = client
+                .get(format!(
+                    "{}/admin/webhooks/{}/deliveries",
+                    base_url, subscription_id
+                ))
+                .header("Authorization", &admin_token)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 200);
+            let deliveries: serde_json::Value = res.json().await.unwrap();
+            if let Some(found) = deliveries.as_array().unwrap().iter().find(|d| {
+                d["body"].as_str().unwrap().contains(&email) && d["status"] == "succeeded"
+            }) {
+                delivery = found.clone();
+                break;
+            }
+        }
+        assert_eq!(delivery["event_type"], "user.created");
+        assert_eq!(delivery["attempts"][0]["status_code"], 204);
+
+        // 受信内容の検証（署名が一致すること）
+        let received_count = || {
+            received
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(verified, event_type, body)| {
+                    *verified && event_type == "user.created" && body["data"]["email"] == email
+                })
+                .count()
+        };
+        assert_eq!(received_count(), 1);
+
+        // 再配信
+        let delivery_id = delivery["delivery_id"].as_str().unwrap();
+        let res = client
+            .post(format!(
+                "{}/admin/webhooks/{}/deliveries/{}/redeliver",
+                base_url, subscription_id, delivery_id
+            ))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let res_body: serde_json::Value = res.json().await.unwrap();
+        assert_eq!(res_body["status"], "succeeded");
+        assert_eq!(received_count(), 2);
+
+        // 購読設定の削除
+        let res = client
+            .delete(format!("{}/admin/webhooks/{}", base_url, subscription_id))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 204);
+
+        // 削除済みの購読設定の配信ログ
+        let res = client
+            .get(format!(
+                "{}/admin/webhooks/{}/deliveries",
+                base_url, subscription_id
+            ))
+            .header("Authorization", &admin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_webhooks_forbidden() {
+        let client = reqwest::Client::new();
+        let operator_token = create_bearer_token("xxxx-xxxx-xxxx-0001", &["operator"]);
+
+        // リクエストを実行
+        let res = client
+            .get("http://localhost:8080/api/v1/admin/webhooks")
+            .header("Authorization", &operator_token)
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(res.status(), 403);
+    }
+}
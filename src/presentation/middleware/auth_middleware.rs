@@ -0,0 +1,134 @@
+// axum
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::{Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// ユースケースのトレイト
+use crate::application::usecase::api_key::api_key_authenticate::ApiKeyAuthenticateUsecaseTrait;
+
+// ロガー設定
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::Logger;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// X-Api-KeyヘッダーからAPIキーを取得
+fn get_api_key(req: &Request) -> Option<&str> {
+    let value = req.headers().get("X-Api-Key")?.to_str().ok()?.trim();
+    if value.is_empty() {
+        return None;
+    }
+    Some(value)
+}
+
+// Authorizationヘッダーからベアラートークンを取得
+fn get_bearer_token(req: &Request) -> Option<&str> {
+    let value = req.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("Bearer") || token.trim().is_empty() {
+        return None;
+    }
+    Some(token.trim())
+}
+
+// Sec-WebSocket-Protocolヘッダーからベアラートークンを取得
+// ※ブラウザのWebSocketはAuthorizationヘッダーを指定できないため「bearer.{トークン}」のサブプロトコルで受け付ける
+// ※「/users/live」へのWebSocketのアップグレードリクエストのみ対象
+fn get_websocket_protocol_token(req: &Request) -> Option<&str> {
+    if !is_live_websocket_upgrade(req) {
+        return None;
+    }
+
+    let value = req
+        .headers()
+        .get(header::SEC_WEBSOCKET_PROTOCOL)?
+        .to_str()
+        .ok()?;
+    value
+        .split(',')
+        .find_map(|protocol| protocol.trim().strip_prefix("bearer."))
+        .filter(|token| !token.is_empty())
+}
+
+// 「/users/live」へのWebSocketのアップグレードリクエストかどうか
+fn is_live_websocket_upgrade(req: &Request) -> bool {
+    let is_upgrade = req
+        .headers()
+        .get(header::UPGRADE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case("websocket"));
+    req.method() == Method::GET && is_upgrade && req.uri().path().ends_with("/users/live")
+}
+
+// 認証用のミドルウェア（JWTまたはAPIキーを検証して共通コンテキストに認証情報を設定）
+pub async fn auth_middleware(
+    State(state): State<Arc<AppState>>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    // 共通コンテキストの取得
+    let mut ctx = req.extensions().get::<ContextRequest>().unwrap().clone();
+
+    // APIキーまたはトークンの検証（APIキーを優先）
+    let bearer_token = get_bearer_token(&req).or_else(|| get_websocket_protocol_token(&req));
+    let result = match (get_api_key(&req), bearer_token) {
+        (Some(api_key), _) => {
+            state
+                .api_key_usecase
+                .api_key_authenticate
+                .exec(&ctx, api_key)
+                .await
+        }
+        (None, Some(token)) => state.auth_token.verify(token),
+        (None, None) => Err(ErrorCommon::CustomError {
+            status_code: StatusCode::UNAUTHORIZED,
+            message: "認証トークンが必要です。".to_string(),
+        }),
+    };
+
+    match result {
+        Ok(auth) => {
+            // 共通コンテキストに認証情報を設定
+            ctx.auth = Some(auth);
+            req.extensions_mut().insert(ctx);
+
+            next.run(req).await
+        }
+        Err(err) => {
+            // ログ出力
+            let logger = Logger::new(module_path!());
+            let err_msg = format!("認証エラー: {}", err);
+            logger.warn(&ctx, &err_msg);
+
+            // レスポンスヘッダーに付与する値の設定
+            let x_request_id = ctx.header.get("X-Request-Id");
+            let request_id = x_request_id.expect("-").to_str().unwrap();
+            let res_header = [("X-Request-Id", request_id), ("WWW-Authenticate", "Bearer")];
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            // ステータスコードの設定
+            let status_code = err.status_code();
+
+            (status_code, res_header, json_msg).into_response()
+        }
+    }
+}
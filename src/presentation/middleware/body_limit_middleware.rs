@@ -0,0 +1,60 @@
+// axum
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::{StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー設定
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::Logger;
+
+// リクエストボディのサイズ制限用のミドルウェア（Content-Lengthが上限を超える場合は413エラー）
+// ※Content-Lengthがないリクエストはルーターに設定したDefaultBodyLimitで制限
+pub async fn body_limit_middleware(
+    State(limit): State<usize>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let content_length = req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+
+    if content_length.is_none_or(|length| length <= limit) {
+        return next.run(req).await;
+    }
+
+    // 共通コンテキストの取得
+    let ctx = req.extensions().get::<ContextRequest>().unwrap().clone();
+
+    // ログ出力
+    let logger = Logger::new(module_path!());
+    let err_msg = format!(
+        "リクエストボディのサイズ超過: {}バイト（上限: {}バイト）",
+        content_length.unwrap_or_default(),
+        limit
+    );
+    logger.warn(&ctx, &err_msg);
+
+    // レスポンスヘッダーに付与する値の設定
+    let x_request_id = ctx.header.get("X-Request-Id");
+    let request_id = x_request_id.expect("-").to_str().unwrap();
+    let res_header = [("X-Request-Id", request_id)];
+
+    // json形式のメッセージを設定
+    let json_msg = Json(json!({
+        "message": format!("リクエストボディのサイズは{}バイト以下にして下さい。", limit)
+    }));
+
+    (StatusCode::PAYLOAD_TOO_LARGE, res_header, json_msg).into_response()
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use axum::{Router, extract::DefaultBodyLimit, http::StatusCode, middleware, routing::post};
+
+    // ミドルウェア
+    use crate::presentation::middleware::body_limit_middleware::body_limit_middleware;
+    use crate::presentation::middleware::common_middleware;
+
+    // テスト用の上限サイズ（バイト）
+    const TEST_LIMIT: usize = 16;
+
+    // テスト用のサーバーを起動してベースURLを返す
+    async fn start_server() -> String {
+        let app = Router::new()
+            .route("/echo", post(|body: String| async move { body }))
+            .layer(DefaultBodyLimit::max(TEST_LIMIT))
+            .layer(middleware::from_fn_with_state(
+                TEST_LIMIT,
+                body_limit_middleware,
+            ))
+            .layer(middleware::from_fn(common_middleware::request_middleware));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        base_url
+    }
+
+    #[tokio::test]
+    async fn test_body_limit() {
+        let base_url = start_server().await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/echo", base_url);
+
+        // テスト実行
+        let ok = client
+            .post(&url)
+            .body("a".repeat(TEST_LIMIT))
+            .send()
+            .await
+            .unwrap();
+        let too_large = client
+            .post(&url)
+            .body("a".repeat(TEST_LIMIT + 1))
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(ok.status(), StatusCode::OK);
+        assert_eq!(too_large.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert!(too_large.headers().contains_key("X-Request-Id"));
+        let body: serde_json::Value = too_large.json().await.unwrap();
+        assert_eq!(
+            body["message"],
+            "リクエストボディのサイズは16バイト以下にして下さい。"
+        );
+    }
+}
@@ -23,7 +23,7 @@ pub async fn request_middleware(mut req: Request, next: Next) -> Response {
     req.extensions_mut().insert(ctx.clone());
 
     // リクエスト単位でログ出力
-    let logger = Logger::new();
+    let logger = Logger::new(module_path!());
     logger.info(&ctx, "start request !!");
 
     next.run(req).await
@@ -0,0 +1,64 @@
+// axum
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// 機能フラグ「users_live」（ユーザーの変更のWebSocket配信）
+pub const FEATURE_USERS_LIVE: &str = "users_live";
+
+// 機能フラグミドルウェアの状態（ルート単位で設定）
+#[derive(Clone)]
+pub struct FeatureFlagState {
+    pub state: Arc<AppState>,
+    pub name: &'static str,
+}
+
+impl FeatureFlagState {
+    // 初期化用メソッド
+    pub fn new(state: Arc<AppState>, name: &'static str) -> Self {
+        FeatureFlagState { state, name }
+    }
+}
+
+// 機能フラグ用のミドルウェア（無効の場合はルートが存在しない扱いで404）
+// ※リクエストごとに現在の設定を参照するため、再読み込みした機能フラグは再起動なしで反映される
+pub async fn feature_flag_middleware(
+    State(feature_flag): State<FeatureFlagState>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if feature_flag
+        .state
+        .reloadable_config
+        .get()
+        .is_feature_enabled(feature_flag.name)
+    {
+        return next.run(req).await;
+    }
+
+    // レスポンスヘッダーに付与する値の設定
+    let ctx = req.extensions().get::<ContextRequest>().unwrap();
+    let request_id = ctx.request_id();
+    let res_header = [("X-Request-Id", request_id)];
+
+    // json形式のメッセージを設定
+    let json_msg = Json(json!({ "message": "対象の機能は無効です。" }));
+
+    (StatusCode::NOT_FOUND, res_header, json_msg).into_response()
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, middleware, routing::get};
+    use std::sync::Arc;
+
+    // コンフィグ
+    use crate::config::config_reloadable::{ReloadableConfig, ReloadableConfigHandle};
+    use crate::config::config_settings::Config;
+
+    // ミドルウェア
+    use crate::presentation::middleware::common_middleware;
+    use crate::presentation::middleware::feature_flag_middleware::{
+        FeatureFlagState, feature_flag_middleware,
+    };
+
+    // レジストリ
+    use crate::registry::registry_settings::AppState;
+
+    // 機能フラグの設定
+    fn new_reloadable_config(feature_flags: &[&str]) -> ReloadableConfig {
+        ReloadableConfig {
+            feature_flags: feature_flags.iter().map(|f| f.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    // テスト用のサーバーを起動してベースURLと再読み込み可能な設定のハンドルを返す
+    async fn start_server(feature_flags: &[&str]) -> (String, ReloadableConfigHandle) {
+        let reloadable_config = ReloadableConfigHandle::new(new_reloadable_config(feature_flags));
+        let state = Arc::new(AppState::new(&Config::default(), reloadable_config.clone()).await);
+
+        let app = Router::new()
+            .route(
+                "/beta",
+                get(|| async { "ok" }).route_layer(middleware::from_fn_with_state(
+                    FeatureFlagState::new(state.clone(), "beta"),
+                    feature_flag_middleware,
+                )),
+            )
+            .layer(middleware::from_fn(common_middleware::request_middleware));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        (base_url, reloadable_config)
+    }
+
+    #[tokio::test]
+    async fn test_enabled() {
+        let (base_url, _) = start_server(&["beta"]).await;
+
+        // テスト実行
+        let res = reqwest::get(format!("{}/beta", base_url)).await.unwrap();
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_disabled() {
+        let (base_url, _) = start_server(&["other"]).await;
+
+        // テスト実行
+        let res = reqwest::get(format!("{}/beta", base_url)).await.unwrap();
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        assert!(res.headers().contains_key("X-Request-Id"));
+        let body: serde_json::Value = res.json().await.unwrap();
+        assert_eq!(body["message"], "対象の機能は無効です。");
+    }
+
+    #[tokio::test]
+    async fn test_reload() {
+        let (base_url, handle) = start_server(&[]).await;
+        let url = format!("{}/beta", base_url);
+        let before = reqwest::get(&url).await.unwrap();
+        assert_eq!(before.status(), StatusCode::NOT_FOUND);
+
+        // テスト実行（機能フラグを有効にする設定に差し替え）
+        handle.replace(new_reloadable_config(&["beta"]));
+        let enabled = reqwest::get(&url).await.unwrap();
+
+        // テスト実行（再度無効にする設定に差し替え）
+        handle.replace(new_reloadable_config(&[]));
+        let disabled = reqwest::get(&url).await.unwrap();
+
+        // 検証（再起動なしで新しい設定が反映される）
+        assert_eq!(enabled.status(), StatusCode::OK);
+        assert_eq!(disabled.status(), StatusCode::NOT_FOUND);
+    }
+}
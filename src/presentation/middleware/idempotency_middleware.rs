@@ -0,0 +1,230 @@
+// axum
+use axum::{
+    Json,
+    body::{Body, to_bytes},
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// ハッシュ化
+use sha2::{Digest, Sha256};
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// 冪等キー
+use crate::application::usecase::idempotency::idempotency_store_trait::{
+    IdempotencyBegin, IdempotencyResponse, IdempotencyStoreTrait,
+};
+
+// ロガー設定
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::Logger;
+
+// 冪等キーの最大文字数
+const MAX_KEY_LENGTH: usize = 255;
+
+// 冪等キー用ミドルウェアの状態
+#[derive(Clone)]
+pub struct IdempotencyState {
+    pub store: Arc<dyn IdempotencyStoreTrait + 'static>,
+    // 処理結果を保存する期間（秒）
+    pub ttl_secs: u64,
+    // リクエストボディの最大サイズ（バイト）
+    pub body_limit: usize,
+}
+
+impl IdempotencyState {
+    // 初期化用メソッド
+    pub fn new(
+        store: Arc<dyn IdempotencyStoreTrait + 'static>,
+        ttl_secs: u64,
+        body_limit: usize,
+    ) -> Self {
+        IdempotencyState {
+            store,
+            ttl_secs,
+            body_limit,
+        }
+    }
+}
+
+// 処理中のキーを処理が中断された場合（タイムアウトや切断等）に削除するためのガード
+struct InFlightGuard {
+    store: Arc<dyn IdempotencyStoreTrait + 'static>,
+    key: Option<String>,
+}
+
+impl InFlightGuard {
+    // 削除を解除（処理結果を保存済み）
+    fn disarm(&mut self) {
+        self.key = None;
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            let store = self.store.clone();
+            tokio::spawn(async move {
+                let _ = store.release(&key).await;
+            });
+        }
+    }
+}
+
+// エラーレスポンスの作成
+fn error_response(ctx: &ContextRequest, status_code: StatusCode, message: &str) -> Response {
+    // ログ出力
+    let logger = Logger::new(module_path!());
+    let err_msg = format!("冪等キーのエラー: {}", message);
+    logger.warn(ctx, &err_msg);
+
+    // レスポンスヘッダーに付与する値の設定
+    let x_request_id = ctx.header.get("X-Request-Id");
+    let request_id = x_request_id.expect("-").to_str().unwrap();
+    let res_header = [("X-Request-Id", request_id)];
+
+    // json形式のメッセージを設定
+    let json_msg = Json(json!({ "message": message }));
+
+    (status_code, res_header, json_msg).into_response()
+}
+
+// 保存したレスポンスの再送
+fn replay_response(ctx: &ContextRequest, stored: IdempotencyResponse) -> Response {
+    let mut res = Response::new(Body::from(stored.body));
+    *res.status_mut() = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+
+    let headers = res.headers_mut();
+    for (key, value) in stored.headers {
+        if let (Ok(key), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            headers.append(key, value);
+        }
+    }
+    if let Some(request_id) = ctx.header.get("X-Request-Id") {
+        headers.insert("X-Request-Id", request_id.clone());
+    }
+    headers.insert("Idempotent-Replayed", HeaderValue::from_static("true"));
+
+    res
+}
+
+// 冪等キー用のミドルウェア（Idempotency-Keyヘッダーを指定した更新系のリクエストが対象）
+// ※同じキーと実行者のリトライには最初のレスポンスを再送する
+pub async fn idempotency_middleware(
+    State(state): State<IdempotencyState>,
+    req: Request,
+    next: Next,
+) -> Response {
+    // 対象のメソッドかどうか
+    let is_target = matches!(
+        *req.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    );
+    let idempotency_key = match req.headers().get("Idempotency-Key") {
+        Some(value) if is_target => value.to_str().unwrap_or_default().trim().to_string(),
+        _ => return next.run(req).await,
+    };
+
+    // 共通コンテキストの取得
+    let ctx = req.extensions().get::<ContextRequest>().unwrap().clone();
+
+    // キーのチェック
+    if idempotency_key.is_empty() || idempotency_key.len() > MAX_KEY_LENGTH {
+        let msg = format!(
+            "Idempotency-Keyは1〜{}文字で指定して下さい。",
+            MAX_KEY_LENGTH
+        );
+        return error_response(&ctx, StatusCode::BAD_REQUEST, &msg);
+    }
+
+    // リクエストボディの取得
+    let (parts, body) = req.into_parts();
+    let bytes = match to_bytes(body, state.body_limit).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            let msg = format!(
+                "リクエストボディのサイズは{}バイト以下にして下さい。",
+                state.body_limit
+            );
+            return error_response(&ctx, StatusCode::PAYLOAD_TOO_LARGE, &msg);
+        }
+    };
+
+    // 実行者単位のキーとリクエスト内容のハッシュ値
+    let key = format!("{}:{}", ctx.actor_id(), idempotency_key);
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{} {}\n", parts.method, parts.uri.path()).as_bytes());
+    hasher.update(&bytes);
+    let request_hash = hex::encode(hasher.finalize());
+
+    match state.store.begin(&key, &request_hash, state.ttl_secs).await {
+        Ok(IdempotencyBegin::Started) => {}
+        Ok(IdempotencyBegin::Completed(stored)) => return replay_response(&ctx, stored),
+        Ok(IdempotencyBegin::InFlight) => {
+            let msg = "同じIdempotency-Keyのリクエストを処理中です。";
+            return error_response(&ctx, StatusCode::CONFLICT, msg);
+        }
+        Ok(IdempotencyBegin::Mismatch) => {
+            let msg = "同じIdempotency-Keyが異なるリクエスト内容で使用されています。";
+            return error_response(&ctx, StatusCode::UNPROCESSABLE_ENTITY, msg);
+        }
+        Err(err) => {
+            let logger = Logger::new(module_path!());
+            let err_msg = format!("冪等キーのストアエラー: {}", err);
+            logger.error(&ctx, &err_msg);
+            return error_response(&ctx, err.status_code(), &err.to_string());
+        }
+    }
+
+    // 処理の実行（中断された場合はガードでキーを削除）
+    let mut guard = InFlightGuard {
+        store: state.store.clone(),
+        key: Some(key.clone()),
+    };
+    let res = next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await;
+
+    // サーバーエラーの場合は保存せず、リトライできるようにする
+    if res.status().is_server_error() {
+        guard.disarm();
+        let _ = state.store.release(&key).await;
+        return res;
+    }
+
+    // レスポンスの保存
+    let (parts, body) = res.into_parts();
+    let body = to_bytes(body, usize::MAX).await.unwrap_or_default();
+    let stored = IdempotencyResponse {
+        status: parts.status.as_u16(),
+        headers: parts
+            .headers
+            .iter()
+            .filter(|(key, _)| !key.as_str().eq_ignore_ascii_case("x-request-id"))
+            .filter_map(|(key, value)| Some((key.to_string(), value.to_str().ok()?.to_string())))
+            .collect(),
+        body: body.to_vec(),
+    };
+    if let Err(err) = state.store.complete(&key, stored).await {
+        let logger = Logger::new(module_path!());
+        let err_msg = format!("冪等キーのストアエラー: {}", err);
+        logger.error(&ctx, &err_msg);
+        let _ = state.store.release(&key).await;
+    }
+    guard.disarm();
+
+    Response::from_parts(parts, Body::from(body))
+}
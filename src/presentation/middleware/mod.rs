@@ -1 +1,16 @@
+pub mod auth_middleware;
+pub mod body_limit_middleware;
 pub mod common_middleware;
+pub mod feature_flag_middleware;
+pub mod idempotency_middleware;
+pub mod panic_middleware;
+pub mod rate_limit_middleware;
+pub mod timeout_middleware;
+
+// テストコード用のモジュール
+pub mod body_limit_middleware_test;
+pub mod feature_flag_middleware_test;
+pub mod idempotency_middleware_test;
+pub mod panic_middleware_test;
+pub mod rate_limit_middleware_test;
+pub mod timeout_middleware_test;
@@ -0,0 +1,63 @@
+// axum
+use axum::{
+    Json,
+    extract::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// パニックの捕捉
+use futures_util::FutureExt;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ドメイン
+use crate::domain::error::error_common::ErrorCommon;
+
+// ロガー設定
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::Logger;
+
+// パニック時のメッセージを取得
+fn get_panic_message(err: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = err.downcast_ref::<&str>() {
+        return msg;
+    }
+    match err.downcast_ref::<String>() {
+        Some(msg) => msg,
+        None => "unknown panic",
+    }
+}
+
+// パニック捕捉用のミドルウェア（ハンドラーでパニックが発生した場合は500エラー）
+pub async fn panic_middleware(req: Request, next: Next) -> Response {
+    // 共通コンテキストの取得
+    let ctx = req.extensions().get::<ContextRequest>().unwrap().clone();
+
+    match AssertUnwindSafe(next.run(req)).catch_unwind().await {
+        Ok(res) => res,
+        Err(err) => {
+            // ログ出力
+            let logger = Logger::new(module_path!());
+            let err_msg = format!("パニックが発生しました: {}", get_panic_message(&*err));
+            logger.error(&ctx, &err_msg);
+
+            // レスポンスヘッダーに付与する値の設定
+            let x_request_id = ctx.header.get("X-Request-Id");
+            let request_id = x_request_id.expect("-").to_str().unwrap();
+            let res_header = [("X-Request-Id", request_id)];
+
+            // json形式のメッセージを設定
+            let err = ErrorCommon::InternalServerError;
+            let json_msg = Json(json!({ "message": err.to_string()}));
+
+            (err.status_code(), res_header, json_msg).into_response()
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, middleware, routing::get};
+
+    // ミドルウェア
+    use crate::presentation::middleware::common_middleware;
+    use crate::presentation::middleware::panic_middleware::panic_middleware;
+
+    // パニックするハンドラー
+    async fn panic_handler() -> &'static str {
+        panic!("test panic");
+    }
+
+    // テスト用のサーバーを起動してベースURLを返す
+    async fn start_server() -> String {
+        let app = Router::new()
+            .route("/ok", get(|| async { "ok" }))
+            .route("/panic", get(panic_handler))
+            .layer(middleware::from_fn(panic_middleware))
+            .layer(middleware::from_fn(common_middleware::request_middleware));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        base_url
+    }
+
+    #[tokio::test]
+    async fn test_panic() {
+        let base_url = start_server().await;
+        let client = reqwest::Client::new();
+
+        // テスト実行
+        let res = client
+            .get(format!("{}/panic", base_url))
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(res.headers().contains_key("X-Request-Id"));
+        let body: serde_json::Value = res.json().await.unwrap();
+        assert_eq!(body["message"], "Internal Server Error");
+
+        // パニック後も同じサーバーでリクエストを処理できる
+        let res = client.get(format!("{}/ok", base_url)).send().await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
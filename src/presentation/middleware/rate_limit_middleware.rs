@@ -0,0 +1,143 @@
+// axum
+use axum::{
+    Json,
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// レート制限
+use crate::application::usecase::rate_limit::{
+    rate_limit_policy::{RateLimitPolicy, find_policy},
+    rate_limit_store_trait::RateLimitDecision,
+};
+
+// ロガー設定
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::Logger;
+
+// レジストリ
+use crate::registry::registry_settings::AppState;
+
+// レート制限ミドルウェアの状態（ルートグループ単位で設定）
+#[derive(Clone)]
+pub struct RateLimitState {
+    pub state: Arc<AppState>,
+    pub group: &'static str,
+}
+
+impl RateLimitState {
+    // 初期化用メソッド
+    pub fn new(state: Arc<AppState>, group: &'static str) -> Self {
+        RateLimitState { state, group }
+    }
+}
+
+// レート制限のキーを取得（認証済みの場合はsubject、未認証の場合はクライアントIP）
+// ※APIキー認証の場合のsubjectは「api_key:<key_id>」
+fn get_client_key(ctx: &ContextRequest, req: &Request) -> String {
+    if let Some(auth) = &ctx.auth {
+        return format!("sub:{}", auth.subject);
+    }
+
+    match req.extensions().get::<ConnectInfo<SocketAddr>>() {
+        Some(ConnectInfo(addr)) => format!("ip:{}", addr.ip()),
+        None => "ip:unknown".to_string(),
+    }
+}
+
+// レスポンスヘッダーにレート制限の情報を設定
+fn set_rate_limit_headers(
+    headers: &mut HeaderMap,
+    policy: &RateLimitPolicy,
+    decision: &RateLimitDecision,
+) {
+    let values = [
+        ("RateLimit-Limit", decision.limit.to_string()),
+        ("RateLimit-Remaining", decision.remaining.to_string()),
+        ("RateLimit-Reset", decision.reset_secs.to_string()),
+        (
+            "RateLimit-Policy",
+            format!("{};w={}", policy.capacity, policy.period_secs),
+        ),
+    ];
+    for (key, value) in values {
+        headers.insert(key, HeaderValue::from_str(&value).unwrap());
+    }
+}
+
+// レート制限用のミドルウェア（トークンバケット方式）
+pub async fn rate_limit_middleware(
+    State(rate_limit): State<RateLimitState>,
+    req: Request,
+    next: Next,
+) -> Response {
+    // ルートグループのポリシーを取得（未設定の場合はレート制限なし）
+    let reloadable_config = rate_limit.state.reloadable_config.get();
+    let policy = match find_policy(&reloadable_config.rate_limit_policies, rate_limit.group) {
+        Some(policy) => policy,
+        None => return next.run(req).await,
+    };
+
+    // 共通コンテキストの取得
+    let ctx = req.extensions().get::<ContextRequest>().unwrap().clone();
+    let logger = Logger::new(module_path!());
+
+    // トークンの取得（ストアのエラー時はリクエストを許可）
+    let key = format!("{}:{}", rate_limit.group, get_client_key(&ctx, &req));
+    let decision = match rate_limit
+        .state
+        .rate_limit_store
+        .acquire(&key, &policy)
+        .await
+    {
+        Ok(decision) => decision,
+        Err(err) => {
+            let err_msg = format!("レート制限のストアエラー: {}", err);
+            logger.error(&ctx, &err_msg);
+            return next.run(req).await;
+        }
+    };
+
+    if !decision.allowed {
+        // ログ出力
+        let err_msg = format!("レート制限超過: key={}", key);
+        logger.warn(&ctx, &err_msg);
+
+        // レスポンスヘッダーに付与する値の設定
+        let x_request_id = ctx.header.get("X-Request-Id");
+        let request_id = x_request_id.expect("-").to_str().unwrap();
+        let retry_after = decision.retry_after_secs.to_string();
+        let res_header = [
+            ("X-Request-Id", request_id),
+            ("Retry-After", retry_after.as_str()),
+        ];
+
+        // json形式のメッセージを設定
+        let json_msg = Json(json!({
+            "message": "リクエスト数が上限を超えました。しばらく待ってから再度実行して下さい。"
+        }));
+
+        let mut res = (StatusCode::TOO_MANY_REQUESTS, res_header, json_msg).into_response();
+        set_rate_limit_headers(res.headers_mut(), &policy, &decision);
+        return res;
+    }
+
+    // 内側のレート制限でヘッダーを設定済みの場合は上書きしない
+    let mut res = next.run(req).await;
+    if !res.headers().contains_key("RateLimit-Limit") {
+        set_rate_limit_headers(res.headers_mut(), &policy, &decision);
+    }
+    res
+}
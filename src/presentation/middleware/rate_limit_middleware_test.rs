@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, middleware, routing::get};
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    // コンフィグ
+    use crate::config::config_reloadable::{ReloadableConfig, ReloadableConfigHandle};
+    use crate::config::config_secret::Secret;
+    use crate::config::config_settings::Config;
+
+    // ミドルウェア
+    use crate::presentation::middleware::rate_limit_middleware::{
+        RateLimitState, rate_limit_middleware,
+    };
+    use crate::presentation::middleware::{auth_middleware, common_middleware};
+
+    // レジストリ
+    use crate::registry::registry_settings::AppState;
+
+    // テスト用のサーバーを起動してベースURLと再読み込み可能な設定のハンドルを返す
+    async fn start_server(rate_limits: &str) -> (String, ReloadableConfigHandle) {
+        let config = Config {
+            jwt_hs256_secret: Some(Secret::new("testing-secret".to_string())),
+            ..Default::default()
+        };
+        let reloadable_config = ReloadableConfig {
+            rate_limits: rate_limits.to_string(),
+            ..Default::default()
+        };
+        let reloadable_config = ReloadableConfigHandle::new(reloadable_config.prepare().unwrap());
+        let state = Arc::new(AppState::new(&config, reloadable_config.clone()).await);
+
+        let users = Router::new()
+            .route("/users", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn_with_state(
+                RateLimitState::new(state.clone(), "users"),
+                rate_limit_middleware,
+            ));
+        let auth = Router::new()
+            .route("/auth", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn_with_state(
+                RateLimitState::new(state.clone(), "auth"),
+                rate_limit_middleware,
+            ));
+        // 認証が必要なルート（本番のルーターと同じく認証前にクライアントIPで制限）
+        let private = Router::new()
+            .route("/private", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn_with_state(
+                RateLimitState::new(state.clone(), "users"),
+                rate_limit_middleware,
+            ))
+            .route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                auth_middleware::auth_middleware,
+            ))
+            .route_layer(middleware::from_fn_with_state(
+                RateLimitState::new(state.clone(), "pre_auth"),
+                rate_limit_middleware,
+            ));
+        let app = users
+            .merge(auth)
+            .merge(private)
+            .layer(middleware::from_fn(common_middleware::request_middleware));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap();
+        });
+
+        (base_url, reloadable_config)
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_exceeded() {
+        let (base_url, _) = start_server("default=2/60,auth=1/60").await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/users", base_url);
+
+        // テスト実行
+        let first = client.get(&url).send().await.unwrap();
+        let second = client.get(&url).send().await.unwrap();
+        let third = client.get(&url).send().await.unwrap();
+
+        // 検証
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(first.headers()["RateLimit-Limit"], "2");
+        assert_eq!(first.headers()["RateLimit-Remaining"], "1");
+        assert_eq!(first.headers()["RateLimit-Policy"], "2;w=60");
+        assert_eq!(second.status(), StatusCode::OK);
+        assert_eq!(second.headers()["RateLimit-Remaining"], "0");
+        assert_eq!(third.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(third.headers()["Retry-After"], "30");
+        assert_eq!(third.headers()["RateLimit-Remaining"], "0");
+        assert!(third.headers().contains_key("X-Request-Id"));
+
+        // ルートグループごとに独立したバケット
+        let auth_url = format!("{}/auth", base_url);
+        let auth_first = client.get(&auth_url).send().await.unwrap();
+        let auth_second = client.get(&auth_url).send().await.unwrap();
+        assert_eq!(auth_first.status(), StatusCode::OK);
+        assert_eq!(auth_first.headers()["RateLimit-Limit"], "1");
+        assert_eq!(auth_second.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(auth_second.headers()["Retry-After"], "60");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_disabled() {
+        let (base_url, _) = start_server("auth=1/60").await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/users", base_url);
+
+        // テスト実行
+        let first = client.get(&url).send().await.unwrap();
+        let second = client.get(&url).send().await.unwrap();
+
+        // 検証（ポリシーが未設定のグループはレート制限なし）
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(second.status(), StatusCode::OK);
+        assert!(!second.headers().contains_key("RateLimit-Limit"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_before_auth() {
+        let (base_url, _) = start_server("default=100/60,pre_auth=2/60").await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/private", base_url);
+
+        // テスト実行（不正なトークンで繰り返しリクエスト）
+        let mut statuses = Vec::new();
+        for _ in 0..3 {
+            let res = client
+                .get(&url)
+                .bearer_auth("invalid-token")
+                .send()
+                .await
+                .unwrap();
+            statuses.push(res.status());
+        }
+
+        // 検証（認証エラーのリクエストも制限される）
+        assert_eq!(
+            statuses,
+            vec![
+                StatusCode::UNAUTHORIZED,
+                StatusCode::UNAUTHORIZED,
+                StatusCode::TOO_MANY_REQUESTS
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_reload() {
+        let (base_url, handle) = start_server("default=1/60").await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/users", base_url);
+
+        // 上限に到達
+        let first = client.get(&url).send().await.unwrap();
+        let second = client.get(&url).send().await.unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // テスト実行（レート制限を無効にする設定に差し替え）
+        let reloadable_config = ReloadableConfig {
+            rate_limits: "".to_string(),
+            ..Default::default()
+        };
+        handle.replace(reloadable_config.prepare().unwrap());
+        let third = client.get(&url).send().await.unwrap();
+
+        // 検証（再起動なしで新しい設定が反映される）
+        assert_eq!(third.status(), StatusCode::OK);
+        assert!(!third.headers().contains_key("RateLimit-Limit"));
+    }
+}
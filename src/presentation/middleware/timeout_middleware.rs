@@ -0,0 +1,106 @@
+// axum
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// ハッシュマップ
+use std::collections::HashMap;
+
+// 時間
+use std::time::Duration;
+
+// json変換用マクロ
+use serde_json::json;
+
+// 共通コンテキスト
+use crate::application::usecase::context::context_request::ContextRequest;
+
+// ロガー設定
+use crate::application::usecase::logger::logger_trait::LoggerTrait;
+use crate::infrastructure::logger::logger_log::Logger;
+
+// ルートグループの指定がない場合に適用するタイムアウト名
+const DEFAULT_GROUP: &str = "default";
+
+// 「グループ名=秒数」のカンマ区切り形式の文字列からグループ別のタイムアウトを生成
+// ※空文字の場合はタイムアウトを無効にする
+pub fn parse_timeouts(rules: &str) -> Result<HashMap<String, u64>, String> {
+    let mut timeouts = HashMap::new();
+
+    for rule in rules.split(',').map(|rule| rule.trim()) {
+        if rule.is_empty() {
+            continue;
+        }
+
+        let (group, secs) = match rule.split_once('=') {
+            Some((group, secs)) if !group.trim().is_empty() => (group.trim(), secs.trim()),
+            _ => return Err(format!("タイムアウトの設定「{}」が不正です。", rule)),
+        };
+
+        match secs.parse::<u64>() {
+            Ok(secs) if secs > 0 => timeouts.insert(group.to_string(), secs),
+            _ => return Err(format!("タイムアウトの設定「{}」が不正です。", rule)),
+        };
+    }
+
+    Ok(timeouts)
+}
+
+// タイムアウト用ミドルウェアの状態（ルートグループ単位で設定）
+#[derive(Clone)]
+pub struct TimeoutState {
+    pub timeout: Option<Duration>,
+}
+
+impl TimeoutState {
+    // ルートグループのタイムアウトを設定（未設定の場合はデフォルトのタイムアウト）
+    pub fn new(timeouts: &HashMap<String, u64>, group: &str) -> Self {
+        let timeout = timeouts
+            .get(group)
+            .or_else(|| timeouts.get(DEFAULT_GROUP))
+            .map(|secs| Duration::from_secs(*secs));
+
+        TimeoutState { timeout }
+    }
+}
+
+// タイムアウト用のミドルウェア（時間内に処理が終わらない場合は504エラー）
+pub async fn timeout_middleware(
+    State(state): State<TimeoutState>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let timeout = match state.timeout {
+        Some(timeout) => timeout,
+        None => return next.run(req).await,
+    };
+
+    // 共通コンテキストの取得
+    let ctx = req.extensions().get::<ContextRequest>().unwrap().clone();
+
+    match tokio::time::timeout(timeout, next.run(req)).await {
+        Ok(res) => res,
+        Err(_) => {
+            // ログ出力
+            let logger = Logger::new(module_path!());
+            let err_msg = format!("タイムアウトエラー: {}秒", timeout.as_secs());
+            logger.error(&ctx, &err_msg);
+
+            // レスポンスヘッダーに付与する値の設定
+            let x_request_id = ctx.header.get("X-Request-Id");
+            let request_id = x_request_id.expect("-").to_str().unwrap();
+            let res_header = [("X-Request-Id", request_id)];
+
+            // json形式のメッセージを設定
+            let json_msg = Json(json!({
+                "message": "リクエストがタイムアウトしました。"
+            }));
+
+            (StatusCode::GATEWAY_TIMEOUT, res_header, json_msg).into_response()
+        }
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, middleware, routing::get};
+    use std::time::Duration;
+
+    // ミドルウェア
+    use crate::presentation::middleware::common_middleware;
+    use crate::presentation::middleware::timeout_middleware::{
+        TimeoutState, parse_timeouts, timeout_middleware,
+    };
+
+    // テスト用のサーバーを起動してベースURLを返す
+    async fn start_server(rules: &str) -> String {
+        let timeouts = parse_timeouts(rules).unwrap();
+        let app = Router::new()
+            .route("/fast", get(|| async { "ok" }))
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    "ok"
+                }),
+            )
+            .route_layer(middleware::from_fn_with_state(
+                TimeoutState::new(&timeouts, "users"),
+                timeout_middleware,
+            ))
+            .layer(middleware::from_fn(common_middleware::request_middleware));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        base_url
+    }
+
+    #[test]
+    fn test_parse_timeouts() {
+        // テスト実行
+        let timeouts = parse_timeouts("default=30, admin=60").unwrap();
+
+        // 検証
+        assert_eq!(timeouts["default"], 30);
+        assert_eq!(timeouts["admin"], 60);
+        assert!(parse_timeouts("").unwrap().is_empty());
+        assert!(parse_timeouts("default").is_err());
+        assert!(parse_timeouts("default=0").is_err());
+        assert!(parse_timeouts("=30").is_err());
+    }
+
+    #[test]
+    fn test_timeout_state() {
+        let timeouts = parse_timeouts("default=30,admin=60").unwrap();
+
+        // 検証
+        let admin = TimeoutState::new(&timeouts, "admin");
+        assert_eq!(admin.timeout, Some(Duration::from_secs(60)));
+        let users = TimeoutState::new(&timeouts, "users");
+        assert_eq!(users.timeout, Some(Duration::from_secs(30)));
+        let disabled = TimeoutState::new(&parse_timeouts("").unwrap(), "users");
+        assert!(disabled.timeout.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let base_url = start_server("users=1").await;
+        let client = reqwest::Client::new();
+
+        // テスト実行
+        let fast = client
+            .get(format!("{}/fast", base_url))
+            .send()
+            .await
+            .unwrap();
+        let slow = client
+            .get(format!("{}/slow", base_url))
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(fast.status(), StatusCode::OK);
+        assert_eq!(slow.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert!(slow.headers().contains_key("X-Request-Id"));
+        let body: serde_json::Value = slow.json().await.unwrap();
+        assert_eq!(body["message"], "リクエストがタイムアウトしました。");
+    }
+}
@@ -0,0 +1,57 @@
+// tower_http
+use tower_http::compression::{
+    CompressionLayer, Predicate,
+    predicate::{And, NotForContentType, SizeAbove},
+};
+use tower_http::decompression::RequestDecompressionLayer;
+
+// コンフィグ
+use crate::config::config_settings::Config;
+
+// レスポンスを圧縮する条件（最小サイズ以上で、画像・gRPC・SSE以外）
+pub type CompressionPredicate =
+    And<And<And<SizeAbove, NotForContentType>, NotForContentType>, NotForContentType>;
+
+// 設定値からレスポンス圧縮のレイヤーを作成（Accept-Encodingで圧縮方式を選択）
+pub fn new_compression_layer(
+    config: &Config,
+) -> Result<CompressionLayer<CompressionPredicate>, String> {
+    let (mut gzip, mut br, mut zstd) = (false, false, false);
+
+    for algorithm in config.compression_algorithms.iter().map(|v| v.trim()) {
+        match algorithm.to_ascii_lowercase().as_str() {
+            "" => {}
+            "gzip" => gzip = true,
+            "br" => br = true,
+            "zstd" => zstd = true,
+            _ => {
+                return Err(format!(
+                    "COMPRESSION_ALGORITHMSの値「{}」が不正です。",
+                    algorithm
+                ));
+            }
+        }
+    }
+
+    let predicate = SizeAbove::new(config.compression_min_size_bytes)
+        .and(NotForContentType::GRPC)
+        .and(NotForContentType::IMAGES)
+        .and(NotForContentType::SSE);
+
+    Ok(CompressionLayer::new()
+        .gzip(gzip)
+        .br(br)
+        .zstd(zstd)
+        .compress_when(predicate))
+}
+
+// 設定値からリクエストボディ展開のレイヤーを作成（Content-Encodingで展開方式を選択）
+// ※無効の場合、圧縮されたリクエストボディは415エラー
+pub fn new_decompression_layer(config: &Config) -> RequestDecompressionLayer {
+    let enabled = config.request_decompression;
+
+    RequestDecompressionLayer::new()
+        .gzip(enabled)
+        .br(enabled)
+        .zstd(enabled)
+}
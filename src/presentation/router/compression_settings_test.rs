@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        http::StatusCode,
+        routing::{get, post},
+    };
+
+    // コンフィグ
+    use crate::config::config_settings::Config;
+
+    // 圧縮設定
+    use crate::presentation::router::compression_settings::{
+        new_compression_layer, new_decompression_layer,
+    };
+
+    // テスト用のレスポンスボディ
+    fn large_body() -> String {
+        "a".repeat(2048)
+    }
+
+    // テスト用のサーバーを起動してベースURLを返す
+    async fn start_server(config: Config) -> String {
+        let app = Router::new()
+            .route("/large", get(|| async { large_body() }))
+            .route("/small", get(|| async { "ok" }))
+            .route("/echo", post(|body: String| async move { body }))
+            .layer(new_decompression_layer(&config))
+            .layer(new_compression_layer(&config).unwrap());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        base_url
+    }
+
+    // Accept-Encodingを指定してレスポンスのContent-Encodingを取得
+    async fn get_content_encoding(url: &str, accept_encoding: &str) -> Option<String> {
+        let res = reqwest::Client::new()
+            .get(url)
+            .header("Accept-Encoding", accept_encoding)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        res.headers()
+            .get("Content-Encoding")
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn test_new_compression_layer_error() {
+        let config = Config {
+            compression_algorithms: vec!["deflate".to_string()],
+            ..Default::default()
+        };
+
+        // 検証
+        assert!(new_compression_layer(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_compression() {
+        let base_url = start_server(Config::default()).await;
+        let url = format!("{}/large", base_url);
+
+        // 検証（Accept-Encodingで圧縮方式を選択）
+        let gzip = get_content_encoding(&url, "gzip").await;
+        assert_eq!(gzip.as_deref(), Some("gzip"));
+        let br = get_content_encoding(&url, "br").await;
+        assert_eq!(br.as_deref(), Some("br"));
+        let zstd = get_content_encoding(&url, "zstd").await;
+        assert_eq!(zstd.as_deref(), Some("zstd"));
+        assert!(get_content_encoding(&url, "identity").await.is_none());
+
+        // 最小サイズ未満は圧縮しない
+        let small_url = format!("{}/small", base_url);
+        assert!(get_content_encoding(&small_url, "gzip").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_compression_disabled() {
+        let config = Config {
+            compression_algorithms: Vec::new(),
+            ..Default::default()
+        };
+        let base_url = start_server(config).await;
+
+        // 検証
+        let url = format!("{}/large", base_url);
+        assert!(get_content_encoding(&url, "gzip, br, zstd").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_request_decompression() {
+        let base_url = start_server(Config::default()).await;
+        let client = reqwest::Client::new();
+
+        // gzipで圧縮されたボディを用意（サーバーの圧縮レスポンスを利用）
+        let compressed = client
+            .get(format!("{}/large", base_url))
+            .header("Accept-Encoding", "gzip")
+            .send()
+            .await
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap();
+        assert!(compressed.len() < large_body().len());
+
+        // テスト実行
+        let res = client
+            .post(format!("{}/echo", base_url))
+            .header("Content-Encoding", "gzip")
+            .body(compressed)
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.text().await.unwrap(), large_body());
+    }
+
+    #[tokio::test]
+    async fn test_request_decompression_disabled() {
+        let config = Config {
+            request_decompression: false,
+            ..Default::default()
+        };
+        let base_url = start_server(config).await;
+
+        // テスト実行
+        let res = reqwest::Client::new()
+            .post(format!("{}/echo", base_url))
+            .header("Content-Encoding", "gzip")
+            .body("not compressed")
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+}
@@ -0,0 +1,112 @@
+// axum
+use axum::http::{HeaderName, HeaderValue, Method};
+
+// 時間
+use std::time::Duration;
+
+// tower_http
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+// コンフィグ
+use crate::config::config_reloadable::ReloadableConfigHandle;
+use crate::config::config_settings::Config;
+
+// ブラウザから参照可能にするレスポンスヘッダー
+const EXPOSE_HEADERS: [&str; 8] = [
+    "x-request-id",
+    "etag",
+    "idempotent-replayed",
+    "retry-after",
+    "ratelimit-limit",
+    "ratelimit-remaining",
+    "ratelimit-reset",
+    "ratelimit-policy",
+];
+
+// 空の値を除外したカンマ区切りの設定値
+fn trim_values(values: &[String]) -> Vec<&str> {
+    values
+        .iter()
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+// リクエストのオリジンが許可されているかどうか
+// ※資格情報の送信を許可する場合、「*」は全てのオリジンの許可として扱わない
+pub fn is_origin_allowed(
+    origins: &[String],
+    origin: &HeaderValue,
+    allow_credentials: bool,
+) -> bool {
+    origins.iter().any(|allowed| {
+        if allowed == "*" {
+            return !allow_credentials;
+        }
+        allowed.as_bytes() == origin.as_bytes()
+    })
+}
+
+// 設定値からCORSのレイヤーを作成
+// ※許可するオリジンは再起動なしで変更できるようにリクエストごとに現在の設定から参照する
+pub fn new_cors_layer(
+    config: &Config,
+    reloadable_config: ReloadableConfigHandle,
+) -> Result<CorsLayer, String> {
+    // オリジン（起動時の設定値のみチェック）
+    let allow_credentials = config.cors_allow_credentials;
+    if allow_credentials
+        && reloadable_config
+            .get()
+            .cors_allowed_origins
+            .iter()
+            .any(|origin| origin == "*")
+    {
+        return Err(
+            "CORS_ALLOW_CREDENTIALSが有効な場合、CORS_ALLOWED_ORIGINSに「*」は指定できません。"
+                .to_string(),
+        );
+    }
+    let allow_origin = AllowOrigin::predicate(move |origin, _| {
+        let current = reloadable_config.get();
+        is_origin_allowed(&current.cors_allowed_origins, origin, allow_credentials)
+    });
+
+    // メソッド
+    let mut methods = Vec::new();
+    for method in trim_values(&config.cors_allowed_methods) {
+        match Method::from_bytes(method.to_ascii_uppercase().as_bytes()) {
+            Ok(value) => methods.push(value),
+            Err(_) => {
+                return Err(format!(
+                    "CORS_ALLOWED_METHODSの値「{}」が不正です。",
+                    method
+                ));
+            }
+        }
+    }
+
+    // リクエストヘッダー
+    let mut headers = Vec::new();
+    for header in trim_values(&config.cors_allowed_headers) {
+        match HeaderName::from_bytes(header.to_ascii_lowercase().as_bytes()) {
+            Ok(value) => headers.push(value),
+            Err(_) => {
+                return Err(format!(
+                    "CORS_ALLOWED_HEADERSの値「{}」が不正です。",
+                    header
+                ));
+            }
+        }
+    }
+
+    let cors = CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(methods)
+        .allow_headers(headers)
+        .expose_headers(EXPOSE_HEADERS.map(HeaderName::from_static))
+        .allow_credentials(config.cors_allow_credentials)
+        .max_age(Duration::from_secs(config.cors_max_age_secs));
+
+    Ok(cors)
+}
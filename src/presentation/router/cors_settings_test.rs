@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderValue, StatusCode};
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    // コンフィグ
+    use crate::config::config_reloadable::{ReloadableConfig, ReloadableConfigHandle};
+    use crate::config::config_secret::Secret;
+    use crate::config::config_settings::Config;
+
+    // ルーター
+    use crate::presentation::router::cors_settings::{is_origin_allowed, new_cors_layer};
+    use crate::presentation::router::router_settings::router;
+
+    // レジストリ
+    use crate::registry::registry_settings::AppState;
+
+    // テスト用の設定値
+    const TEST_ORIGIN: &str = "http://localhost:3000";
+
+    // テスト用のコンフィグ
+    fn new_config() -> Config {
+        Config {
+            jwt_hs256_secret: Some(Secret::new("testing-secret".to_string())),
+            cors_allow_credentials: true,
+            ..Default::default()
+        }
+    }
+
+    // テスト用の再読み込み可能な設定のハンドル
+    fn new_reloadable_config(origins: &[&str]) -> ReloadableConfigHandle {
+        ReloadableConfigHandle::new(ReloadableConfig {
+            cors_allowed_origins: origins.iter().map(|origin| origin.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+
+    // テスト用のサーバーを起動してベースURLを返す
+    async fn start_server(config: Config, reloadable_config: ReloadableConfigHandle) -> String {
+        let state = Arc::new(AppState::new(&config, reloadable_config).await);
+        let app = router(state, &config);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap();
+        });
+
+        base_url
+    }
+
+    #[test]
+    fn test_is_origin_allowed() {
+        let origin = HeaderValue::from_static(TEST_ORIGIN);
+        let origins = vec![TEST_ORIGIN.to_string()];
+        let any = vec!["*".to_string()];
+
+        // 検証
+        assert!(is_origin_allowed(&origins, &origin, true));
+        assert!(!is_origin_allowed(&Vec::new(), &origin, false));
+        assert!(!is_origin_allowed(
+            &origins,
+            &HeaderValue::from_static("http://evil.example.com"),
+            false
+        ));
+        assert!(is_origin_allowed(&any, &origin, false));
+        // 資格情報の送信を許可する場合は「*」で許可しない
+        assert!(!is_origin_allowed(&any, &origin, true));
+    }
+
+    #[test]
+    fn test_new_cors_layer_error() {
+        // 資格情報の許可と全てのオリジンの許可は併用不可
+        assert!(new_cors_layer(&new_config(), new_reloadable_config(&["*"])).is_err());
+
+        // 不正なメソッド
+        let config = Config {
+            cors_allowed_methods: vec!["GET POST".to_string()],
+            ..new_config()
+        };
+        assert!(new_cors_layer(&config, new_reloadable_config(&[TEST_ORIGIN])).is_err());
+
+        // 不正なヘッダー
+        let config = Config {
+            cors_allowed_headers: vec!["x api key".to_string()],
+            ..new_config()
+        };
+        assert!(new_cors_layer(&config, new_reloadable_config(&[TEST_ORIGIN])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_preflight() {
+        let base_url = start_server(new_config(), new_reloadable_config(&[TEST_ORIGIN])).await;
+        let client = reqwest::Client::new();
+
+        // テスト実行（認証が必要なAPIへのプリフライトリクエスト）
+        let res = client
+            .request(
+                reqwest::Method::OPTIONS,
+                format!("{}/api/v1/users", base_url),
+            )
+            .header("Origin", TEST_ORIGIN)
+            .header("Access-Control-Request-Method", "POST")
+            .header(
+                "Access-Control-Request-Headers",
+                "authorization,content-type",
+            )
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::OK);
+        let headers = res.headers();
+        assert_eq!(headers["access-control-allow-origin"], TEST_ORIGIN);
+        assert_eq!(headers["access-control-allow-credentials"], "true");
+        assert_eq!(headers["access-control-max-age"], "600");
+        let allow_methods = headers["access-control-allow-methods"].to_str().unwrap();
+        assert!(allow_methods.contains("POST"));
+        let allow_headers = headers["access-control-allow-headers"].to_str().unwrap();
+        assert!(allow_headers.contains("authorization"));
+        assert!(allow_headers.contains("x-api-key"));
+    }
+
+    #[tokio::test]
+    async fn test_preflight_not_allowed_origin() {
+        let base_url = start_server(new_config(), new_reloadable_config(&[TEST_ORIGIN])).await;
+        let client = reqwest::Client::new();
+
+        // テスト実行
+        let res = client
+            .request(
+                reqwest::Method::OPTIONS,
+                format!("{}/api/v1/users", base_url),
+            )
+            .header("Origin", "http://evil.example.com")
+            .header("Access-Control-Request-Method", "GET")
+            .send()
+            .await
+            .unwrap();
+
+        // 検証（許可されていないオリジンにはCORSヘッダーを返さない）
+        assert!(!res.headers().contains_key("access-control-allow-origin"));
+    }
+
+    #[tokio::test]
+    async fn test_actual_request() {
+        let base_url = start_server(new_config(), new_reloadable_config(&[TEST_ORIGIN])).await;
+        let client = reqwest::Client::new();
+
+        // テスト実行（認証エラーのレスポンスにもCORSヘッダーを付与）
+        let res = client
+            .get(format!("{}/api/v1/users", base_url))
+            .header("Origin", TEST_ORIGIN)
+            .send()
+            .await
+            .unwrap();
+
+        // 検証
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+        let headers = res.headers();
+        assert_eq!(headers["access-control-allow-origin"], TEST_ORIGIN);
+        let expose_headers = headers["access-control-expose-headers"].to_str().unwrap();
+        assert!(expose_headers.contains("x-request-id"));
+    }
+
+    #[tokio::test]
+    async fn test_preflight_reload() {
+        let reloadable_config = new_reloadable_config(&[]);
+        let base_url = start_server(new_config(), reloadable_config.clone()).await;
+        let client = reqwest::Client::new();
+        let preflight = || {
+            client
+                .request(
+                    reqwest::Method::OPTIONS,
+                    format!("{}/api/v1/users", base_url),
+                )
+                .header("Origin", TEST_ORIGIN)
+                .header("Access-Control-Request-Method", "GET")
+                .send()
+        };
+
+        // オリジンが未設定の場合はCORSヘッダーを返さない
+        let res = preflight().await.unwrap();
+        assert!(!res.headers().contains_key("access-control-allow-origin"));
+
+        // テスト実行（許可するオリジンを追加した設定に差し替え）
+        reloadable_config.replace(ReloadableConfig {
+            cors_allowed_origins: vec![TEST_ORIGIN.to_string()],
+            ..Default::default()
+        });
+        let res = preflight().await.unwrap();
+
+        // 検証（再起動なしで新しい設定が反映される）
+        assert_eq!(res.headers()["access-control-allow-origin"], TEST_ORIGIN);
+    }
+}
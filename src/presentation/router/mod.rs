@@ -1 +1,7 @@
+pub mod compression_settings;
+pub mod cors_settings;
 pub mod router_settings;
+
+// テストコード用のモジュール
+pub mod compression_settings_test;
+pub mod cors_settings_test;
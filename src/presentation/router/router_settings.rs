@@ -1,5 +1,10 @@
 // axum
-use axum::{Router, middleware, routing::get};
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    middleware,
+    routing::{delete, get, post, put},
+};
 
 // Arc（ヒープ上に確保されたある値の所有権を、複数のスレッド間で安全に共有するためのスマートポインタ）
 use std::sync::Arc;
@@ -7,24 +12,177 @@ use std::sync::Arc;
 // tower_http
 use tower_http::trace::TraceLayer;
 
+// コンフィグ
+use crate::config::config_settings::Config;
+
 // レジストリ
 use crate::registry::registry_settings::AppState;
 
+// 圧縮・CORS設定
+use crate::presentation::router::compression_settings::{
+    new_compression_layer, new_decompression_layer,
+};
+use crate::presentation::router::cors_settings::new_cors_layer;
+
 // ハンドラー
-use crate::presentation::handler::user::user_handler;
+use crate::presentation::handler::{
+    admin::admin_handler, api_key::api_key_handler, auth::auth_handler, job::job_handler,
+    user::user_handler, webhook::webhook_handler,
+};
 
 // ミドルウェア
-use crate::presentation::middleware::common_middleware;
+use crate::presentation::middleware::{
+    auth_middleware, body_limit_middleware, common_middleware,
+    feature_flag_middleware::{self, FEATURE_USERS_LIVE, FeatureFlagState},
+    idempotency_middleware::{self, IdempotencyState},
+    panic_middleware,
+    rate_limit_middleware::{self, RateLimitState},
+    timeout_middleware::{self, TimeoutState, parse_timeouts},
+};
+
+pub fn router(state: Arc<AppState>, config: &Config) -> Router {
+    // ルートグループ別のタイムアウト
+    let timeouts = parse_timeouts(&config.request_timeouts).expect("タイムアウトの設定エラー");
+
+    // 冪等キー（更新系のルートグループに設定）
+    let idempotency = IdempotencyState::new(
+        state.idempotency_store.clone(),
+        config.idempotency_ttl_secs,
+        config.request_body_limit_bytes,
+    );
+
+    // CORS
+    let cors = new_cors_layer(config, state.reloadable_config.clone()).expect("CORSの設定エラー");
+
+    // レスポンス圧縮
+    let compression = new_compression_layer(config).expect("圧縮の設定エラー");
+
+    // ルートグループ「users」
+    let users = Router::new()
+        .route(
+            "/users",
+            get(user_handler::find_all).post(user_handler::create),
+        )
+        .route("/users/stream", get(user_handler::stream))
+        .route(
+            "/users/live",
+            get(user_handler::live).route_layer(middleware::from_fn_with_state(
+                FeatureFlagState::new(state.clone(), FEATURE_USERS_LIVE),
+                feature_flag_middleware::feature_flag_middleware,
+            )),
+        )
+        .route(
+            "/users/{uid}",
+            get(user_handler::find_by_uid)
+                .put(user_handler::update)
+                .delete(user_handler::delete),
+        )
+        .route("/users/{uid}/password", put(auth_handler::update_password))
+        .route("/users/{uid}/audit", get(user_handler::find_audit))
+        .route_layer(middleware::from_fn_with_state(
+            TimeoutState::new(&timeouts, "users"),
+            timeout_middleware::timeout_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            idempotency.clone(),
+            idempotency_middleware::idempotency_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            RateLimitState::new(state.clone(), "users"),
+            rate_limit_middleware::rate_limit_middleware,
+        ));
+
+    // ルートグループ「admin」
+    let admin = Router::new()
+        .route(
+            "/admin/log-level",
+            get(admin_handler::find_log_level).put(admin_handler::update_log_level),
+        )
+        .route(
+            "/admin/api-keys",
+            get(api_key_handler::find_all).post(api_key_handler::issue),
+        )
+        .route("/admin/api-keys/{key_id}", delete(api_key_handler::revoke))
+        .route("/admin/outbox/stuck", get(admin_handler::find_stuck_outbox))
+        .route(
+            "/admin/webhooks",
+            get(webhook_handler::find_all).post(webhook_handler::create),
+        )
+        .route(
+            "/admin/webhooks/{subscription_id}",
+            delete(webhook_handler::delete),
+        )
+        .route(
+            "/admin/webhooks/{subscription_id}/deliveries",
+            get(webhook_handler::find_deliveries),
+        )
+        .route(
+            "/admin/webhooks/{subscription_id}/deliveries/{delivery_id}/redeliver",
+            post(webhook_handler::redeliver),
+        )
+        .route("/admin/jobs", post(job_handler::enqueue))
+        .route(
+            "/admin/jobs/dead-letter",
+            get(job_handler::find_dead_letter),
+        )
+        .route("/admin/jobs/{job_id}/retry", post(job_handler::retry))
+        .route_layer(middleware::from_fn_with_state(
+            TimeoutState::new(&timeouts, "admin"),
+            timeout_middleware::timeout_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            idempotency.clone(),
+            idempotency_middleware::idempotency_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            RateLimitState::new(state.clone(), "admin"),
+            rate_limit_middleware::rate_limit_middleware,
+        ));
+
+    // ルートグループ「auth」（認証不要のルート）
+    let auth = Router::new()
+        .route("/auth/login", post(auth_handler::login))
+        .route("/auth/refresh", post(auth_handler::refresh))
+        .route("/auth/oidc/authorize", get(auth_handler::oidc_authorize))
+        .route("/auth/oidc/callback", get(auth_handler::oidc_callback))
+        .route_layer(middleware::from_fn_with_state(
+            TimeoutState::new(&timeouts, "auth"),
+            timeout_middleware::timeout_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            RateLimitState::new(state.clone(), "auth"),
+            rate_limit_middleware::rate_limit_middleware,
+        ));
 
-pub fn router(state: Arc<AppState>) -> Router {
     // グループ設定「v1」
-    let v1 = Router::new().route("/users", get(user_handler::find_all));
+    let v1 = users
+        .merge(admin)
+        // 認証ミドルウェアの設定（レート制限より先に実行して認証済みのsubjectをキーにする）
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware::auth_middleware,
+        ))
+        // 認証前のレート制限（クライアントIPをキーにして認証情報の総当たりを防ぐ）
+        .route_layer(middleware::from_fn_with_state(
+            RateLimitState::new(state.clone(), "pre_auth"),
+            rate_limit_middleware::rate_limit_middleware,
+        ))
+        // 認証不要のルート（route_layerより後に追加したルートには認証ミドルウェアを適用しない）
+        .merge(auth);
 
     // ルーター設定
     Router::new()
         .nest("/api/v1", v1)
         // 共通ミドルウェアの設定（下から順番に読み込み）
+        .layer(DefaultBodyLimit::max(config.request_body_limit_bytes))
+        .layer(new_decompression_layer(config))
+        .layer(middleware::from_fn_with_state(
+            config.request_body_limit_bytes,
+            body_limit_middleware::body_limit_middleware,
+        ))
+        .layer(middleware::from_fn(panic_middleware::panic_middleware))
         .layer(middleware::from_fn(common_middleware::request_middleware))
+        .layer(compression)
         .layer(TraceLayer::new_for_http().on_response(
             |res: &axum::response::Response,
              latency: std::time::Duration,
@@ -45,4 +203,6 @@ pub fn router(state: Arc<AppState>) -> Router {
             },
         ))
         .with_state(state)
+        // CORSの設定（プリフライトリクエストは認証やレート制限の前に応答するため最後に設定）
+        .layer(cors)
 }
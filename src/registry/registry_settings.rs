@@ -1,4 +1,9 @@
 use std::sync::Arc;
+use std::time::Duration;
+
+// コンフィグ
+use crate::config::config_reloadable::ReloadableConfigHandle;
+use crate::config::config_settings::Config;
 
 // DB
 use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
@@ -6,46 +11,683 @@ use crate::infrastructure::database::database_dummy::new_db_dummy_connection;
 // ロガー
 use crate::infrastructure::logger::logger_log::Logger;
 
+// 認証
+use crate::application::usecase::auth::auth_oidc_trait::AuthOidcTrait;
+use crate::application::usecase::auth::auth_token_trait::AuthTokenTrait;
+use crate::infrastructure::auth::auth_jwt::AuthJwt;
+use crate::infrastructure::auth::auth_oidc::AuthOidc;
+use crate::infrastructure::auth::auth_password::AuthPasswordArgon2;
+
+// ドメインイベント
+use crate::application::usecase::event::domain_event_dispatcher::{
+    DomainEventDispatcher, DomainEventDispatcherTrait, DomainEventHandlerTrait,
+};
+use crate::application::usecase::event::domain_event_log_handler::DomainEventLogHandler;
+use crate::application::usecase::event::domain_event_stream::DomainEventStream;
+use crate::application::usecase::event::user_live_hub::UserLiveHub;
+
+// リトライ設定
+use crate::domain::retry::retry_policy::RetryPolicy;
+
+// メッセージブローカー
+use crate::application::usecase::event::event_publisher_trait::EventPublisherTrait;
+use crate::infrastructure::event::event_broker_memory::EventBrokerMemory;
+#[cfg(feature = "kafka")]
+use crate::infrastructure::event::event_publisher_kafka::EventPublisherKafka;
+#[cfg(feature = "nats")]
+use crate::infrastructure::event::event_publisher_nats::EventPublisherNats;
+
+// アウトボックス
+use crate::application::usecase::outbox::outbox_publisher_broker::OutboxPublisherBroker;
+use crate::application::usecase::outbox::outbox_publisher_group::OutboxPublisherGroup;
+use crate::application::usecase::outbox::outbox_publisher_trait::OutboxPublisherTrait;
+use crate::infrastructure::outbox::outbox_publisher_log::OutboxPublisherLog;
+
+// Webhook
+use crate::application::usecase::webhook::webhook_fanout::{
+    WebhookFanoutPublisher, WebhookFanoutRepository,
+};
+use crate::infrastructure::webhook::webhook_sender_reqwest::WebhookSenderReqwest;
+
+// バックグラウンドジョブ
+use crate::application::usecase::job::job_handler_trait::JobHandlerTrait;
+use crate::application::usecase::job::job_scheduler::parse_job_schedules;
+
+// 冪等キー
+use crate::application::usecase::idempotency::idempotency_store_trait::IdempotencyStoreTrait;
+use crate::infrastructure::idempotency::idempotency_memory::IdempotencyMemory;
+
+// レート制限
+use crate::application::usecase::rate_limit::rate_limit_store_trait::RateLimitStoreTrait;
+use crate::infrastructure::rate_limit::rate_limit_memory::RateLimitMemory;
+
 // リポジトリ
+use crate::infrastructure::persistence::api_key::api_key_repository::ApiKeyRepository;
+use crate::infrastructure::persistence::audit::audit_log_repository::AuditLogRepository;
+use crate::infrastructure::persistence::credential::credential_repository::CredentialRepository;
+use crate::infrastructure::persistence::credential::oidc_session_repository::OidcSessionRepository;
+use crate::infrastructure::persistence::credential::refresh_token_repository::RefreshTokenRepository;
+use crate::infrastructure::persistence::job::job_repository::JobRepository;
+use crate::infrastructure::persistence::outbox::outbox_repository::OutboxRepository;
+use crate::infrastructure::persistence::transaction::unit_of_work::UnitOfWork;
 use crate::infrastructure::persistence::user::user_repository::UserRepository;
+use crate::infrastructure::persistence::webhook::webhook_delivery_repository::WebhookDeliveryRepository;
+use crate::infrastructure::persistence::webhook::webhook_subscription_repository::WebhookSubscriptionRepository;
 
 // ユースケース
+use crate::application::usecase::admin::admin_log_level_find::AdminLogLevelFindUsecase;
+use crate::application::usecase::admin::admin_log_level_update::AdminLogLevelUpdateUsecase;
+use crate::application::usecase::admin::admin_outbox_find_stuck::AdminOutboxFindStuckRepository;
+use crate::application::usecase::admin::admin_outbox_find_stuck::AdminOutboxFindStuckUsecase;
+use crate::application::usecase::api_key::api_key_authenticate::ApiKeyAuthenticateRepository;
+use crate::application::usecase::api_key::api_key_authenticate::ApiKeyAuthenticateUsecase;
+use crate::application::usecase::api_key::api_key_find_all::ApiKeyFindAllRepository;
+use crate::application::usecase::api_key::api_key_find_all::ApiKeyFindAllUsecase;
+use crate::application::usecase::api_key::api_key_issue::ApiKeyIssueRepository;
+use crate::application::usecase::api_key::api_key_issue::ApiKeyIssueUsecase;
+use crate::application::usecase::api_key::api_key_revoke::ApiKeyRevokeRepository;
+use crate::application::usecase::api_key::api_key_revoke::ApiKeyRevokeUsecase;
+use crate::application::usecase::auth::auth_login::AuthLoginRepository;
+use crate::application::usecase::auth::auth_login::AuthLoginUsecase;
+use crate::application::usecase::auth::auth_oidc_authorize::AuthOidcAuthorizeRepository;
+use crate::application::usecase::auth::auth_oidc_authorize::AuthOidcAuthorizeUsecase;
+use crate::application::usecase::auth::auth_oidc_callback::AuthOidcCallbackRepository;
+use crate::application::usecase::auth::auth_oidc_callback::AuthOidcCallbackUsecase;
+use crate::application::usecase::auth::auth_password_update::AuthPasswordUpdateRepository;
+use crate::application::usecase::auth::auth_password_update::AuthPasswordUpdateUsecase;
+use crate::application::usecase::auth::auth_refresh::AuthRefreshRepository;
+use crate::application::usecase::auth::auth_refresh::AuthRefreshUsecase;
+use crate::application::usecase::job::job_dead_letter_find_all::JobDeadLetterFindAllRepository;
+use crate::application::usecase::job::job_dead_letter_find_all::JobDeadLetterFindAllUsecase;
+use crate::application::usecase::job::job_enqueue::JobEnqueueRepository;
+use crate::application::usecase::job::job_enqueue::JobEnqueueUsecase;
+use crate::application::usecase::job::job_retry::JobRetryRepository;
+use crate::application::usecase::job::job_retry::JobRetryUsecase;
+use crate::application::usecase::job::job_scheduler::JobSchedulerRepository;
+use crate::application::usecase::job::job_scheduler::JobSchedulerUsecase;
+use crate::application::usecase::job::job_worker::JobWorkerRepository;
+use crate::application::usecase::job::job_worker::JobWorkerUsecase;
+use crate::application::usecase::outbox::outbox_relay::OutboxRelayRepository;
+use crate::application::usecase::outbox::outbox_relay::OutboxRelayUsecase;
+use crate::application::usecase::user::user_audit_find_all::UserAuditFindAllRepository;
+use crate::application::usecase::user::user_audit_find_all::UserAuditFindAllUsecase;
+use crate::application::usecase::user::user_create::UserCreateRepository;
+use crate::application::usecase::user::user_create::UserCreateUsecase;
+use crate::application::usecase::user::user_delete::UserDeleteRepository;
+use crate::application::usecase::user::user_delete::UserDeleteUsecase;
 use crate::application::usecase::user::user_find_all::UserFindAllRepository;
 use crate::application::usecase::user::user_find_all::UserFindAllUsecase;
+use crate::application::usecase::user::user_find_by_uid::UserFindByUidRepository;
+use crate::application::usecase::user::user_find_by_uid::UserFindByUidUsecase;
+use crate::application::usecase::user::user_live::UserLiveRepository;
+use crate::application::usecase::user::user_live::UserLiveUsecase;
+use crate::application::usecase::user::user_purge::UserPurgeMode;
+use crate::application::usecase::user::user_purge::UserPurgeRepository;
+use crate::application::usecase::user::user_purge::UserPurgeUsecase;
+use crate::application::usecase::user::user_stream::UserStreamRepository;
+use crate::application::usecase::user::user_stream::UserStreamUsecase;
+use crate::application::usecase::user::user_update::UserUpdateRepository;
+use crate::application::usecase::user::user_update::UserUpdateUsecase;
+use crate::application::usecase::webhook::webhook_deliver::WebhookDeliverRepository;
+use crate::application::usecase::webhook::webhook_deliver::WebhookDeliverUsecase;
+use crate::application::usecase::webhook::webhook_delivery_find_all::WebhookDeliveryFindAllRepository;
+use crate::application::usecase::webhook::webhook_delivery_find_all::WebhookDeliveryFindAllUsecase;
+use crate::application::usecase::webhook::webhook_delivery_redeliver::WebhookDeliveryRedeliverRepository;
+use crate::application::usecase::webhook::webhook_delivery_redeliver::WebhookDeliveryRedeliverUsecase;
+use crate::application::usecase::webhook::webhook_subscription_create::WebhookSubscriptionCreateRepository;
+use crate::application::usecase::webhook::webhook_subscription_create::WebhookSubscriptionCreateUsecase;
+use crate::application::usecase::webhook::webhook_subscription_delete::WebhookSubscriptionDeleteRepository;
+use crate::application::usecase::webhook::webhook_subscription_delete::WebhookSubscriptionDeleteUsecase;
+use crate::application::usecase::webhook::webhook_subscription_find_all::WebhookSubscriptionFindAllRepository;
+use crate::application::usecase::webhook::webhook_subscription_find_all::WebhookSubscriptionFindAllUsecase;
 
 // Userユースケース
 #[derive(Clone)]
 pub struct UserUsecase {
     pub user_find_all: UserFindAllUsecase,
+    pub user_find_by_uid: UserFindByUidUsecase,
+    pub user_create: UserCreateUsecase,
+    pub user_update: UserUpdateUsecase,
+    pub user_delete: UserDeleteUsecase,
+    pub user_audit_find_all: UserAuditFindAllUsecase,
+    pub user_stream: UserStreamUsecase,
+    pub user_live: UserLiveUsecase,
+}
+
+// 管理用ユースケース
+#[derive(Clone)]
+pub struct AdminUsecase {
+    pub admin_log_level_find: AdminLogLevelFindUsecase,
+    pub admin_log_level_update: AdminLogLevelUpdateUsecase,
+    pub admin_outbox_find_stuck: AdminOutboxFindStuckUsecase,
+}
+
+// APIキーユースケース
+#[derive(Clone)]
+pub struct ApiKeyUsecase {
+    pub api_key_find_all: ApiKeyFindAllUsecase,
+    pub api_key_issue: ApiKeyIssueUsecase,
+    pub api_key_revoke: ApiKeyRevokeUsecase,
+    pub api_key_authenticate: ApiKeyAuthenticateUsecase,
+}
+
+// Webhookユースケース
+#[derive(Clone)]
+pub struct WebhookUsecase {
+    pub webhook_subscription_find_all: WebhookSubscriptionFindAllUsecase,
+    pub webhook_subscription_create: WebhookSubscriptionCreateUsecase,
+    pub webhook_subscription_delete: WebhookSubscriptionDeleteUsecase,
+    pub webhook_delivery_find_all: WebhookDeliveryFindAllUsecase,
+    pub webhook_delivery_redeliver: WebhookDeliveryRedeliverUsecase,
+}
+
+// 認証ユースケース
+#[derive(Clone)]
+pub struct AuthUsecase {
+    pub auth_login: AuthLoginUsecase,
+    pub auth_refresh: AuthRefreshUsecase,
+    pub auth_password_update: AuthPasswordUpdateUsecase,
+    pub auth_oidc_authorize: AuthOidcAuthorizeUsecase,
+    pub auth_oidc_callback: AuthOidcCallbackUsecase,
+}
+
+// ジョブユースケース
+#[derive(Clone)]
+pub struct JobUsecase {
+    pub job_enqueue: JobEnqueueUsecase,
+    pub job_dead_letter_find_all: JobDeadLetterFindAllUsecase,
+    pub job_retry: JobRetryUsecase,
 }
 
 // アプリケーション全体で共有する状態（DIコンテナ）
 #[derive(Clone)]
 pub struct AppState {
     pub user_usecase: UserUsecase,
+    pub admin_usecase: AdminUsecase,
+    pub api_key_usecase: ApiKeyUsecase,
+    pub auth_usecase: AuthUsecase,
+    pub webhook_usecase: WebhookUsecase,
+    pub job_usecase: JobUsecase,
+    // アウトボックスの送信処理（main.rsでバックグラウンドタスクとして起動）
+    pub outbox_relay: OutboxRelayUsecase,
+    // Webhookの配信処理（main.rsでバックグラウンドタスクとして起動）
+    pub webhook_deliver: WebhookDeliverUsecase,
+    // ジョブのワーカーとスケジューラー（main.rsでバックグラウンドタスクとして起動）
+    pub job_worker: JobWorkerUsecase,
+    pub job_scheduler: JobSchedulerUsecase,
+    // 認証トークンの検証
+    pub auth_token: Arc<dyn AuthTokenTrait + 'static>,
+    // レート制限のストア（ポリシーは再読み込み可能な設定から取得）
+    pub rate_limit_store: Arc<dyn RateLimitStoreTrait + 'static>,
+    // 冪等キーのストア
+    pub idempotency_store: Arc<dyn IdempotencyStoreTrait + 'static>,
+    // ドメインイベントのディスパッチャー（ハンドラーはregister_event_handlerで登録）
+    pub event_dispatcher: Arc<dyn DomainEventDispatcherTrait + 'static>,
+    // 再起動なしで変更可能な設定
+    pub reloadable_config: ReloadableConfigHandle,
 }
 
 impl AppState {
-    pub async fn new() -> Self {
+    pub async fn new(config: &Config, reloadable_config: ReloadableConfigHandle) -> Self {
         // DB設定
         let db = new_db_dummy_connection().await.unwrap();
 
-        // ロガー設定
-        let logger = Arc::new(Logger::new());
+        // ロガー設定（ターゲットをレイヤー単位にしてモジュール別のログフィルターに対応）
+        let repo_logger = Arc::new(Logger::new("rust_axum_domain::infrastructure::persistence"));
+        let usecase_logger = Arc::new(Logger::new("rust_axum_domain::application::usecase"));
+
+        // 認証設定
+        let auth_token = Arc::new(AuthJwt::new(config).expect("JWT認証の初期化エラー"));
+        let auth_password = Arc::new(AuthPasswordArgon2::new());
+        let auth_oidc: Option<Arc<dyn AuthOidcTrait>> = match AuthOidc::new(config) {
+            Some(auth_oidc) => Some(Arc::new(auth_oidc)),
+            None => None,
+        };
+
+        // レート制限設定
+        let rate_limit_store = Arc::new(RateLimitMemory::new());
+
+        // 冪等キー設定
+        let idempotency_store = Arc::new(IdempotencyMemory::new());
+
+        // ドメインイベントのディスパッチャー設定（ハンドラーはAppStateの作成後に登録）
+        let event_dispatcher = Arc::new(DomainEventDispatcher::new(usecase_logger.clone()));
+
+        // ユーザーの変更通知（SSE）の設定
+        let event_stream = Arc::new(DomainEventStream::new(
+            config.user_stream_buffer_size,
+            config.user_stream_channel_capacity,
+        ));
+
+        // ユーザーの変更と接続状態の配信（WebSocket）の設定
+        let live_hub = Arc::new(UserLiveHub::new(config.user_live_channel_capacity));
 
         // リポジトリのインスタンス化
-        let user_repo = Arc::new(UserRepository::new(db, logger.clone()));
+        let user_repo = Arc::new(UserRepository::new(db.clone(), repo_logger.clone()));
+        let api_key_repo = Arc::new(ApiKeyRepository::new(db.clone(), repo_logger.clone()));
+        let credential_repo = Arc::new(CredentialRepository::new(db.clone(), repo_logger.clone()));
+        let refresh_token_repo =
+            Arc::new(RefreshTokenRepository::new(db.clone(), repo_logger.clone()));
+        let oidc_session_repo =
+            Arc::new(OidcSessionRepository::new(db.clone(), repo_logger.clone()));
+        let outbox_repo = Arc::new(OutboxRepository::new(db.clone(), repo_logger.clone()));
+        let audit_log_repo = Arc::new(AuditLogRepository::new(db.clone(), repo_logger.clone()));
+        let webhook_subscription_repo = Arc::new(WebhookSubscriptionRepository::new(
+            db.clone(),
+            repo_logger.clone(),
+        ));
+        let webhook_delivery_repo = Arc::new(WebhookDeliveryRepository::new(
+            db.clone(),
+            repo_logger.clone(),
+        ));
+        let job_repo = Arc::new(JobRepository::new(db.clone(), repo_logger.clone()));
+
+        // ユニットオブワーク（トランザクション）のインスタンス化
+        let unit_of_work = Arc::new(UnitOfWork::new(db, repo_logger.clone()));
 
         // Userユースケースのインスタンス化とまとめ
         let user_find_all_repo = UserFindAllRepository {
             user_repository: user_repo.clone(),
         };
-        let user_find_all_usecase = UserFindAllUsecase::new(user_find_all_repo, logger.clone());
+        let user_find_all_usecase =
+            UserFindAllUsecase::new(user_find_all_repo, usecase_logger.clone());
+        let user_find_by_uid_repo = UserFindByUidRepository {
+            user_repository: user_repo.clone(),
+        };
+        let user_find_by_uid_usecase =
+            UserFindByUidUsecase::new(user_find_by_uid_repo, usecase_logger.clone());
+        let user_create_repo = UserCreateRepository {
+            unit_of_work: unit_of_work.clone(),
+        };
+        let user_create_usecase = UserCreateUsecase::new(
+            user_create_repo,
+            event_dispatcher.clone(),
+            usecase_logger.clone(),
+        );
+        let user_update_repo = UserUpdateRepository {
+            user_repository: user_repo.clone(),
+            unit_of_work: unit_of_work.clone(),
+        };
+        let user_update_usecase = UserUpdateUsecase::new(
+            user_update_repo,
+            event_dispatcher.clone(),
+            usecase_logger.clone(),
+        );
+        let user_delete_repo = UserDeleteRepository {
+            user_repository: user_repo.clone(),
+            unit_of_work: unit_of_work.clone(),
+        };
+        let user_delete_usecase = UserDeleteUsecase::new(
+            user_delete_repo,
+            event_dispatcher.clone(),
+            usecase_logger.clone(),
+        );
+        let user_audit_find_all_repo = UserAuditFindAllRepository {
+            user_repository: user_repo.clone(),
+            audit_log_repository: audit_log_repo.clone(),
+        };
+        let user_audit_find_all_usecase =
+            UserAuditFindAllUsecase::new(user_audit_find_all_repo, usecase_logger.clone());
+        let user_stream_repo = UserStreamRepository {
+            event_stream: event_stream.clone(),
+        };
+        let user_stream_usecase = UserStreamUsecase::new(
+            user_stream_repo,
+            Duration::from_secs(config.user_stream_keep_alive_secs),
+            usecase_logger.clone(),
+        );
+        let user_live_repo = UserLiveRepository {
+            live_hub: live_hub.clone(),
+        };
+        let user_live_usecase = UserLiveUsecase::new(
+            user_live_repo,
+            config.user_live_max_subscriptions,
+            config.user_live_max_message_bytes,
+            Duration::from_secs(config.user_live_ping_interval_secs),
+            usecase_logger.clone(),
+        );
         let user_usecase = UserUsecase {
             user_find_all: user_find_all_usecase,
+            user_find_by_uid: user_find_by_uid_usecase,
+            user_create: user_create_usecase,
+            user_update: user_update_usecase,
+            user_delete: user_delete_usecase,
+            user_audit_find_all: user_audit_find_all_usecase,
+            user_stream: user_stream_usecase,
+            user_live: user_live_usecase,
+        };
+
+        // 管理用ユースケースのインスタンス化とまとめ
+        let admin_log_level_find_usecase = AdminLogLevelFindUsecase::new(usecase_logger.clone());
+        let admin_log_level_update_usecase =
+            AdminLogLevelUpdateUsecase::new(usecase_logger.clone());
+        let admin_outbox_find_stuck_repo = AdminOutboxFindStuckRepository {
+            outbox_repository: outbox_repo.clone(),
+        };
+        let admin_outbox_find_stuck_usecase =
+            AdminOutboxFindStuckUsecase::new(admin_outbox_find_stuck_repo, usecase_logger.clone());
+        let admin_usecase = AdminUsecase {
+            admin_log_level_find: admin_log_level_find_usecase,
+            admin_log_level_update: admin_log_level_update_usecase,
+            admin_outbox_find_stuck: admin_outbox_find_stuck_usecase,
+        };
+
+        // APIキーユースケースのインスタンス化とまとめ
+        let api_key_find_all_repo = ApiKeyFindAllRepository {
+            api_key_repository: api_key_repo.clone(),
+        };
+        let api_key_find_all_usecase =
+            ApiKeyFindAllUsecase::new(api_key_find_all_repo, usecase_logger.clone());
+        let api_key_issue_repo = ApiKeyIssueRepository {
+            api_key_repository: api_key_repo.clone(),
+        };
+        let api_key_issue_usecase =
+            ApiKeyIssueUsecase::new(api_key_issue_repo, usecase_logger.clone());
+        let api_key_revoke_repo = ApiKeyRevokeRepository {
+            api_key_repository: api_key_repo.clone(),
+        };
+        let api_key_revoke_usecase =
+            ApiKeyRevokeUsecase::new(api_key_revoke_repo, usecase_logger.clone());
+        let api_key_authenticate_repo = ApiKeyAuthenticateRepository {
+            api_key_repository: api_key_repo.clone(),
+        };
+        let api_key_authenticate_usecase =
+            ApiKeyAuthenticateUsecase::new(api_key_authenticate_repo, usecase_logger.clone());
+        let api_key_usecase = ApiKeyUsecase {
+            api_key_find_all: api_key_find_all_usecase,
+            api_key_issue: api_key_issue_usecase,
+            api_key_revoke: api_key_revoke_usecase,
+            api_key_authenticate: api_key_authenticate_usecase,
+        };
+
+        // 認証ユースケースのインスタンス化とまとめ
+        let auth_login_repo = AuthLoginRepository {
+            user_repository: user_repo.clone(),
+            credential_repository: credential_repo.clone(),
+            refresh_token_repository: refresh_token_repo.clone(),
+        };
+        let auth_login_usecase = AuthLoginUsecase::new(
+            auth_login_repo,
+            auth_token.clone(),
+            auth_password.clone(),
+            config.refresh_token_ttl_secs,
+            usecase_logger.clone(),
+        );
+        let auth_refresh_repo = AuthRefreshRepository {
+            user_repository: user_repo.clone(),
+            refresh_token_repository: refresh_token_repo.clone(),
+        };
+        let auth_refresh_usecase = AuthRefreshUsecase::new(
+            auth_refresh_repo,
+            auth_token.clone(),
+            config.refresh_token_ttl_secs,
+            usecase_logger.clone(),
+        );
+        let auth_password_update_repo = AuthPasswordUpdateRepository {
+            user_repository: user_repo.clone(),
+            credential_repository: credential_repo.clone(),
+            refresh_token_repository: refresh_token_repo.clone(),
+        };
+        let auth_password_update_usecase = AuthPasswordUpdateUsecase::new(
+            auth_password_update_repo,
+            auth_password.clone(),
+            usecase_logger.clone(),
+        );
+        let auth_oidc_authorize_repo = AuthOidcAuthorizeRepository {
+            oidc_session_repository: oidc_session_repo.clone(),
+        };
+        let auth_oidc_authorize_usecase = AuthOidcAuthorizeUsecase::new(
+            auth_oidc_authorize_repo,
+            auth_oidc.clone(),
+            usecase_logger.clone(),
+        );
+        let auth_oidc_callback_repo = AuthOidcCallbackRepository {
+            user_repository: user_repo.clone(),
+            refresh_token_repository: refresh_token_repo.clone(),
+            oidc_session_repository: oidc_session_repo.clone(),
+        };
+        let auth_oidc_callback_usecase = AuthOidcCallbackUsecase::new(
+            auth_oidc_callback_repo,
+            Arc::new(user_usecase.user_create.clone()),
+            auth_token.clone(),
+            auth_oidc.clone(),
+            config.refresh_token_ttl_secs,
+            usecase_logger.clone(),
+        );
+        let auth_usecase = AuthUsecase {
+            auth_login: auth_login_usecase,
+            auth_refresh: auth_refresh_usecase,
+            auth_password_update: auth_password_update_usecase,
+            auth_oidc_authorize: auth_oidc_authorize_usecase,
+            auth_oidc_callback: auth_oidc_callback_usecase,
+        };
+
+        // Webhookの配信処理のインスタンス化
+        let webhook_deliver_repo = WebhookDeliverRepository {
+            webhook_subscription_repository: webhook_subscription_repo.clone(),
+            webhook_delivery_repository: webhook_delivery_repo.clone(),
+        };
+        let webhook_deliver = WebhookDeliverUsecase::new(
+            webhook_deliver_repo,
+            Arc::new(WebhookSenderReqwest::new(config.webhook_timeout_secs)),
+            RetryPolicy {
+                max_attempts: config.webhook_max_attempts,
+                backoff_base_secs: config.webhook_backoff_base_secs,
+                backoff_max_secs: config.webhook_backoff_max_secs,
+            },
+            config.webhook_delivery_batch_size,
+            config.webhook_lease_secs,
+            usecase_logger.clone(),
+        );
+
+        // Webhookユースケースのインスタンス化とまとめ
+        let webhook_subscription_find_all_repo = WebhookSubscriptionFindAllRepository {
+            webhook_subscription_repository: webhook_subscription_repo.clone(),
+        };
+        let webhook_subscription_find_all_usecase = WebhookSubscriptionFindAllUsecase::new(
+            webhook_subscription_find_all_repo,
+            usecase_logger.clone(),
+        );
+        let webhook_subscription_create_repo = WebhookSubscriptionCreateRepository {
+            webhook_subscription_repository: webhook_subscription_repo.clone(),
+        };
+        let webhook_subscription_create_usecase = WebhookSubscriptionCreateUsecase::new(
+            webhook_subscription_create_repo,
+            usecase_logger.clone(),
+        );
+        let webhook_subscription_delete_repo = WebhookSubscriptionDeleteRepository {
+            webhook_subscription_repository: webhook_subscription_repo.clone(),
+        };
+        let webhook_subscription_delete_usecase = WebhookSubscriptionDeleteUsecase::new(
+            webhook_subscription_delete_repo,
+            usecase_logger.clone(),
+        );
+        let webhook_delivery_find_all_repo = WebhookDeliveryFindAllRepository {
+            webhook_subscription_repository: webhook_subscription_repo.clone(),
+            webhook_delivery_repository: webhook_delivery_repo.clone(),
+        };
+        let webhook_delivery_find_all_usecase = WebhookDeliveryFindAllUsecase::new(
+            webhook_delivery_find_all_repo,
+            usecase_logger.clone(),
+        );
+        let webhook_delivery_redeliver_repo = WebhookDeliveryRedeliverRepository {
+            webhook_delivery_repository: webhook_delivery_repo.clone(),
+        };
+        let webhook_delivery_redeliver_usecase = WebhookDeliveryRedeliverUsecase::new(
+            webhook_delivery_redeliver_repo,
+            Arc::new(webhook_deliver.clone()),
+            usecase_logger.clone(),
+        );
+        let webhook_usecase = WebhookUsecase {
+            webhook_subscription_find_all: webhook_subscription_find_all_usecase,
+            webhook_subscription_create: webhook_subscription_create_usecase,
+            webhook_subscription_delete: webhook_subscription_delete_usecase,
+            webhook_delivery_find_all: webhook_delivery_find_all_usecase,
+            webhook_delivery_redeliver: webhook_delivery_redeliver_usecase,
+        };
+
+        // アウトボックスの送信処理のインスタンス化（送信先はログ出力とWebhookの配信作成、メッセージブローカー）
+        let webhook_fanout_repo = WebhookFanoutRepository {
+            webhook_subscription_repository: webhook_subscription_repo.clone(),
+            webhook_delivery_repository: webhook_delivery_repo.clone(),
+        };
+        let mut outbox_publishers: Vec<Arc<dyn OutboxPublisherTrait>> = vec![
+            Arc::new(OutboxPublisherLog::new(usecase_logger.clone())),
+            Arc::new(WebhookFanoutPublisher::new(webhook_fanout_repo)),
+        ];
+        if let Some(event_publisher) = new_event_publisher(config).await {
+            log::info!("メッセージブローカー: {}", event_publisher.name());
+            outbox_publishers.push(Arc::new(OutboxPublisherBroker::new(
+                event_publisher,
+                &config.event_broker_topic_prefix,
+            )));
+        }
+        let outbox_publisher = OutboxPublisherGroup::new(outbox_publishers);
+        let outbox_relay_repo = OutboxRelayRepository {
+            outbox_repository: outbox_repo.clone(),
+            unit_of_work: unit_of_work.clone(),
+        };
+        let outbox_relay = OutboxRelayUsecase::new(
+            outbox_relay_repo,
+            Arc::new(outbox_publisher),
+            RetryPolicy {
+                max_attempts: config.outbox_max_attempts,
+                backoff_base_secs: config.outbox_backoff_base_secs,
+                backoff_max_secs: config.outbox_backoff_max_secs,
+            },
+            config.outbox_relay_batch_size,
+            usecase_logger.clone(),
+        );
+
+        // 保持期間が経過した論理削除済みユーザーの物理削除・匿名化（ジョブのハンドラー）
+        let user_purge_repo = UserPurgeRepository {
+            user_repository: user_repo.clone(),
+            unit_of_work: unit_of_work.clone(),
+        };
+        let user_purge = UserPurgeUsecase::new(
+            user_purge_repo,
+            config.user_purge_retention_days,
+            UserPurgeMode::parse(&config.user_purge_mode).expect("ユーザーの物理削除の設定エラー"),
+            config.user_purge_dry_run,
+            usecase_logger.clone(),
+        );
+
+        // ジョブのワーカーのインスタンス化（ハンドラーをジョブの種類ごとに登録）
+        let job_handlers: Vec<Arc<dyn JobHandlerTrait>> = vec![Arc::new(user_purge)];
+        let job_worker_repo = JobWorkerRepository {
+            job_repository: job_repo.clone(),
+        };
+        let job_worker = JobWorkerUsecase::new(
+            job_worker_repo,
+            job_handlers,
+            RetryPolicy {
+                max_attempts: config.job_max_attempts,
+                backoff_base_secs: config.job_backoff_base_secs,
+                backoff_max_secs: config.job_backoff_max_secs,
+            },
+            config.job_batch_size,
+            config.job_lease_secs,
+            config.job_timeout_secs,
+            usecase_logger.clone(),
+        );
+        let job_types = job_worker.job_types();
+
+        // ジョブのスケジューラーのインスタンス化（ハンドラーが未登録のスケジュールは設定エラー）
+        let job_schedules =
+            parse_job_schedules(&config.job_schedules).expect("ジョブのスケジュールの設定エラー");
+        if let Some(schedule) = job_schedules
+            .iter()
+            .find(|schedule| !job_types.contains(&schedule.job_type))
+        {
+            panic!(
+                "ジョブのスケジュールの設定エラー: ジョブの種類「{}」のハンドラーが登録されていません",
+                schedule.job_type
+            );
+        }
+        let job_scheduler_repo = JobSchedulerRepository {
+            job_repository: job_repo.clone(),
+        };
+        let job_scheduler =
+            JobSchedulerUsecase::new(job_scheduler_repo, job_schedules, usecase_logger.clone());
+
+        // ジョブユースケースのインスタンス化とまとめ
+        let job_enqueue_repo = JobEnqueueRepository {
+            job_repository: job_repo.clone(),
+        };
+        let job_enqueue_usecase =
+            JobEnqueueUsecase::new(job_enqueue_repo, job_types, usecase_logger.clone());
+        let job_dead_letter_find_all_repo = JobDeadLetterFindAllRepository {
+            job_repository: job_repo.clone(),
+        };
+        let job_dead_letter_find_all_usecase =
+            JobDeadLetterFindAllUsecase::new(job_dead_letter_find_all_repo, usecase_logger.clone());
+        let job_retry_repo = JobRetryRepository {
+            job_repository: job_repo.clone(),
+        };
+        let job_retry_usecase = JobRetryUsecase::new(job_retry_repo, usecase_logger.clone());
+        let job_usecase = JobUsecase {
+            job_enqueue: job_enqueue_usecase,
+            job_dead_letter_find_all: job_dead_letter_find_all_usecase,
+            job_retry: job_retry_usecase,
         };
 
         // 戻り値の設定
-        Self { user_usecase }
+        let app_state = Self {
+            user_usecase,
+            admin_usecase,
+            api_key_usecase,
+            auth_usecase,
+            webhook_usecase,
+            job_usecase,
+            outbox_relay,
+            webhook_deliver,
+            job_worker,
+            job_scheduler,
+            auth_token,
+            rate_limit_store,
+            idempotency_store,
+            event_dispatcher,
+            reloadable_config,
+        };
+
+        // ドメインイベントのハンドラーの登録（ログ出力・SSE・WebSocket）
+        app_state.register_event_handler(Arc::new(DomainEventLogHandler::new(usecase_logger)));
+        app_state.register_event_handler(event_stream);
+        app_state.register_event_handler(live_hub);
+
+        app_state
+    }
+
+    // ドメインイベントのハンドラーの登録（プロセス内でドメインイベントの通知を受け取る）
+    pub fn register_event_handler(&self, handler: Arc<dyn DomainEventHandlerTrait + 'static>) {
+        self.event_dispatcher.register(handler);
+    }
+}
+
+// メッセージブローカーの設定（NATS・Kafkaはcargoのfeatureで有効化）
+async fn new_event_publisher(config: &Config) -> Option<Arc<dyn EventPublisherTrait>> {
+    let url = config.event_broker_url.as_deref();
+    match config.event_broker.as_str() {
+        "none" => None,
+        "memory" => Some(Arc::new(EventBrokerMemory::new())),
+        #[cfg(feature = "nats")]
+        "nats" => {
+            let url = url.expect("EVENT_BROKER_URLを設定して下さい");
+            let publisher = EventPublisherNats::connect(url)
+                .await
+                .expect("メッセージブローカーの初期化エラー");
+            Some(Arc::new(publisher))
+        }
+        #[cfg(feature = "kafka")]
+        "kafka" => {
+            let url = url.expect("EVENT_BROKER_URLを設定して下さい");
+            let publisher =
+                EventPublisherKafka::new(url).expect("メッセージブローカーの初期化エラー");
+            Some(Arc::new(publisher))
+        }
+        broker => panic!(
+            "メッセージブローカーの設定エラー: {}（接続先: {:?}、nats・kafkaはfeatureの有効化が必要です）",
+            broker, url
+        ),
     }
 }